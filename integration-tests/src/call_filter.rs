@@ -305,3 +305,50 @@ fn create_contract_from_evm_pallet_should_be_filtered_by_call_filter() {
 		assert!(!hydradx_runtime::CallFilter::contains(&call));
 	});
 }
+
+#[test]
+fn router_trade_should_be_filtered_when_paused_for_asset_of_intermediate_hop() {
+	use hydradx_traits::router::{PoolType, Trade};
+
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		assert_ok!(hydradx_runtime::TransactionPause::pause_asset_transaction(
+			hydradx_runtime::RuntimeOrigin::root(),
+			b"Router".to_vec(),
+			b"sell".to_vec(),
+			DOT,
+			None,
+		));
+
+		let sell = |route: Vec<Trade<AssetId>>| {
+			hydradx_runtime::RuntimeCall::Router(pallet_route_executor::Call::sell {
+				asset_in: HDX,
+				asset_out: DAI,
+				amount_in: UNITS,
+				min_amount_out: 0,
+				route,
+			})
+		};
+		let direct_route = vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: DAI,
+		}];
+		let route_via_dot = vec![
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DOT,
+			},
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: DOT,
+				asset_out: DAI,
+			},
+		];
+
+		assert!(hydradx_runtime::CallFilter::contains(&sell(direct_route)));
+		assert!(!hydradx_runtime::CallFilter::contains(&sell(route_via_dot)));
+	});
+}
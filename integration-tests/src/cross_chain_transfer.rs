@@ -5,16 +5,23 @@ use frame_support::{assert_noop, assert_ok};
 
 use polkadot_xcm::{latest::prelude::*, v3::WeightLimit, VersionedMultiAssets, VersionedXcm};
 
+use crate::dca::create_bounded_vec;
 use cumulus_primitives_core::ParaId;
+use frame_support::dispatch::GetDispatchInfo;
 use frame_support::weights::Weight;
+use frame_system::RawOrigin;
 use hex_literal::hex;
 use hydradx_traits::registry::Mutate;
+use hydradx_traits::router::{PoolType, Trade};
 use orml_traits::currency::MultiCurrency;
+use pallet_dca::types::{Order, Schedule};
 use pretty_assertions::assert_eq;
 use primitives::AccountId;
 use sp_core::H256;
+use sp_runtime::codec::Encode;
 use sp_runtime::traits::{AccountIdConversion, BlakeTwo256, Hash};
-use xcm_emulator::TestExt;
+use sp_runtime::Permill;
+use xcm_emulator::{ConvertLocation, TestExt};
 
 // Determine the hash for assets expected to be have been trapped.
 fn determine_hash<M>(origin: &MultiLocation, assets: M) -> H256
@@ -567,3 +574,303 @@ fn polkadot_xcm_execute_extrinsic_should_be_allowed() {
 		),);
 	});
 }
+
+#[test]
+fn remote_account_should_sell_via_router_when_transact_is_sent_from_sibling_chain() {
+	// Arrange
+	TestNet::reset();
+	let remote_account = remote_account_at_hydra();
+
+	Hydra::execute_with(|| {
+		init_omnipool();
+		fund_remote_account(&remote_account);
+	});
+
+	// Act
+	transact_from_acala_account(hydradx_runtime::RuntimeCall::Router(
+		pallet_route_executor::Call::sell {
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 10 * UNITS,
+			min_amount_out: 0,
+			route: vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DAI,
+			}],
+		},
+	));
+
+	// Assert
+	Hydra::execute_with(|| {
+		assert_xcm_message_processed();
+		assert!(hydradx_runtime::Currencies::free_balance(DAI, &remote_account) > 0);
+	});
+}
+
+#[test]
+fn remote_account_should_add_liquidity_to_omnipool_when_transact_is_sent_from_sibling_chain() {
+	// Arrange
+	TestNet::reset();
+	let remote_account = remote_account_at_hydra();
+	let position_id = Hydra::execute_with(|| {
+		init_omnipool();
+		fund_remote_account(&remote_account);
+		assert_ok!(hydradx_runtime::Tokens::set_balance(
+			RawOrigin::Root.into(),
+			remote_account.clone(),
+			DAI,
+			1_000 * UNITS,
+			0,
+		));
+		hydradx_runtime::Omnipool::next_position_id()
+	});
+
+	// Act
+	transact_from_acala_account(hydradx_runtime::RuntimeCall::Omnipool(
+		pallet_omnipool::Call::add_liquidity {
+			asset: DAI,
+			amount: 100 * UNITS,
+		},
+	));
+
+	// Assert
+	Hydra::execute_with(|| {
+		assert_xcm_message_processed();
+		assert_eq!(
+			hydradx_runtime::Currencies::free_balance(DAI, &remote_account),
+			900 * UNITS
+		);
+		assert_ok!(hydradx_runtime::Omnipool::load_position(
+			position_id,
+			remote_account.clone()
+		));
+	});
+}
+
+#[test]
+fn remote_account_should_manage_dca_schedule_when_transact_is_sent_from_sibling_chain() {
+	// Arrange
+	TestNet::reset();
+	let remote_account = remote_account_at_hydra();
+
+	Hydra::execute_with(|| {
+		init_omnipool();
+		fund_remote_account(&remote_account);
+	});
+
+	let schedule = Schedule {
+		owner: remote_account.clone(),
+		period: 3u32,
+		total_amount: 2_000 * UNITS,
+		max_retries: None,
+		stability_threshold: None,
+		oracle_bounds: Default::default(),
+		slippage: Some(Permill::from_percent(10)),
+		limit_price: None,
+		order: Order::Sell {
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 100 * UNITS,
+			min_amount_out: 0,
+			route: create_bounded_vec(vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DAI,
+			}]),
+		},
+	};
+
+	// Act
+	transact_from_acala_account(hydradx_runtime::RuntimeCall::DCA(pallet_dca::Call::schedule {
+		schedule,
+		start_execution_block: None,
+	}));
+
+	// Assert
+	let schedule_id = 0;
+	Hydra::execute_with(|| {
+		assert_xcm_message_processed();
+		let schedule = hydradx_runtime::DCA::schedules(schedule_id).expect("schedule should be created");
+		assert_eq!(schedule.owner, remote_account);
+	});
+
+	// Act
+	transact_from_acala_account(hydradx_runtime::RuntimeCall::DCA(pallet_dca::Call::pause {
+		schedule_id,
+	}));
+
+	// Assert
+	Hydra::execute_with(|| {
+		assert_xcm_message_processed();
+		assert!(pallet_dca::PausedSchedules::<hydradx_runtime::Runtime>::contains_key(
+			schedule_id
+		));
+	});
+
+	// Act
+	transact_from_acala_account(hydradx_runtime::RuntimeCall::DCA(pallet_dca::Call::resume {
+		schedule_id,
+		start_execution_block: None,
+	}));
+
+	// Assert
+	Hydra::execute_with(|| {
+		assert_xcm_message_processed();
+		assert!(!pallet_dca::PausedSchedules::<hydradx_runtime::Runtime>::contains_key(
+			schedule_id
+		));
+	});
+
+	// Act
+	transact_from_acala_account(hydradx_runtime::RuntimeCall::DCA(pallet_dca::Call::terminate {
+		schedule_id,
+	}));
+
+	// Assert
+	Hydra::execute_with(|| {
+		assert_xcm_message_processed();
+		assert!(hydradx_runtime::DCA::schedules(schedule_id).is_none());
+	});
+}
+
+#[test]
+fn transact_should_be_filtered_when_dex_call_is_not_allowed_for_remote_account() {
+	// Arrange
+	TestNet::reset();
+	let remote_account = remote_account_at_hydra();
+
+	Hydra::execute_with(|| {
+		init_omnipool();
+		fund_remote_account(&remote_account);
+	});
+
+	// Act
+	transact_from_acala_account(hydradx_runtime::RuntimeCall::Omnipool(
+		pallet_omnipool::Call::set_asset_weight_cap {
+			asset_id: DAI,
+			cap: Permill::from_percent(100),
+		},
+	));
+
+	// Assert
+	Hydra::execute_with(|| {
+		assert_xcm_message_filtered();
+	});
+}
+
+#[test]
+fn transact_should_be_filtered_when_router_route_is_too_long() {
+	// Arrange
+	TestNet::reset();
+	let remote_account = remote_account_at_hydra();
+
+	Hydra::execute_with(|| {
+		init_omnipool();
+		fund_remote_account(&remote_account);
+	});
+
+	let trade = Trade {
+		pool: PoolType::Omnipool,
+		asset_in: HDX,
+		asset_out: DAI,
+	};
+
+	// Act
+	transact_from_acala_account(hydradx_runtime::RuntimeCall::Router(
+		pallet_route_executor::Call::sell {
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 10 * UNITS,
+			min_amount_out: 0,
+			route: vec![trade; pallet_route_executor::MAX_NUMBER_OF_TRADES as usize + 1],
+		},
+	));
+
+	// Assert
+	Hydra::execute_with(|| {
+		assert_xcm_message_filtered();
+	});
+}
+
+fn remote_account_location() -> MultiLocation {
+	MultiLocation::new(
+		1,
+		X2(
+			Parachain(ACALA_PARA_ID),
+			Junction::AccountId32 {
+				network: None,
+				id: ALICE,
+			},
+		),
+	)
+}
+
+fn remote_account_at_hydra() -> AccountId {
+	hydradx_runtime::LocationToAccountId::convert_location(&remote_account_location()).unwrap()
+}
+
+fn fund_remote_account(account: &AccountId) {
+	assert_ok!(hydradx_runtime::Balances::force_set_balance(
+		hydradx_runtime::RuntimeOrigin::root(),
+		account.clone(),
+		10_000 * UNITS,
+	));
+}
+
+/// Sends `call` from ALICE's account on Acala, dispatched on HydraDX by ALICE's derived account.
+fn transact_from_acala_account(call: hydradx_runtime::RuntimeCall) {
+	let remote_account = remote_account_at_hydra();
+	let hdx_location = MultiLocation::new(1, X2(Parachain(HYDRA_PARA_ID), GeneralIndex(0)));
+
+	Acala::execute_with(|| {
+		let message = Xcm(vec![
+			WithdrawAsset((hdx_location, 900 * UNITS).into()),
+			BuyExecution {
+				fees: (hdx_location, 800 * UNITS).into(),
+				weight_limit: Unlimited,
+			},
+			Transact {
+				require_weight_at_most: call.get_dispatch_info().weight,
+				origin_kind: OriginKind::SovereignAccount,
+				call: call.encode().into(),
+			},
+			ExpectTransactStatus(MaybeErrorCode::Success),
+			RefundSurplus,
+			DepositAsset {
+				assets: All.into(),
+				beneficiary: Junction::AccountId32 {
+					id: remote_account.into(),
+					network: None,
+				}
+				.into(),
+			},
+		]);
+
+		assert_ok!(hydradx_runtime::PolkadotXcm::send_xcm(
+			X1(Junction::AccountId32 {
+				network: None,
+				id: ALICE,
+			}),
+			MultiLocation::new(1, X1(Parachain(HYDRA_PARA_ID))),
+			message
+		));
+	});
+}
+
+fn assert_xcm_message_processed() {
+	assert!(hydradx_runtime::System::events().iter().any(|r| matches!(
+		r.event,
+		hydradx_runtime::RuntimeEvent::XcmpQueue(cumulus_pallet_xcmp_queue::Event::Success { .. })
+	)));
+}
+
+fn assert_xcm_message_filtered() {
+	assert!(hydradx_runtime::System::events().iter().any(|r| matches!(
+		r.event,
+		hydradx_runtime::RuntimeEvent::XcmpQueue(cumulus_pallet_xcmp_queue::Event::Fail {
+			error: cumulus_primitives_core::XcmError::NoPermission,
+			..
+		})
+	)));
+}
@@ -162,7 +162,9 @@ mod omnipool {
 				total_amount: dca_budget,
 				max_retries: None,
 				stability_threshold: None,
+				oracle_bounds: Default::default(),
 				slippage: Some(Permill::from_percent(5)),
+				limit_price: None,
 				order: Order::Buy {
 					asset_in: HDX,
 					asset_out: DAI,
@@ -674,7 +676,9 @@ mod omnipool {
 				total_amount: dca_budget,
 				max_retries: None,
 				stability_threshold: None,
+				oracle_bounds: Default::default(),
 				slippage: Some(Permill::from_percent(1)),
+				limit_price: None,
 				order: Order::Sell {
 					asset_in: HDX,
 					asset_out: DAI,
@@ -1424,7 +1428,9 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					oracle_bounds: Default::default(),
 					slippage: Some(Permill::from_percent(10)),
+					limit_price: None,
 					order: Order::Sell {
 						asset_in: HDX,
 						asset_out: stable_asset_1,
@@ -1704,7 +1710,9 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					oracle_bounds: Default::default(),
 					slippage: Some(Permill::from_percent(10)),
+					limit_price: None,
 					order: Order::Sell {
 						asset_in: stable_asset_1,
 						asset_out: HDX,
@@ -2012,7 +2020,9 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					oracle_bounds: Default::default(),
 					slippage: Some(Permill::from_percent(10)),
+					limit_price: None,
 					order: Order::Buy {
 						asset_in: HDX,
 						asset_out: stable_asset_1,
@@ -2193,7 +2203,9 @@ mod stableswap {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					oracle_bounds: Default::default(),
 					slippage: Some(Permill::from_percent(70)),
+					limit_price: None,
 					order: Order::Buy {
 						asset_in: stable_asset_1,
 						asset_out: HDX,
@@ -2446,7 +2458,9 @@ mod all_pools {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					oracle_bounds: Default::default(),
 					slippage: Some(Permill::from_percent(15)),
+					limit_price: None,
 					order: Order::Sell {
 						asset_in: HDX,
 						asset_out: DAI,
@@ -2594,7 +2608,9 @@ mod with_onchain_route {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					oracle_bounds: Default::default(),
 					slippage: Some(Permill::from_percent(10)),
+					limit_price: None,
 					order: Order::Buy {
 						asset_in: HDX,
 						asset_out: stable_asset_1,
@@ -2708,7 +2724,9 @@ mod with_onchain_route {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					oracle_bounds: Default::default(),
 					slippage: Some(Permill::from_percent(10)),
+					limit_price: None,
 					order: Order::Sell {
 						asset_in: HDX,
 						asset_out: stable_asset_1,
@@ -2792,7 +2810,9 @@ mod with_onchain_route {
 				total_amount: dca_budget,
 				max_retries: None,
 				stability_threshold: None,
+				oracle_bounds: Default::default(),
 				slippage: Some(Permill::from_percent(10)),
+				limit_price: None,
 				order: Order::Sell {
 					asset_in: DOT,
 					asset_out: HDX,
@@ -2939,7 +2959,9 @@ mod with_onchain_route {
 					total_amount: dca_budget,
 					max_retries: None,
 					stability_threshold: None,
+					oracle_bounds: Default::default(),
 					slippage: Some(Permill::from_percent(10)),
+					limit_price: None,
 					order: Order::Sell {
 						asset_in: stable_asset_1,
 						asset_out: HDX,
@@ -3059,7 +3081,9 @@ mod with_onchain_route {
 				total_amount: dca_budget,
 				max_retries: None,
 				stability_threshold: None,
+				oracle_bounds: Default::default(),
 				slippage: Some(Permill::from_percent(10)),
+				limit_price: None,
 				order: Order::Sell {
 					asset_in: DOT,
 					asset_out: HDX,
@@ -3164,7 +3188,9 @@ fn schedule_fake_with_buy_order_with_route(
 		total_amount: budget,
 		max_retries: None,
 		stability_threshold: None,
+		oracle_bounds: Default::default(),
 		slippage: Some(Permill::from_percent(10)),
+		limit_price: None,
 		order: Order::Buy {
 			asset_in,
 			asset_out,
@@ -3211,7 +3237,9 @@ fn schedule_fake_with_sell_order_with_route(
 		total_amount,
 		max_retries: None,
 		stability_threshold: None,
+		oracle_bounds: Default::default(),
 		slippage: Some(Permill::from_percent(10)),
+		limit_price: None,
 		order: Order::Sell {
 			asset_in,
 			asset_out,
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use crate::polkadot_test_net::*;
-use frame_support::assert_ok;
+use frame_support::{assert_noop, assert_ok};
 use pallet_dynamic_fees::types::FeeEntry;
 use primitives::AssetId;
 use sp_runtime::{FixedU128, Permill};
@@ -311,6 +311,56 @@ fn fees_should_change_only_one_when_trades_happen_in_the_same_block() {
 	});
 }
 
+#[test]
+fn fees_should_be_updated_when_trade_happens_after_liquidity_is_added_with_limit() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		init_oracle();
+		hydradx_run_to_block(10);
+
+		set_balance(DAVE.into(), DOT, 1_000 * DOT_UNITS as i128);
+		set_balance(DAVE.into(), HDX, 1_000 * UNITS as i128);
+
+		// Shares are never minted above the amount added because the pool only accumulates fees.
+		assert_noop!(
+			hydradx_runtime::Omnipool::add_liquidity_with_limit(
+				hydradx_runtime::RuntimeOrigin::signed(DAVE.into()),
+				DOT,
+				100 * DOT_UNITS,
+				100 * DOT_UNITS + 1,
+			),
+			pallet_omnipool::Error::<hydradx_runtime::Runtime>::SlippageLimit
+		);
+
+		assert_ok!(hydradx_runtime::Omnipool::add_liquidity_with_limit(
+			hydradx_runtime::RuntimeOrigin::signed(DAVE.into()),
+			DOT,
+			100 * DOT_UNITS,
+			99 * DOT_UNITS,
+		));
+
+		//Act
+		hydradx_run_to_block(11);
+		assert_ok!(hydradx_runtime::Omnipool::sell(
+			hydradx_runtime::RuntimeOrigin::signed(DAVE.into()),
+			HDX,
+			DOT,
+			10 * UNITS,
+			0,
+		));
+
+		//Assert
+		let asset_fee_params = <hydradx_runtime::Runtime as pallet_dynamic_fees::Config>::AssetFeeParameters::get();
+		let current_fee = hydradx_runtime::DynamicFees::current_fees(DOT).unwrap();
+		assert_eq!(current_fee.timestamp, 11_u32);
+		assert!(current_fee.asset_fee >= asset_fee_params.min_fee);
+		assert!(current_fee.asset_fee <= asset_fee_params.max_fee);
+	});
+}
+
 fn set_balance(who: hydradx_runtime::AccountId, currency: AssetId, amount: i128) {
 	assert_ok!(hydradx_runtime::Currencies::update_balance(
 		hydradx_runtime::RuntimeOrigin::root(),
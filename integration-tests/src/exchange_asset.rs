@@ -298,6 +298,101 @@ fn transfer_and_swap_should_work_with_4_hops() {
 	});
 }
 
+#[test]
+fn exchange_asset_should_be_weighed_by_onchain_route() {
+	use hydradx_runtime::{RouterWeightInfo, XcmWeigher};
+	use hydradx_traits::router::{AssetPair, PoolType, Trade};
+	use pallet_route_executor::AmmTradeWeights;
+	use xcm_executor::traits::WeightBounds;
+
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		register_aca();
+		let give = MultiAsset::from((GeneralIndex(CORE_ASSET_ID.into()), 100 * UNITS));
+		let want = MultiAsset::from((
+			MultiLocation::new(1, X2(Parachain(ACALA_PARA_ID), GeneralIndex(0))),
+			300 * UNITS,
+		));
+		let exchange_weight = || {
+			let mut message = Xcm::<hydradx_runtime::RuntimeCall>(vec![ExchangeAsset {
+				give: Definite(give.clone().into()),
+				want: want.clone().into(),
+				maximal: SELL,
+			}]);
+			XcmWeigher::weight(&mut message).expect("weighing should not fail")
+		};
+		let default_route = vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: ACA,
+		}];
+		let onchain_route = vec![
+			Trade {
+				pool: PoolType::XYK,
+				asset_in: HDX,
+				asset_out: DOT,
+			},
+			Trade {
+				pool: PoolType::XYK,
+				asset_in: DOT,
+				asset_out: ACA,
+			},
+		];
+
+		//Act
+		let default_route_weight = exchange_weight();
+		assert_ok!(hydradx_runtime::Router::force_insert_route(
+			hydradx_runtime::RuntimeOrigin::root(),
+			AssetPair::new(HDX, ACA),
+			onchain_route.clone(),
+		));
+		let onchain_route_weight = exchange_weight();
+
+		//Assert
+		assert_ne!(default_route_weight, onchain_route_weight);
+		assert_eq!(
+			default_route_weight.saturating_sub(RouterWeightInfo::sell_weight(&default_route)),
+			onchain_route_weight.saturating_sub(RouterWeightInfo::sell_weight(&onchain_route))
+		);
+	});
+}
+
+#[test]
+fn exchange_asset_should_be_weighed_by_worst_case_route_when_give_asset_is_not_specified() {
+	use hydradx_runtime::{RouterWeightInfo, XcmWeigher};
+	use hydradx_traits::router::{PoolType, Trade};
+	use pallet_route_executor::{AmmTradeWeights, MAX_NUMBER_OF_TRADES};
+	use xcm_executor::traits::WeightBounds;
+
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		let want = MultiAsset::from((GeneralIndex(CORE_ASSET_ID.into()), 300 * UNITS));
+		let mut message = Xcm::<hydradx_runtime::RuntimeCall>(vec![ExchangeAsset {
+			give: Wild(AllCounted(1)),
+			want: want.into(),
+			maximal: BUY,
+		}]);
+		let omnipool_route = vec![
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: 0,
+				asset_out: 0,
+			};
+			MAX_NUMBER_OF_TRADES as usize
+		];
+
+		//Act
+		let weight = XcmWeigher::weight(&mut message).expect("weighing should not fail");
+
+		//Assert
+		assert!(weight.all_gte(RouterWeightInfo::buy_weight(&omnipool_route)));
+	});
+}
+
 fn register_glmr() {
 	assert_ok!(AssetRegistry::register_sufficient_asset(
 		Some(GLMR),
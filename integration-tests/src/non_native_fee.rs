@@ -486,11 +486,12 @@ fn omnipool_spotprice_and_onchain_price_should_be_very_similar() {
 		let spot_price = Omnipool::spot_price(DAI, DOT).unwrap();
 
 		let default_route = Router::get_route(AssetPair::new(DAI, DOT));
-		let onchain_oracle_price = OraclePriceProvider::<AssetId, EmaOracle, hydradx_runtime::LRNA>::price(
-			&default_route,
-			OraclePeriod::Short,
-		)
-		.unwrap();
+		let onchain_oracle_price =
+			OraclePriceProvider::<AssetId, EmaOracle, hydradx_runtime::LRNA, hydradx_runtime::Stableswap>::price(
+				&default_route,
+				OraclePeriod::Short,
+			)
+			.unwrap();
 
 		let onchain_oracle_price = FixedU128::from_rational(onchain_oracle_price.n, onchain_oracle_price.d);
 
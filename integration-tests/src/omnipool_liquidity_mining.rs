@@ -208,6 +208,125 @@ fn deposit_shares_should_work_when_yield_farm_exists() {
 	});
 }
 
+#[test]
+fn deposit_shares_should_work_when_position_is_created_with_limit() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		//Arrange
+		init_omnipool();
+
+		//NOTE: necessary to get oracle price.
+		hydradx_run_to_block(100);
+		set_relaychain_block_number(100);
+		create_global_farm(None);
+
+		set_relaychain_block_number(200);
+		create_yield_farm(global_farm_id, ETH);
+
+		set_relaychain_block_number(300);
+
+		assert_ok!(hydradx_runtime::Currencies::update_balance(
+			hydradx_runtime::RuntimeOrigin::root(),
+			CHARLIE.into(),
+			ETH,
+			10_000 * UNITS as i128,
+		));
+
+		assert_noop!(
+			hydradx_runtime::Omnipool::add_liquidity_with_limit(
+				RuntimeOrigin::signed(CHARLIE.into()),
+				ETH,
+				1_000 * UNITS,
+				1_000 * UNITS + 1,
+			),
+			pallet_omnipool::Error::<hydradx_runtime::Runtime>::SlippageLimit
+		);
+
+		let position_id = omnipool_add_liquidity_with_limit(CHARLIE.into(), ETH, 1_000 * UNITS, 1_000 * UNITS);
+		assert_nft_owner!(
+			hydradx_runtime::OmnipoolCollectionId::get(),
+			position_id,
+			CHARLIE.into()
+		);
+
+		//Act
+		set_relaychain_block_number(400);
+		assert_ok!(hydradx_runtime::OmnipoolLiquidityMining::deposit_shares(
+			RuntimeOrigin::signed(CHARLIE.into()),
+			global_farm_id,
+			yield_farm_id,
+			position_id
+		));
+
+		//Assert
+		let deposit = hydradx_runtime::OmnipoolWarehouseLM::deposit(1).unwrap();
+		let mut expected_deposit = DepositData::new(1_000_000_000_000_000, ETH);
+		expected_deposit
+			.add_yield_farm_entry(YieldFarmEntry::new(
+				global_farm_id,
+				yield_farm_id,
+				71_145_071_145_u128,
+				FixedU128::zero(),
+				40,
+				0,
+			))
+			.unwrap();
+
+		assert_eq!(deposit, expected_deposit);
+
+		//position locked in LM can't be removed
+		assert_noop!(
+			hydradx_runtime::Omnipool::remove_liquidity_with_limit(
+				RuntimeOrigin::signed(CHARLIE.into()),
+				position_id,
+				1_000 * UNITS,
+				0,
+				0,
+			),
+			pallet_omnipool::Error::<hydradx_runtime::Runtime>::Forbidden
+		);
+
+		set_relaychain_block_number(500);
+		assert_ok!(hydradx_runtime::OmnipoolLiquidityMining::withdraw_shares(
+			RuntimeOrigin::signed(CHARLIE.into()),
+			1,
+			yield_farm_id
+		));
+		assert_nft_owner!(
+			hydradx_runtime::OmnipoolCollectionId::get(),
+			position_id,
+			CHARLIE.into()
+		);
+
+		//withdrawal fee is always applied so full amount can't be received back
+		assert_noop!(
+			hydradx_runtime::Omnipool::remove_liquidity_with_limit(
+				RuntimeOrigin::signed(CHARLIE.into()),
+				position_id,
+				1_000 * UNITS,
+				1_000 * UNITS,
+				0,
+			),
+			pallet_omnipool::Error::<hydradx_runtime::Runtime>::SlippageLimit
+		);
+
+		let eth_balance = hydradx_runtime::Currencies::free_balance(ETH, &CHARLIE.into());
+		assert_ok!(hydradx_runtime::Omnipool::remove_liquidity_with_limit(
+			RuntimeOrigin::signed(CHARLIE.into()),
+			position_id,
+			1_000 * UNITS,
+			990 * UNITS,
+			0,
+		));
+		assert!(hydradx_runtime::Currencies::free_balance(ETH, &CHARLIE.into()) >= eth_balance + 990 * UNITS);
+		assert!(hydradx_runtime::Omnipool::positions(position_id).is_none());
+	});
+}
+
 #[test]
 fn redeposit_shares_multiple_times_should_work_when_shares_already_deposited() {
 	TestNet::reset();
@@ -639,6 +758,26 @@ fn omnipool_add_liquidity(lp: AccountId, asset: AssetId, amount: Balance) -> pri
 	current_position_id
 }
 
+fn omnipool_add_liquidity_with_limit(
+	lp: AccountId,
+	asset: AssetId,
+	amount: Balance,
+	min_shares_limit: Balance,
+) -> primitives::ItemId {
+	use hydradx_runtime::Omnipool;
+
+	let current_position_id = Omnipool::next_position_id();
+
+	assert_ok!(Omnipool::add_liquidity_with_limit(
+		RuntimeOrigin::signed(lp),
+		asset,
+		amount,
+		min_shares_limit
+	));
+
+	current_position_id
+}
+
 //This function add initial amount in native currency to pot to prevent dusting.
 fn seed_lm_pot() {
 	//prevent pot account from dusting
@@ -37,8 +37,8 @@ pub fn hydradx_run_to_block(to: BlockNumber) {
 
 const HDX: AssetId = CORE_ASSET_ID;
 
-const SUPPORTED_PERIODS: &[OraclePeriod] = &[LastBlock, Short, TenMinutes];
-const UNSUPPORTED_PERIODS: &[OraclePeriod] = &[Hour, Day, Week];
+const SUPPORTED_PERIODS: &[OraclePeriod] = &[LastBlock, Short, TenMinutes, Day];
+const UNSUPPORTED_PERIODS: &[OraclePeriod] = &[Hour, Week];
 
 #[test]
 fn omnipool_trades_are_ingested_into_oracle() {
@@ -0,0 +1,469 @@
+#![cfg(test)]
+
+use crate::polkadot_test_net::*;
+use frame_support::storage::with_transaction;
+use frame_support::{assert_noop, assert_ok};
+use hydradx_runtime::{Omnipool, OmnipoolLiquidityMining, RuntimeOrigin, Zap};
+use hydradx_traits::router::{PoolType, Trade};
+use orml_traits::MultiCurrency;
+use pallet_zap::ZapTarget;
+use primitives::{constants::currency::UNITS, AssetId, Balance};
+use sp_runtime::{traits::One, DispatchResult, FixedU128, Permill, Perquintill, TransactionOutcome};
+use warehouse_liquidity_mining::{GlobalFarmId, LoyaltyCurve};
+use xcm_emulator::TestExt;
+
+#[test]
+fn zap_should_add_liquidity_to_omnipool_when_asset_in_is_omnipool_asset() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		hydradx_run_to_next_block();
+
+		let amount = 100 * UNITS;
+		let position_id = Omnipool::next_position_id();
+
+		//Act
+		assert_ok!(Zap::zap(
+			RuntimeOrigin::signed(ALICE.into()),
+			DAI,
+			amount,
+			ZapTarget::Omnipool { asset: DAI },
+			vec![],
+			0,
+			None,
+		));
+
+		//Assert
+		let position = Omnipool::positions(position_id).unwrap();
+		assert_eq!(position.asset_id, DAI);
+		assert_eq!(position.amount, amount);
+		assert_eq!(
+			hydradx_runtime::Uniques::owner(hydradx_runtime::OmnipoolCollectionId::get(), position_id).unwrap(),
+			AccountId::from(ALICE)
+		);
+		assert_eq!(
+			Currencies::free_balance(DAI, &AccountId::from(ALICE)),
+			ALICE_INITIAL_DAI_BALANCE - amount
+		);
+
+		expect_hydra_events(vec![pallet_zap::Event::Zapped {
+			who: ALICE.into(),
+			asset_in: DAI,
+			amount_in: amount,
+			target: ZapTarget::Omnipool { asset: DAI },
+			shares: position.shares,
+			position_id: Some(position_id),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn zap_should_swap_and_add_liquidity_to_omnipool_when_asset_in_is_different() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		hydradx_run_to_next_block();
+
+		let amount = 10 * UNITS;
+		let position_id = Omnipool::next_position_id();
+
+		//Act
+		assert_ok!(Zap::zap(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			amount,
+			ZapTarget::Omnipool { asset: DAI },
+			vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DAI,
+			}],
+			0,
+			None,
+		));
+
+		//Assert
+		let position = Omnipool::positions(position_id).unwrap();
+		assert_eq!(position.asset_id, DAI);
+		assert!(position.amount > 0);
+
+		assert_eq!(
+			Currencies::free_balance(HDX, &AccountId::from(ALICE)),
+			ALICE_INITIAL_NATIVE_BALANCE - amount
+		);
+		// whole amount received from the swap is provided as liquidity
+		assert_eq!(
+			Currencies::free_balance(DAI, &AccountId::from(ALICE)),
+			ALICE_INITIAL_DAI_BALANCE
+		);
+	});
+}
+
+#[test]
+fn zap_should_use_onchain_route_when_route_is_not_specified() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		hydradx_run_to_next_block();
+
+		let amount = 10 * UNITS;
+		let position_id = Omnipool::next_position_id();
+
+		//Act
+		assert_ok!(Zap::zap(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			amount,
+			ZapTarget::Omnipool { asset: DAI },
+			vec![],
+			0,
+			None,
+		));
+
+		//Assert
+		assert_eq!(Omnipool::positions(position_id).unwrap().asset_id, DAI);
+		assert_eq!(
+			Currencies::free_balance(HDX, &AccountId::from(ALICE)),
+			ALICE_INITIAL_NATIVE_BALANCE - amount
+		);
+	});
+}
+
+#[test]
+fn zap_should_add_liquidity_to_stableswap_pool_when_target_is_stableswap() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			let (pool_id, stable_asset, _) = crate::router::init_stableswap().unwrap();
+			let initial_balance = Currencies::free_balance(stable_asset, &AccountId::from(CHARLIE));
+
+			let amount = 100 * UNITS;
+
+			//Act
+			assert_ok!(Zap::zap(
+				RuntimeOrigin::signed(CHARLIE.into()),
+				stable_asset,
+				amount,
+				ZapTarget::Stableswap {
+					pool_id,
+					asset: stable_asset
+				},
+				vec![],
+				0,
+				None,
+			));
+
+			//Assert
+			let shares = Currencies::free_balance(pool_id, &AccountId::from(CHARLIE));
+			assert!(shares > 0);
+			assert_eq!(
+				Currencies::free_balance(stable_asset, &AccountId::from(CHARLIE)),
+				initial_balance - amount
+			);
+
+			expect_hydra_events(vec![pallet_zap::Event::Zapped {
+				who: CHARLIE.into(),
+				asset_in: stable_asset,
+				amount_in: amount,
+				target: ZapTarget::Stableswap {
+					pool_id,
+					asset: stable_asset,
+				},
+				shares,
+				position_id: None,
+			}
+			.into()]);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn zap_should_add_stableswap_shares_to_omnipool_when_target_is_stableswap_omnipool() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		let _ = with_transaction(|| {
+			//Arrange
+			let (pool_id, stable_asset, _) = crate::router::init_stableswap().unwrap();
+			init_omnipool_with_stableswap_pool(pool_id);
+			hydradx_run_to_next_block();
+
+			let initial_balance = Currencies::free_balance(stable_asset, &AccountId::from(CHARLIE));
+			let amount = 100 * UNITS;
+			let position_id = Omnipool::next_position_id();
+
+			//Act
+			assert_ok!(Zap::zap(
+				RuntimeOrigin::signed(CHARLIE.into()),
+				stable_asset,
+				amount,
+				ZapTarget::StableswapOmnipool {
+					pool_id,
+					asset: stable_asset
+				},
+				vec![],
+				0,
+				None,
+			));
+
+			//Assert
+			let position = Omnipool::positions(position_id).unwrap();
+			assert_eq!(position.asset_id, pool_id);
+			assert!(position.amount > 0);
+
+			// all received stableswap shares are provided to omnipool
+			assert_eq!(Currencies::free_balance(pool_id, &AccountId::from(CHARLIE)), 0);
+			assert_eq!(
+				Currencies::free_balance(stable_asset, &AccountId::from(CHARLIE)),
+				initial_balance - amount
+			);
+
+			expect_hydra_events(vec![pallet_zap::Event::Zapped {
+				who: CHARLIE.into(),
+				asset_in: stable_asset,
+				amount_in: amount,
+				target: ZapTarget::StableswapOmnipool {
+					pool_id,
+					asset: stable_asset,
+				},
+				shares: position.shares,
+				position_id: Some(position_id),
+			}
+			.into()]);
+
+			TransactionOutcome::Commit(DispatchResult::Ok(()))
+		});
+	});
+}
+
+#[test]
+fn zap_should_deposit_position_to_yield_farm_when_farm_entry_is_specified() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		//Arrange
+		init_omnipool();
+
+		//NOTE: necessary to get oracle price.
+		hydradx_run_to_block(100);
+		set_relaychain_block_number(100);
+		create_global_farm();
+
+		set_relaychain_block_number(200);
+		create_yield_farm(global_farm_id, DAI);
+
+		set_relaychain_block_number(300);
+
+		let position_id = Omnipool::next_position_id();
+
+		//Act
+		assert_ok!(Zap::zap(
+			RuntimeOrigin::signed(ALICE.into()),
+			DAI,
+			100 * UNITS,
+			ZapTarget::Omnipool { asset: DAI },
+			vec![],
+			0,
+			Some((global_farm_id, yield_farm_id)),
+		));
+
+		//Assert
+		let shares = Omnipool::positions(position_id).unwrap().shares;
+		assert!(hydradx_runtime::OmnipoolWarehouseLM::deposit(1).is_some());
+
+		assert_eq!(
+			hydradx_runtime::Uniques::owner(hydradx_runtime::OmnipoolLMCollectionId::get(), 1).unwrap(),
+			AccountId::from(ALICE)
+		);
+		assert_eq!(
+			hydradx_runtime::Uniques::owner(hydradx_runtime::OmnipoolCollectionId::get(), position_id).unwrap(),
+			OmnipoolLiquidityMining::account_id()
+		);
+
+		expect_hydra_events(vec![
+			pallet_omnipool_liquidity_mining::Event::SharesDeposited {
+				global_farm_id,
+				yield_farm_id,
+				deposit_id: 1,
+				asset_id: DAI,
+				who: ALICE.into(),
+				shares_amount: shares,
+				position_id,
+			}
+			.into(),
+			pallet_zap::Event::Zapped {
+				who: ALICE.into(),
+				asset_in: DAI,
+				amount_in: 100 * UNITS,
+				target: ZapTarget::Omnipool { asset: DAI },
+				shares,
+				position_id: Some(position_id),
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn zap_should_fail_when_shares_are_lower_than_limit() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		hydradx_run_to_next_block();
+
+		//Act & Assert
+		assert_noop!(
+			Zap::zap(
+				RuntimeOrigin::signed(ALICE.into()),
+				HDX,
+				10 * UNITS,
+				ZapTarget::Omnipool { asset: DAI },
+				vec![],
+				Balance::MAX,
+				None,
+			),
+			pallet_zap::Error::<hydradx_runtime::Runtime>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn zap_should_fail_when_farm_entry_is_specified_for_stableswap_target() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Act & Assert
+		assert_noop!(
+			Zap::zap(
+				RuntimeOrigin::signed(ALICE.into()),
+				DAI,
+				10 * UNITS,
+				ZapTarget::Stableswap {
+					pool_id: 100,
+					asset: DAI
+				},
+				vec![],
+				0,
+				Some((1, 2)),
+			),
+			pallet_zap::Error::<hydradx_runtime::Runtime>::YieldFarmNotSupported
+		);
+	});
+}
+
+#[test]
+fn zap_should_fail_when_route_is_specified_for_liquidity_asset() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+
+		//Act & Assert
+		assert_noop!(
+			Zap::zap(
+				RuntimeOrigin::signed(ALICE.into()),
+				DAI,
+				10 * UNITS,
+				ZapTarget::Omnipool { asset: DAI },
+				vec![Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: DAI,
+				}],
+				0,
+				None,
+			),
+			pallet_zap::Error::<hydradx_runtime::Runtime>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn zap_should_fail_when_amount_is_zero() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Act & Assert
+		assert_noop!(
+			Zap::zap(
+				RuntimeOrigin::signed(ALICE.into()),
+				DAI,
+				0,
+				ZapTarget::Omnipool { asset: DAI },
+				vec![],
+				0,
+				None,
+			),
+			pallet_zap::Error::<hydradx_runtime::Runtime>::InvalidAssetAmount
+		);
+	});
+}
+
+fn init_omnipool_with_stableswap_pool(pool_id: AssetId) {
+	init_omnipool();
+
+	assert_ok!(Currencies::update_balance(
+		RuntimeOrigin::root(),
+		Omnipool::protocol_account(),
+		pool_id,
+		30_000_000 * UNITS as i128,
+	));
+
+	assert_ok!(Omnipool::add_token(
+		RuntimeOrigin::root(),
+		pool_id,
+		FixedU128::from_rational(50, 100),
+		Permill::from_percent(100),
+		AccountId::from(BOB),
+	));
+	set_zero_reward_for_referrals(pool_id);
+}
+
+fn create_global_farm() {
+	let total_rewards = 1_000_000 * UNITS;
+
+	assert_ok!(hydradx_runtime::Balances::force_set_balance(
+		RuntimeOrigin::root(),
+		Treasury::account_id(),
+		total_rewards,
+	));
+
+	assert_ok!(OmnipoolLiquidityMining::create_global_farm(
+		RuntimeOrigin::root(),
+		total_rewards,
+		1_000_000,
+		10,
+		HDX,
+		Treasury::account_id(),
+		Perquintill::from_parts(570_776_255_707),
+		1_000,
+		FixedU128::one()
+	));
+}
+
+fn create_yield_farm(id: GlobalFarmId, asset: AssetId) {
+	assert_ok!(OmnipoolLiquidityMining::create_yield_farm(
+		RuntimeOrigin::signed(Treasury::account_id()),
+		id,
+		asset,
+		FixedU128::one(),
+		Some(LoyaltyCurve::default())
+	));
+}
@@ -0,0 +1,222 @@
+use crate::concentrated::types::*;
+use crate::concentrated::*;
+use crate::types::Balance;
+use primitive_types::U256;
+use proptest::prelude::*;
+use sp_arithmetic::Permill;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+const TICK_RANGE: (Tick, Tick) = (-200_000, 200_000);
+
+fn any_tick() -> impl Strategy<Value = Tick> {
+	MIN_TICK..MAX_TICK
+}
+
+fn tick() -> impl Strategy<Value = Tick> {
+	TICK_RANGE.0..TICK_RANGE.1
+}
+
+fn sqrt_price() -> impl Strategy<Value = SqrtPrice> {
+	(any::<u128>(), 0u32..64).prop_map(|(mantissa, shift)| {
+		let price = U256::from(mantissa) << shift;
+		price.max(MIN_SQRT_PRICE).min(MAX_SQRT_PRICE - 1)
+	})
+}
+
+fn liquidity() -> impl Strategy<Value = Liquidity> {
+	1_000 * ONE..100_000_000 * ONE
+}
+
+fn trade_amount() -> impl Strategy<Value = Balance> {
+	ONE..1_000 * ONE
+}
+
+fn fee() -> impl Strategy<Value = Permill> {
+	(0u32..100_000).prop_map(Permill::from_parts)
+}
+
+fn pool_state() -> impl Strategy<Value = PoolState> {
+	(tick(), liquidity()).prop_map(|(tick, liquidity)| PoolState {
+		sqrt_price: sqrt_price_at_tick(tick).unwrap(),
+		tick,
+		liquidity,
+	})
+}
+
+/// Single full range position providing all liquidity of the pool.
+fn full_range_ticks(liquidity: Liquidity) -> Vec<TickLiquidity> {
+	vec![
+		TickLiquidity::new(MIN_TICK, liquidity as i128),
+		TickLiquidity::new(MAX_TICK, -(liquidity as i128)),
+	]
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn tick_at_sqrt_price_should_be_inverse_of_sqrt_price_at_tick(tick in any_tick()) {
+		let sqrt_price = sqrt_price_at_tick(tick).unwrap();
+		let next_sqrt_price = sqrt_price_at_tick(tick + 1).unwrap();
+
+		assert!(sqrt_price < next_sqrt_price);
+		assert_eq!(tick_at_sqrt_price(sqrt_price).unwrap(), tick);
+		assert_eq!(tick_at_sqrt_price(next_sqrt_price - 1).unwrap(), tick);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn tick_at_sqrt_price_should_return_greatest_tick_below_price(sqrt_price in sqrt_price()) {
+		let tick = tick_at_sqrt_price(sqrt_price).unwrap();
+
+		assert!(sqrt_price_at_tick(tick).unwrap() <= sqrt_price);
+		assert!(sqrt_price_at_tick(tick + 1).unwrap() > sqrt_price);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn amounts_for_liquidity_should_not_exceed_provided_amounts(
+		current in tick(),
+		lower in tick(),
+		width in 1..10_000i32,
+		amount_0 in trade_amount(),
+		amount_1 in trade_amount(),
+	) {
+		let sqrt_price = sqrt_price_at_tick(current).unwrap();
+		let sqrt_price_lower = sqrt_price_at_tick(lower).unwrap();
+		let sqrt_price_upper = sqrt_price_at_tick(lower + width).unwrap();
+
+		let liquidity = calculate_liquidity_for_amounts(sqrt_price, sqrt_price_lower, sqrt_price_upper, amount_0, amount_1).unwrap();
+		let (required_0, required_1) = calculate_amounts_for_liquidity(sqrt_price, sqrt_price_lower, sqrt_price_upper, liquidity, true).unwrap();
+
+		assert!(required_0 <= amount_0);
+		assert!(required_1 <= amount_1);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn amounts_for_liquidity_should_round_in_favor_of_pool(
+		current in tick(),
+		lower in tick(),
+		width in 1..10_000i32,
+		liquidity in liquidity(),
+	) {
+		let sqrt_price = sqrt_price_at_tick(current).unwrap();
+		let sqrt_price_lower = sqrt_price_at_tick(lower).unwrap();
+		let sqrt_price_upper = sqrt_price_at_tick(lower + width).unwrap();
+
+		let (added_0, added_1) = calculate_amounts_for_liquidity(sqrt_price, sqrt_price_lower, sqrt_price_upper, liquidity, true).unwrap();
+		let (removed_0, removed_1) = calculate_amounts_for_liquidity(sqrt_price, sqrt_price_lower, sqrt_price_upper, liquidity, false).unwrap();
+
+		assert!(removed_0 <= added_0);
+		assert!(removed_1 <= added_1);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn sell_and_sell_back_should_not_return_more_than_sold(
+		state in pool_state(),
+		amount in trade_amount(),
+		zero_for_one in any::<bool>(),
+		fee in fee(),
+	) {
+		let ticks = full_range_ticks(state.liquidity);
+
+		let sell = calculate_out_given_in(&state, &ticks, zero_for_one, amount, fee).unwrap();
+		let sell_back = calculate_out_given_in(&sell.state, &ticks, !zero_for_one, sell.amount_out, fee).unwrap();
+
+		assert_eq!(sell.amount_in, amount);
+		assert!(sell_back.amount_out <= amount);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn buy_should_not_cost_less_than_equivalent_sell(
+		state in pool_state(),
+		amount in trade_amount(),
+		zero_for_one in any::<bool>(),
+		fee in fee(),
+	) {
+		let ticks = full_range_ticks(state.liquidity);
+
+		let sell = calculate_out_given_in(&state, &ticks, zero_for_one, amount, fee).unwrap();
+		prop_assume!(sell.amount_out > 0);
+		let buy = calculate_in_given_out(&state, &ticks, zero_for_one, sell.amount_out, fee).unwrap();
+
+		assert_eq!(buy.amount_out, sell.amount_out);
+		assert!(buy.amount_in <= sell.amount_in);
+		// buying exact amount out must not be cheaper than the price of the pool allows
+		let rebuy = calculate_out_given_in(&state, &ticks, zero_for_one, buy.amount_in, fee).unwrap();
+		assert!(rebuy.amount_out >= buy.amount_out);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn pool_reserves_should_cover_liquidity_after_trade(
+		state in pool_state(),
+		amount in trade_amount(),
+		zero_for_one in any::<bool>(),
+		fee in fee(),
+	) {
+		let lower = -100_000;
+		let upper = 300_000;
+		let ticks = vec![
+			TickLiquidity::new(lower, state.liquidity as i128),
+			TickLiquidity::new(upper, -(state.liquidity as i128)),
+		];
+		let sqrt_price_lower = sqrt_price_at_tick(lower).unwrap();
+		let sqrt_price_upper = sqrt_price_at_tick(upper).unwrap();
+		let state = PoolState {
+			sqrt_price: sqrt_price_at_tick(state.tick.max(lower).min(upper - 1)).unwrap(),
+			tick: state.tick.max(lower).min(upper - 1),
+			liquidity: state.liquidity,
+		};
+
+		let (reserve_0, reserve_1) = calculate_amounts_for_liquidity(state.sqrt_price, sqrt_price_lower, sqrt_price_upper, state.liquidity, true).unwrap();
+
+		let Ok(result) = calculate_out_given_in(&state, &ticks, zero_for_one, amount, fee) else {
+			return Ok(());
+		};
+
+		let (reserve_0, reserve_1) = if zero_for_one {
+			(reserve_0 + result.amount_in, reserve_1 - result.amount_out)
+		} else {
+			(reserve_0 - result.amount_out, reserve_1 + result.amount_in)
+		};
+
+		let (required_0, required_1) = calculate_amounts_for_liquidity(result.state.sqrt_price, sqrt_price_lower, sqrt_price_upper, result.state.liquidity, false).unwrap();
+
+		assert!(reserve_0 >= required_0);
+		assert!(reserve_1 >= required_1);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn accrued_fees_should_not_exceed_collected_fees(
+		state in pool_state(),
+		amount in trade_amount(),
+		zero_for_one in any::<bool>(),
+		fee in fee(),
+	) {
+		let ticks = full_range_ticks(state.liquidity);
+
+		let result = calculate_out_given_in(&state, &ticks, zero_for_one, amount, fee).unwrap();
+		let accrued = calculate_accrued_fees(state.liquidity, result.fee_growth_delta, U256::zero()).unwrap();
+
+		assert!(accrued <= result.fee_amount);
+	}
+}
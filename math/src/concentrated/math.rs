@@ -0,0 +1,676 @@
+use crate::concentrated::types::*;
+use crate::support::rational::{round_u512_to_rational, Rounding};
+use crate::types::Balance;
+use crate::{
+	ensure, to_balance, MathError,
+	MathError::{DivisionByZero, InsufficientOutReserve, Overflow, PriceOutOfRange, TickOutOfRange},
+};
+use core::convert::TryFrom;
+use fixed::types::U64F64;
+use num_traits::Zero;
+use primitive_types::{U256, U512};
+use sp_arithmetic::Permill;
+use sp_std::vec::Vec;
+
+/// Lowest tick. `1.0001^MIN_TICK` is approximately `2^-128`.
+pub const MIN_TICK: Tick = -887272;
+/// Highest tick. `1.0001^MAX_TICK` is approximately `2^128`.
+pub const MAX_TICK: Tick = 887272;
+
+/// Square root price at `MIN_TICK`.
+pub const MIN_SQRT_PRICE: U256 = U256([4295128739, 0, 0, 0]);
+/// Square root price at `MAX_TICK`.
+pub const MAX_SQRT_PRICE: U256 = U256([0x5d951d5263988d26, 0xefd1fc6a50648849, 0x00000000fffd8963, 0]);
+
+const RESOLUTION: usize = 96;
+
+/// `1 / sqrt(1.0001)^(2^i)` as Q128.128 numbers.
+const TICK_RATIOS: [u128; 20] = [
+	0xfffcb933bd6fad37aa2d162d1a594001,
+	0xfff97272373d413259a46990580e213a,
+	0xfff2e50f5f656932ef12357cf3c7fdcc,
+	0xffe5caca7e10e4e61c3624eaa0941cd0,
+	0xffcb9843d60f6159c9db58835c926644,
+	0xff973b41fa98c081472e6896dfb254c0,
+	0xff2ea16466c96a3843ec78b326b52861,
+	0xfe5dee046a99a2a811c461f1969c3053,
+	0xfcbe86c7900a88aedcffc83b479aa3a4,
+	0xf987a7253ac413176f2b074cf7815e54,
+	0xf3392b0822b70005940c7a398e4b70f3,
+	0xe7159475a2c29b7443b29c7fa6e889d9,
+	0xd097f3bdfd2022b8845ad8f792aa5825,
+	0xa9f746462d870fdf8a65dc1f90e061e5,
+	0x70d869a156d2a1b890bb3df62baf32f7,
+	0x31be135f97d08fd981231505542fcfa6,
+	0x9aa508b5b7a84e1c677de54f3e99bc9,
+	0x5d6af8dedb81196699c329225ee604,
+	0x2216e584f5fa1ea926041bedfe98,
+	0x48a170391f7dc42444e8fa2,
+];
+
+/// `log2(1.0001)` as Q64.64 number.
+const LOG2_TICK_BASE: i128 = 2661169563308230;
+
+/// `1` as Q64.96 number.
+pub fn q96() -> U256 {
+	U256::one() << RESOLUTION
+}
+
+fn mul_div(a: U256, b: U256, denominator: U256, round_up: bool) -> Result<U256, MathError> {
+	ensure!(!denominator.is_zero(), DivisionByZero);
+	let (quotient, remainder) = a.full_mul(b).div_mod(U512::from(denominator));
+	let quotient = if round_up && !remainder.is_zero() {
+		quotient.checked_add(U512::one()).ok_or(Overflow)?
+	} else {
+		quotient
+	};
+	U256::try_from(quotient).map_err(|_| Overflow)
+}
+
+fn div_rounding_up(a: U256, b: U256) -> Result<U256, MathError> {
+	ensure!(!b.is_zero(), DivisionByZero);
+	let (quotient, remainder) = a.div_mod(b);
+	if remainder.is_zero() {
+		Ok(quotient)
+	} else {
+		quotient.checked_add(U256::one()).ok_or(Overflow)
+	}
+}
+
+/// Calculate square root price at given tick.
+///
+/// Formula: `sqrt(1.0001^tick) * 2^96`, rounded up.
+pub fn sqrt_price_at_tick(tick: Tick) -> Result<SqrtPrice, MathError> {
+	ensure!((MIN_TICK..=MAX_TICK).contains(&tick), TickOutOfRange);
+
+	let abs_tick = tick.unsigned_abs();
+	let mut ratio = if abs_tick & 1 != 0 {
+		U256::from(TICK_RATIOS[0])
+	} else {
+		U256::one() << 128
+	};
+	for (i, r) in TICK_RATIOS.iter().enumerate().skip(1) {
+		if abs_tick & (1 << i) != 0 {
+			ratio = (ratio * U256::from(*r)) >> 128;
+		}
+	}
+
+	if tick > 0 {
+		ratio = U256::MAX / ratio;
+	}
+
+	// Q128.128 to Q64.96, rounding up so that `tick_at_sqrt_price(sqrt_price_at_tick(tick)) == tick`.
+	let remainder = ratio & ((U256::one() << 32) - U256::one());
+	let sqrt_price = ratio >> 32;
+	Ok(if remainder.is_zero() {
+		sqrt_price
+	} else {
+		sqrt_price + U256::one()
+	})
+}
+
+/// Calculate greatest tick whose square root price is lower than or equal to `sqrt_price`.
+///
+/// The tick is estimated from `log2(sqrt_price)` and then corrected against `sqrt_price_at_tick`.
+pub fn tick_at_sqrt_price(sqrt_price: SqrtPrice) -> Result<Tick, MathError> {
+	ensure!(
+		sqrt_price >= MIN_SQRT_PRICE && sqrt_price < MAX_SQRT_PRICE,
+		PriceOutOfRange
+	);
+
+	// sqrt_price = 2^msb * mantissa, where mantissa is in [1, 2)
+	let msb = sqrt_price.bits() - 1;
+	let mantissa_bits = if msb >= 64 {
+		sqrt_price >> (msb - 64)
+	} else {
+		sqrt_price << (64 - msb)
+	};
+	let mantissa = U64F64::from_bits(mantissa_bits.low_u128());
+	let (log2_mantissa, _) = crate::transcendental::log2::<U64F64, U64F64>(mantissa).map_err(|_| Overflow)?;
+
+	let log2_sqrt_price = ((msb as i128 - RESOLUTION as i128) << 64) + log2_mantissa.to_bits() as i128;
+	let estimate = (log2_sqrt_price * 2).div_euclid(LOG2_TICK_BASE);
+	let mut tick = Tick::try_from(estimate)
+		.map_err(|_| Overflow)?
+		.clamp(MIN_TICK, MAX_TICK);
+
+	while tick > MIN_TICK && sqrt_price_at_tick(tick)? > sqrt_price {
+		tick -= 1;
+	}
+	while tick < MAX_TICK && sqrt_price_at_tick(tick + 1)? <= sqrt_price {
+		tick += 1;
+	}
+
+	Ok(tick)
+}
+
+/// Calculate price of asset 0 denominated in asset 1 as a rational number.
+///
+/// Formula: `sqrt_price^2 / 2^192`
+pub fn calculate_spot_price(sqrt_price: SqrtPrice) -> (Balance, Balance) {
+	round_u512_to_rational(
+		(sqrt_price.full_mul(sqrt_price), U512::one() << (2 * RESOLUTION)),
+		Rounding::Nearest,
+	)
+}
+
+/// Calculate amount of asset 0 between two prices for given liquidity.
+///
+/// Formula: `L * (sqrt_b - sqrt_a) / (sqrt_a * sqrt_b)`
+pub fn calculate_amount_0_delta(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<Balance, MathError> {
+	let (lower, upper) = if sqrt_price_a <= sqrt_price_b {
+		(sqrt_price_a, sqrt_price_b)
+	} else {
+		(sqrt_price_b, sqrt_price_a)
+	};
+	ensure!(!lower.is_zero(), DivisionByZero);
+
+	let numerator = U256::from(liquidity) << RESOLUTION;
+	let diff = upper - lower;
+
+	let amount = if round_up {
+		div_rounding_up(mul_div(numerator, diff, upper, true)?, lower)?
+	} else {
+		mul_div(numerator, diff, upper, false)? / lower
+	};
+
+	to_balance!(amount)
+}
+
+/// Calculate amount of asset 1 between two prices for given liquidity.
+///
+/// Formula: `L * (sqrt_b - sqrt_a)`
+pub fn calculate_amount_1_delta(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<Balance, MathError> {
+	let (lower, upper) = if sqrt_price_a <= sqrt_price_b {
+		(sqrt_price_a, sqrt_price_b)
+	} else {
+		(sqrt_price_b, sqrt_price_a)
+	};
+
+	let amount = mul_div(U256::from(liquidity), upper - lower, q96(), round_up)?;
+
+	to_balance!(amount)
+}
+
+/// Calculate amounts of both assets represented by `liquidity` in range `[sqrt_price_lower, sqrt_price_upper]`
+/// at current price `sqrt_price`.
+///
+/// Amounts are rounded up when liquidity is added and rounded down when liquidity is removed.
+pub fn calculate_amounts_for_liquidity(
+	sqrt_price: SqrtPrice,
+	sqrt_price_lower: SqrtPrice,
+	sqrt_price_upper: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<(Balance, Balance), MathError> {
+	ensure!(sqrt_price_lower < sqrt_price_upper, PriceOutOfRange);
+
+	if sqrt_price <= sqrt_price_lower {
+		Ok((
+			calculate_amount_0_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?,
+			0,
+		))
+	} else if sqrt_price < sqrt_price_upper {
+		Ok((
+			calculate_amount_0_delta(sqrt_price, sqrt_price_upper, liquidity, round_up)?,
+			calculate_amount_1_delta(sqrt_price_lower, sqrt_price, liquidity, round_up)?,
+		))
+	} else {
+		Ok((
+			0,
+			calculate_amount_1_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?,
+		))
+	}
+}
+
+/// Calculate liquidity provided by `amount_0` of asset 0 in range `[sqrt_price_a, sqrt_price_b]`.
+///
+/// Formula: `amount_0 * sqrt_a * sqrt_b / (sqrt_b - sqrt_a)`, rounded down.
+pub fn calculate_liquidity_for_amount_0(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	amount_0: Balance,
+) -> Result<Liquidity, MathError> {
+	let (lower, upper) = if sqrt_price_a <= sqrt_price_b {
+		(sqrt_price_a, sqrt_price_b)
+	} else {
+		(sqrt_price_b, sqrt_price_a)
+	};
+	ensure!(lower != upper, DivisionByZero);
+
+	let intermediate = mul_div(lower, upper, q96(), false)?;
+	let liquidity = mul_div(U256::from(amount_0), intermediate, upper - lower, false)?;
+
+	Liquidity::try_from(liquidity).map_err(|_| Overflow)
+}
+
+/// Calculate liquidity provided by `amount_1` of asset 1 in range `[sqrt_price_a, sqrt_price_b]`.
+///
+/// Formula: `amount_1 / (sqrt_b - sqrt_a)`, rounded down.
+pub fn calculate_liquidity_for_amount_1(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	amount_1: Balance,
+) -> Result<Liquidity, MathError> {
+	let (lower, upper) = if sqrt_price_a <= sqrt_price_b {
+		(sqrt_price_a, sqrt_price_b)
+	} else {
+		(sqrt_price_b, sqrt_price_a)
+	};
+	ensure!(lower != upper, DivisionByZero);
+
+	let liquidity = mul_div(U256::from(amount_1), q96(), upper - lower, false)?;
+
+	Liquidity::try_from(liquidity).map_err(|_| Overflow)
+}
+
+/// Calculate maximum liquidity which can be provided by given amounts in range `[sqrt_price_lower, sqrt_price_upper]`
+/// at current price `sqrt_price`.
+pub fn calculate_liquidity_for_amounts(
+	sqrt_price: SqrtPrice,
+	sqrt_price_lower: SqrtPrice,
+	sqrt_price_upper: SqrtPrice,
+	amount_0: Balance,
+	amount_1: Balance,
+) -> Result<Liquidity, MathError> {
+	ensure!(sqrt_price_lower < sqrt_price_upper, PriceOutOfRange);
+
+	if sqrt_price <= sqrt_price_lower {
+		calculate_liquidity_for_amount_0(sqrt_price_lower, sqrt_price_upper, amount_0)
+	} else if sqrt_price < sqrt_price_upper {
+		let liquidity_0 = calculate_liquidity_for_amount_0(sqrt_price, sqrt_price_upper, amount_0)?;
+		let liquidity_1 = calculate_liquidity_for_amount_1(sqrt_price_lower, sqrt_price, amount_1)?;
+		Ok(liquidity_0.min(liquidity_1))
+	} else {
+		calculate_liquidity_for_amount_1(sqrt_price_lower, sqrt_price_upper, amount_1)
+	}
+}
+
+/// Apply signed liquidity delta to liquidity.
+pub fn add_liquidity_delta(liquidity: Liquidity, delta: i128) -> Result<Liquidity, MathError> {
+	if delta < 0 {
+		liquidity.checked_sub(delta.unsigned_abs()).ok_or(Overflow)
+	} else {
+		liquidity.checked_add(delta.unsigned_abs()).ok_or(Overflow)
+	}
+}
+
+/// Calculate next square root price after `amount` of asset 0 is added to or removed from the pool.
+///
+/// Formula: `L * sqrt_p / (L +- amount * sqrt_p)`, rounded up.
+fn next_sqrt_price_from_amount_0(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount: Balance,
+	add: bool,
+) -> Result<SqrtPrice, MathError> {
+	if amount.is_zero() {
+		return Ok(sqrt_price);
+	}
+	let numerator = U256::from(liquidity) << RESOLUTION;
+	let product = U256::from(amount).checked_mul(sqrt_price).ok_or(Overflow)?;
+
+	let denominator = if add {
+		numerator.checked_add(product).ok_or(Overflow)?
+	} else {
+		ensure!(numerator > product, InsufficientOutReserve);
+		numerator - product
+	};
+
+	mul_div(numerator, sqrt_price, denominator, true)
+}
+
+/// Calculate next square root price after `amount` of asset 1 is added to or removed from the pool.
+///
+/// Formula: `sqrt_p +- amount / L`, rounded down.
+fn next_sqrt_price_from_amount_1(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount: Balance,
+	add: bool,
+) -> Result<SqrtPrice, MathError> {
+	ensure!(!liquidity.is_zero(), DivisionByZero);
+	if add {
+		let quotient = mul_div(U256::from(amount), q96(), U256::from(liquidity), false)?;
+		sqrt_price.checked_add(quotient).ok_or(Overflow)
+	} else {
+		let quotient = mul_div(U256::from(amount), q96(), U256::from(liquidity), true)?;
+		ensure!(sqrt_price > quotient, InsufficientOutReserve);
+		Ok(sqrt_price - quotient)
+	}
+}
+
+/// Calculate next square root price after `amount_in` is sold to the pool.
+pub fn calculate_next_sqrt_price_from_input(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount_in: Balance,
+	zero_for_one: bool,
+) -> Result<SqrtPrice, MathError> {
+	if zero_for_one {
+		next_sqrt_price_from_amount_0(sqrt_price, liquidity, amount_in, true)
+	} else {
+		next_sqrt_price_from_amount_1(sqrt_price, liquidity, amount_in, true)
+	}
+}
+
+/// Calculate next square root price after `amount_out` is bought from the pool.
+pub fn calculate_next_sqrt_price_from_output(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount_out: Balance,
+	zero_for_one: bool,
+) -> Result<SqrtPrice, MathError> {
+	if zero_for_one {
+		next_sqrt_price_from_amount_1(sqrt_price, liquidity, amount_out, false)
+	} else {
+		next_sqrt_price_from_amount_0(sqrt_price, liquidity, amount_out, false)
+	}
+}
+
+fn fee_for_amount_in(amount_in: Balance, fee: Permill) -> Result<Balance, MathError> {
+	let fee_parts = fee.deconstruct() as u128;
+	let remaining_parts = (Permill::one().deconstruct() as u128).saturating_sub(fee_parts);
+	ensure!(!remaining_parts.is_zero(), DivisionByZero);
+	let fee_amount = mul_div(
+		U256::from(amount_in),
+		U256::from(fee_parts),
+		U256::from(remaining_parts),
+		true,
+	)?;
+	to_balance!(fee_amount)
+}
+
+/// Calculate a swap step within a price range of constant liquidity.
+///
+/// Price moves from `sqrt_price` towards `sqrt_price_target` until `amount_remaining` is used up.
+/// `amount_remaining` is amount in (including fee) when `exact_in` is true, amount out otherwise.
+pub fn calculate_swap_step(
+	sqrt_price: SqrtPrice,
+	sqrt_price_target: SqrtPrice,
+	liquidity: Liquidity,
+	amount_remaining: Balance,
+	exact_in: bool,
+	fee: Permill,
+) -> Result<SwapStep, MathError> {
+	let zero_for_one = sqrt_price >= sqrt_price_target;
+
+	let amount_in_to_target = |round_up: bool, next: SqrtPrice| {
+		if zero_for_one {
+			calculate_amount_0_delta(next, sqrt_price, liquidity, round_up)
+		} else {
+			calculate_amount_1_delta(sqrt_price, next, liquidity, round_up)
+		}
+	};
+	let amount_out_to_target = |next: SqrtPrice| {
+		if zero_for_one {
+			calculate_amount_1_delta(next, sqrt_price, liquidity, false)
+		} else {
+			calculate_amount_0_delta(sqrt_price, next, liquidity, false)
+		}
+	};
+
+	if exact_in {
+		let amount_remaining_less_fee = amount_remaining.saturating_sub(fee.mul_ceil(amount_remaining));
+		// amount needed to reach a distant target may not fit into balance, target is then out of reach
+		let amount_in_max = match amount_in_to_target(true, sqrt_price_target) {
+			Err(Overflow) => Balance::MAX,
+			result => result?,
+		};
+
+		let sqrt_price_next = if amount_remaining_less_fee >= amount_in_max {
+			sqrt_price_target
+		} else if liquidity.is_zero() {
+			sqrt_price
+		} else {
+			calculate_next_sqrt_price_from_input(sqrt_price, liquidity, amount_remaining_less_fee, zero_for_one)?
+		};
+		let reached_target = sqrt_price_next == sqrt_price_target;
+
+		let amount_in = if reached_target {
+			amount_in_max
+		} else {
+			amount_in_to_target(true, sqrt_price_next)?
+		};
+		let amount_out = amount_out_to_target(sqrt_price_next)?;
+		let fee_amount = if reached_target {
+			fee_for_amount_in(amount_in, fee)?
+		} else {
+			// price did not reach the target, so the remainder is taken as fee
+			amount_remaining.saturating_sub(amount_in)
+		};
+
+		Ok(SwapStep {
+			sqrt_price_next,
+			amount_in,
+			amount_out,
+			fee_amount,
+		})
+	} else {
+		let amount_out_max = match amount_out_to_target(sqrt_price_target) {
+			Err(Overflow) => Balance::MAX,
+			result => result?,
+		};
+
+		let sqrt_price_next = if amount_remaining >= amount_out_max {
+			sqrt_price_target
+		} else if liquidity.is_zero() {
+			sqrt_price
+		} else {
+			calculate_next_sqrt_price_from_output(sqrt_price, liquidity, amount_remaining, zero_for_one)?
+		};
+		let reached_target = sqrt_price_next == sqrt_price_target;
+
+		let amount_in = amount_in_to_target(true, sqrt_price_next)?;
+		let amount_out = if reached_target {
+			amount_out_max
+		} else {
+			amount_out_to_target(sqrt_price_next)?
+		}
+		.min(amount_remaining);
+		let fee_amount = fee_for_amount_in(amount_in, fee)?;
+
+		Ok(SwapStep {
+			sqrt_price_next,
+			amount_in,
+			amount_out,
+			fee_amount,
+		})
+	}
+}
+
+/// Calculate fee growth per unit of liquidity for given fee amount.
+///
+/// Formula: `fee_amount * 2^128 / L`
+pub fn calculate_fee_growth(fee_amount: Balance, liquidity: Liquidity) -> FeeGrowth {
+	if liquidity.is_zero() {
+		return FeeGrowth::zero();
+	}
+	(U256::from(fee_amount) << 128) / U256::from(liquidity)
+}
+
+fn swap(
+	state: &PoolState,
+	ticks: &[TickLiquidity],
+	zero_for_one: bool,
+	amount: Balance,
+	exact_in: bool,
+	fee: Permill,
+) -> Result<SwapResult, MathError> {
+	let mut current = *state;
+	let mut amount_remaining = amount;
+	let mut amount_in: Balance = 0;
+	let mut amount_out: Balance = 0;
+	let mut fee_amount: Balance = 0;
+	let mut fee_growth_delta = FeeGrowth::zero();
+	let mut crossed_ticks = Vec::new();
+
+	// index of the first initialized tick above current tick, moved with every crossed tick
+	let mut cursor = ticks.partition_point(|t| t.tick <= current.tick);
+
+	while !amount_remaining.is_zero() {
+		let next_tick = if zero_for_one {
+			cursor.checked_sub(1).and_then(|idx| ticks.get(idx))
+		} else {
+			ticks.get(cursor)
+		};
+		let sqrt_price_target = match next_tick {
+			Some(t) => sqrt_price_at_tick(t.tick)?,
+			None if zero_for_one => MIN_SQRT_PRICE,
+			None => MAX_SQRT_PRICE,
+		};
+
+		let step = calculate_swap_step(
+			current.sqrt_price,
+			sqrt_price_target,
+			current.liquidity,
+			amount_remaining,
+			exact_in,
+			fee,
+		)?;
+
+		let step_in = step.amount_in.checked_add(step.fee_amount).ok_or(Overflow)?;
+		let step_spent = if exact_in { step_in } else { step.amount_out };
+		amount_remaining = amount_remaining.checked_sub(step_spent).ok_or(Overflow)?;
+		amount_in = amount_in.checked_add(step_in).ok_or(Overflow)?;
+		amount_out = amount_out.checked_add(step.amount_out).ok_or(Overflow)?;
+		fee_amount = fee_amount.checked_add(step.fee_amount).ok_or(Overflow)?;
+		fee_growth_delta = fee_growth_delta
+			.overflowing_add(calculate_fee_growth(step.fee_amount, current.liquidity))
+			.0;
+		current.sqrt_price = step.sqrt_price_next;
+
+		if step.sqrt_price_next == sqrt_price_target {
+			match next_tick {
+				Some(t) => {
+					crossed_ticks.push((t.tick, fee_growth_delta));
+					if zero_for_one {
+						current.liquidity =
+							add_liquidity_delta(current.liquidity, t.liquidity_net.checked_neg().ok_or(Overflow)?)?;
+						current.tick = t.tick - 1;
+						cursor -= 1;
+					} else {
+						current.liquidity = add_liquidity_delta(current.liquidity, t.liquidity_net)?;
+						current.tick = t.tick;
+						cursor += 1;
+					}
+				}
+				// price reached the boundary and there is no more liquidity
+				None if !amount_remaining.is_zero() => return Err(InsufficientOutReserve),
+				None => current.tick = tick_at_sqrt_price(current.sqrt_price.min(MAX_SQRT_PRICE - 1))?,
+			}
+		} else {
+			current.tick = tick_at_sqrt_price(current.sqrt_price)?;
+		}
+	}
+
+	Ok(SwapResult {
+		amount_in,
+		amount_out,
+		fee_amount,
+		state: current,
+		fee_growth_delta,
+		crossed_ticks,
+	})
+}
+
+/// Calculate amount out when `amount_in` (including fee) is sold to the pool.
+///
+/// `ticks` are initialized ticks sorted in ascending order.
+/// Asset 0 is sold when `zero_for_one` is true, asset 1 otherwise.
+///
+/// Returns `InsufficientOutReserve` if there is not enough liquidity to sell the whole amount.
+pub fn calculate_out_given_in(
+	state: &PoolState,
+	ticks: &[TickLiquidity],
+	zero_for_one: bool,
+	amount_in: Balance,
+	fee: Permill,
+) -> Result<SwapResult, MathError> {
+	swap(state, ticks, zero_for_one, amount_in, true, fee)
+}
+
+/// Calculate amount in (including fee) required to buy `amount_out` from the pool.
+///
+/// `ticks` are initialized ticks sorted in ascending order.
+/// Asset 0 is sold when `zero_for_one` is true, asset 1 otherwise.
+///
+/// Returns `InsufficientOutReserve` if there is not enough liquidity to buy the whole amount.
+pub fn calculate_in_given_out(
+	state: &PoolState,
+	ticks: &[TickLiquidity],
+	zero_for_one: bool,
+	amount_out: Balance,
+	fee: Permill,
+) -> Result<SwapResult, MathError> {
+	swap(state, ticks, zero_for_one, amount_out, false, fee)
+}
+
+/// Calculate fee growth outside of a tick when the tick is initialized.
+///
+/// By convention, all growth before a tick was initialized happened below the tick.
+pub fn calculate_initial_fee_growth_outside(tick: Tick, current_tick: Tick, fee_growth_global: FeeGrowth) -> FeeGrowth {
+	if tick <= current_tick {
+		fee_growth_global
+	} else {
+		FeeGrowth::zero()
+	}
+}
+
+/// Calculate fee growth outside of a tick after the price crosses the tick.
+pub fn calculate_fee_growth_outside_after_cross(
+	fee_growth_global: FeeGrowth,
+	fee_growth_outside: FeeGrowth,
+) -> FeeGrowth {
+	fee_growth_global.overflowing_sub(fee_growth_outside).0
+}
+
+/// Calculate fee growth inside range `[tick_lower, tick_upper)`.
+pub fn calculate_fee_growth_inside(
+	tick_lower: Tick,
+	tick_upper: Tick,
+	current_tick: Tick,
+	fee_growth_global: FeeGrowth,
+	fee_growth_outside_lower: FeeGrowth,
+	fee_growth_outside_upper: FeeGrowth,
+) -> FeeGrowth {
+	let fee_growth_below = if current_tick >= tick_lower {
+		fee_growth_outside_lower
+	} else {
+		fee_growth_global.overflowing_sub(fee_growth_outside_lower).0
+	};
+	let fee_growth_above = if current_tick < tick_upper {
+		fee_growth_outside_upper
+	} else {
+		fee_growth_global.overflowing_sub(fee_growth_outside_upper).0
+	};
+
+	fee_growth_global
+		.overflowing_sub(fee_growth_below)
+		.0
+		.overflowing_sub(fee_growth_above)
+		.0
+}
+
+/// Calculate fees accrued by a position since fee growth inside its range was last recorded.
+///
+/// Formula: `(fee_growth_inside - fee_growth_inside_last) * L / 2^128`, rounded down.
+pub fn calculate_accrued_fees(
+	liquidity: Liquidity,
+	fee_growth_inside: FeeGrowth,
+	fee_growth_inside_last: FeeGrowth,
+) -> Result<Balance, MathError> {
+	let delta = fee_growth_inside.overflowing_sub(fee_growth_inside_last).0;
+	let fees = mul_div(delta, U256::from(liquidity), U256::one() << 128, false)?;
+	to_balance!(fees)
+}
@@ -0,0 +1,295 @@
+use crate::concentrated::types::*;
+use crate::concentrated::*;
+use crate::MathError::{InsufficientOutReserve, PriceOutOfRange, TickOutOfRange};
+use primitive_types::U256;
+use sp_arithmetic::Permill;
+
+const ONE: u128 = 1_000_000_000_000;
+
+fn state_at_tick(tick: Tick, liquidity: Liquidity) -> PoolState {
+	PoolState {
+		sqrt_price: sqrt_price_at_tick(tick).unwrap(),
+		tick,
+		liquidity,
+	}
+}
+
+#[test]
+fn sqrt_price_at_tick_should_work() {
+	assert_eq!(sqrt_price_at_tick(0), Ok(q96()));
+	assert_eq!(sqrt_price_at_tick(MIN_TICK), Ok(MIN_SQRT_PRICE));
+	assert_eq!(sqrt_price_at_tick(MAX_TICK), Ok(MAX_SQRT_PRICE));
+	// 1.0001^6932 ~= 2.000036
+	let sqrt_two = sqrt_price_at_tick(6932).unwrap();
+	let (n, d) = calculate_spot_price(sqrt_two);
+	assert_eq!(n / (d / 1_000_000), 2_000_036);
+}
+
+#[test]
+fn sqrt_price_at_tick_should_fail_when_tick_is_out_of_range() {
+	assert_eq!(sqrt_price_at_tick(MIN_TICK - 1), Err(TickOutOfRange));
+	assert_eq!(sqrt_price_at_tick(MAX_TICK + 1), Err(TickOutOfRange));
+}
+
+#[test]
+fn tick_at_sqrt_price_should_work() {
+	assert_eq!(tick_at_sqrt_price(q96()), Ok(0));
+	assert_eq!(tick_at_sqrt_price(q96() - 1), Ok(-1));
+	assert_eq!(tick_at_sqrt_price(MIN_SQRT_PRICE), Ok(MIN_TICK));
+	assert_eq!(tick_at_sqrt_price(MAX_SQRT_PRICE - 1), Ok(MAX_TICK - 1));
+}
+
+#[test]
+fn tick_at_sqrt_price_should_fail_when_price_is_out_of_range() {
+	assert_eq!(tick_at_sqrt_price(MIN_SQRT_PRICE - 1), Err(PriceOutOfRange));
+	assert_eq!(tick_at_sqrt_price(MAX_SQRT_PRICE), Err(PriceOutOfRange));
+}
+
+#[test]
+fn tick_at_sqrt_price_should_return_tick_when_price_is_exactly_at_tick() {
+	for tick in [MIN_TICK, -500_000, -6932, -1, 0, 1, 60, 6932, 500_000, MAX_TICK - 1] {
+		assert_eq!(tick_at_sqrt_price(sqrt_price_at_tick(tick).unwrap()), Ok(tick));
+	}
+}
+
+#[test]
+fn amount_deltas_should_be_correct_when_price_range_is_one_to_four() {
+	let sqrt_price_a = q96();
+	let sqrt_price_b = q96() * 2;
+	let liquidity = 1_000_000 * ONE;
+
+	assert_eq!(
+		calculate_amount_0_delta(sqrt_price_a, sqrt_price_b, liquidity, false),
+		Ok(500_000 * ONE)
+	);
+	assert_eq!(
+		calculate_amount_1_delta(sqrt_price_a, sqrt_price_b, liquidity, false),
+		Ok(1_000_000 * ONE)
+	);
+	// order of prices does not matter
+	assert_eq!(
+		calculate_amount_0_delta(sqrt_price_b, sqrt_price_a, liquidity, true),
+		Ok(500_000 * ONE)
+	);
+}
+
+#[test]
+fn amounts_for_liquidity_should_depend_on_current_price() {
+	let lower = q96();
+	let upper = q96() * 2;
+	let liquidity = 1_000_000 * ONE;
+
+	assert_eq!(
+		calculate_amounts_for_liquidity(lower - 1, lower, upper, liquidity, false),
+		Ok((500_000 * ONE, 0))
+	);
+	assert_eq!(
+		calculate_amounts_for_liquidity(upper, lower, upper, liquidity, false),
+		Ok((0, 1_000_000 * ONE))
+	);
+
+	// price 2.25
+	let current = q96() * 3 / 2;
+	let (amount_0, amount_1) = calculate_amounts_for_liquidity(current, lower, upper, liquidity, false).unwrap();
+	assert_eq!(amount_0, 166_666_666_666_666_666);
+	assert_eq!(amount_1, 500_000 * ONE);
+}
+
+#[test]
+fn liquidity_for_amounts_should_be_inverse_of_amounts_for_liquidity() {
+	let lower = q96();
+	let upper = q96() * 2;
+	let current = q96() * 3 / 2;
+	let liquidity = 1_000_000 * ONE;
+
+	let (amount_0, amount_1) = calculate_amounts_for_liquidity(current, lower, upper, liquidity, false).unwrap();
+	let result = calculate_liquidity_for_amounts(current, lower, upper, amount_0, amount_1).unwrap();
+
+	assert!(result <= liquidity);
+	assert!(liquidity - result <= 10);
+}
+
+#[test]
+fn liquidity_for_amounts_should_be_limited_by_scarce_asset() {
+	let lower = q96();
+	let upper = q96() * 2;
+	let current = q96() * 3 / 2;
+
+	let liquidity = calculate_liquidity_for_amounts(current, lower, upper, 1_000 * ONE, 1_000_000 * ONE).unwrap();
+	let (amount_0, amount_1) = calculate_amounts_for_liquidity(current, lower, upper, liquidity, true).unwrap();
+
+	assert!(amount_0 <= 1_000 * ONE);
+	assert!(amount_1 < 1_000_000 * ONE);
+}
+
+#[test]
+fn out_given_in_should_match_constant_product_when_no_tick_is_crossed() {
+	let liquidity = 1_000_000 * ONE;
+	let state = state_at_tick(0, liquidity);
+	let amount_in = 1_000 * ONE;
+
+	let result = calculate_out_given_in(&state, &[], true, amount_in, Permill::zero()).unwrap();
+
+	// virtual reserves are x = y = L at price 1
+	let expected = crate::xyk::calculate_out_given_in(liquidity, liquidity, amount_in).unwrap();
+	assert_eq!(result.amount_in, amount_in);
+	assert!(result.amount_out <= expected);
+	assert!(expected - result.amount_out <= 1);
+	assert!(result.state.sqrt_price < state.sqrt_price);
+	assert_eq!(result.state.tick, tick_at_sqrt_price(result.state.sqrt_price).unwrap());
+	assert!(result.crossed_ticks.is_empty());
+}
+
+#[test]
+fn out_given_in_should_charge_fee_when_fee_is_set() {
+	let state = state_at_tick(0, 1_000_000 * ONE);
+	let amount_in = 1_000 * ONE;
+
+	let no_fee = calculate_out_given_in(&state, &[], false, amount_in, Permill::zero()).unwrap();
+	let with_fee = calculate_out_given_in(&state, &[], false, amount_in, Permill::from_percent(1)).unwrap();
+
+	assert_eq!(with_fee.amount_in, amount_in);
+	assert_eq!(with_fee.fee_amount, 10 * ONE);
+	assert!(with_fee.amount_out < no_fee.amount_out);
+	assert_eq!(
+		with_fee.fee_growth_delta,
+		calculate_fee_growth(with_fee.fee_amount, state.liquidity)
+	);
+}
+
+#[test]
+fn out_given_in_should_cross_ticks_when_range_is_exhausted() {
+	// position 1: [-60, 60] with liquidity 1_000 * ONE, position 2: [60, 6000] with liquidity 5_000 * ONE
+	let ticks = [
+		TickLiquidity::new(-60, 1_000 * ONE as i128),
+		TickLiquidity::new(60, 4_000 * ONE as i128),
+		TickLiquidity::new(6000, -(5_000 * ONE as i128)),
+	];
+	let state = state_at_tick(0, 1_000 * ONE);
+
+	let result = calculate_out_given_in(&state, &ticks, false, 100 * ONE, Permill::zero()).unwrap();
+
+	assert_eq!(result.crossed_ticks.len(), 1);
+	assert_eq!(result.crossed_ticks[0].0, 60);
+	assert_eq!(result.state.liquidity, 5_000 * ONE);
+	assert!(result.state.tick >= 60);
+	assert!(result.state.tick < 6000);
+}
+
+#[test]
+fn out_given_in_should_cross_ticks_when_selling_asset_0() {
+	let ticks = [
+		TickLiquidity::new(-6000, 5_000 * ONE as i128),
+		TickLiquidity::new(-60, -(4_000 * ONE as i128)),
+		TickLiquidity::new(60, -(1_000 * ONE as i128)),
+	];
+	let state = state_at_tick(0, 1_000 * ONE);
+
+	let result = calculate_out_given_in(&state, &ticks, true, 100 * ONE, Permill::zero()).unwrap();
+
+	assert_eq!(result.crossed_ticks.len(), 1);
+	assert_eq!(result.crossed_ticks[0].0, -60);
+	assert_eq!(result.state.liquidity, 5_000 * ONE);
+	assert!(result.state.tick < -60);
+	assert!(result.state.tick >= -6000);
+}
+
+#[test]
+fn out_given_in_should_fail_when_liquidity_is_insufficient() {
+	let ticks = [
+		TickLiquidity::new(-60, 1_000 * ONE as i128),
+		TickLiquidity::new(60, -(1_000 * ONE as i128)),
+	];
+	let state = state_at_tick(0, 1_000 * ONE);
+
+	assert_eq!(
+		calculate_out_given_in(&state, &ticks, true, 100 * ONE, Permill::zero()),
+		Err(InsufficientOutReserve)
+	);
+}
+
+#[test]
+fn in_given_out_should_return_exact_amount_out() {
+	let ticks = [
+		TickLiquidity::new(-60, 1_000 * ONE as i128),
+		TickLiquidity::new(60, 4_000 * ONE as i128),
+		TickLiquidity::new(6000, -(5_000 * ONE as i128)),
+	];
+	let state = state_at_tick(0, 1_000 * ONE);
+
+	let result = calculate_in_given_out(&state, &ticks, false, 50 * ONE, Permill::from_float(0.003)).unwrap();
+
+	assert_eq!(result.amount_out, 50 * ONE);
+	assert_eq!(result.crossed_ticks.len(), 1);
+
+	let sell = calculate_out_given_in(&state, &ticks, false, result.amount_in, Permill::from_float(0.003)).unwrap();
+	assert!(sell.amount_out >= 50 * ONE);
+}
+
+#[test]
+fn in_given_out_should_fail_when_liquidity_is_insufficient() {
+	let ticks = [
+		TickLiquidity::new(-60, 1_000 * ONE as i128),
+		TickLiquidity::new(60, -(1_000 * ONE as i128)),
+	];
+	let state = state_at_tick(0, 1_000 * ONE);
+
+	assert_eq!(
+		calculate_in_given_out(&state, &ticks, true, 1_000 * ONE, Permill::zero()),
+		Err(InsufficientOutReserve)
+	);
+}
+
+#[test]
+fn fee_growth_inside_should_only_include_fees_accrued_in_range() {
+	let global = U256::from(1_000);
+
+	// price is below the range
+	assert_eq!(
+		calculate_fee_growth_inside(-60, 60, -100, global, U256::from(100), U256::from(50)),
+		U256::zero()
+			.overflowing_add(global)
+			.0
+			.overflowing_sub(global - U256::from(100))
+			.0
+			.overflowing_sub(U256::from(50))
+			.0
+	);
+
+	// price is in the range
+	assert_eq!(
+		calculate_fee_growth_inside(-60, 60, 0, global, U256::from(100), U256::from(50)),
+		U256::from(850)
+	);
+
+	// price is above the range
+	assert_eq!(
+		calculate_fee_growth_inside(-60, 60, 100, global, U256::from(100), U256::from(950)),
+		U256::from(850)
+	);
+}
+
+#[test]
+fn accrued_fees_should_be_proportional_to_liquidity() {
+	let fee_growth = calculate_fee_growth(1_000 * ONE, 4_000 * ONE);
+
+	assert_eq!(
+		calculate_accrued_fees(1_000 * ONE, fee_growth, U256::zero()),
+		Ok(250 * ONE)
+	);
+	assert_eq!(calculate_accrued_fees(4_000 * ONE, fee_growth, fee_growth), Ok(0));
+}
+
+#[test]
+fn fee_growth_outside_should_flip_when_tick_is_crossed() {
+	let global = U256::from(1_000);
+	let outside = calculate_initial_fee_growth_outside(-60, 0, global);
+	assert_eq!(outside, global);
+	assert_eq!(calculate_initial_fee_growth_outside(60, 0, global), U256::zero());
+
+	let new_global = U256::from(1_500);
+	assert_eq!(
+		calculate_fee_growth_outside_after_cross(new_global, outside),
+		U256::from(500)
+	);
+}
@@ -0,0 +1,64 @@
+use crate::types::Balance;
+use primitive_types::U256;
+use sp_std::vec::Vec;
+
+/// Tick index. Price at tick `i` is `1.0001^i`.
+pub type Tick = i32;
+
+/// Square root of price as Q64.96 fixed point number.
+pub type SqrtPrice = U256;
+
+/// Virtual liquidity `L = sqrt(x * y)` of a position or a pool.
+pub type Liquidity = u128;
+
+/// Accumulated fees per unit of liquidity as Q128.128 fixed point number.
+///
+/// Values are intended to wrap on overflow - only differences of fee growth values are meaningful.
+pub type FeeGrowth = U256;
+
+/// Liquidity change when price crosses an initialized tick from left to right.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TickLiquidity {
+	pub tick: Tick,
+	pub liquidity_net: i128,
+}
+
+impl TickLiquidity {
+	pub fn new(tick: Tick, liquidity_net: i128) -> Self {
+		Self { tick, liquidity_net }
+	}
+}
+
+/// Current price and in-range liquidity of a pool.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PoolState {
+	pub sqrt_price: SqrtPrice,
+	/// Greatest tick whose price is lower than or equal to the current price.
+	pub tick: Tick,
+	pub liquidity: Liquidity,
+}
+
+/// Result of a single swap step within a price range of constant liquidity.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SwapStep {
+	pub sqrt_price_next: SqrtPrice,
+	/// Amount in without fee.
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	pub fee_amount: Balance,
+}
+
+/// Result of a swap across ticks.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapResult {
+	/// Amount in, including fee.
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	pub fee_amount: Balance,
+	/// Pool state after the swap.
+	pub state: PoolState,
+	/// Fee growth of the asset in accumulated during the swap.
+	pub fee_growth_delta: FeeGrowth,
+	/// Crossed ticks with the fee growth delta accumulated before each tick was crossed.
+	pub crossed_ticks: Vec<(Tick, FeeGrowth)>,
+}
@@ -15,6 +15,7 @@ extern crate approx;
 #[cfg(test)]
 mod test_pow_accuracy;
 
+pub mod concentrated;
 pub mod dynamic_fees;
 pub mod ema;
 pub mod fee;
@@ -31,6 +32,7 @@ pub mod support;
 #[cfg(test)]
 pub mod test_utils;
 pub mod transcendental;
+pub mod twap;
 pub mod types;
 pub mod xyk;
 
@@ -89,6 +91,8 @@ pub enum MathError {
 	ZeroReserve,
 	ZeroDuration,
 	DivisionByZero,
+	TickOutOfRange,
+	PriceOutOfRange,
 }
 
 #[cfg(test)]
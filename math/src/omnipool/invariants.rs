@@ -4,7 +4,7 @@ use crate::omnipool::*;
 use crate::to_balance;
 use crate::types::Balance;
 use crate::MathError::Overflow;
-use primitive_types::U256;
+use primitive_types::{U256, U512};
 use proptest::prelude::*;
 use sp_arithmetic::{traits::Zero, FixedPointNumber, FixedU128, Permill};
 
@@ -613,3 +613,51 @@ proptest! {
 		}
 	}
 }
+
+fn position_with_price() -> impl Strategy<Value = Position<Balance>> {
+	(1..u128::MAX / 2, ONE..u128::MAX, ONE..u128::MAX).prop_map(|(shares, price_n, price_d)| Position {
+		amount: shares,
+		shares,
+		price: (price_n, price_d),
+	})
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn merged_position_price_should_be_rounded_up_and_bounded_by_position_prices(
+		position in position_with_price(),
+		other_position in position_with_price(),
+	) {
+		let merged_price = calculate_merged_position_price(&position, &other_position).unwrap();
+
+		let (n, d) = (U512::from(merged_price.0), U512::from(merged_price.1));
+		let (n1, d1, s1) = (U512::from(position.price.0), U512::from(position.price.1), U512::from(position.shares));
+		let (n2, d2, s2) = (U512::from(other_position.price.0), U512::from(other_position.price.1), U512::from(other_position.shares));
+
+		// Merged price is never lower than share weighted average
+		// n / d >= (n1 * d2 * s1 + n2 * d1 * s2) / (d1 * d2 * (s1 + s2))
+		assert!(n * d1 * d2 * (s1 + s2) >= (n1 * d2 * s1 + n2 * d1 * s2) * d);
+
+		// Merged price is between prices of the positions
+		let (min_price, max_price) = if n1 * d2 <= n2 * d1 { ((n1, d1), (n2, d2)) } else { ((n2, d2), (n1, d1)) };
+		assert!(n * min_price.1 >= min_price.0 * d);
+		assert!(n * max_price.1 <= max_price.0 * d);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn split_position_amount_should_be_rounded_down(
+		position in position(),
+		split_ratio in 1u128..100u128,
+	) {
+		let shares = position.shares * split_ratio / 100;
+		let amount = calculate_split_position_amount(&position, shares).unwrap();
+
+		assert!(amount <= position.amount);
+		// amount / shares <= position amount / position shares
+		assert!(U256::from(amount) * U256::from(position.shares) <= U256::from(position.amount) * U256::from(shares));
+	}
+}
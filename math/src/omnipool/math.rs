@@ -3,11 +3,12 @@ use crate::omnipool::types::{
 	AssetReserveState, AssetStateChange, BalanceUpdate, HubTradeStateChange, LiquidityStateChange, Position, TradeFee,
 	TradeStateChange, I129,
 };
+use crate::support::rational::{round_u512_to_rational, Rounding};
 use crate::types::Balance;
 use crate::MathError::Overflow;
 use crate::{to_balance, to_u256};
 use num_traits::{CheckedDiv, CheckedMul, CheckedSub, One, Zero};
-use primitive_types::U256;
+use primitive_types::{U256, U512};
 use sp_arithmetic::traits::Saturating;
 use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
 use sp_std::cmp::min;
@@ -407,6 +408,82 @@ pub fn calculate_remove_liquidity_state_changes(
 	})
 }
 
+/// Calculate price of a position created by merging two positions of the same asset.
+///
+/// Merged price is share-weighted average of the positions' prices.
+/// If the average cannot be represented exactly, it is rounded up, so it is never lower than the exact average,
+/// but it never exceeds the higher of the two prices.
+pub fn calculate_merged_position_price(
+	position: &Position<Balance>,
+	other_position: &Position<Balance>,
+) -> Option<(Balance, Balance)> {
+	let (price_n, price_d, shares) = (
+		U512::from(position.price.0),
+		U512::from(position.price.1),
+		U512::from(position.shares),
+	);
+	let (other_price_n, other_price_d, other_shares) = (
+		U512::from(other_position.price.0),
+		U512::from(other_position.price.1),
+		U512::from(other_position.shares),
+	);
+
+	if price_d.is_zero() || other_price_d.is_zero() {
+		return None;
+	}
+
+	let cross = price_n.checked_mul(other_price_d)?;
+	let other_cross = other_price_n.checked_mul(price_d)?;
+
+	if cross == other_cross {
+		return Some(position.price);
+	}
+
+	let numerator = cross
+		.checked_mul(shares)?
+		.checked_add(other_cross.checked_mul(other_shares)?)?;
+	let denominator = price_d
+		.checked_mul(other_price_d)?
+		.checked_mul(shares.checked_add(other_shares)?)?;
+
+	if denominator.is_zero() {
+		return None;
+	}
+
+	let merged_price = round_u512_to_rational((numerator, denominator), Rounding::Up);
+
+	let max_price = if cross > other_cross {
+		position.price
+	} else {
+		other_position.price
+	};
+
+	// Rounding up must not move the price above the higher of the two prices.
+	let exceeds_max_price = U512::from(merged_price.0).checked_mul(U512::from(max_price.1))?
+		> U512::from(max_price.0).checked_mul(U512::from(merged_price.1))?;
+
+	if exceeds_max_price {
+		Some(max_price)
+	} else {
+		Some(merged_price)
+	}
+}
+
+/// Calculate amount of a position which is moved to a new position when `shares` are split from the position.
+///
+/// Amount is rounded down, the rest of the amount remains in the original position, so total amount is preserved.
+pub fn calculate_split_position_amount(position: &Position<Balance>, shares: Balance) -> Option<Balance> {
+	if shares > position.shares {
+		return None;
+	}
+
+	let (amount_hp, shares_hp, position_shares_hp) = to_u256!(position.amount, shares, position.shares);
+
+	let amount_hp = amount_hp.checked_mul(shares_hp)?.checked_div(position_shares_hp)?;
+
+	to_balance!(amount_hp).ok()
+}
+
 pub fn calculate_tvl(hub_reserve: Balance, stable_asset: (Balance, Balance)) -> Option<Balance> {
 	let (hub_reserve_hp, stable_reserve_hp, stable_hub_reserve_hp) =
 		to_u256!(hub_reserve, stable_asset.0, stable_asset.1);
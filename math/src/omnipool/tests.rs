@@ -1,13 +1,13 @@
 use crate::omnipool::types::{AssetReserveState, BalanceUpdate, Position, TradeFee, I129};
 use crate::omnipool::{
 	calculate_add_liquidity_state_changes, calculate_buy_for_hub_asset_state_changes, calculate_buy_state_changes,
-	calculate_cap_difference, calculate_delta_imbalance, calculate_fee_amount_for_buy,
+	calculate_cap_difference, calculate_delta_imbalance, calculate_fee_amount_for_buy, calculate_merged_position_price,
 	calculate_remove_liquidity_state_changes, calculate_sell_hub_state_changes, calculate_sell_state_changes,
-	calculate_tvl_cap_difference, calculate_withdrawal_fee, verify_asset_cap,
+	calculate_split_position_amount, calculate_tvl_cap_difference, calculate_withdrawal_fee, verify_asset_cap,
 };
 use crate::types::Balance;
 use num_traits::{One, Zero};
-use sp_arithmetic::{FixedU128, Permill};
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
 use std::str::FromStr;
 
 const UNIT: Balance = 1_000_000_000_000;
@@ -1171,3 +1171,93 @@ fn calculate_buy_should_charge_more_when_fee_is_not_zero() {
 		}
 	);
 }
+
+#[test]
+fn calculate_merged_position_price_should_keep_price_when_prices_are_same() {
+	let position = Position {
+		amount: 100 * UNIT,
+		shares: 100 * UNIT,
+		price: (2 * UNIT, 4 * UNIT),
+	};
+	let other_position = Position {
+		amount: 300 * UNIT,
+		shares: 250 * UNIT,
+		price: (UNIT, 2 * UNIT),
+	};
+
+	assert_eq!(
+		calculate_merged_position_price(&position, &other_position),
+		Some((2 * UNIT, 4 * UNIT))
+	);
+}
+
+#[test]
+fn calculate_merged_position_price_should_be_share_weighted_average() {
+	let position = Position {
+		amount: 100 * UNIT,
+		shares: 100,
+		price: (1, 1),
+	};
+	let other_position = Position {
+		amount: 300 * UNIT,
+		shares: 300,
+		price: (3, 1),
+	};
+
+	// (1 * 100 + 3 * 300) / 400
+	assert_eq!(
+		calculate_merged_position_price(&position, &other_position),
+		Some((1000, 400))
+	);
+}
+
+#[test]
+fn calculate_merged_position_price_should_round_up_when_price_is_not_exact() {
+	let position = Position {
+		amount: 100 * UNIT,
+		shares: u128::MAX / 3,
+		price: (u128::MAX / 7, u128::MAX / 5),
+	};
+	let other_position = Position {
+		amount: 300 * UNIT,
+		shares: u128::MAX / 2,
+		price: (u128::MAX / 3, u128::MAX / 11),
+	};
+
+	let (n, d) = calculate_merged_position_price(&position, &other_position).unwrap();
+
+	let merged = FixedU128::checked_from_rational(n, d).unwrap();
+	let price = FixedU128::checked_from_rational(position.price.0, position.price.1).unwrap();
+	let other_price = FixedU128::checked_from_rational(other_position.price.0, other_position.price.1).unwrap();
+	assert!(merged > price);
+	assert!(merged < other_price);
+}
+
+#[test]
+fn calculate_merged_position_price_should_fail_when_price_is_invalid() {
+	let position = Position {
+		amount: 100 * UNIT,
+		shares: 100 * UNIT,
+		price: (1, 0),
+	};
+	let other_position = Position {
+		amount: 300 * UNIT,
+		shares: 300 * UNIT,
+		price: (3, 1),
+	};
+
+	assert_eq!(calculate_merged_position_price(&position, &other_position), None);
+}
+
+#[test]
+fn calculate_split_position_amount_should_round_down() {
+	let position = Position {
+		amount: 1000,
+		shares: 300,
+		price: (1, 1),
+	};
+
+	assert_eq!(calculate_split_position_amount(&position, 100), Some(333));
+	assert_eq!(calculate_split_position_amount(&position, 300), Some(1000));
+	assert_eq!(calculate_split_position_amount(&position, 301), None);
+}
@@ -4,7 +4,7 @@ use crate::to_u256;
 use crate::types::Balance;
 use num_traits::{CheckedDiv, CheckedMul, One, Zero};
 use primitive_types::U256;
-use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
+use sp_arithmetic::{traits::SaturatedConversion, FixedPointNumber, FixedU128, Permill};
 use sp_std::ops::Div;
 use sp_std::prelude::*;
 
@@ -33,21 +33,11 @@ pub fn calculate_out_given_in<const D: u8, const Y: u8>(
 	if idx_in >= initial_reserves.len() || idx_out >= initial_reserves.len() {
 		return None;
 	}
-	let reserves = normalize_reserves(initial_reserves);
-	let amount_in = normalize_value(
-		amount_in,
-		initial_reserves[idx_in].decimals,
-		TARGET_PRECISION,
-		Rounding::Up,
-	);
+	let reserves = normalize_reserves(initial_reserves)?;
+	let amount_in = normalize_amount(amount_in, &initial_reserves[idx_in], Rounding::Up)?;
 	let new_reserve_out = calculate_y_given_in::<D, Y>(amount_in, idx_in, idx_out, &reserves, amplification)?;
 	let amount_out = reserves[idx_out].checked_sub(new_reserve_out)?;
-	let amount_out = normalize_value(
-		amount_out,
-		TARGET_PRECISION,
-		initial_reserves[idx_out].decimals,
-		Rounding::Down,
-	);
+	let amount_out = denormalize_amount(amount_out, &initial_reserves[idx_out], Rounding::Down)?;
 	Some(amount_out.saturating_sub(1u128))
 }
 
@@ -67,21 +57,11 @@ pub fn calculate_in_given_out<const D: u8, const Y: u8>(
 	if idx_in >= initial_reserves.len() || idx_out >= initial_reserves.len() {
 		return None;
 	}
-	let reserves = normalize_reserves(initial_reserves);
-	let amount_out = normalize_value(
-		amount_out,
-		initial_reserves[idx_out].decimals,
-		TARGET_PRECISION,
-		Rounding::Down,
-	);
+	let reserves = normalize_reserves(initial_reserves)?;
+	let amount_out = normalize_amount(amount_out, &initial_reserves[idx_out], Rounding::Down)?;
 	let new_reserve_in = calculate_y_given_out::<D, Y>(amount_out, idx_in, idx_out, &reserves, amplification)?;
 	let amount_in = new_reserve_in.checked_sub(reserves[idx_in])?;
-	let amount_in = normalize_value(
-		amount_in,
-		TARGET_PRECISION,
-		initial_reserves[idx_in].decimals,
-		Rounding::Up,
-	);
+	let amount_in = denormalize_amount(amount_in, &initial_reserves[idx_in], Rounding::Up)?;
 	Some(amount_in.saturating_add(1u128))
 }
 
@@ -160,10 +140,7 @@ pub fn calculate_shares<const D: u8>(
 				let ideal_balance = d1.checked_mul(initial_reserve)?.checked_div(d0)?;
 				let diff = Balance::try_from(updated_reserve.abs_diff(ideal_balance)).ok()?;
 				let fee_amount = fee.checked_mul_int(diff)?;
-				Some(AssetReserve::new(
-					asset_reserve.amount.saturating_sub(fee_amount),
-					asset_reserve.decimals,
-				))
+				Some(asset_reserve.with_amount(asset_reserve.amount.saturating_sub(fee_amount)))
 			})
 			.collect::<Option<Vec<AssetReserve>>>()?
 	} else {
@@ -207,7 +184,7 @@ pub fn calculate_shares_for_amount<const D: u8>(
 		.enumerate()
 		.map(|(idx, v)| -> Option<AssetReserve> {
 			if idx == asset_idx {
-				Some(AssetReserve::new(v.amount.checked_sub(amount)?, v.decimals))
+				Some(v.with_amount(v.amount.checked_sub(amount)?))
 			} else {
 				Some(*v)
 			}
@@ -225,10 +202,7 @@ pub fn calculate_shares_for_amount<const D: u8>(
 			let ideal_balance = d1.checked_mul(initial_reserve)?.checked_div(d0)?;
 			let diff = Balance::try_from(updated_reserve.abs_diff(ideal_balance)).ok()?;
 			let fee_amount = fee.checked_mul_int(diff)?;
-			Some(AssetReserve::new(
-				asset_reserve.amount.saturating_sub(fee_amount),
-				asset_reserve.decimals,
-			))
+			Some(asset_reserve.with_amount(asset_reserve.amount.saturating_sub(fee_amount)))
 		})
 		.collect::<Option<Vec<AssetReserve>>>()?;
 
@@ -267,8 +241,8 @@ pub fn calculate_withdraw_one_asset<const D: u8, const Y: u8>(
 	if n_coins <= 1 {
 		return None;
 	}
-	let asset_out_decimals = reserves[asset_index].decimals;
-	let reserves = normalize_reserves(reserves);
+	let asset_out = reserves[asset_index];
+	let reserves = normalize_reserves(reserves)?;
 
 	let fixed_fee = FixedU128::from(fee);
 	let fee = fixed_fee
@@ -320,11 +294,36 @@ pub fn calculate_withdraw_one_asset<const D: u8, const Y: u8>(
 	let dy_0 = reserves[asset_index].checked_sub(y)?;
 	let fee = dy_0.checked_sub(dy)?;
 
-	let amount_out = normalize_value(dy, TARGET_PRECISION, asset_out_decimals, Rounding::Down);
-	let fee = normalize_value(fee, TARGET_PRECISION, asset_out_decimals, Rounding::Down);
+	let amount_out = denormalize_amount(dy, &asset_out, Rounding::Down)?;
+	let fee = denormalize_amount(fee, &asset_out, Rounding::Down)?;
 	Some((amount_out, fee))
 }
 
+/// Given amount of shares and asset reserves, calculate amounts of all pool assets to be withdrawn.
+/// Each reserve is reduced by the same proportion as the share issuance, so no fee is applied.
+/// Amounts are rounded down in favour of the pool.
+pub fn calculate_liquidity_out(
+	reserves: &[AssetReserve],
+	shares: Balance,
+	share_asset_issuance: Balance,
+) -> Option<Vec<Balance>> {
+	if share_asset_issuance.is_zero() || shares > share_asset_issuance {
+		return None;
+	}
+
+	let (shares_hp, issuance_hp) = to_u256!(shares, share_asset_issuance);
+
+	reserves
+		.iter()
+		.map(|reserve| {
+			let amount = to_u256!(reserve.amount)
+				.checked_mul(shares_hp)?
+				.checked_div(issuance_hp)?;
+			Balance::try_from(amount).ok()
+		})
+		.collect()
+}
+
 /// Calculate amount of an asset that has to be added as liquidity to the pool in exchange of given amount of shares.
 pub fn calculate_add_one_asset<const D: u8, const Y: u8>(
 	reserves: &[AssetReserve],
@@ -348,8 +347,8 @@ pub fn calculate_add_one_asset<const D: u8, const Y: u8>(
 		return None;
 	}
 
-	let asset_in_decimals = reserves[asset_index].decimals;
-	let reserves = normalize_reserves(reserves);
+	let asset_in = reserves[asset_index];
+	let reserves = normalize_reserves(reserves)?;
 
 	let initial_d = calculate_d_internal::<D>(&reserves, amplification)?;
 	let (shares_hp, issuance_hp, d_hp) = to_u256!(shares, share_asset_issuance, initial_d);
@@ -399,12 +398,12 @@ pub fn calculate_add_one_asset<const D: u8, const Y: u8>(
 	let dy = y1.checked_sub(asset_reserve)?;
 	let dy_0 = y.checked_sub(asset_reserve)?;
 	let fee = dy.checked_sub(dy_0)?;
-	let amount_in = normalize_value(dy, TARGET_PRECISION, asset_in_decimals, Rounding::Up);
-	let fee = normalize_value(fee, TARGET_PRECISION, asset_in_decimals, Rounding::Down);
+	let amount_in = denormalize_amount(dy, &asset_in, Rounding::Up)?;
+	let fee = denormalize_amount(fee, &asset_in, Rounding::Down)?;
 	Some((amount_in, fee))
 }
 pub fn calculate_d<const D: u8>(reserves: &[AssetReserve], amplification: Balance) -> Option<Balance> {
-	let n_reserves = normalize_reserves(reserves);
+	let n_reserves = normalize_reserves(reserves)?;
 	calculate_d_internal::<D>(&n_reserves, amplification)
 }
 
@@ -602,6 +601,25 @@ pub fn calculate_amplification(
 	}
 }
 
+/// Calculate trade fee of a pool which fee is changing linearly from `initial_fee` to `final_fee`
+/// between `initial_block` and `final_block`.
+pub fn calculate_fee(
+	initial_fee: Permill,
+	final_fee: Permill,
+	initial_block: u128,
+	final_block: u128,
+	current_block: u128,
+) -> Permill {
+	let fee = calculate_amplification(
+		initial_fee.deconstruct().into(),
+		final_fee.deconstruct().into(),
+		initial_block,
+		final_block,
+		current_block,
+	);
+	Permill::from_parts(fee.saturated_into())
+}
+
 #[inline]
 fn has_converged(v0: U256, v1: U256, precision: U256) -> bool {
 	let diff = abs_diff(v0, v1);
@@ -619,6 +637,7 @@ fn abs_diff(d0: U256, d1: U256) -> U256 {
 	}
 }
 
+#[derive(Clone, Copy)]
 pub(crate) enum Rounding {
 	Down,
 	Up,
@@ -631,13 +650,40 @@ fn calculate_fee_amount(amount: Balance, fee: Permill, rounding: Rounding) -> Ba
 	}
 }
 
-pub(crate) fn normalize_reserves(reserves: &[AssetReserve]) -> Vec<Balance> {
+/// Convert reserves to target precision and scale them by asset rates.
+pub(crate) fn normalize_reserves(reserves: &[AssetReserve]) -> Option<Vec<Balance>> {
 	reserves
 		.iter()
-		.map(|v| normalize_value(v.amount, v.decimals, TARGET_PRECISION, Rounding::Down))
+		.map(|v| normalize_amount(v.amount, v, Rounding::Down))
 		.collect()
 }
 
+/// Convert amount of an asset to target precision and express it in the pool's peg using the asset's rate.
+pub(crate) fn normalize_amount(amount: Balance, reserve: &AssetReserve, rounding: Rounding) -> Option<Balance> {
+	let amount = normalize_value(amount, reserve.decimals, TARGET_PRECISION, rounding);
+	apply_rate(amount, reserve.rate, rounding)
+}
+
+/// Inverse of `normalize_amount` - convert amount expressed in the pool's peg back to the asset's units and decimals.
+pub(crate) fn denormalize_amount(amount: Balance, reserve: &AssetReserve, rounding: Rounding) -> Option<Balance> {
+	let (rate_n, rate_d) = reserve.rate;
+	let amount = apply_rate(amount, (rate_d, rate_n), rounding)?;
+	Some(normalize_value(amount, TARGET_PRECISION, reserve.decimals, rounding))
+}
+
+fn apply_rate(amount: Balance, (n, d): (Balance, Balance), rounding: Rounding) -> Option<Balance> {
+	if n == d && !d.is_zero() {
+		return Some(amount);
+	}
+	let (amount_hp, n_hp, d_hp) = to_u256!(amount, n, d);
+	let scaled = amount_hp.checked_mul(n_hp)?;
+	let result = match rounding {
+		Rounding::Down => scaled.checked_div(d_hp)?,
+		Rounding::Up => scaled.checked_add(d_hp.checked_sub(U256::one())?)?.checked_div(d_hp)?,
+	};
+	Balance::try_from(result).ok()
+}
+
 pub(crate) fn normalize_value(amount: Balance, decimals: u8, target_decimals: u8, rounding: Rounding) -> Balance {
 	if target_decimals == decimals {
 		return amount;
@@ -692,7 +738,7 @@ pub fn calculate_share_price<const D: u8>(
 	} else {
 		calculate_d::<D>(reserves, amplification)?
 	};
-	let n_reserves = normalize_reserves(reserves);
+	let n_reserves = normalize_reserves(reserves)?;
 
 	let c = n_reserves
 		.iter()
@@ -725,8 +771,17 @@ pub fn calculate_share_price<const D: u8>(
 		(num, denom)
 	};
 	let (num, denom) = round_to_rational((num, denom), crate::support::rational::Rounding::Down);
-	//dbg!(FixedU128::checked_from_rational(num, denom));
-	Some((num, denom))
+
+	// Normalized reserve is expressed in the pool's peg, convert the price back to units of the asset.
+	let (rate_n, rate_d) = reserves[asset_idx].rate;
+	if rate_n == rate_d {
+		return Some((num, denom));
+	}
+	let (num, denom, rate_n, rate_d) = to_u256!(num, denom, rate_n, rate_d);
+	Some(round_to_rational(
+		(num.checked_mul(rate_d)?, denom.checked_mul(rate_n)?),
+		crate::support::rational::Rounding::Down,
+	))
 }
 
 pub fn calculate_spot_price(
@@ -741,7 +796,7 @@ pub fn calculate_spot_price(
 	}
 	let ann = calculate_ann(n, amplification)?;
 
-	let mut n_reserves = normalize_reserves(reserves);
+	let mut n_reserves = normalize_reserves(reserves)?;
 
 	let x0 = n_reserves[0];
 	let xi = n_reserves[asset_idx];
@@ -757,8 +812,21 @@ pub fn calculate_spot_price(
 	let num = x0.checked_mul(ann.checked_mul(xi)?.checked_add(c)?)?;
 	let denom = xi.checked_mul(ann.checked_mul(x0)?.checked_add(c)?)?;
 
+	let (num, denom) = round_to_rational((num, denom), crate::support::rational::Rounding::Down);
+
+	// Normalized reserves are expressed in the pool's peg, convert the price back to units of the assets.
+	let (rate_0_n, rate_0_d) = reserves[0].rate;
+	let (rate_i_n, rate_i_d) = reserves[asset_idx].rate;
+	if rate_0_n == rate_0_d && rate_i_n == rate_i_d {
+		return Some((num, denom));
+	}
+	let (num, denom, rate_0_n, rate_0_d, rate_i_n, rate_i_d) =
+		to_u256!(num, denom, rate_0_n, rate_0_d, rate_i_n, rate_i_d);
 	Some(round_to_rational(
-		(num, denom),
+		(
+			num.checked_mul(rate_i_n)?.checked_mul(rate_0_d)?,
+			denom.checked_mul(rate_i_d)?.checked_mul(rate_0_n)?,
+		),
 		crate::support::rational::Rounding::Down,
 	))
 }
@@ -1,4 +1,5 @@
-use crate::stableswap::calculate_amplification;
+use crate::stableswap::{calculate_amplification, calculate_fee};
+use sp_arithmetic::Permill;
 
 #[test]
 fn calculate_amplification_should_short_circuit_when_future_and_initial_amp_are_equal() {
@@ -39,3 +40,26 @@ fn test_calculate_amplification_step_decrease_amplification() {
 		assert_eq!(result, 5000 - idx * 3);
 	}
 }
+
+#[test]
+fn calculate_fee_should_return_initial_fee_when_start_block_is_not_reached() {
+	let result = calculate_fee(Permill::from_percent(1), Permill::from_percent(3), 10, 110, 5);
+	assert_eq!(result, Permill::from_percent(1));
+}
+
+#[test]
+fn calculate_fee_should_return_final_fee_when_end_block_is_reached() {
+	let result = calculate_fee(Permill::from_percent(1), Permill::from_percent(3), 10, 110, 110);
+	assert_eq!(result, Permill::from_percent(3));
+}
+
+#[test]
+fn calculate_fee_should_interpolate_linearly_when_fee_is_changing() {
+	for idx in 0..100 {
+		let increasing = calculate_fee(Permill::from_percent(1), Permill::from_percent(3), 0, 100, idx);
+		assert_eq!(increasing, Permill::from_parts(10_000 + idx as u32 * 200));
+
+		let decreasing = calculate_fee(Permill::from_percent(3), Permill::from_percent(1), 0, 100, idx);
+		assert_eq!(decreasing, Permill::from_parts(30_000 - idx as u32 * 200));
+	}
+}
@@ -1,6 +1,7 @@
 use crate::stableswap::types::AssetReserve;
 use crate::stableswap::*;
 use crate::types::Balance;
+use primitive_types::U256;
 use proptest::prelude::*;
 use proptest::proptest;
 
@@ -415,3 +416,136 @@ proptest! {
 		assert!(diff <= 1000)
 	}
 }
+
+const RATE_PRECISION: Balance = 1_000_000_000_000_000_000;
+
+fn rate() -> impl Strategy<Value = (Balance, Balance)> {
+	(RATE_PRECISION..2 * RATE_PRECISION).prop_map(|n| (n, RATE_PRECISION))
+}
+
+fn rescale(amount: Balance, (n, d): (Balance, Balance)) -> Balance {
+	let (amount, n, d) = crate::to_u256!(amount, n, d);
+	(amount * n / d).as_u128()
+}
+
+fn some_pool_with_rates(size: usize) -> impl Strategy<Value = Vec<AssetReserve>> {
+	prop::collection::vec(
+		(asset_reserve(), decimals(), rate())
+			.prop_map(|(v, dec, rate)| AssetReserve::new(to_precision(v, dec), dec).with_rate(rate)),
+		size,
+	)
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn out_given_in_with_rates(
+		pool in some_pool_with_rates(3),
+		amount in trade_amount(),
+		amp in amplification(),
+		(idx_in, idx_out) in trade_pair(3),
+	) {
+		let d0 = calculate_d::<D_ITERATIONS>(&pool, amp).unwrap();
+		let amount_in = to_precision(amount, pool[idx_in].decimals);
+
+		let amount_out = calculate_out_given_in::<D_ITERATIONS,Y_ITERATIONS>(&pool, idx_in, idx_out, amount_in, amp).unwrap();
+		let updated_pool: Vec<AssetReserve> = pool
+			.into_iter()
+			.enumerate()
+			.map(|(idx, v)| {
+				if idx == idx_in {
+					v.with_amount(v.amount + amount_in)
+				} else if idx == idx_out {
+					v.with_amount(v.amount - amount_out)
+				} else {
+					v
+				}
+			})
+			.collect();
+		let d1 = calculate_d::<D_ITERATIONS>(&updated_pool, amp).unwrap();
+		assert!(d1 >= d0);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn in_given_out_with_rates(
+		pool in some_pool_with_rates(3),
+		amount in trade_amount(),
+		amp in amplification(),
+		(idx_in, idx_out) in trade_pair(3),
+	) {
+		let d0 = calculate_d::<D_ITERATIONS>(&pool, amp).unwrap();
+		let amount_out = to_precision(amount, pool[idx_out].decimals);
+
+		let amount_in = calculate_in_given_out::<D_ITERATIONS,Y_ITERATIONS>(&pool, idx_in, idx_out, amount_out, amp).unwrap();
+		let updated_pool: Vec<AssetReserve> = pool
+			.into_iter()
+			.enumerate()
+			.map(|(idx, v)| {
+				if idx == idx_in {
+					v.with_amount(v.amount + amount_in)
+				} else if idx == idx_out {
+					v.with_amount(v.amount - amount_out)
+				} else {
+					v
+				}
+			})
+			.collect();
+		let d1 = calculate_d::<D_ITERATIONS>(&updated_pool, amp).unwrap();
+		assert!(d1 >= d0);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn out_given_in_with_rate_should_equal_out_given_in_of_rescaled_pool(
+		reserve_a in asset_reserve(),
+		reserve_b in asset_reserve(),
+		amount in trade_amount(),
+		amp in amplification(),
+		rate in rate(),
+	) {
+		let reserve_a = to_precision(reserve_a, 18);
+		let reserve_b = to_precision(reserve_b, 18);
+		let amount_in = to_precision(amount, 18);
+
+		let pool = vec![
+			AssetReserve::new(reserve_a, 18).with_rate(rate),
+			AssetReserve::new(reserve_b, 18),
+		];
+		let rescaled_pool = vec![
+			AssetReserve::new(rescale(reserve_a, rate), 18),
+			AssetReserve::new(reserve_b, 18),
+		];
+
+		let amount_out = calculate_out_given_in::<D_ITERATIONS,Y_ITERATIONS>(&pool, 0, 1, amount_in, amp).unwrap();
+		let expected_out = calculate_out_given_in::<D_ITERATIONS,Y_ITERATIONS>(&rescaled_pool, 0, 1, rescale(amount_in, rate), amp).unwrap();
+
+		// Amount in is rounded up when scaled by the rate.
+		assert!(amount_out >= expected_out);
+		assert!(amount_out - expected_out <= 10);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn shares_for_liquidity_with_rates_should_not_exceed_withdrawn_amount(
+		pool in some_pool_with_rates(2),
+		amount in trade_amount(),
+		amp in amplification(),
+	) {
+		let issuance = calculate_d::<D_ITERATIONS>(&pool, amp).unwrap();
+		let amount = to_precision(amount, pool[0].decimals);
+		let shares = calculate_shares_for_amount::<D_ITERATIONS>(&pool, 0, amount, amp, issuance, Permill::zero()).unwrap();
+
+		let received =
+			calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(&pool, shares, 0, issuance, amp, Permill::zero())
+				.unwrap();
+		// LP should not receive more than provided.
+		assert!(received.0 <= amount);
+	}
+}
@@ -1,6 +1,7 @@
 mod amplification;
 mod invariants;
 mod multi_assets;
+mod rates;
 mod two_assets;
 
 use crate::types::Balance;
@@ -805,3 +805,42 @@ fn share_price_calculation_should_work_with_6_decimals() {
 		Some((279206572581786940496760242, 279158579738033226972960348441675415837))
 	);
 }
+
+#[test]
+fn calculate_liquidity_out_should_withdraw_proportional_amounts_of_all_assets() {
+	let balances = [
+		AssetReserve::new(10_000, 12),
+		AssetReserve::new(20_000, 12),
+		AssetReserve::new(30_000, 6),
+	];
+
+	let result = calculate_liquidity_out(&balances, 250, 1_000);
+
+	assert_eq!(result, Some(vec![2_500, 5_000, 7_500]));
+}
+
+#[test]
+fn calculate_liquidity_out_should_round_down() {
+	let balances = [AssetReserve::new(10_000, 12), AssetReserve::new(10_001, 12)];
+
+	let result = calculate_liquidity_out(&balances, 1, 3);
+
+	assert_eq!(result, Some(vec![3_333, 3_333]));
+}
+
+#[test]
+fn calculate_liquidity_out_should_withdraw_all_reserves_when_all_shares_are_provided() {
+	let balances = [AssetReserve::new(10_000, 12); MAX_BALANCES];
+
+	let result = calculate_liquidity_out(&balances, 1_000, 1_000);
+
+	assert_eq!(result, Some(vec![10_000; MAX_BALANCES]));
+}
+
+#[test]
+fn calculate_liquidity_out_should_fail_when_shares_exceed_issuance() {
+	let balances = [AssetReserve::new(10_000, 12); MAX_BALANCES];
+
+	assert!(calculate_liquidity_out(&balances, 1_001, 1_000).is_none());
+	assert!(calculate_liquidity_out(&balances, 0, 0).is_none());
+}
@@ -0,0 +1,127 @@
+const D_ITERATIONS: u8 = 128;
+const Y_ITERATIONS: u8 = 64;
+
+use super::*;
+use crate::stableswap::types::AssetReserve;
+use crate::stableswap::*;
+use crate::test_utils::assert_approx_eq;
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
+
+const RATE_PRECISION: Balance = 1_000_000_000_000_000_000;
+
+// vDOT accrued 50% of yield - one vDOT is worth 1.5 DOT.
+const VDOT_RATE: (Balance, Balance) = (3 * RATE_PRECISION / 2, RATE_PRECISION);
+
+fn lst_pool() -> Vec<AssetReserve> {
+	vec![
+		AssetReserve::new(1_000_000 * ONE, 12).with_rate(VDOT_RATE),
+		AssetReserve::new(1_500_000 * ONE, 12),
+	]
+}
+
+#[test]
+fn calculate_d_should_scale_reserves_by_rate() {
+	let pool = lst_pool();
+	let pegged_pool = vec![
+		AssetReserve::new(1_500_000 * ONE, 12),
+		AssetReserve::new(1_500_000 * ONE, 12),
+	];
+
+	assert_eq!(
+		calculate_d::<D_ITERATIONS>(&pool, 1000),
+		calculate_d::<D_ITERATIONS>(&pegged_pool, 1000)
+	);
+}
+
+#[test]
+fn sell_should_apply_rate_of_both_assets() {
+	let pool = lst_pool();
+
+	let amount_out = calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&pool, 0, 1, ONE, 1000).unwrap();
+	assert_eq!(amount_out, 1_499_999_998_500);
+
+	let amount_out = calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&pool, 1, 0, 3 * ONE, 1000).unwrap();
+	assert_eq!(amount_out, 1_999_999_996_002);
+}
+
+#[test]
+fn buy_should_apply_rate_of_both_assets() {
+	let pool = lst_pool();
+
+	let amount_in = calculate_in_given_out::<D_ITERATIONS, Y_ITERATIONS>(&pool, 0, 1, 3 * ONE, 1000).unwrap();
+	assert_eq!(amount_in, 2_000_000_003_998);
+}
+
+#[test]
+fn sell_should_fail_when_rate_is_invalid() {
+	let pool = vec![
+		AssetReserve::new(1_000_000 * ONE, 12).with_rate((1, 0)),
+		AssetReserve::new(1_500_000 * ONE, 12),
+	];
+
+	assert!(calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&pool, 0, 1, ONE, 1000).is_none());
+}
+
+#[test]
+fn share_price_should_be_expressed_in_asset_units() {
+	let pool = lst_pool();
+	let issuance = calculate_d::<D_ITERATIONS>(&pool, 1000).unwrap();
+
+	let prices = calculate_share_prices::<D_ITERATIONS>(&pool, 1000, issuance).unwrap();
+	let vdot_price = FixedU128::from_rational(prices[0].0, prices[0].1);
+	let dot_price = FixedU128::from_rational(prices[1].0, prices[1].1);
+
+	// Balanced pool - one share is worth 1 DOT, which is 2/3 of vDOT.
+	assert_eq!(dot_price.saturating_mul_int(3 * RATE_PRECISION), 3 * ONE);
+	assert_eq!(vdot_price.saturating_mul_int(3 * RATE_PRECISION), 2_000_000_000_001);
+}
+
+#[test]
+fn withdraw_one_asset_should_apply_rate() {
+	let pool = lst_pool();
+	let issuance = calculate_d::<D_ITERATIONS>(&pool, 1000).unwrap();
+
+	// Share precision is 18 decimals, 3 DOT worth of shares.
+	let shares = 3 * RATE_PRECISION;
+	let (amount, fee) =
+		calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(&pool, shares, 0, issuance, 1000, Permill::zero())
+			.unwrap();
+	assert_eq!(fee, 0);
+	assert_eq!(amount, 1_999_999_999_000);
+}
+
+#[test]
+fn spot_price_should_be_expressed_in_asset_units() {
+	let pool = lst_pool();
+	let d = calculate_d::<D_ITERATIONS>(&pool, 1000).unwrap();
+
+	let (n, d) = calculate_spot_price(&pool, 1000, d, 1).unwrap();
+	let dot_price = FixedU128::from_rational(n, d);
+
+	// Balanced pool - one DOT is worth 2/3 of vDOT.
+	assert_approx_eq!(
+		dot_price.saturating_mul_int(3 * RATE_PRECISION),
+		2 * RATE_PRECISION,
+		1_000,
+		"spot price should not be expressed in the pool's peg"
+	);
+}
+
+#[test]
+fn rate_as_raw_ratio_should_be_normalized_by_peg_decimals() {
+	// Oracle price of vDOT (10 decimals) in DOT (12 decimals) is a ratio of raw amounts.
+	let raw_rate = (3 * ONE, 2 * 10_000_000_000);
+	let pool = vec![
+		AssetReserve {
+			amount: 1_000_000 * 10_000_000_000,
+			decimals: 12,
+			rate: raw_rate,
+		},
+		AssetReserve::new(1_500_000 * ONE, 12),
+	];
+
+	assert_eq!(
+		calculate_d::<D_ITERATIONS>(&pool, 1000),
+		calculate_d::<D_ITERATIONS>(&lst_pool(), 1000)
+	);
+}
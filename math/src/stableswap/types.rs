@@ -1,15 +1,32 @@
 use crate::types::Balance;
-use num_traits::Zero;
+use num_traits::{One, Zero};
 
 #[derive(Debug, Clone, Copy)]
 pub struct AssetReserve {
 	pub amount: Balance,
 	pub decimals: u8,
+	/// Value of one unit of the asset expressed in the pool's peg as (numerator, denominator).
+	/// Assets which are pegged 1:1 have rate of (1, 1).
+	/// Rated amount is normalized by `decimals`, so if the rate is a ratio of raw amounts (eg. an oracle price),
+	/// `decimals` must be the decimals of the peg.
+	pub rate: (Balance, Balance),
 }
 
 impl AssetReserve {
 	pub fn new(amount: Balance, decimals: u8) -> Self {
-		Self { amount, decimals }
+		Self {
+			amount,
+			decimals,
+			rate: (Balance::one(), Balance::one()),
+		}
+	}
+
+	pub fn with_rate(self, rate: (Balance, Balance)) -> Self {
+		Self { rate, ..self }
+	}
+
+	pub fn with_amount(self, amount: Balance) -> Self {
+		Self { amount, ..self }
 	}
 
 	pub fn is_zero(&self) -> bool {
@@ -0,0 +1,46 @@
+use crate::ema::EmaPrice;
+use crate::support::rational::{round_to_rational, Rounding};
+
+use primitive_types::U256;
+
+/// Number of fractional bits of the fixed point prices summed up in the cumulative price.
+pub const RESOLUTION: usize = 96;
+
+/// Sum of fixed point prices multiplied by the number of blocks they were valid for.
+///
+/// The value is allowed to overflow (wrap around). Only differences between two cumulative prices
+/// are meaningful and these are correct as long as the true difference fits into 256 bits.
+pub type CumulativePrice = U256;
+
+/// Convert `price` into a fixed point number with `RESOLUTION` fractional bits.
+///
+/// Returns `None` if the denominator is zero.
+pub fn price_to_fixed(price: EmaPrice) -> Option<U256> {
+	if price.d == 0 {
+		return None;
+	}
+	Some((U256::from(price.n) << RESOLUTION) / U256::from(price.d))
+}
+
+/// Advance `cumulative` by `price` having been valid for `blocks` blocks.
+///
+/// Wraps around on overflow.
+pub fn accumulate_price(cumulative: CumulativePrice, price: EmaPrice, blocks: u64) -> Option<CumulativePrice> {
+	let fixed_price = price_to_fixed(price)?;
+	let (increment, _) = fixed_price.overflowing_mul(U256::from(blocks));
+	let (cumulative, _) = cumulative.overflowing_add(increment);
+	Some(cumulative)
+}
+
+/// Calculate the time weighted average price between two cumulative prices `blocks` blocks apart.
+///
+/// Returns `None` if `blocks` is zero.
+pub fn time_weighted_average_price(start: CumulativePrice, end: CumulativePrice, blocks: u64) -> Option<EmaPrice> {
+	if blocks == 0 {
+		return None;
+	}
+	let (difference, _) = end.overflowing_sub(start);
+	let average = difference / U256::from(blocks);
+	let (n, d) = round_to_rational((average, U256::one() << RESOLUTION), Rounding::Nearest);
+	Some(EmaPrice::new(n, d))
+}
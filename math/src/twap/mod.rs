@@ -0,0 +1,6 @@
+mod math;
+
+#[cfg(test)]
+mod tests;
+
+pub use math::*;
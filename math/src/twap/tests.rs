@@ -0,0 +1,56 @@
+use super::*;
+use crate::ema::EmaPrice;
+
+use primitive_types::U256;
+
+#[test]
+fn price_to_fixed_should_work() {
+	assert_eq!(price_to_fixed(EmaPrice::one()), Some(U256::one() << RESOLUTION));
+	assert_eq!(
+		price_to_fixed(EmaPrice::new(1, 4)),
+		Some(U256::one() << (RESOLUTION - 2))
+	);
+	assert_eq!(price_to_fixed(EmaPrice::new_unchecked(1, 0)), None);
+}
+
+#[test]
+fn accumulate_price_should_add_price_for_each_block() {
+	let cumulative = accumulate_price(U256::zero(), EmaPrice::new(2, 1), 3).unwrap();
+	assert_eq!(cumulative, U256::from(6) << RESOLUTION);
+
+	let cumulative = accumulate_price(cumulative, EmaPrice::new(1, 2), 2).unwrap();
+	assert_eq!(cumulative, U256::from(7) << RESOLUTION);
+}
+
+#[test]
+fn accumulate_price_should_wrap_around_on_overflow() {
+	let cumulative = accumulate_price(U256::MAX, EmaPrice::one(), 1).unwrap();
+	assert_eq!(cumulative, (U256::one() << RESOLUTION) - 1);
+}
+
+#[test]
+fn time_weighted_average_price_should_average_over_blocks() {
+	let start = accumulate_price(U256::zero(), EmaPrice::new(2, 1), 3).unwrap();
+	let end = accumulate_price(start, EmaPrice::new(1, 2), 2).unwrap();
+	let end = accumulate_price(end, EmaPrice::new(4, 1), 5).unwrap();
+
+	let twap = time_weighted_average_price(start, end, 7).unwrap();
+	// (2 * 0.5 + 5 * 4) / 7 = 3
+	assert_eq!(twap.n / twap.d, 3);
+	assert_eq!(twap.n % twap.d, 0);
+}
+
+#[test]
+fn time_weighted_average_price_should_work_across_overflow() {
+	let start = U256::MAX - (U256::one() << RESOLUTION);
+	let end = accumulate_price(start, EmaPrice::new(5, 2), 4).unwrap();
+	assert!(end < start);
+
+	let twap = time_weighted_average_price(start, end, 4).unwrap();
+	assert_eq!((twap.n * 2) / twap.d, 5);
+}
+
+#[test]
+fn time_weighted_average_price_should_return_none_when_blocks_is_zero() {
+	assert_eq!(time_weighted_average_price(U256::zero(), U256::one(), 0), None);
+}
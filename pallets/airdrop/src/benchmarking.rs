@@ -0,0 +1,110 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::BoundedVec;
+use frame_system::RawOrigin;
+use sp_core::crypto::KeyTypeId;
+use sp_io::hashing::keccak_256;
+use sp_std::vec::Vec;
+
+const ONE: Balance = 1_000_000_000_000;
+const AMOUNT: Balance = 1_000 * ONE;
+
+/// Sign the claim message of `who` with a newly generated Ethereum key.
+fn sign_claim_message<T: Config>(distribution_id: DistributionId, who: &T::AccountId) -> (Claimant, ClaimSignature) {
+	let key_type = KeyTypeId(*b"aird");
+	let public = sp_io::crypto::ecdsa_generate(key_type, None);
+	let message = Pallet::<T>::claim_message(distribution_id, who);
+	let message_hash = keccak_256(&EcdsaSignature::ethereum_signable_message(&message, T::Prefix::get()));
+	let signature = sp_io::crypto::ecdsa_sign_prehashed(key_type, &public, &message_hash).expect("Failed to sign");
+
+	let mut raw_signature = [0u8; 65];
+	raw_signature.copy_from_slice(signature.as_ref());
+	let signature = EcdsaSignature(raw_signature);
+	let address = signature
+		.recover(&message, T::Prefix::get())
+		.expect("Invalid signature");
+
+	(Claimant::Ethereum(address), ClaimSignature::Ecdsa(signature))
+}
+
+fn fund_treasury<T: Config>(asset_id: T::AssetId) -> DispatchResult {
+	T::Currency::mint_into(asset_id, &T::TreasuryAccount::get(), 2 * AMOUNT)?;
+	Ok(())
+}
+
+fn set_block_number<T: Config>(n: u32) {
+	frame_system::Pallet::<T>::set_block_number(n.into());
+}
+
+benchmarks! {
+	where_clause { where
+		T::AssetId: From<u32>,
+	}
+
+	create_distribution{
+		let asset_id: T::AssetId = 0u32.into();
+		fund_treasury::<T>(asset_id)?;
+		let merkle_root = H256::repeat_byte(1);
+	}: _(RawOrigin::Root, asset_id, merkle_root, AMOUNT, 100u32.into())
+	verify {
+		assert!(Pallet::<T>::distributions(0).is_some());
+	}
+
+	claim{
+		let p in 0 .. T::MaxProofLength::get();
+
+		let asset_id: T::AssetId = 0u32.into();
+		fund_treasury::<T>(asset_id)?;
+
+		let caller: T::AccountId = account("caller", 0, 1);
+		let (claimant, signature) = sign_claim_message::<T>(0, &caller);
+		let proof: Vec<H256> = (0..p).map(|i| H256::repeat_byte(i as u8)).collect();
+		let merkle_root = proof.iter().fold(merkle::leaf_hash(&claimant, &AMOUNT), |node, sibling| merkle::hash_pair(&node, sibling));
+		Pallet::<T>::create_distribution(RawOrigin::Root.into(), asset_id, merkle_root, AMOUNT, 100u32.into())?;
+		let proof: BoundedVec<H256, T::MaxProofLength> = proof.try_into().unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), 0, claimant, AMOUNT, proof, signature)
+	verify {
+		assert!(Pallet::<T>::claimed(0, claimant).is_some());
+		assert_eq!(T::Currency::balance(asset_id, &caller), AMOUNT);
+	}
+
+	sweep{
+		let asset_id: T::AssetId = 0u32.into();
+		fund_treasury::<T>(asset_id)?;
+		let caller: T::AccountId = account("caller", 0, 1);
+		let merkle_root = H256::repeat_byte(1);
+		Pallet::<T>::create_distribution(RawOrigin::Root.into(), asset_id, merkle_root, AMOUNT, 100u32.into())?;
+		set_block_number::<T>(100);
+	}: _(RawOrigin::Signed(caller), 0)
+	verify {
+		assert!(Pallet::<T>::distributions(0).is_none());
+		assert_eq!(T::Currency::balance(asset_id, &Pallet::<T>::distribution_account(0)), 0);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(Pallet, super::ExtBuilder::default().build(), super::Test);
+}
@@ -0,0 +1,541 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Airdrop pallet
+//!
+//! Merkle-root based token distributions.
+//!
+//! ## Overview
+//!
+//! Unlike `pallet_claims`, which stores every eligible address on chain, a distribution only stores
+//! the root of a Merkle tree of `(claimant, amount)` entries. New distributions can be registered
+//! by governance at any time without a storage migration.
+//!
+//! When a distribution is created, its full amount is transferred from the treasury to the distribution
+//! account. Claimants prove their entry with a Merkle proof and authorize the destination account
+//! with a signature made by the key of the entry - ECDSA (Ethereum `personal_sign`) or sr25519.
+//!
+//! Claims are fee-less. The `ValidateClaim` signed extension verifies the proof and the signature
+//! before the transaction is accepted to the pool, so invalid claims cannot be used to spam the chain.
+//!
+//! After the expiry block, anyone can sweep the unclaimed funds of a distribution back to the treasury.
+//!
+//! ### Terminology
+//!
+//! * **Distribution:**  Merkle root, asset and expiry registered by governance.
+//! * **Claimant:**  Ethereum address or sr25519 public key included in the tree.
+//! * **Claim message:**  `Prefix` followed by the ASCII hex of SCALE encoded `(distribution_id, account)`.
+//!   For Ethereum claimants, the message is wrapped by `personal_sign`. For sr25519 claimants, the message
+//!   can also be wrapped in `<Bytes>`/`</Bytes>` as done by browser extensions.
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarking;
+pub mod merkle;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	dispatch::{DispatchClass, Pays},
+	ensure,
+	pallet_prelude::{DispatchResult, Get},
+	sp_runtime::{
+		traits::{DispatchInfoOf, SignedExtension},
+		transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction},
+	},
+	traits::{
+		fungibles::{Inspect, Mutate},
+		tokens::{Fortitude, Preservation},
+		IsSubType,
+	},
+	PalletId,
+};
+use frame_system::{
+	ensure_signed,
+	pallet_prelude::{BlockNumberFor, OriginFor},
+};
+use pallet_claims::{to_ascii_hex, EcdsaSignature, EthereumAddress};
+use scale_info::TypeInfo;
+use sp_core::{sr25519, H256};
+use sp_runtime::{
+	traits::{AccountIdConversion, Verify, Zero},
+	DispatchError, ModuleError, RuntimeDebug,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+pub use pallet::*;
+
+use weights::WeightInfo;
+
+pub type Balance = u128;
+pub type DistributionId = u32;
+
+/// Entry owner in the distribution tree.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum Claimant {
+	/// Ethereum address, proven by an ECDSA signature of the claim message made with `personal_sign`.
+	Ethereum(EthereumAddress),
+	/// sr25519 public key, proven by an sr25519 signature of the claim message.
+	Sr25519([u8; 32]),
+}
+
+/// Signature of the claim message made by the claimant's key.
+#[derive(Clone, Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum ClaimSignature {
+	Ecdsa(EcdsaSignature),
+	Sr25519(sr25519::Signature),
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct DistributionInfo<AssetId, BlockNumber> {
+	/// Root of the tree of `(claimant, amount)` entries.
+	pub merkle_root: H256,
+	/// Asset which is distributed.
+	pub asset_id: AssetId,
+	/// Amount which has not been claimed yet.
+	pub remaining: Balance,
+	/// Claims are possible until this block. Unclaimed funds can be swept afterwards.
+	pub expires_at: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that can create distributions.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Asset type.
+		type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// Support for transfers.
+		type Currency: Mutate<Self::AccountId, AssetId = Self::AssetId, Balance = Balance>;
+
+		/// Pallet id. Each distribution holds its funds in a sub-account of this id.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Account which funds distributions and receives unclaimed funds.
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Prefix of the claim message.
+		type Prefix: Get<&'static [u8]>;
+
+		/// Maximum number of hashes in a Merkle proof.
+		#[pallet::constant]
+		type MaxProofLength: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Id of the next distribution.
+	#[pallet::storage]
+	#[pallet::getter(fn next_distribution_id)]
+	pub type NextDistributionId<T: Config> = StorageValue<_, DistributionId, ValueQuery>;
+
+	/// Active distributions.
+	#[pallet::storage]
+	#[pallet::getter(fn distributions)]
+	pub type Distributions<T: Config> =
+		StorageMap<_, Blake2_128Concat, DistributionId, DistributionInfo<T::AssetId, BlockNumberFor<T>>>;
+
+	/// Entries which have been claimed.
+	///
+	/// Entries are kept after a distribution is swept. Distribution ids are never reused.
+	#[pallet::storage]
+	#[pallet::getter(fn claimed)]
+	pub type Claimed<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, DistributionId, Blake2_128Concat, Claimant, (), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// New distribution has been created.
+		DistributionCreated {
+			id: DistributionId,
+			asset_id: T::AssetId,
+			merkle_root: H256,
+			amount: Balance,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// Entry of a distribution has been claimed.
+		Claimed {
+			id: DistributionId,
+			claimant: Claimant,
+			who: T::AccountId,
+			amount: Balance,
+		},
+		/// Unclaimed funds of an expired distribution have been transferred to the treasury.
+		DistributionSwept {
+			id: DistributionId,
+			asset_id: T::AssetId,
+			amount: Balance,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Distribution does not exist.
+		DistributionNotFound,
+		/// Distribution amount must be greater than zero.
+		ZeroAmount,
+		/// Expiry block must be in the future.
+		InvalidExpiry,
+		/// Distribution has expired.
+		DistributionExpired,
+		/// Distribution has not expired yet.
+		DistributionNotExpired,
+		/// Entry has already been claimed.
+		AlreadyClaimed,
+		/// Merkle proof does not match the distribution root.
+		InvalidProof,
+		/// Signature was not made by the claimant.
+		InvalidSignature,
+		/// Claimed amount exceeds the remaining amount of the distribution.
+		InsufficientFunds,
+		/// Distribution id overflow.
+		DistributionIdOverflow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create new distribution.
+		///
+		/// `amount` of `asset_id` is transferred from the treasury account to the distribution account.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Parameters:
+		/// - `origin`: Authority origin.
+		/// - `asset_id`: asset which is distributed.
+		/// - `merkle_root`: root of the tree of `(claimant, amount)` entries.
+		/// - `amount`: total amount of the distribution.
+		/// - `expires_at`: block after which unclaimed funds can be swept to the treasury.
+		///
+		/// Emits `DistributionCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_distribution())]
+		pub fn create_distribution(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			merkle_root: H256,
+			amount: Balance,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+			ensure!(
+				expires_at > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+
+			let id = NextDistributionId::<T>::get();
+			NextDistributionId::<T>::put(id.checked_add(1).ok_or(Error::<T>::DistributionIdOverflow)?);
+
+			T::Currency::transfer(
+				asset_id,
+				&T::TreasuryAccount::get(),
+				&Self::distribution_account(id),
+				amount,
+				Preservation::Preserve,
+			)?;
+
+			Distributions::<T>::insert(
+				id,
+				DistributionInfo {
+					merkle_root,
+					asset_id,
+					remaining: amount,
+					expires_at,
+				},
+			);
+
+			Self::deposit_event(Event::DistributionCreated {
+				id,
+				asset_id,
+				merkle_root,
+				amount,
+				expires_at,
+			});
+
+			Ok(())
+		}
+
+		/// Claim entry of a distribution.
+		///
+		/// `amount` is transferred to the origin. `signature` must be made by the claimant's key
+		/// over the claim message of `distribution_id` and the origin account.
+		///
+		/// The call is fee-less. Invalid claims are rejected by the `ValidateClaim` signed extension.
+		///
+		/// Parameters:
+		/// - `origin`: account which receives the claimed amount.
+		/// - `distribution_id`: id of the distribution.
+		/// - `claimant`: owner of the entry.
+		/// - `amount`: amount of the entry.
+		/// - `proof`: Merkle proof of the entry.
+		/// - `signature`: signature of the claim message.
+		///
+		/// Emits `Claimed` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight((<T as Config>::WeightInfo::claim(proof.len() as u32), DispatchClass::Normal, Pays::No))]
+		pub fn claim(
+			origin: OriginFor<T>,
+			distribution_id: DistributionId,
+			claimant: Claimant,
+			amount: Balance,
+			proof: BoundedVec<H256, T::MaxProofLength>,
+			signature: ClaimSignature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let distribution = Self::validate_claim(&who, distribution_id, &claimant, amount, &proof, &signature)?;
+
+			T::Currency::transfer(
+				distribution.asset_id,
+				&Self::distribution_account(distribution_id),
+				&who,
+				amount,
+				Preservation::Expendable,
+			)?;
+
+			Claimed::<T>::insert(distribution_id, claimant, ());
+			Distributions::<T>::mutate(distribution_id, |maybe_distribution| {
+				if let Some(d) = maybe_distribution {
+					d.remaining = d.remaining.saturating_sub(amount);
+				}
+			});
+
+			Self::deposit_event(Event::Claimed {
+				id: distribution_id,
+				claimant,
+				who,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Transfer unclaimed funds of an expired distribution to the treasury and remove the distribution.
+		///
+		/// Can be called by any signed origin.
+		///
+		/// Parameters:
+		/// - `origin`: Signed origin.
+		/// - `distribution_id`: id of the distribution.
+		///
+		/// Emits `DistributionSwept` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::sweep())]
+		pub fn sweep(origin: OriginFor<T>, distribution_id: DistributionId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let distribution = Distributions::<T>::get(distribution_id).ok_or(Error::<T>::DistributionNotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= distribution.expires_at,
+				Error::<T>::DistributionNotExpired
+			);
+
+			let account = Self::distribution_account(distribution_id);
+			let amount = T::Currency::reducible_balance(
+				distribution.asset_id,
+				&account,
+				Preservation::Expendable,
+				Fortitude::Polite,
+			);
+			if !amount.is_zero() {
+				T::Currency::transfer(
+					distribution.asset_id,
+					&account,
+					&T::TreasuryAccount::get(),
+					amount,
+					Preservation::Expendable,
+				)?;
+			}
+
+			Distributions::<T>::remove(distribution_id);
+
+			Self::deposit_event(Event::DistributionSwept {
+				id: distribution_id,
+				asset_id: distribution.asset_id,
+				amount,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account which holds the funds of a distribution.
+	pub fn distribution_account(id: DistributionId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(id)
+	}
+
+	/// Message which the claimant signs to claim an entry of `distribution_id` to `who`.
+	pub fn claim_message(distribution_id: DistributionId, who: &T::AccountId) -> Vec<u8> {
+		(distribution_id, who).using_encoded(to_ascii_hex)
+	}
+
+	/// Check if a claim is valid.
+	///
+	/// Verifies that the distribution is active, the entry has not been claimed yet, the entry is
+	/// included in the distribution tree and the signature was made by the claimant.
+	fn validate_claim(
+		who: &T::AccountId,
+		distribution_id: DistributionId,
+		claimant: &Claimant,
+		amount: Balance,
+		proof: &[H256],
+		signature: &ClaimSignature,
+	) -> Result<DistributionInfo<T::AssetId, BlockNumberFor<T>>, Error<T>> {
+		let distribution = Distributions::<T>::get(distribution_id).ok_or(Error::<T>::DistributionNotFound)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() < distribution.expires_at,
+			Error::<T>::DistributionExpired
+		);
+		ensure!(
+			!Claimed::<T>::contains_key(distribution_id, claimant),
+			Error::<T>::AlreadyClaimed
+		);
+		ensure!(amount <= distribution.remaining, Error::<T>::InsufficientFunds);
+		ensure!(
+			merkle::verify_proof(&distribution.merkle_root, merkle::leaf_hash(claimant, &amount), proof),
+			Error::<T>::InvalidProof
+		);
+		ensure!(
+			Self::verify_signature(who, distribution_id, claimant, signature),
+			Error::<T>::InvalidSignature
+		);
+
+		Ok(distribution)
+	}
+
+	fn verify_signature(
+		who: &T::AccountId,
+		distribution_id: DistributionId,
+		claimant: &Claimant,
+		signature: &ClaimSignature,
+	) -> bool {
+		let message = Self::claim_message(distribution_id, who);
+		match (claimant, signature) {
+			(Claimant::Ethereum(address), ClaimSignature::Ecdsa(signature)) => {
+				signature.recover(&message, T::Prefix::get()) == Some(*address)
+			}
+			(Claimant::Sr25519(public), ClaimSignature::Sr25519(signature)) => {
+				let public = sr25519::Public::from_raw(*public);
+				let prefixed = [T::Prefix::get(), &message[..]].concat();
+				let wrapped = [&b"<Bytes>"[..], &prefixed[..], &b"</Bytes>"[..]].concat();
+				signature.verify(&prefixed[..], &public) || signature.verify(&wrapped[..], &public)
+			}
+			_ => false,
+		}
+	}
+}
+
+/// Signed extension that checks for the `claim` call and in that case, it verifies the proof and the signature.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ValidateClaim<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for ValidateClaim<T> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ValidateAirdropClaim")
+	}
+}
+
+/// Convert an Error to a custom InvalidTransaction with the inner code being the error number.
+pub fn error_to_invalid<T: Config>(error: Error<T>) -> InvalidTransaction {
+	let error_number = match error.into() {
+		DispatchError::Module(ModuleError { error, .. }) => error[0],
+		_ => 0, // this case should never happen because an Error is always converted to DispatchError::Module(ModuleError)
+	};
+	InvalidTransaction::Custom(error_number)
+}
+
+impl<T: Config + Send + Sync> SignedExtension for ValidateClaim<T>
+where
+	<T as frame_system::Config>::RuntimeCall: IsSubType<Call<T>>,
+{
+	const IDENTIFIER: &'static str = "ValidateAirdropClaim";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		match call.is_sub_type() {
+			Some(Call::claim {
+				distribution_id,
+				claimant,
+				amount,
+				proof,
+				signature,
+			}) => match Pallet::<T>::validate_claim(who, *distribution_id, claimant, *amount, proof, signature) {
+				Ok(_) => ValidTransaction::with_tag_prefix("AirdropClaim")
+					.and_provides((distribution_id, claimant))
+					.build(),
+				Err(error) => error_to_invalid(error).into(),
+			},
+			_ => Ok(Default::default()),
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		self.validate(who, call, info, len).map(|_| ())
+	}
+}
+
+impl<T: Config + Send + Sync> ValidateClaim<T> {
+	#[cfg_attr(feature = "cargo-clippy", allow(clippy::new_without_default))]
+	pub fn new() -> Self {
+		Self(sp_std::marker::PhantomData)
+	}
+}
@@ -0,0 +1,45 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Merkle proof verification of distribution entries.
+//!
+//! Leaves are `keccak256(SCALE(claimant, amount))` and inner nodes are `keccak256` of the two child
+//! hashes in ascending order, so a proof is just the list of sibling hashes from the leaf up to the root.
+
+use codec::Encode;
+use sp_core::H256;
+use sp_io::hashing::keccak_256;
+
+/// Hash of a single distribution entry.
+pub fn leaf_hash<Claimant: Encode, Balance: Encode>(claimant: &Claimant, amount: &Balance) -> H256 {
+	H256::from(keccak_256(&(claimant, amount).encode()))
+}
+
+/// Hash of two sibling nodes. Nodes are sorted first so proofs do not need to carry the position.
+pub fn hash_pair(a: &H256, b: &H256) -> H256 {
+	let (first, second) = if a <= b { (a, b) } else { (b, a) };
+	let mut data = [0u8; 64];
+	data[..32].copy_from_slice(first.as_bytes());
+	data[32..].copy_from_slice(second.as_bytes());
+	H256::from(keccak_256(&data))
+}
+
+/// Returns true if `leaf` is included in the tree with root `root`.
+pub fn verify_proof(root: &H256, leaf: H256, proof: &[H256]) -> bool {
+	let computed = proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling));
+	computed == *root
+}
@@ -0,0 +1,257 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod claim;
+mod create;
+mod sweep;
+mod validate;
+
+use crate as pallet_airdrop;
+use crate::*;
+
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, parameter_types,
+	sp_runtime::traits::{BlakeTwo256, ConstU32, ConstU64, IdentityLookup},
+	traits::Everything,
+	BoundedVec, PalletId,
+};
+use frame_system::EnsureRoot;
+use hex_literal::hex;
+use orml_traits::{parameter_type_with_key, MultiCurrency};
+use sp_core::{ecdsa, sr25519, Pair};
+use sp_io::hashing::keccak_256;
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(crate) type AccountId = u64;
+pub(crate) type AssetId = u32;
+
+pub(crate) const ONE: Balance = 1_000_000_000_000;
+
+pub const HDX: AssetId = 0;
+pub const DOT: AssetId = 5;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const TREASURY: AccountId = 400;
+
+pub(crate) const INITIAL_TREASURY_BALANCE: Balance = 1_000_000 * ONE;
+pub(crate) const EXPIRY: u64 = 100;
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Airdrop: pallet_airdrop,
+		Tokens: orml_tokens,
+	}
+);
+
+parameter_types! {
+	pub const AirdropPalletId: PalletId = PalletId(*b"airdrop!");
+	pub const TreasuryAccount: AccountId = TREASURY;
+	pub Prefix: &'static [u8] = b"Claim airdrop to:";
+	pub const MaxProofLength: u32 = 16;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type PalletId = AirdropPalletId;
+	type TreasuryAccount = TreasuryAccount;
+	type Prefix = Prefix;
+	type MaxProofLength = MaxProofLength;
+	type WeightInfo = ();
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_asset_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type DustRemovalWhitelist = Everything;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			endowed_accounts: vec![
+				(TREASURY, HDX, INITIAL_TREASURY_BALANCE),
+				(TREASURY, DOT, INITIAL_TREASURY_BALANCE),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts.extend(accounts);
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+		r.register_extension(KeystoreExt::new(MemoryKeystore::new()));
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+		});
+
+		r
+	}
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
+
+/// Build a Merkle tree of `entries` and return its root and the proof of each entry.
+pub fn merkle_tree(entries: &[(Claimant, Balance)]) -> (H256, Vec<Vec<H256>>) {
+	let mut layer: Vec<H256> = entries
+		.iter()
+		.map(|(claimant, amount)| merkle::leaf_hash(claimant, amount))
+		.collect();
+	let mut positions: Vec<usize> = (0..entries.len()).collect();
+	let mut proofs: Vec<Vec<H256>> = vec![vec![]; entries.len()];
+
+	while layer.len() > 1 {
+		for (proof, position) in proofs.iter_mut().zip(positions.iter_mut()) {
+			let sibling = *position ^ 1;
+			if sibling < layer.len() {
+				proof.push(layer[sibling]);
+			}
+			*position /= 2;
+		}
+		layer = layer
+			.chunks(2)
+			.map(|pair| {
+				if pair.len() == 2 {
+					merkle::hash_pair(&pair[0], &pair[1])
+				} else {
+					pair[0]
+				}
+			})
+			.collect();
+	}
+
+	(layer[0], proofs)
+}
+
+// Well known development keys.
+pub const ETH_KEY_1: [u8; 32] = hex!["ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"];
+pub const ETH_ADDRESS_1: [u8; 20] = hex!["f39fd6e51aad88f6f4ce6ab8827279cfffb92266"];
+pub const ETH_KEY_2: [u8; 32] = hex!["59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d"];
+pub const ETH_ADDRESS_2: [u8; 20] = hex!["70997970c51812dc3a010c7d01b50e0d17dc79c8"];
+
+pub fn ethereum_claimant(address: [u8; 20]) -> Claimant {
+	Claimant::Ethereum(EthereumAddress(address))
+}
+
+pub fn sr25519_claimant(pair: &sr25519::Pair) -> Claimant {
+	Claimant::Sr25519(pair.public().0)
+}
+
+pub fn sign_ecdsa(key: [u8; 32], distribution_id: DistributionId, who: AccountId) -> ClaimSignature {
+	let pair = ecdsa::Pair::from_seed(&key);
+	let message = Airdrop::claim_message(distribution_id, &who);
+	let hash = keccak_256(&EcdsaSignature::ethereum_signable_message(&message, Prefix::get()));
+	let mut signature = [0u8; 65];
+	signature.copy_from_slice(pair.sign_prehashed(&hash).as_ref());
+	ClaimSignature::Ecdsa(EcdsaSignature(signature))
+}
+
+pub fn sign_sr25519(pair: &sr25519::Pair, distribution_id: DistributionId, who: AccountId) -> ClaimSignature {
+	let message = [Prefix::get(), &Airdrop::claim_message(distribution_id, &who)[..]].concat();
+	ClaimSignature::Sr25519(pair.sign(&message))
+}
+
+pub fn bounded_proof(proof: Vec<H256>) -> BoundedVec<H256, MaxProofLength> {
+	proof.try_into().unwrap()
+}
+
+pub fn create_distribution(asset_id: AssetId, merkle_root: H256, amount: Balance) -> DistributionId {
+	let id = Airdrop::next_distribution_id();
+	assert_ok!(Airdrop::create_distribution(
+		RuntimeOrigin::root(),
+		asset_id,
+		merkle_root,
+		amount,
+		EXPIRY
+	));
+	id
+}
+
+#[macro_export]
+macro_rules! assert_balance {
+	( $x:expr, $y:expr, $z:expr) => {{
+		assert_eq!(Tokens::free_balance($y, &$x), $z);
+	}};
+}
@@ -0,0 +1,368 @@
+use super::*;
+use frame_support::dispatch::{GetDispatchInfo, Pays};
+use pretty_assertions::assert_eq;
+
+const ETH_AMOUNT: Balance = 100 * ONE;
+const SR_AMOUNT: Balance = 200 * ONE;
+const OTHER_AMOUNT: Balance = 300 * ONE;
+
+fn sr25519_pair() -> sr25519::Pair {
+	sr25519::Pair::from_seed(&[1u8; 32])
+}
+
+/// Creates a distribution of three entries and returns the proof of each entry.
+fn setup_distribution() -> Vec<Vec<H256>> {
+	let entries = vec![
+		(ethereum_claimant(ETH_ADDRESS_1), ETH_AMOUNT),
+		(sr25519_claimant(&sr25519_pair()), SR_AMOUNT),
+		(ethereum_claimant(ETH_ADDRESS_2), OTHER_AMOUNT),
+	];
+	let (root, proofs) = merkle_tree(&entries);
+	create_distribution(HDX, root, ETH_AMOUNT + SR_AMOUNT + OTHER_AMOUNT);
+	proofs
+}
+
+#[test]
+fn claim_should_work_when_signed_by_ethereum_claimant() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let proofs = setup_distribution();
+
+		// Act
+		assert_ok!(Airdrop::claim(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			ethereum_claimant(ETH_ADDRESS_1),
+			ETH_AMOUNT,
+			bounded_proof(proofs[0].clone()),
+			sign_ecdsa(ETH_KEY_1, 0, ALICE),
+		));
+
+		// Assert
+		assert_balance!(ALICE, HDX, ETH_AMOUNT);
+		assert_balance!(Airdrop::distribution_account(0), HDX, SR_AMOUNT + OTHER_AMOUNT);
+		assert_eq!(Airdrop::distributions(0).unwrap().remaining, SR_AMOUNT + OTHER_AMOUNT);
+		assert_eq!(Airdrop::claimed(0, ethereum_claimant(ETH_ADDRESS_1)), Some(()));
+		expect_events(vec![Event::Claimed {
+			id: 0,
+			claimant: ethereum_claimant(ETH_ADDRESS_1),
+			who: ALICE,
+			amount: ETH_AMOUNT,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn claim_should_work_when_signed_by_sr25519_claimant() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let proofs = setup_distribution();
+		let pair = sr25519_pair();
+
+		// Act
+		assert_ok!(Airdrop::claim(
+			RuntimeOrigin::signed(BOB),
+			0,
+			sr25519_claimant(&pair),
+			SR_AMOUNT,
+			bounded_proof(proofs[1].clone()),
+			sign_sr25519(&pair, 0, BOB),
+		));
+
+		// Assert
+		assert_balance!(BOB, HDX, SR_AMOUNT);
+		assert_eq!(Airdrop::claimed(0, sr25519_claimant(&pair)), Some(()));
+	});
+}
+
+#[test]
+fn claim_should_work_when_sr25519_message_is_wrapped_in_bytes_tags() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let proofs = setup_distribution();
+		let pair = sr25519_pair();
+		let message = [
+			&b"<Bytes>"[..],
+			Prefix::get(),
+			&Airdrop::claim_message(0, &BOB)[..],
+			&b"</Bytes>"[..],
+		]
+		.concat();
+
+		// Act
+		assert_ok!(Airdrop::claim(
+			RuntimeOrigin::signed(BOB),
+			0,
+			sr25519_claimant(&pair),
+			SR_AMOUNT,
+			bounded_proof(proofs[1].clone()),
+			ClaimSignature::Sr25519(pair.sign(&message)),
+		));
+
+		// Assert
+		assert_balance!(BOB, HDX, SR_AMOUNT);
+	});
+}
+
+#[test]
+fn claim_should_be_feeless() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call = RuntimeCall::Airdrop(Call::claim {
+			distribution_id: 0,
+			claimant: ethereum_claimant(ETH_ADDRESS_1),
+			amount: ETH_AMOUNT,
+			proof: bounded_proof(vec![]),
+			signature: sign_ecdsa(ETH_KEY_1, 0, ALICE),
+		});
+
+		assert_eq!(call.get_dispatch_info().pays_fee, Pays::No);
+	});
+}
+
+#[test]
+fn claim_should_fail_when_entry_is_already_claimed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let proofs = setup_distribution();
+		assert_ok!(Airdrop::claim(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			ethereum_claimant(ETH_ADDRESS_1),
+			ETH_AMOUNT,
+			bounded_proof(proofs[0].clone()),
+			sign_ecdsa(ETH_KEY_1, 0, ALICE),
+		));
+
+		// Act & Assert
+		assert_noop!(
+			Airdrop::claim(
+				RuntimeOrigin::signed(BOB),
+				0,
+				ethereum_claimant(ETH_ADDRESS_1),
+				ETH_AMOUNT,
+				bounded_proof(proofs[0].clone()),
+				sign_ecdsa(ETH_KEY_1, 0, BOB),
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+	});
+}
+
+#[test]
+fn claim_should_fail_when_amount_does_not_match_entry() {
+	ExtBuilder::default().build().execute_with(|| {
+		let proofs = setup_distribution();
+
+		assert_noop!(
+			Airdrop::claim(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ethereum_claimant(ETH_ADDRESS_1),
+				ETH_AMOUNT + 1,
+				bounded_proof(proofs[0].clone()),
+				sign_ecdsa(ETH_KEY_1, 0, ALICE),
+			),
+			Error::<Test>::InvalidProof
+		);
+	});
+}
+
+#[test]
+fn claim_should_fail_when_proof_belongs_to_other_entry() {
+	ExtBuilder::default().build().execute_with(|| {
+		let proofs = setup_distribution();
+
+		assert_noop!(
+			Airdrop::claim(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ethereum_claimant(ETH_ADDRESS_1),
+				ETH_AMOUNT,
+				bounded_proof(proofs[2].clone()),
+				sign_ecdsa(ETH_KEY_1, 0, ALICE),
+			),
+			Error::<Test>::InvalidProof
+		);
+	});
+}
+
+#[test]
+fn claim_should_fail_when_signed_by_other_key() {
+	ExtBuilder::default().build().execute_with(|| {
+		let proofs = setup_distribution();
+
+		assert_noop!(
+			Airdrop::claim(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ethereum_claimant(ETH_ADDRESS_1),
+				ETH_AMOUNT,
+				bounded_proof(proofs[0].clone()),
+				sign_ecdsa(ETH_KEY_2, 0, ALICE),
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn claim_should_fail_when_signature_was_made_for_other_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		let proofs = setup_distribution();
+
+		assert_noop!(
+			Airdrop::claim(
+				RuntimeOrigin::signed(BOB),
+				0,
+				ethereum_claimant(ETH_ADDRESS_1),
+				ETH_AMOUNT,
+				bounded_proof(proofs[0].clone()),
+				sign_ecdsa(ETH_KEY_1, 0, ALICE),
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn claim_should_fail_when_signature_was_made_for_other_distribution() {
+	ExtBuilder::default().build().execute_with(|| {
+		let proofs = setup_distribution();
+
+		assert_noop!(
+			Airdrop::claim(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ethereum_claimant(ETH_ADDRESS_1),
+				ETH_AMOUNT,
+				bounded_proof(proofs[0].clone()),
+				sign_ecdsa(ETH_KEY_1, 1, ALICE),
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn claim_should_fail_when_signature_type_does_not_match_claimant() {
+	ExtBuilder::default().build().execute_with(|| {
+		let proofs = setup_distribution();
+		let pair = sr25519_pair();
+
+		assert_noop!(
+			Airdrop::claim(
+				RuntimeOrigin::signed(BOB),
+				0,
+				sr25519_claimant(&pair),
+				SR_AMOUNT,
+				bounded_proof(proofs[1].clone()),
+				sign_ecdsa(ETH_KEY_1, 0, BOB),
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn claim_should_fail_when_distribution_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Airdrop::claim(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ethereum_claimant(ETH_ADDRESS_1),
+				ETH_AMOUNT,
+				bounded_proof(vec![]),
+				sign_ecdsa(ETH_KEY_1, 0, ALICE),
+			),
+			Error::<Test>::DistributionNotFound
+		);
+	});
+}
+
+#[test]
+fn claim_should_fail_when_distribution_has_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let proofs = setup_distribution();
+		System::set_block_number(EXPIRY);
+
+		// Act & Assert
+		assert_noop!(
+			Airdrop::claim(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ethereum_claimant(ETH_ADDRESS_1),
+				ETH_AMOUNT,
+				bounded_proof(proofs[0].clone()),
+				sign_ecdsa(ETH_KEY_1, 0, ALICE),
+			),
+			Error::<Test>::DistributionExpired
+		);
+	});
+}
+
+#[test]
+fn claim_should_fail_when_amount_exceeds_remaining_funds() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let entries = vec![(ethereum_claimant(ETH_ADDRESS_1), ETH_AMOUNT)];
+		let (root, proofs) = merkle_tree(&entries);
+		create_distribution(HDX, root, ETH_AMOUNT - 1);
+
+		// Act & Assert
+		assert_noop!(
+			Airdrop::claim(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ethereum_claimant(ETH_ADDRESS_1),
+				ETH_AMOUNT,
+				bounded_proof(proofs[0].clone()),
+				sign_ecdsa(ETH_KEY_1, 0, ALICE),
+			),
+			Error::<Test>::InsufficientFunds
+		);
+	});
+}
+
+#[test]
+fn all_entries_should_be_claimable() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let proofs = setup_distribution();
+		let pair = sr25519_pair();
+
+		// Act
+		assert_ok!(Airdrop::claim(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			ethereum_claimant(ETH_ADDRESS_1),
+			ETH_AMOUNT,
+			bounded_proof(proofs[0].clone()),
+			sign_ecdsa(ETH_KEY_1, 0, ALICE),
+		));
+		assert_ok!(Airdrop::claim(
+			RuntimeOrigin::signed(BOB),
+			0,
+			sr25519_claimant(&pair),
+			SR_AMOUNT,
+			bounded_proof(proofs[1].clone()),
+			sign_sr25519(&pair, 0, BOB),
+		));
+		assert_ok!(Airdrop::claim(
+			RuntimeOrigin::signed(BOB),
+			0,
+			ethereum_claimant(ETH_ADDRESS_2),
+			OTHER_AMOUNT,
+			bounded_proof(proofs[2].clone()),
+			sign_ecdsa(ETH_KEY_2, 0, BOB),
+		));
+
+		// Assert
+		assert_balance!(ALICE, HDX, ETH_AMOUNT);
+		assert_balance!(BOB, HDX, SR_AMOUNT + OTHER_AMOUNT);
+		assert_balance!(Airdrop::distribution_account(0), HDX, 0);
+		assert_eq!(Airdrop::distributions(0).unwrap().remaining, 0);
+	});
+}
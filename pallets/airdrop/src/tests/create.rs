@@ -0,0 +1,108 @@
+use super::*;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn create_distribution_should_work_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let merkle_root = H256::repeat_byte(1);
+
+		// Act
+		assert_ok!(Airdrop::create_distribution(
+			RuntimeOrigin::root(),
+			HDX,
+			merkle_root,
+			1_000 * ONE,
+			EXPIRY
+		));
+
+		// Assert
+		assert_eq!(
+			Airdrop::distributions(0),
+			Some(DistributionInfo {
+				merkle_root,
+				asset_id: HDX,
+				remaining: 1_000 * ONE,
+				expires_at: EXPIRY,
+			})
+		);
+		assert_eq!(Airdrop::next_distribution_id(), 1);
+		assert_balance!(Airdrop::distribution_account(0), HDX, 1_000 * ONE);
+		assert_balance!(TREASURY, HDX, INITIAL_TREASURY_BALANCE - 1_000 * ONE);
+		expect_events(vec![Event::DistributionCreated {
+			id: 0,
+			asset_id: HDX,
+			merkle_root,
+			amount: 1_000 * ONE,
+			expires_at: EXPIRY,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_distribution_should_use_separate_account_for_each_distribution() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		let first = create_distribution(HDX, H256::repeat_byte(1), 1_000 * ONE);
+		let second = create_distribution(DOT, H256::repeat_byte(2), 2_000 * ONE);
+
+		// Assert
+		assert_eq!((first, second), (0, 1));
+		assert_ne!(Airdrop::distribution_account(0), Airdrop::distribution_account(1));
+		assert_balance!(Airdrop::distribution_account(0), HDX, 1_000 * ONE);
+		assert_balance!(Airdrop::distribution_account(1), DOT, 2_000 * ONE);
+	});
+}
+
+#[test]
+fn create_distribution_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Airdrop::create_distribution(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				H256::repeat_byte(1),
+				1_000 * ONE,
+				EXPIRY
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_distribution_should_fail_when_amount_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Airdrop::create_distribution(RuntimeOrigin::root(), HDX, H256::repeat_byte(1), 0, EXPIRY),
+			Error::<Test>::ZeroAmount
+		);
+	});
+}
+
+#[test]
+fn create_distribution_should_fail_when_expiry_is_not_in_future() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Airdrop::create_distribution(RuntimeOrigin::root(), HDX, H256::repeat_byte(1), 1_000 * ONE, 1),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn create_distribution_should_fail_when_treasury_has_insufficient_balance() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(Airdrop::create_distribution(
+			RuntimeOrigin::root(),
+			HDX,
+			H256::repeat_byte(1),
+			INITIAL_TREASURY_BALANCE + 1,
+			EXPIRY
+		)
+		.is_err());
+		assert_eq!(Airdrop::distributions(0), None);
+	});
+}
@@ -0,0 +1,92 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+const AMOUNT: Balance = 100 * ONE;
+
+fn setup_distribution() -> Vec<Vec<H256>> {
+	let entries = vec![
+		(ethereum_claimant(ETH_ADDRESS_1), AMOUNT),
+		(ethereum_claimant(ETH_ADDRESS_2), AMOUNT),
+	];
+	let (root, proofs) = merkle_tree(&entries);
+	create_distribution(HDX, root, 2 * AMOUNT);
+	proofs
+}
+
+#[test]
+fn sweep_should_transfer_unclaimed_funds_to_treasury_when_distribution_has_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let proofs = setup_distribution();
+		assert_ok!(Airdrop::claim(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			ethereum_claimant(ETH_ADDRESS_1),
+			AMOUNT,
+			bounded_proof(proofs[0].clone()),
+			sign_ecdsa(ETH_KEY_1, 0, ALICE),
+		));
+		System::set_block_number(EXPIRY);
+
+		// Act
+		assert_ok!(Airdrop::sweep(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert_eq!(Airdrop::distributions(0), None);
+		assert_balance!(Airdrop::distribution_account(0), HDX, 0);
+		assert_balance!(TREASURY, HDX, INITIAL_TREASURY_BALANCE - AMOUNT);
+		assert_eq!(Airdrop::claimed(0, ethereum_claimant(ETH_ADDRESS_1)), Some(()));
+		expect_events(vec![Event::DistributionSwept {
+			id: 0,
+			asset_id: HDX,
+			amount: AMOUNT,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sweep_should_fail_when_distribution_has_not_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_distribution();
+		System::set_block_number(EXPIRY - 1);
+
+		assert_noop!(
+			Airdrop::sweep(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::DistributionNotExpired
+		);
+	});
+}
+
+#[test]
+fn sweep_should_fail_when_distribution_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Airdrop::sweep(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::DistributionNotFound
+		);
+	});
+}
+
+#[test]
+fn claim_should_fail_when_distribution_has_been_swept() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let proofs = setup_distribution();
+		System::set_block_number(EXPIRY);
+		assert_ok!(Airdrop::sweep(RuntimeOrigin::signed(BOB), 0));
+
+		// Act & Assert
+		assert_noop!(
+			Airdrop::claim(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				ethereum_claimant(ETH_ADDRESS_2),
+				AMOUNT,
+				bounded_proof(proofs[1].clone()),
+				sign_ecdsa(ETH_KEY_2, 0, ALICE),
+			),
+			Error::<Test>::DistributionNotFound
+		);
+	});
+}
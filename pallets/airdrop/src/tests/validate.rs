@@ -0,0 +1,93 @@
+use super::*;
+use frame_support::dispatch::DispatchInfo;
+use pretty_assertions::assert_eq;
+
+const AMOUNT: Balance = 100 * ONE;
+
+fn claim_call(proof: Vec<H256>, signature: ClaimSignature) -> RuntimeCall {
+	RuntimeCall::Airdrop(Call::claim {
+		distribution_id: 0,
+		claimant: ethereum_claimant(ETH_ADDRESS_1),
+		amount: AMOUNT,
+		proof: bounded_proof(proof),
+		signature,
+	})
+}
+
+fn setup_distribution() -> Vec<Vec<H256>> {
+	let entries = vec![
+		(ethereum_claimant(ETH_ADDRESS_1), AMOUNT),
+		(ethereum_claimant(ETH_ADDRESS_2), AMOUNT),
+	];
+	let (root, proofs) = merkle_tree(&entries);
+	create_distribution(HDX, root, 2 * AMOUNT);
+	proofs
+}
+
+#[test]
+fn validate_claim_should_accept_valid_claim() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let proofs = setup_distribution();
+		let call = claim_call(proofs[0].clone(), sign_ecdsa(ETH_KEY_1, 0, ALICE));
+
+		// Act
+		let result = ValidateClaim::<Test>::new().validate(&ALICE, &call, &DispatchInfo::default(), 0);
+
+		// Assert
+		let valid = result.unwrap();
+		assert_eq!(valid.provides.len(), 1);
+	});
+}
+
+#[test]
+fn validate_claim_should_reject_claim_with_invalid_signature() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let proofs = setup_distribution();
+		let call = claim_call(proofs[0].clone(), sign_ecdsa(ETH_KEY_2, 0, ALICE));
+
+		// Act
+		let result = ValidateClaim::<Test>::new().validate(&ALICE, &call, &DispatchInfo::default(), 0);
+
+		// Assert
+		assert_eq!(
+			result,
+			Err(TransactionValidityError::Invalid(error_to_invalid(
+				Error::<Test>::InvalidSignature
+			)))
+		);
+	});
+}
+
+#[test]
+fn validate_claim_should_reject_claim_with_invalid_proof() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let proofs = setup_distribution();
+		let call = claim_call(proofs[1].clone(), sign_ecdsa(ETH_KEY_1, 0, ALICE));
+
+		// Act
+		let result = ValidateClaim::<Test>::new().pre_dispatch(&ALICE, &call, &DispatchInfo::default(), 0);
+
+		// Assert
+		assert_eq!(
+			result,
+			Err(TransactionValidityError::Invalid(error_to_invalid(
+				Error::<Test>::InvalidProof
+			)))
+		);
+	});
+}
+
+#[test]
+fn validate_claim_should_ignore_other_calls() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call = RuntimeCall::Airdrop(Call::sweep { distribution_id: 0 });
+
+		assert_eq!(
+			ValidateClaim::<Test>::new().validate(&ALICE, &call, &DispatchInfo::default(), 0),
+			Ok(ValidTransaction::default())
+		);
+	});
+}
@@ -136,5 +136,76 @@ benchmarks! {
 	}
 
 
+	set_owner {
+		let asset_id = T::AssetId::from(3);
+		let name = vec![97u8; T::StringLimit::get() as usize].try_into().unwrap();
+		let _ = Pallet::<T>::register(RawOrigin::Root.into(), Some(asset_id), Some(name), AssetType::Token, Some(1_000_000_u128), None, None, None, None, true);
+
+		let owner: T::AccountId = account("owner", 0, 1);
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(origin, asset_id, Some(owner.clone()))
+	verify {
+		assert_eq!(Pallet::<T>::owner(asset_id), Some(owner));
+	}
+
+	update_as_owner {
+		let asset_id = T::AssetId::from(3);
+		let name = vec![97u8; T::StringLimit::get() as usize].try_into().unwrap();
+		let _ = Pallet::<T>::register(RawOrigin::Root.into(), Some(asset_id), Some(name), AssetType::Token, Some(1_000_000_u128), None, None, None, None, true);
+
+		let owner: T::AccountId = account("owner", 0, 1);
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		let _ = Pallet::<T>::set_owner(origin, asset_id, Some(owner.clone()));
+
+		let new_name: BoundedVec<u8, T::StringLimit> = vec![98u8; T::StringLimit::get() as usize].try_into().unwrap();
+		let new_symbol: BoundedVec<u8, T::StringLimit> = vec![98u8; T::StringLimit::get() as usize].try_into().unwrap();
+		let new_decimals = 12_u8;
+	}: _(RawOrigin::Signed(owner), asset_id, Some(new_name.clone()), Some(new_symbol.clone()), Some(new_decimals))
+	verify {
+		assert_eq!(Pallet::<T>::asset_ids(&new_name), Some(asset_id));
+
+		let details = Pallet::<T>::assets(asset_id).unwrap();
+		assert_eq!(details.symbol, Some(new_symbol));
+		assert_eq!(details.decimals, Some(new_decimals));
+	}
+
+	set_metadata {
+		let asset_id = T::AssetId::from(3);
+		let name = vec![97u8; T::StringLimit::get() as usize].try_into().unwrap();
+		let _ = Pallet::<T>::register(RawOrigin::Root.into(), Some(asset_id), Some(name), AssetType::Token, Some(1_000_000_u128), None, None, None, None, true);
+
+		let owner: T::AccountId = account("owner", 0, 1);
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		let _ = Pallet::<T>::set_owner(origin, asset_id, Some(owner.clone()));
+
+		let value: MetadataValue<T> = vec![97u8; T::MetadataValueLimit::get() as usize].try_into().unwrap();
+		let deposit = Pallet::<T>::metadata_deposit(value.len());
+		T::Currency::mint_into(T::DepositAssetId::get(), &owner, deposit.saturating_mul(10))?;
+	}: _(RawOrigin::Signed(owner.clone()), asset_id, MetadataKey::Logo, Some(value.clone()))
+	verify {
+		assert_eq!(Pallet::<T>::metadata(asset_id, MetadataKey::Logo), Some(MetadataEntry {
+			value,
+			deposit: Some((owner, deposit)),
+		}));
+	}
+
+	attest_metadata {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let asset_id = Pallet::<T>::next_asset_id().unwrap();
+		let location: T::AssetNativeLocation = Default::default();
+		let _ = Pallet::<T>::register_external(RawOrigin::Signed(caller).into(), location);
+
+		let symbol: BoundedVec<u8, T::StringLimit> = vec![97u8; T::StringLimit::get() as usize].try_into().unwrap();
+		let decimals = 12_u8;
+		let origin = T::AttestOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(origin, asset_id, symbol.clone(), decimals)
+	verify {
+		assert_eq!(Pallet::<T>::verification_status(asset_id), Some(VerificationStatus::Verified));
+
+		let details = Pallet::<T>::assets(asset_id).unwrap();
+		assert_eq!(details.symbol, Some(symbol));
+		assert_eq!(details.decimals, Some(decimals));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
@@ -44,13 +44,14 @@ pub use types::AssetType;
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
-pub use crate::types::{AssetDetails, Balance, Name, Symbol};
+pub use crate::types::{AssetDetails, Balance, MetadataEntry, MetadataKey, Name, Symbol, VerificationStatus};
 use frame_support::storage::with_transaction;
 use frame_support::BoundedVec;
 use hydradx_traits::{
 	registry::{Create, Inspect, Mutate},
 	AssetKind,
 };
+use orml_traits::MultiReservableCurrency;
 use sp_runtime::TransactionOutcome;
 
 /// Default value of existential deposit. This value is used if existential deposit wasn't
@@ -68,6 +69,11 @@ pub mod pallet {
 
 	pub type AssetDetailsT<T> = AssetDetails<<T as Config>::StringLimit>;
 
+	pub type MetadataEntryT<T> =
+		MetadataEntry<<T as frame_system::Config>::AccountId, <T as Config>::MetadataValueLimit>;
+
+	pub type MetadataValue<T> = BoundedVec<u8, <T as Config>::MetadataValueLimit>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -78,6 +84,10 @@ pub mod pallet {
 		/// The origin which can update assets' detail.
 		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The origin which can attest symbol and decimals of an asset.
+		/// It can be governance, an oracle or asset's reserve chain via XCM.
+		type AttestOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Asset type
 		type AssetId: Parameter
 			+ Member
@@ -110,6 +120,25 @@ pub mod pallet {
 		#[pallet::constant]
 		type RegExternalWeightMultiplier: Get<u64>;
 
+		/// Multi currency mechanism used to reserve deposits for asset's metadata.
+		type DepositCurrency: MultiReservableCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Balance>;
+
+		/// Asset in which deposits for asset's metadata are reserved.
+		#[pallet::constant]
+		type DepositAssetId: Get<Self::AssetId>;
+
+		/// Base deposit reserved for each metadata entry set by asset's owner.
+		#[pallet::constant]
+		type MetadataDepositBase: Get<Balance>;
+
+		/// Deposit reserved for each byte of metadata value set by asset's owner.
+		#[pallet::constant]
+		type MetadataDepositPerByte: Get<Balance>;
+
+		/// The maximum length of a metadata value stored on-chain.
+		#[pallet::constant]
+		type MetadataValueLimit: Get<u32> + Debug + PartialEq;
+
 		/// Weight information for the extrinsics
 		type WeightInfo: WeightInfo;
 	}
@@ -174,6 +203,21 @@ pub mod pallet {
 
 		/// Asset is not banned.
 		AssetNotBanned,
+
+		/// Asset is banned.
+		AssetIsBanned,
+
+		/// Account is not owner of the asset.
+		NotAssetOwner,
+
+		/// Metadata entry was not found.
+		MetadataNotFound,
+
+		/// Verified symbol or decimals can't be changed by asset's owner.
+		MetadataAlreadyVerified,
+
+		/// Symbol or decimals pending verification can't be set by asset's owner.
+		MetadataPendingVerification,
 	}
 
 	#[pallet::type_value]
@@ -215,6 +259,30 @@ pub mod pallet {
 	pub type LocationAssets<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetNativeLocation, T::AssetId, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn owner)]
+	/// Owner of an asset. Owner can manage asset's name, symbol, decimals and metadata.
+	pub type AssetOwners<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, T::AccountId, OptionQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn metadata)]
+	/// Additional metadata of an asset such as logo URI or website.
+	pub type AssetMetadata<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		MetadataKey,
+		MetadataEntryT<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn verification_status)]
+	/// Verification status of asset's symbol and decimals.
+	pub type AssetVerification<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, VerificationStatus, OptionQuery>;
+
 	#[pallet::storage]
 	/// Number of accounts that paid existential deposits for insufficient assets.
 	/// This storage is used by `SufficiencyCheck`.
@@ -337,6 +405,37 @@ pub mod pallet {
 
 		/// Asset's ban was removed.
 		AssetUnbanned { asset_id: T::AssetId },
+
+		/// Asset's owner was set or removed.
+		OwnerSet {
+			asset_id: T::AssetId,
+			owner: Option<T::AccountId>,
+		},
+
+		/// Asset's metadata entry was set.
+		MetadataSet {
+			asset_id: T::AssetId,
+			key: MetadataKey,
+			value: MetadataValue<T>,
+			deposit: Balance,
+		},
+
+		/// Asset's metadata entry was removed.
+		MetadataRemoved { asset_id: T::AssetId, key: MetadataKey },
+
+		/// Asset's symbol and decimals are waiting for attestation of values from asset's native location.
+		/// They can be set only by `AttestOrigin` via `attest_metadata`. No XCM query is sent.
+		VerificationRequested {
+			asset_id: T::AssetId,
+			location: T::AssetNativeLocation,
+		},
+
+		/// Asset's symbol and decimals were attested.
+		MetadataVerified {
+			asset_id: T::AssetId,
+			symbol: Symbol<T::StringLimit>,
+			decimals: u8,
+		},
 	}
 
 	#[pallet::call]
@@ -413,88 +512,43 @@ pub mod pallet {
 				T::UpdateOrigin::ensure_origin(origin)?;
 			}
 
-			if let Some(n) = name.as_ref() {
-				ensure!(n.len() >= T::MinStringLimit::get() as usize, Error::<T>::TooShort);
-			}
-
-			Self::validate_symbol(&symbol)?;
-
-			Assets::<T>::try_mutate(asset_id, |maybe_detail| -> DispatchResult {
-				let detail = maybe_detail.as_mut().ok_or(Error::<T>::AssetNotFound)?;
-
-				if let Some(new_name) = name.as_ref() {
-					ensure!(Self::asset_ids(new_name).is_none(), Error::<T>::AssetAlreadyRegistered);
-
-					if let Some(old_name) = &detail.name {
-						AssetIds::<T>::remove(old_name);
-					}
-
-					if Some(new_name.clone()) != detail.name {
-						AssetIds::<T>::insert(new_name, asset_id);
-					}
-				};
-
-				detail.name = name.or_else(|| detail.name.clone());
-				detail.asset_type = asset_type.unwrap_or(detail.asset_type);
-				detail.existential_deposit = existential_deposit.unwrap_or(detail.existential_deposit);
-				detail.xcm_rate_limit = xcm_rate_limit.or(detail.xcm_rate_limit);
-				detail.symbol = symbol.or_else(|| detail.symbol.clone());
-
-				let suff = is_sufficient.unwrap_or(detail.is_sufficient);
-				if detail.is_sufficient != suff {
-					//NOTE: Change sufficient -> insufficient require storage migration and is not
-					//allowed by extrinsic.
-					ensure!(!detail.is_sufficient, Error::<T>::ForbiddenSufficiencyChange);
-					detail.is_sufficient = suff;
-				}
-
-				if decimals.is_some() {
-					if detail.decimals.is_none() {
-						detail.decimals = decimals;
-					} else {
-						//Only highest origin can change decimal if it was set previously.
-						ensure!(is_registry_origin, Error::<T>::Forbidden);
-						detail.decimals = decimals;
-					};
-				}
-
-				if let Some(loc) = location {
-					//Only highest origin can update location.
-					ensure!(is_registry_origin, Error::<T>::Forbidden);
-
-					if let Some(old_location) = AssetLocations::<T>::take(asset_id) {
-						LocationAssets::<T>::remove(&old_location);
-					}
-					Self::do_set_location(asset_id, loc)?;
-				}
-
-				Self::deposit_event(Event::Updated {
-					asset_id,
-					asset_name: detail.name.clone(),
-					asset_type: detail.asset_type,
-					existential_deposit: detail.existential_deposit,
-					xcm_rate_limit: detail.xcm_rate_limit,
-					symbol: detail.symbol.clone(),
-					decimals: detail.decimals,
-					is_sufficient: detail.is_sufficient,
-				});
-
-				Ok(())
-			})
+			Self::do_update(
+				asset_id,
+				name,
+				asset_type,
+				existential_deposit,
+				xcm_rate_limit,
+				is_sufficient,
+				symbol,
+				decimals,
+				location,
+				is_registry_origin,
+			)
 		}
 
 		//NOTE: call indices 2 and 3 were used by removed extrinsics.
+		/// Register external asset.
+		///
+		/// Registered asset has no owner. Owner of an external asset can be assigned only by `UpdateOrigin`.
+		///
+		/// Asset is registered without symbol and decimals. It is marked as pending verification until
+		/// its symbol and decimals are attested by `AttestOrigin`. Attestation is the only way to set them.
+		///
+		/// Emits `Registered`, `LocationSet` and `VerificationRequested` events when successful.
 		#[pallet::call_index(4)]
 		#[pallet::weight(<T as Config>::WeightInfo::register_external().saturating_mul(<T as Config>::RegExternalWeightMultiplier::get()))]
 		pub fn register_external(origin: OriginFor<T>, location: T::AssetNativeLocation) -> DispatchResult {
-			let _ = ensure_signed(origin)?;
+			ensure_signed(origin)?;
 
-			Self::do_register_asset(
+			let asset_id = Self::do_register_asset(
 				None,
 				&AssetDetails::new(None, AssetType::External, DEFAULT_ED, None, None, None, false),
-				Some(location),
+				Some(location.clone()),
 			)?;
 
+			AssetVerification::<T>::insert(asset_id, VerificationStatus::Pending);
+			Self::deposit_event(Event::VerificationRequested { asset_id, location });
+
 			Ok(())
 		}
 
@@ -528,6 +582,162 @@ pub mod pallet {
 			Self::deposit_event(Event::AssetUnbanned { asset_id });
 			Ok(())
 		}
+
+		/// Set or remove owner of an asset.
+		///
+		/// Owner can update asset's name, symbol, decimals and metadata.
+		///
+		/// Can be called only by `UpdateOrigin`.
+		///
+		/// Emits `OwnerSet` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_owner())]
+		pub fn set_owner(origin: OriginFor<T>, asset_id: T::AssetId, owner: Option<T::AccountId>) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(Assets::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+
+			Self::do_set_owner(asset_id, owner);
+			Ok(())
+		}
+
+		/// Update asset's name, symbol or decimals.
+		///
+		/// Can be called only by asset's owner. Parameters are optional and value is not updated if param is `None`.
+		///
+		/// `decimals` - can be updated by owner only if it wasn't set yet.
+		///
+		/// `symbol` and `decimals` can't be updated by owner while they are pending verification
+		/// or once they were verified.
+		///
+		/// Emits `Updated` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_as_owner())]
+		pub fn update_as_owner(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			name: Option<Name<T::StringLimit>>,
+			symbol: Option<Symbol<T::StringLimit>>,
+			decimals: Option<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_owner(&who, asset_id)?;
+
+			match Self::verification_status(asset_id) {
+				Some(VerificationStatus::Pending) => ensure!(
+					symbol.is_none() && decimals.is_none(),
+					Error::<T>::MetadataPendingVerification
+				),
+				Some(VerificationStatus::Verified) => ensure!(
+					symbol.is_none() && decimals.is_none(),
+					Error::<T>::MetadataAlreadyVerified
+				),
+				None => {}
+			}
+
+			Self::do_update(asset_id, name, None, None, None, None, symbol, decimals, None, false)
+		}
+
+		/// Set or remove asset's metadata entry.
+		///
+		/// Can be called by asset's owner or by `UpdateOrigin`.
+		///
+		/// Owner reserves deposit in `DepositAssetId` for each entry. Deposit depends on the length of the value
+		/// and it is released when the entry is changed or removed. Entries set by `UpdateOrigin` require no deposit.
+		///
+		/// Emits `MetadataSet` or `MetadataRemoved` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_metadata())]
+		pub fn set_metadata(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			key: MetadataKey,
+			value: Option<MetadataValue<T>>,
+		) -> DispatchResult {
+			let depositor = if T::UpdateOrigin::ensure_origin(origin.clone()).is_ok() {
+				ensure!(Assets::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+				None
+			} else {
+				let who = ensure_signed(origin)?;
+				Self::ensure_owner(&who, asset_id)?;
+				Some(who)
+			};
+
+			AssetMetadata::<T>::try_mutate_exists(asset_id, key, |maybe_entry| -> DispatchResult {
+				ensure!(value.is_some() || maybe_entry.is_some(), Error::<T>::MetadataNotFound);
+
+				if let Some((who, amount)) = maybe_entry.take().and_then(|entry| entry.deposit) {
+					T::DepositCurrency::unreserve(T::DepositAssetId::get(), &who, amount);
+				}
+
+				match value {
+					Some(value) => {
+						let deposit = match depositor {
+							Some(who) => {
+								let amount = Self::metadata_deposit(value.len());
+								T::DepositCurrency::reserve(T::DepositAssetId::get(), &who, amount)
+									.map_err(|_| Error::<T>::InsufficientBalance)?;
+								Some((who, amount))
+							}
+							None => None,
+						};
+
+						Self::deposit_event(Event::MetadataSet {
+							asset_id,
+							key,
+							value: value.clone(),
+							deposit: deposit.as_ref().map(|(_, amount)| *amount).unwrap_or_default(),
+						});
+
+						*maybe_entry = Some(MetadataEntry { value, deposit });
+					}
+					None => {
+						Self::deposit_event(Event::MetadataRemoved { asset_id, key });
+					}
+				}
+
+				Ok(())
+			})
+		}
+
+		/// Attest asset's symbol and decimals.
+		///
+		/// Attested values are stored in asset's details and asset is marked as verified.
+		/// Attestation overrides previously set symbol and decimals.
+		///
+		/// Can be called only by `AttestOrigin`.
+		///
+		/// Emits `MetadataVerified` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::attest_metadata())]
+		pub fn attest_metadata(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			symbol: Symbol<T::StringLimit>,
+			decimals: u8,
+		) -> DispatchResult {
+			T::AttestOrigin::ensure_origin(origin)?;
+
+			Self::validate_symbol(&Some(symbol.clone()))?;
+
+			Assets::<T>::try_mutate(asset_id, |maybe_detail| -> DispatchResult {
+				let detail = maybe_detail.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+
+				detail.symbol = Some(symbol.clone());
+				detail.decimals = Some(decimals);
+
+				AssetVerification::<T>::insert(asset_id, VerificationStatus::Verified);
+
+				Self::deposit_event(Event::MetadataVerified {
+					asset_id,
+					symbol,
+					decimals,
+				});
+
+				Ok(())
+			})
+		}
 	}
 }
 
@@ -544,6 +754,107 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	#[allow(clippy::too_many_arguments)]
+	fn do_update(
+		asset_id: T::AssetId,
+		name: Option<Name<T::StringLimit>>,
+		asset_type: Option<AssetType>,
+		existential_deposit: Option<Balance>,
+		xcm_rate_limit: Option<Balance>,
+		is_sufficient: Option<bool>,
+		symbol: Option<Symbol<T::StringLimit>>,
+		decimals: Option<u8>,
+		location: Option<T::AssetNativeLocation>,
+		is_registry_origin: bool,
+	) -> DispatchResult {
+		if let Some(n) = name.as_ref() {
+			ensure!(n.len() >= T::MinStringLimit::get() as usize, Error::<T>::TooShort);
+		}
+
+		Self::validate_symbol(&symbol)?;
+
+		Assets::<T>::try_mutate(asset_id, |maybe_detail| -> DispatchResult {
+			let detail = maybe_detail.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+
+			if let Some(new_name) = name.as_ref() {
+				ensure!(Self::asset_ids(new_name).is_none(), Error::<T>::AssetAlreadyRegistered);
+
+				if let Some(old_name) = &detail.name {
+					AssetIds::<T>::remove(old_name);
+				}
+
+				if Some(new_name.clone()) != detail.name {
+					AssetIds::<T>::insert(new_name, asset_id);
+				}
+			};
+
+			detail.name = name.or_else(|| detail.name.clone());
+			detail.asset_type = asset_type.unwrap_or(detail.asset_type);
+			detail.existential_deposit = existential_deposit.unwrap_or(detail.existential_deposit);
+			detail.xcm_rate_limit = xcm_rate_limit.or(detail.xcm_rate_limit);
+			detail.symbol = symbol.or_else(|| detail.symbol.clone());
+
+			let suff = is_sufficient.unwrap_or(detail.is_sufficient);
+			if detail.is_sufficient != suff {
+				//NOTE: Change sufficient -> insufficient require storage migration and is not
+				//allowed by extrinsic.
+				ensure!(!detail.is_sufficient, Error::<T>::ForbiddenSufficiencyChange);
+				detail.is_sufficient = suff;
+			}
+
+			if decimals.is_some() {
+				if detail.decimals.is_none() {
+					detail.decimals = decimals;
+				} else {
+					//Only highest origin can change decimal if it was set previously.
+					ensure!(is_registry_origin, Error::<T>::Forbidden);
+					detail.decimals = decimals;
+				};
+			}
+
+			if let Some(loc) = location {
+				//Only highest origin can update location.
+				ensure!(is_registry_origin, Error::<T>::Forbidden);
+
+				if let Some(old_location) = AssetLocations::<T>::take(asset_id) {
+					LocationAssets::<T>::remove(&old_location);
+				}
+				Self::do_set_location(asset_id, loc)?;
+			}
+
+			Self::deposit_event(Event::Updated {
+				asset_id,
+				asset_name: detail.name.clone(),
+				asset_type: detail.asset_type,
+				existential_deposit: detail.existential_deposit,
+				xcm_rate_limit: detail.xcm_rate_limit,
+				symbol: detail.symbol.clone(),
+				decimals: detail.decimals,
+				is_sufficient: detail.is_sufficient,
+			});
+
+			Ok(())
+		})
+	}
+
+	fn ensure_owner(who: &T::AccountId, asset_id: T::AssetId) -> DispatchResult {
+		ensure!(Assets::<T>::contains_key(asset_id), Error::<T>::AssetNotFound);
+		ensure!(!BannedAssets::<T>::contains_key(asset_id), Error::<T>::AssetIsBanned);
+		ensure!(Self::owner(asset_id).as_ref() == Some(who), Error::<T>::NotAssetOwner);
+		Ok(())
+	}
+
+	fn do_set_owner(asset_id: T::AssetId, owner: Option<T::AccountId>) {
+		AssetOwners::<T>::set(asset_id, owner.clone());
+
+		Self::deposit_event(Event::OwnerSet { asset_id, owner });
+	}
+
+	/// Deposit reserved by asset's owner for metadata value of given length.
+	pub fn metadata_deposit(len: usize) -> Balance {
+		T::MetadataDepositBase::get().saturating_add(T::MetadataDepositPerByte::get().saturating_mul(len as Balance))
+	}
+
 	pub fn next_asset_id() -> Option<T::AssetId> {
 		NextAssetId::<T>::get().checked_add(&T::SequentialIdStartAt::get())
 	}
@@ -18,7 +18,7 @@
 #![cfg(test)]
 
 use crate::Balance;
-use frame_support::parameter_types;
+use frame_support::{ord_parameter_types, parameter_types};
 use frame_system as system;
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
@@ -38,6 +38,7 @@ pub type AssetId = u32;
 
 pub const UNIT: Balance = 1_000_000_000_000;
 pub const ALICE: u64 = 1_000;
+pub const BOB: u64 = 1_001;
 pub const TREASURY: u64 = 2_222;
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -61,6 +62,14 @@ parameter_types! {
 	#[derive(PartialEq, Debug)]
 	pub const RegistryMinStringLimit: u32 = 2;
 	pub const SequentialIdStart: u32 = 1_000_000;
+	#[derive(PartialEq, Debug)]
+	pub const RegistryMetadataValueLimit: u32 = 32;
+	pub const MetadataDepositBase: Balance = 10 * UNIT;
+	pub const MetadataDepositPerByte: Balance = UNIT;
+}
+
+ord_parameter_types! {
+	pub const Governance: u64 = ALICE;
 }
 
 impl system::Config for Test {
@@ -99,13 +108,19 @@ impl pallet_asset_registry::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Tokens;
 	type RegistryOrigin = frame_system::EnsureRoot<u64>;
-	type UpdateOrigin = frame_system::EnsureSigned<u64>;
+	type UpdateOrigin = frame_system::EnsureSignedBy<Governance, u64>;
+	type AttestOrigin = frame_system::EnsureRoot<u64>;
 	type AssetId = u32;
 	type AssetNativeLocation = AssetLocation;
 	type StringLimit = RegistryStringLimit;
 	type MinStringLimit = RegistryMinStringLimit;
 	type SequentialIdStartAt = SequentialIdStart;
 	type RegExternalWeightMultiplier = frame_support::traits::ConstU64<1>;
+	type DepositCurrency = Tokens;
+	type DepositAssetId = NativeAssetId;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type MetadataValueLimit = RegistryMetadataValueLimit;
 	type WeightInfo = ();
 }
 
@@ -7,10 +7,12 @@ mod create_trait;
 mod inspect_trait;
 pub(crate) mod mock;
 mod mutate_trait;
+mod owner;
 mod register;
 #[allow(clippy::module_inception)]
 mod tests;
 mod update;
+mod verification;
 
 #[macro_export]
 macro_rules! assert_last_event {
@@ -0,0 +1,340 @@
+use super::*;
+
+use crate::types::AssetType;
+use frame_support::traits::tokens::fungibles::Mutate as MutateFungibles;
+use mock::{AssetId, Registry};
+use mock::{RegistryMetadataValueLimit, RegistryStringLimit};
+use orml_traits::MultiReservableCurrency;
+use polkadot_xcm::v3::{
+	Junction::{self, Parachain},
+	Junctions::X2,
+	MultiLocation,
+};
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+const ASSET_ID: AssetId = 1;
+
+fn logo() -> BoundedVec<u8, RegistryMetadataValueLimit> {
+	b"ipfs://logo".to_vec().try_into().unwrap()
+}
+
+fn ext_with_owner() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_assets(vec![(
+			Some(ASSET_ID),
+			Some(b"Tkn1".to_vec().try_into().unwrap()),
+			UNIT,
+			None,
+			None,
+			None,
+			true,
+		)])
+		.build();
+	ext.execute_with(|| {
+		assert_ok!(Tokens::mint_into(NativeAssetId::get(), &BOB, 1_000 * UNIT));
+		assert_ok!(Registry::set_owner(RuntimeOrigin::signed(ALICE), ASSET_ID, Some(BOB)));
+	});
+	ext
+}
+
+#[test]
+fn set_owner_should_work_when_origin_is_update_origin() {
+	ext_with_owner().execute_with(|| {
+		assert_eq!(Registry::owner(ASSET_ID), Some(BOB));
+		assert_last_event!(Event::<Test>::OwnerSet {
+			asset_id: ASSET_ID,
+			owner: Some(BOB)
+		}
+		.into());
+
+		assert_ok!(Registry::set_owner(RuntimeOrigin::signed(ALICE), ASSET_ID, None));
+
+		assert_eq!(Registry::owner(ASSET_ID), None);
+	});
+}
+
+#[test]
+fn set_owner_should_not_work_when_origin_is_not_update_origin() {
+	ext_with_owner().execute_with(|| {
+		assert_noop!(
+			Registry::set_owner(RuntimeOrigin::signed(BOB), ASSET_ID, Some(BOB)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_owner_should_not_work_when_asset_does_not_exist() {
+	ext_with_owner().execute_with(|| {
+		assert_noop!(
+			Registry::set_owner(RuntimeOrigin::signed(ALICE), 2, Some(BOB)),
+			Error::<Test>::AssetNotFound
+		);
+	});
+}
+
+#[test]
+fn register_external_should_not_set_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		let expected_id = Pallet::<Test>::next_asset_id().unwrap();
+		let key = Junction::from(BoundedVec::try_from(528.encode()).unwrap());
+		let asset_location = AssetLocation(MultiLocation::new(0, X2(Parachain(200), key)));
+
+		assert_ok!(Registry::register_external(RuntimeOrigin::signed(BOB), asset_location));
+
+		assert_eq!(Registry::owner(expected_id), None);
+		assert!(!has_event(
+			Event::<Test>::OwnerSet {
+				asset_id: expected_id,
+				owner: Some(BOB)
+			}
+			.into()
+		));
+		assert_noop!(
+			Registry::update_as_owner(RuntimeOrigin::signed(BOB), expected_id, None, None, Some(12)),
+			Error::<Test>::NotAssetOwner
+		);
+	});
+}
+
+#[test]
+fn update_as_owner_should_work_when_caller_is_owner() {
+	ext_with_owner().execute_with(|| {
+		let name: BoundedVec<u8, RegistryStringLimit> = b"New Tkn 1".to_vec().try_into().unwrap();
+		let symbol: BoundedVec<u8, RegistryStringLimit> = b"nTkn1".to_vec().try_into().unwrap();
+
+		assert_ok!(Registry::update_as_owner(
+			RuntimeOrigin::signed(BOB),
+			ASSET_ID,
+			Some(name.clone()),
+			Some(symbol.clone()),
+			Some(12),
+		));
+
+		assert_eq!(
+			Registry::assets(ASSET_ID),
+			Some(AssetDetails {
+				name: Some(name.clone()),
+				asset_type: AssetType::Token,
+				existential_deposit: UNIT,
+				xcm_rate_limit: None,
+				symbol: Some(symbol.clone()),
+				decimals: Some(12),
+				is_sufficient: true,
+			})
+		);
+		assert_eq!(Registry::asset_ids(name.clone()), Some(ASSET_ID));
+		assert_eq!(
+			Registry::asset_ids::<BoundedVec<u8, RegistryStringLimit>>(b"Tkn1".to_vec().try_into().unwrap()),
+			None
+		);
+
+		assert_last_event!(Event::<Test>::Updated {
+			asset_id: ASSET_ID,
+			asset_name: Some(name),
+			asset_type: AssetType::Token,
+			existential_deposit: UNIT,
+			xcm_rate_limit: None,
+			symbol: Some(symbol),
+			decimals: Some(12),
+			is_sufficient: true,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn update_as_owner_should_not_work_when_caller_is_not_owner() {
+	ext_with_owner().execute_with(|| {
+		assert_noop!(
+			Registry::update_as_owner(RuntimeOrigin::signed(ALICE), ASSET_ID, None, None, Some(12)),
+			Error::<Test>::NotAssetOwner
+		);
+	});
+}
+
+#[test]
+fn update_as_owner_should_not_work_when_decimals_are_already_set() {
+	ext_with_owner().execute_with(|| {
+		assert_ok!(Registry::update_as_owner(
+			RuntimeOrigin::signed(BOB),
+			ASSET_ID,
+			None,
+			None,
+			Some(12)
+		));
+
+		assert_noop!(
+			Registry::update_as_owner(RuntimeOrigin::signed(BOB), ASSET_ID, None, None, Some(18)),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn update_as_owner_should_not_work_when_asset_is_banned() {
+	ext_with_owner().execute_with(|| {
+		assert_ok!(Registry::ban_asset(RuntimeOrigin::signed(ALICE), ASSET_ID));
+
+		assert_noop!(
+			Registry::update_as_owner(RuntimeOrigin::signed(BOB), ASSET_ID, None, None, Some(12)),
+			Error::<Test>::AssetIsBanned
+		);
+	});
+}
+
+#[test]
+fn set_metadata_should_reserve_deposit_when_caller_is_owner() {
+	ext_with_owner().execute_with(|| {
+		let expected_deposit = MetadataDepositBase::get() + MetadataDepositPerByte::get() * logo().len() as u128;
+
+		assert_ok!(Registry::set_metadata(
+			RuntimeOrigin::signed(BOB),
+			ASSET_ID,
+			MetadataKey::Logo,
+			Some(logo())
+		));
+
+		assert_eq!(
+			Registry::metadata(ASSET_ID, MetadataKey::Logo),
+			Some(MetadataEntry {
+				value: logo(),
+				deposit: Some((BOB, expected_deposit)),
+			})
+		);
+		assert_eq!(Tokens::reserved_balance(NativeAssetId::get(), &BOB), expected_deposit);
+		assert_last_event!(Event::<Test>::MetadataSet {
+			asset_id: ASSET_ID,
+			key: MetadataKey::Logo,
+			value: logo(),
+			deposit: expected_deposit,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn set_metadata_should_adjust_deposit_when_value_is_changed() {
+	ext_with_owner().execute_with(|| {
+		let website: BoundedVec<u8, RegistryMetadataValueLimit> = b"https://hydradx.io".to_vec().try_into().unwrap();
+		assert_ok!(Registry::set_metadata(
+			RuntimeOrigin::signed(BOB),
+			ASSET_ID,
+			MetadataKey::Website,
+			Some(logo())
+		));
+
+		assert_ok!(Registry::set_metadata(
+			RuntimeOrigin::signed(BOB),
+			ASSET_ID,
+			MetadataKey::Website,
+			Some(website.clone())
+		));
+
+		assert_eq!(
+			Tokens::reserved_balance(NativeAssetId::get(), &BOB),
+			Registry::metadata_deposit(website.len())
+		);
+	});
+}
+
+#[test]
+fn set_metadata_should_release_deposit_when_value_is_removed() {
+	ext_with_owner().execute_with(|| {
+		assert_ok!(Registry::set_metadata(
+			RuntimeOrigin::signed(BOB),
+			ASSET_ID,
+			MetadataKey::Logo,
+			Some(logo())
+		));
+
+		assert_ok!(Registry::set_metadata(
+			RuntimeOrigin::signed(BOB),
+			ASSET_ID,
+			MetadataKey::Logo,
+			None
+		));
+
+		assert_eq!(Registry::metadata(ASSET_ID, MetadataKey::Logo), None);
+		assert_eq!(Tokens::reserved_balance(NativeAssetId::get(), &BOB), 0);
+		assert_last_event!(Event::<Test>::MetadataRemoved {
+			asset_id: ASSET_ID,
+			key: MetadataKey::Logo,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn set_metadata_should_release_owners_deposit_when_update_origin_overrides_value() {
+	ext_with_owner().execute_with(|| {
+		let coingecko_id: BoundedVec<u8, RegistryMetadataValueLimit> = b"hydradx".to_vec().try_into().unwrap();
+		assert_ok!(Registry::set_metadata(
+			RuntimeOrigin::signed(BOB),
+			ASSET_ID,
+			MetadataKey::CoingeckoId,
+			Some(logo())
+		));
+
+		assert_ok!(Registry::set_metadata(
+			RuntimeOrigin::signed(ALICE),
+			ASSET_ID,
+			MetadataKey::CoingeckoId,
+			Some(coingecko_id.clone())
+		));
+
+		assert_eq!(
+			Registry::metadata(ASSET_ID, MetadataKey::CoingeckoId),
+			Some(MetadataEntry {
+				value: coingecko_id,
+				deposit: None,
+			})
+		);
+		assert_eq!(Tokens::reserved_balance(NativeAssetId::get(), &BOB), 0);
+	});
+}
+
+#[test]
+fn set_metadata_should_not_work_when_caller_is_not_owner() {
+	ext_with_owner().execute_with(|| {
+		assert_ok!(Registry::set_owner(RuntimeOrigin::signed(ALICE), ASSET_ID, None));
+
+		assert_noop!(
+			Registry::set_metadata(RuntimeOrigin::signed(BOB), ASSET_ID, MetadataKey::Logo, Some(logo())),
+			Error::<Test>::NotAssetOwner
+		);
+	});
+}
+
+#[test]
+fn set_metadata_should_not_work_when_owner_has_insufficient_balance() {
+	ext_with_owner().execute_with(|| {
+		let owner_without_balance = 1_002;
+		assert_ok!(Registry::set_owner(
+			RuntimeOrigin::signed(ALICE),
+			ASSET_ID,
+			Some(owner_without_balance)
+		));
+
+		assert_noop!(
+			Registry::set_metadata(
+				RuntimeOrigin::signed(owner_without_balance),
+				ASSET_ID,
+				MetadataKey::Logo,
+				Some(logo())
+			),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn set_metadata_should_not_work_when_removed_entry_does_not_exist() {
+	ext_with_owner().execute_with(|| {
+		assert_noop!(
+			Registry::set_metadata(RuntimeOrigin::signed(BOB), ASSET_ID, MetadataKey::Logo, None),
+			Error::<Test>::MetadataNotFound
+		);
+	});
+}
@@ -0,0 +1,187 @@
+use super::*;
+
+use crate::types::AssetType;
+use mock::{AssetId, Registry, RegistryStringLimit};
+use polkadot_xcm::v3::{
+	Junction::{self, Parachain},
+	Junctions::X2,
+	MultiLocation,
+};
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn register_external_asset() -> AssetId {
+	let asset_id = Pallet::<Test>::next_asset_id().unwrap();
+	let key = Junction::from(BoundedVec::try_from(528.encode()).unwrap());
+	let asset_location = AssetLocation(MultiLocation::new(0, X2(Parachain(200), key)));
+
+	assert_ok!(Registry::register_external(RuntimeOrigin::signed(BOB), asset_location));
+	assert_ok!(Registry::set_owner(RuntimeOrigin::signed(ALICE), asset_id, Some(BOB)));
+
+	asset_id
+}
+
+fn symbol() -> Symbol<RegistryStringLimit> {
+	b"xTKN".to_vec().try_into().unwrap()
+}
+
+#[test]
+fn register_external_should_mark_asset_as_pending_verification() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = register_external_asset();
+
+		assert_eq!(
+			Registry::verification_status(asset_id),
+			Some(VerificationStatus::Pending)
+		);
+
+		let key = Junction::from(BoundedVec::try_from(528.encode()).unwrap());
+		assert!(has_event(
+			Event::<Test>::VerificationRequested {
+				asset_id,
+				location: AssetLocation(MultiLocation::new(0, X2(Parachain(200), key))),
+			}
+			.into()
+		));
+	});
+}
+
+#[test]
+fn attest_metadata_should_store_verified_metadata_when_asset_is_pending() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = register_external_asset();
+
+		assert_ok!(Registry::attest_metadata(RuntimeOrigin::root(), asset_id, symbol(), 10));
+
+		assert_eq!(
+			Registry::verification_status(asset_id),
+			Some(VerificationStatus::Verified)
+		);
+		assert_eq!(
+			Registry::assets(asset_id),
+			Some(AssetDetails {
+				name: None,
+				asset_type: AssetType::External,
+				existential_deposit: crate::DEFAULT_ED,
+				xcm_rate_limit: None,
+				symbol: Some(symbol()),
+				decimals: Some(10),
+				is_sufficient: false,
+			})
+		);
+		assert_last_event!(Event::<Test>::MetadataVerified {
+			asset_id,
+			symbol: symbol(),
+			decimals: 10,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn update_as_owner_should_not_work_when_metadata_is_pending_verification() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = register_external_asset();
+
+		assert_noop!(
+			Registry::update_as_owner(RuntimeOrigin::signed(BOB), asset_id, None, None, Some(18)),
+			Error::<Test>::MetadataPendingVerification
+		);
+		assert_noop!(
+			Registry::update_as_owner(
+				RuntimeOrigin::signed(BOB),
+				asset_id,
+				None,
+				Some(b"TKN".to_vec().try_into().unwrap()),
+				None
+			),
+			Error::<Test>::MetadataPendingVerification
+		);
+
+		let name: Name<RegistryStringLimit> = b"Token".to_vec().try_into().unwrap();
+		assert_ok!(Registry::update_as_owner(
+			RuntimeOrigin::signed(BOB),
+			asset_id,
+			Some(name.clone()),
+			None,
+			None
+		));
+		assert_eq!(Registry::assets(asset_id).unwrap().name, Some(name));
+		assert_eq!(
+			Registry::verification_status(asset_id),
+			Some(VerificationStatus::Pending)
+		);
+	});
+}
+
+#[test]
+fn attest_metadata_should_not_work_when_origin_is_not_attest_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = register_external_asset();
+
+		assert_noop!(
+			Registry::attest_metadata(RuntimeOrigin::signed(BOB), asset_id, symbol(), 10),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn attest_metadata_should_not_work_when_asset_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Registry::attest_metadata(RuntimeOrigin::root(), 1, symbol(), 10),
+			Error::<Test>::AssetNotFound
+		);
+	});
+}
+
+#[test]
+fn attest_metadata_should_not_work_when_symbol_is_not_valid() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = register_external_asset();
+
+		assert_noop!(
+			Registry::attest_metadata(
+				RuntimeOrigin::root(),
+				asset_id,
+				b"x TKN".to_vec().try_into().unwrap(),
+				10
+			),
+			Error::<Test>::InvalidSymbol
+		);
+	});
+}
+
+#[test]
+fn update_as_owner_should_not_work_when_metadata_is_verified() {
+	ExtBuilder::default().build().execute_with(|| {
+		let asset_id = register_external_asset();
+		assert_ok!(Registry::attest_metadata(RuntimeOrigin::root(), asset_id, symbol(), 10));
+
+		assert_noop!(
+			Registry::update_as_owner(RuntimeOrigin::signed(BOB), asset_id, None, None, Some(18)),
+			Error::<Test>::MetadataAlreadyVerified
+		);
+		assert_noop!(
+			Registry::update_as_owner(
+				RuntimeOrigin::signed(BOB),
+				asset_id,
+				None,
+				Some(b"TKN".to_vec().try_into().unwrap()),
+				None
+			),
+			Error::<Test>::MetadataAlreadyVerified
+		);
+
+		let name: Name<RegistryStringLimit> = b"Token".to_vec().try_into().unwrap();
+		assert_ok!(Registry::update_as_owner(
+			RuntimeOrigin::signed(BOB),
+			asset_id,
+			Some(name.clone()),
+			None,
+			None
+		));
+		assert_eq!(Registry::assets(asset_id).unwrap().name, Some(name));
+	});
+}
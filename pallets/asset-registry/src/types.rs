@@ -108,3 +108,36 @@ impl<StringLimit: Get<u32>> AssetDetails<StringLimit> {
 		}
 	}
 }
+
+/// Keys of asset's metadata which can be managed by asset's owner.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum MetadataKey {
+	/// URI of asset's logo.
+	Logo,
+	/// Asset's website.
+	Website,
+	/// Asset's id on coingecko.
+	CoingeckoId,
+}
+
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(ValueLimit))]
+pub struct MetadataEntry<AccountId, ValueLimit: Get<u32>> {
+	/// Metadata value. Limited in length by `ValueLimit`.
+	pub value: BoundedVec<u8, ValueLimit>,
+
+	/// Account which reserved the deposit and reserved amount.
+	/// `None` if entry was set by `UpdateOrigin`.
+	pub deposit: Option<(AccountId, Balance)>,
+}
+
+/// Verification status of asset's symbol and decimals.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum VerificationStatus {
+	/// Asset's metadata is waiting for attestation.
+	Pending,
+	/// Asset's symbol and decimals were attested by `AttestOrigin`.
+	Verified,
+}
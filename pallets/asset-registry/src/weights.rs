@@ -52,6 +52,10 @@ pub trait WeightInfo {
 	fn register_external() -> Weight;
 	fn ban_asset() -> Weight;
 	fn unban_asset() -> Weight;
+	fn set_owner() -> Weight;
+	fn update_as_owner() -> Weight;
+	fn set_metadata() -> Weight;
+	fn attest_metadata() -> Weight;
 }
 /// Weights for pallet_asset_registry using the hydraDX node and recommended hardware.
 pub struct HydraWeight<T>(PhantomData<T>);
@@ -95,11 +99,13 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: AssetRegistry AssetLocations (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
 	// Storage: AssetRegistry Assets (r:0 w:1)
 	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetVerification (r:0 w:1)
+	// Proof: AssetRegistry AssetVerification (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
 	fn register_external() -> Weight {
-		// Minimum execution time: 63_891 nanoseconds.
-		Weight::from_parts(64_922_000, 0)
+		// Minimum execution time: 68_032 nanoseconds.
+		Weight::from_parts(68_871_000, 0)
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 
 	// Storage: AssetRegistry Assets (r:1 w:0)
@@ -120,6 +126,56 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: AssetRegistry Assets (r:1 w:0)
+	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetOwners (r:0 w:1)
+	// Proof: AssetRegistry AssetOwners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_owner() -> Weight {
+		// Minimum execution time: 20_314 nanoseconds.
+		Weight::from_parts(20_862_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetRegistry Assets (r:1 w:1)
+	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	// Storage: AssetRegistry BannedAssets (r:1 w:0)
+	// Proof: AssetRegistry BannedAssets (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetOwners (r:1 w:0)
+	// Proof: AssetRegistry AssetOwners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetIds (r:1 w:2)
+	// Proof: AssetRegistry AssetIds (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	fn update_as_owner() -> Weight {
+		// Minimum execution time: 41_108 nanoseconds.
+		Weight::from_parts(41_796_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: AssetRegistry Assets (r:1 w:0)
+	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	// Storage: AssetRegistry BannedAssets (r:1 w:0)
+	// Proof: AssetRegistry BannedAssets (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetOwners (r:1 w:0)
+	// Proof: AssetRegistry AssetOwners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetMetadata (r:1 w:1)
+	// Proof: AssetRegistry AssetMetadata (max_values: None, max_size: Some(344), added: 2819, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn set_metadata() -> Weight {
+		// Minimum execution time: 46_923 nanoseconds.
+		Weight::from_parts(47_581_000, 0)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: AssetRegistry Assets (r:1 w:1)
+	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetVerification (r:0 w:1)
+	// Proof: AssetRegistry AssetVerification (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	fn attest_metadata() -> Weight {
+		// Minimum execution time: 24_519 nanoseconds.
+		Weight::from_parts(25_047_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 }
 
 impl WeightInfo for () {
@@ -161,11 +217,13 @@ impl WeightInfo for () {
 	// Proof: AssetRegistry AssetLocations (max_values: None, max_size: Some(622), added: 3097, mode: MaxEncodedLen)
 	// Storage: AssetRegistry Assets (r:0 w:1)
 	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetVerification (r:0 w:1)
+	// Proof: AssetRegistry AssetVerification (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
 	fn register_external() -> Weight {
-		// Minimum execution time: 63_891 nanoseconds.
-		Weight::from_parts(64_922_000, 0)
+		// Minimum execution time: 68_032 nanoseconds.
+		Weight::from_parts(68_871_000, 0)
 			.saturating_add(RocksDbWeight::get().reads(4 as u64))
-			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
 	}
 
 	// Storage: AssetRegistry Assets (r:1 w:0)
@@ -186,4 +244,54 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// Storage: AssetRegistry Assets (r:1 w:0)
+	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetOwners (r:0 w:1)
+	// Proof: AssetRegistry AssetOwners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn set_owner() -> Weight {
+		// Minimum execution time: 20_314 nanoseconds.
+		Weight::from_parts(20_862_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: AssetRegistry Assets (r:1 w:1)
+	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	// Storage: AssetRegistry BannedAssets (r:1 w:0)
+	// Proof: AssetRegistry BannedAssets (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetOwners (r:1 w:0)
+	// Proof: AssetRegistry AssetOwners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetIds (r:1 w:2)
+	// Proof: AssetRegistry AssetIds (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
+	fn update_as_owner() -> Weight {
+		// Minimum execution time: 41_108 nanoseconds.
+		Weight::from_parts(41_796_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: AssetRegistry Assets (r:1 w:0)
+	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	// Storage: AssetRegistry BannedAssets (r:1 w:0)
+	// Proof: AssetRegistry BannedAssets (max_values: None, max_size: Some(20), added: 2495, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetOwners (r:1 w:0)
+	// Proof: AssetRegistry AssetOwners (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetMetadata (r:1 w:1)
+	// Proof: AssetRegistry AssetMetadata (max_values: None, max_size: Some(344), added: 2819, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn set_metadata() -> Weight {
+		// Minimum execution time: 46_923 nanoseconds.
+		Weight::from_parts(47_581_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: AssetRegistry Assets (r:1 w:1)
+	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(133), added: 2608, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetVerification (r:0 w:1)
+	// Proof: AssetRegistry AssetVerification (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	fn attest_metadata() -> Weight {
+		// Minimum execution time: 24_519 nanoseconds.
+		Weight::from_parts(25_047_000, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
 }
@@ -139,6 +139,28 @@ benchmarks! {
 		assert_eq!(LiquidityRemoveLimitPerAsset::<T>::get(asset_id), trade_limit);
 	}
 
+	set_trade_volume_window_limit {
+		let asset_id = T::AssetId::from(2u32);
+		let trade_limit = Some((crate::MAX_LIMIT_VALUE, 1));
+
+	}: _(RawOrigin::Root, asset_id, trade_limit)
+	verify {
+		assert_eq!(TradeVolumeWindowLimitPerAsset::<T>::get(asset_id), trade_limit);
+	}
+
+	resume_trading {
+		let asset_id = T::AssetId::from(2u32);
+		let halted_until: BlockNumberFor<T> = 10u32.into();
+		let state = T::TradingHaltHandler::halt_trading(asset_id)?;
+		HaltedAssets::<T>::insert(asset_id, (halted_until, state));
+		TradingResumes::<T>::insert(halted_until, asset_id, ());
+
+	}: _(RawOrigin::Root, asset_id)
+	verify {
+		assert!(HaltedAssets::<T>::get(asset_id).is_none());
+		assert!(TradingResumes::<T>::get(halted_until, asset_id).is_none());
+	}
+
 	ensure_add_liquidity_limit {
 		let user: T::AccountId = account("user", 0, 1);
 		let asset_id = T::AssetId::from(2u32);
@@ -176,6 +198,17 @@ benchmarks! {
 		let asset_out_id = T::AssetId::from(3u32);
 		let asset_out_reserve = 200_000_000_000_000u128;
 		let amount_out = 10_000_000_000_000u128;
+
+		// worst case: volume windows of both assets are tracked and have to be rolled
+		let window_limit = Some((crate::MAX_LIMIT_VALUE, 1));
+		TradeVolumeWindowLimitPerAsset::<T>::insert(asset_in_id, window_limit);
+		TradeVolumeWindowLimitPerAsset::<T>::insert(asset_out_id, window_limit);
+		crate::Pallet::<T>::ensure_pool_state_change_limit(asset_in_id, asset_in_reserve.into(), amount_in.into(), asset_out_id, asset_out_reserve.into(), amount_out.into())?;
+		crate::Pallet::<T>::on_finalize(frame_system::Pallet::<T>::block_number());
+
+		let now = frame_system::Pallet::<T>::block_number().saturating_add(T::TradeVolumeWindowLength::get());
+		frame_system::Pallet::<T>::set_block_number(now);
+
 		let before_in = AllowedTradeVolumeLimitPerAsset::<T>::get(asset_in_id);
 		let before_out = AllowedTradeVolumeLimitPerAsset::<T>::get(asset_out_id);
 	}: {
@@ -187,6 +220,9 @@ benchmarks! {
 
 		assert!(before_in != after_in);
 		assert!(before_out != after_out);
+
+		assert_eq!(TradeVolumeWindowPerAsset::<T>::get(asset_in_id).map(|window| window.start), Some(now));
+		assert_eq!(TradeVolumeWindowPerAsset::<T>::get(asset_out_id).map(|window| window.start), Some(now));
 	}
 
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
@@ -17,6 +17,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use crate::traits::TradingHaltHandler;
 use codec::{Decode, Encode};
 use frame_support::traits::{Contains, EnsureOrigin};
 use frame_support::weights::Weight;
@@ -25,9 +26,10 @@ use frame_system::ensure_signed_or_root;
 use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 use scale_info::TypeInfo;
 use sp_core::MaxEncodedLen;
-use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Zero};
-use sp_runtime::{ArithmeticError, DispatchError, RuntimeDebug};
+use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Saturating, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, Perbill, RuntimeDebug};
 
+pub mod traits;
 pub mod weights;
 
 #[cfg(any(feature = "runtime-benchmarks", test))]
@@ -120,6 +122,97 @@ where
 	}
 }
 
+/// Net trade volume of an asset tracked over a rolling multi-block window.
+///
+/// The window is approximated by two consecutive fixed-length windows. Volumes of the previous window
+/// are weighted by the part of it which still overlaps with the rolling window ending in the current block.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
+#[scale_info(skip_type_params(T))]
+pub struct TradeVolumeWindow<T: Config> {
+	pub start: BlockNumberFor<T>,
+	pub volume_in: T::Balance,
+	pub volume_out: T::Balance,
+	pub previous_volume_in: T::Balance,
+	pub previous_volume_out: T::Balance,
+	pub limit: T::Balance,
+}
+
+impl<T: Config> TradeVolumeWindow<T>
+where
+	T::Balance: PartialOrd,
+{
+	pub fn new(start: BlockNumberFor<T>, limit: T::Balance) -> Self {
+		Self {
+			start,
+			volume_in: Zero::zero(),
+			volume_out: Zero::zero(),
+			previous_volume_in: Zero::zero(),
+			previous_volume_out: Zero::zero(),
+			limit,
+		}
+	}
+
+	/// Returns `true` if the window which started in `start` block has ended before `now`.
+	pub fn is_expired(&self, now: BlockNumberFor<T>, length: BlockNumberFor<T>) -> bool {
+		now >= self.start.saturating_add(length)
+	}
+
+	/// Move the window forward so that it contains `now` block.
+	/// Volumes of the current window become volumes of the previous window, or are dropped completely
+	/// if the previous window does not overlap with the rolling window anymore.
+	pub fn roll(&mut self, now: BlockNumberFor<T>, length: BlockNumberFor<T>, limit: T::Balance) {
+		let next_start = self.start.saturating_add(length);
+		if now >= next_start.saturating_add(length) {
+			self.previous_volume_in = Zero::zero();
+			self.previous_volume_out = Zero::zero();
+			self.start = now;
+		} else {
+			self.previous_volume_in = self.volume_in;
+			self.previous_volume_out = self.volume_out;
+			self.start = next_start;
+		}
+		self.volume_in = Zero::zero();
+		self.volume_out = Zero::zero();
+		self.limit = limit;
+	}
+
+	pub fn update_amounts(&mut self, amount_in: T::Balance, amount_out: T::Balance) -> DispatchResult {
+		self.volume_in = self
+			.volume_in
+			.checked_add(&amount_in)
+			.ok_or(ArithmeticError::Overflow)?;
+		self.volume_out = self
+			.volume_out
+			.checked_add(&amount_out)
+			.ok_or(ArithmeticError::Overflow)?;
+		Ok(())
+	}
+
+	/// Net volumes of the rolling window ending in `now` block.
+	pub fn rolling_volume(&self, now: BlockNumberFor<T>, length: BlockNumberFor<T>) -> TradeVolumeLimit<T> {
+		let elapsed = now.saturating_sub(self.start).min(length);
+		let previous_weight = Perbill::from_rational(length.saturating_sub(elapsed), length);
+
+		TradeVolumeLimit::<T> {
+			volume_in: self
+				.volume_in
+				.saturating_add(previous_weight.mul_floor(self.previous_volume_in)),
+			volume_out: self
+				.volume_out
+				.saturating_add(previous_weight.mul_floor(self.previous_volume_out)),
+			limit: self.limit,
+		}
+	}
+
+	pub fn is_limit_exceeded(&self, now: BlockNumberFor<T>, length: BlockNumberFor<T>) -> bool {
+		self.rolling_volume(now, length).check_limits().is_err()
+	}
+}
+
+/// Trading state of an asset before its trading was halted.
+pub type TradingHaltStateOf<T> =
+	<<T as Config>::TradingHaltHandler as TradingHaltHandler<<T as Config>::AssetId>>::State;
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -133,8 +226,32 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
-			T::WeightInfo::on_finalize(0, 0)
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			let mut scheduled: u64 = 0;
+			let mut resumed: u64 = 0;
+			for (asset_id, ()) in <TradingResumes<T>>::drain_prefix(n) {
+				scheduled.saturating_inc();
+				let Some((_, state)) = <HaltedAssets<T>>::get(asset_id) else {
+					continue;
+				};
+
+				resumed.saturating_inc();
+				// Asset stays halted if its trading can't be resumed, so it can be resumed later by `TechnicalOrigin`.
+				if T::TradingHaltHandler::resume_trading(asset_id, state).is_ok() {
+					<HaltedAssets<T>>::remove(asset_id);
+					Self::deposit_event(Event::TradingResumed { asset_id });
+				}
+			}
+
+			T::DbWeight::get()
+				.reads(1)
+				.saturating_add(T::DbWeight::get().reads_writes(2, 1).saturating_mul(scheduled))
+				.saturating_add(
+					T::DbWeight::get()
+						.writes(1)
+						.saturating_add(T::TradingHaltHandler::resume_trading_weight())
+						.saturating_mul(resumed),
+				)
 		}
 
 		fn on_finalize(_n: BlockNumberFor<T>) {
@@ -162,6 +279,23 @@ pub mod pallet {
 					"Circuit Breaker: Max remove liquidity limit per block is set to invalid value."
 				);
 			}
+
+			if let Some(trade_volume_limit) = T::DefaultMaxNetTradeVolumeLimitPerWindow::get() {
+				assert!(
+					Self::validate_limit(trade_volume_limit).is_ok(),
+					"Circuit Breaker: Max net trade volume limit per window is set to invalid value."
+				);
+			}
+
+			assert!(
+				!T::TradeVolumeWindowLength::get().is_zero(),
+				"Circuit Breaker: Trade volume window length must be non-zero."
+			);
+
+			assert!(
+				!T::TradingHaltCooldown::get().is_zero(),
+				"Circuit Breaker: Trading halt cooldown must be non-zero."
+			);
 		}
 	}
 
@@ -217,6 +351,23 @@ pub mod pallet {
 		#[pallet::constant]
 		type DefaultMaxRemoveLiquidityLimitPerBlock: Get<Option<(u32, u32)>>;
 
+		/// The maximum percentage of a pool's liquidity that can be traded in a rolling window of `TradeVolumeWindowLength` blocks.
+		/// Represented as an optional non-zero fraction (nominator, denominator) with the max value being 10_000.
+		/// If set to None, the limits are not enforced.
+		#[pallet::constant]
+		type DefaultMaxNetTradeVolumeLimitPerWindow: Get<Option<(u32, u32)>>;
+
+		/// Length of the rolling window in blocks in which the net trade volume is tracked.
+		#[pallet::constant]
+		type TradeVolumeWindowLength: Get<BlockNumberFor<Self>>;
+
+		/// Number of blocks for which trading of an asset is halted when its window limit is breached.
+		#[pallet::constant]
+		type TradingHaltCooldown: Get<BlockNumberFor<Self>>;
+
+		/// Handler which halts and resumes trading of an asset.
+		type TradingHaltHandler: TradingHaltHandler<Self::AssetId>;
+
 		/// Omnipool's hub asset id. The limits are not tracked for this asset.
 		type OmnipoolHubAsset: Get<Self::AssetId>;
 
@@ -284,6 +435,37 @@ pub mod pallet {
 	pub type AllowedRemoveLiquidityAmountPerAsset<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetId, LiquidityLimit<T>>;
 
+	/// Default maximum net trade volume limit per window
+	#[pallet::type_value]
+	pub fn DefaultTradeVolumeWindowLimit<T: Config>() -> Option<(u32, u32)> {
+		T::DefaultMaxNetTradeVolumeLimitPerWindow::get()
+	}
+
+	#[pallet::storage]
+	/// Trade volume limits of assets per rolling window set by set_trade_volume_window_limit.
+	/// If not set, returns the default limit.
+	#[pallet::getter(fn trade_volume_window_limit_per_asset)]
+	pub type TradeVolumeWindowLimitPerAsset<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, Option<(u32, u32)>, ValueQuery, DefaultTradeVolumeWindowLimit<T>>;
+
+	#[pallet::storage]
+	/// Trade volumes per asset in the current and previous window
+	#[pallet::getter(fn trade_volume_window_per_asset)]
+	pub type TradeVolumeWindowPerAsset<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, TradeVolumeWindow<T>>;
+
+	#[pallet::storage]
+	/// Assets with halted trading, the block number until which the trading is halted
+	/// and the trading state of the asset before the halt.
+	#[pallet::getter(fn halted_assets)]
+	pub type HaltedAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, (BlockNumberFor<T>, TradingHaltStateOf<T>)>;
+
+	#[pallet::storage]
+	/// Halted assets whose trading is resumed at the beginning of the block.
+	#[pallet::getter(fn trading_resumes)]
+	pub type TradingResumes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Blake2_128Concat, T::AssetId, ()>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -302,6 +484,18 @@ pub mod pallet {
 			asset_id: T::AssetId,
 			liquidity_limit: Option<(u32, u32)>,
 		},
+		/// Trade volume window limit of an asset was changed.
+		TradeVolumeWindowLimitChanged {
+			asset_id: T::AssetId,
+			trade_volume_limit: Option<(u32, u32)>,
+		},
+		/// Trade volume window limit of an asset was breached and its trading was halted.
+		TradingHalted {
+			asset_id: T::AssetId,
+			until: BlockNumberFor<T>,
+		},
+		/// Trading of an asset was resumed.
+		TradingResumed { asset_id: T::AssetId },
 	}
 
 	#[pallet::error]
@@ -319,6 +513,8 @@ pub mod pallet {
 		MaxLiquidityLimitPerBlockReached,
 		/// Asset is not allowed to have a limit
 		NotAllowed,
+		/// Trading of the asset is not halted
+		TradingNotHalted,
 	}
 
 	#[pallet::call]
@@ -422,6 +618,66 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set trade volume limit per rolling window for an asset.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `TechnicalOrigin`
+		/// - `asset_id`: The identifier of an asset
+		/// - `trade_volume_limit`: Optional trade volume limit represented as a percentage
+		///
+		/// Emits `TradeVolumeWindowLimitChanged` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_trade_volume_window_limit())]
+		pub fn set_trade_volume_window_limit(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			trade_volume_limit: Option<(u32, u32)>,
+		) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_id != T::OmnipoolHubAsset::get(), Error::<T>::NotAllowed);
+
+			if let Some(limit) = trade_volume_limit {
+				Self::validate_limit(limit)?;
+			}
+
+			<TradeVolumeWindowLimitPerAsset<T>>::insert(asset_id, trade_volume_limit);
+			<TradeVolumeWindowPerAsset<T>>::remove(asset_id);
+
+			Self::deposit_event(Event::TradeVolumeWindowLimitChanged {
+				asset_id,
+				trade_volume_limit,
+			});
+
+			Ok(())
+		}
+
+		/// Resume trading of an asset halted due to breached trade volume window limit before the cooldown expires.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `TechnicalOrigin`
+		/// - `asset_id`: The identifier of an asset
+		///
+		/// Emits `TradingResumed` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::resume_trading()
+			.saturating_add(T::TradingHaltHandler::resume_trading_weight()))]
+		pub fn resume_trading(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			let (until, state) = <HaltedAssets<T>>::get(asset_id).ok_or(Error::<T>::TradingNotHalted)?;
+
+			T::TradingHaltHandler::resume_trading(asset_id, state)?;
+			<HaltedAssets<T>>::remove(asset_id);
+			<TradingResumes<T>>::remove(until, asset_id);
+
+			Self::deposit_event(Event::TradingResumed { asset_id });
+
+			Ok(())
+		}
 	}
 }
 
@@ -515,6 +771,63 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn ensure_and_update_trade_volume_window(
+		asset_id: T::AssetId,
+		asset_reserve: T::Balance,
+		amount_in: T::Balance,
+		amount_out: T::Balance,
+	) -> DispatchResult {
+		// ignore Omnipool's hub asset and assets which are already halted
+		if asset_id == T::OmnipoolHubAsset::get() || <HaltedAssets<T>>::contains_key(asset_id) {
+			return Ok(());
+		}
+
+		let Some(window_limit) = Pallet::<T>::trade_volume_window_limit_per_asset(asset_id) else {
+			return Ok(());
+		};
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let length = T::TradeVolumeWindowLength::get();
+
+		let mut window = match Pallet::<T>::trade_volume_window_per_asset(asset_id) {
+			Some(mut window) => {
+				if window.is_expired(now, length) {
+					window.roll(now, length, Self::calculate_limit(asset_reserve, window_limit)?);
+				}
+				window
+			}
+			None => TradeVolumeWindow::<T>::new(now, Self::calculate_limit(asset_reserve, window_limit)?),
+		};
+
+		window.update_amounts(amount_in, amount_out)?;
+
+		if window.is_limit_exceeded(now, length) {
+			Self::halt_trading(asset_id, now)?;
+		} else {
+			<TradeVolumeWindowPerAsset<T>>::insert(asset_id, window);
+		}
+
+		Ok(())
+	}
+
+	/// Halt trading of an asset for `TradingHaltCooldown` blocks.
+	///
+	/// The trade which breached the window limit is still executed, as it is within the per block limit.
+	/// All following trades are rejected by the AMM until the trading is resumed.
+	fn halt_trading(asset_id: T::AssetId, now: BlockNumberFor<T>) -> DispatchResult {
+		let until = now.saturating_add(T::TradingHaltCooldown::get());
+
+		let state = T::TradingHaltHandler::halt_trading(asset_id)?;
+
+		<HaltedAssets<T>>::insert(asset_id, (until, state));
+		<TradingResumes<T>>::insert(until, asset_id, ());
+		<TradeVolumeWindowPerAsset<T>>::remove(asset_id);
+
+		Self::deposit_event(Event::TradingHalted { asset_id, until });
+
+		Ok(())
+	}
+
 	fn ensure_and_update_add_liquidity_limit(asset_id: T::AssetId, added_liquidity: T::Balance) -> DispatchResult {
 		if asset_id != T::OmnipoolHubAsset::get() && Pallet::<T>::add_liquidity_limit_per_asset(asset_id).is_some() {
 			let mut allowed_liquidity_limit = Pallet::<T>::allowed_add_liquidity_limit_per_asset(asset_id)
@@ -579,6 +892,8 @@ impl<T: Config> Pallet<T> {
 		Pallet::<T>::initialize_trade_limit(asset_in, asset_in_reserve)?;
 		Pallet::<T>::initialize_trade_limit(asset_out, asset_out_reserve)?;
 		Pallet::<T>::ensure_and_update_trade_volume_limit(asset_in, amount_in, asset_out, amount_out)?;
+		Pallet::<T>::ensure_and_update_trade_volume_window(asset_in, asset_in_reserve, amount_in, Zero::zero())?;
+		Pallet::<T>::ensure_and_update_trade_volume_window(asset_out, asset_out_reserve, Zero::zero(), amount_out)?;
 
 		Ok(T::WeightInfo::ensure_pool_state_change_limit())
 	}
@@ -24,7 +24,7 @@ use frame_system::EnsureRoot;
 use hydra_dx_math::omnipool::types::BalanceUpdate;
 use orml_traits::{parameter_type_with_key, GetByKey};
 use sp_core::H256;
-use sp_runtime::traits::{ConstU128, ConstU32, Zero};
+use sp_runtime::traits::{ConstU128, ConstU32, ConstU64, Zero};
 use sp_runtime::DispatchResult;
 use sp_runtime::FixedU128;
 use sp_runtime::Permill;
@@ -63,6 +63,9 @@ pub const NATIVE_AMOUNT: Balance = 10_000 * ONE;
 pub const FIVE_PERCENT: (u32, u32) = (500, 10_000);
 pub const TEN_PERCENT: (u32, u32) = (1_000, 10_000);
 
+pub const WINDOW_LENGTH: u64 = 10;
+pub const HALT_COOLDOWN: u64 = 20;
+
 thread_local! {
 	pub static POSITIONS: RefCell<HashMap<u32, u64>> = RefCell::new(HashMap::default());
 	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u32>> = RefCell::new(HashMap::default());
@@ -76,6 +79,8 @@ thread_local! {
 	pub static MAX_NET_TRADE_VOLUME_LIMIT_PER_BLOCK: RefCell<(u32, u32)> = RefCell::new((2_000, 10_000)); // 20%
 	pub static MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = RefCell::new(Some((4_000, 10_000))); // 40%
 	pub static MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = RefCell::new(Some((2_000, 10_000))); // 20%
+	pub static MAX_NET_TRADE_VOLUME_LIMIT_PER_WINDOW: RefCell<Option<(u32, u32)>> = RefCell::new(None);
+	pub static TRADING_RESUME_FAILS: RefCell<bool> = RefCell::new(false);
 }
 
 frame_support::construct_runtime!(
@@ -124,6 +129,9 @@ parameter_types! {
 	pub DefaultMaxNetTradeVolumeLimitPerBlock: (u32, u32) = MAX_NET_TRADE_VOLUME_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub DefaultMaxAddLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub DefaultMaxRemoveLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
+	pub DefaultMaxNetTradeVolumeLimitPerWindow: Option<(u32, u32)> = MAX_NET_TRADE_VOLUME_LIMIT_PER_WINDOW.with(|v| *v.borrow());
+	pub const TradeVolumeWindowLength: u64 = WINDOW_LENGTH;
+	pub const TradingHaltCooldown: u64 = HALT_COOLDOWN;
 	pub const OmnipoolHubAsset: AssetId = LRNA;
 }
 
@@ -136,10 +144,45 @@ impl pallet_circuit_breaker::Config for Test {
 	type DefaultMaxNetTradeVolumeLimitPerBlock = DefaultMaxNetTradeVolumeLimitPerBlock;
 	type DefaultMaxAddLiquidityLimitPerBlock = DefaultMaxAddLiquidityLimitPerBlock;
 	type DefaultMaxRemoveLiquidityLimitPerBlock = DefaultMaxRemoveLiquidityLimitPerBlock;
+	type DefaultMaxNetTradeVolumeLimitPerWindow = DefaultMaxNetTradeVolumeLimitPerWindow;
+	type TradeVolumeWindowLength = TradeVolumeWindowLength;
+	type TradingHaltCooldown = TradingHaltCooldown;
+	type TradingHaltHandler = OmnipoolTradingHalt;
 	type OmnipoolHubAsset = OmnipoolHubAsset;
 	type WeightInfo = ();
 }
 
+pub struct OmnipoolTradingHalt;
+
+impl TradingHaltHandler<AssetId> for OmnipoolTradingHalt {
+	type State = Tradability;
+
+	fn halt_trading(asset_id: AssetId) -> Result<Self::State, DispatchError> {
+		let previous = Omnipool::assets(asset_id)
+			.ok_or(pallet_omnipool::Error::<Test>::AssetNotFound)?
+			.tradable;
+		Omnipool::update_asset_tradable_state(asset_id, |state| state.remove(Tradability::SELL | Tradability::BUY))?;
+		Ok(previous)
+	}
+
+	fn resume_trading(asset_id: AssetId, state: Self::State) -> DispatchResult {
+		if TRADING_RESUME_FAILS.with(|v| *v.borrow()) {
+			return Err(DispatchError::Other("trading can't be resumed"));
+		}
+		Omnipool::update_asset_tradable_state(asset_id, |current| {
+			current.insert(state & (Tradability::SELL | Tradability::BUY))
+		})
+	}
+
+	fn halt_trading_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn resume_trading_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 pub struct CircuitBreakerWhitelist;
 
 impl Contains<AccountId> for CircuitBreakerWhitelist {
@@ -223,6 +266,10 @@ impl pallet_omnipool::Config for Test {
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type ListingPriceOracle = ();
+	type ListingBond = ConstU128<0>;
+	type ListingChallengePeriod = ConstU64<0>;
+	type ListingWeightCap = AssetWeightCap;
 }
 
 pub struct CircuitBreakerHooks<T>(PhantomData<T>);
@@ -362,9 +409,11 @@ impl<AccountId: From<u64> + Into<u64> + Copy> Mutate<AccountId> for DummyNFT {
 	}
 }
 
+use crate::traits::TradingHaltHandler;
 use crate::Config;
 use hydradx_traits::registry::{AssetKind, Inspect as InspectRegistry};
 use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
+use pallet_omnipool::types::Tradability;
 
 pub struct DummyRegistry<T>(sp_std::marker::PhantomData<T>);
 
@@ -425,6 +474,7 @@ pub struct ExtBuilder {
 	max_net_trade_volume_limit_per_block: (u32, u32),
 	max_add_liquidity_limit_per_block: Option<(u32, u32)>,
 	max_remove_liquidity_limit_per_block: Option<(u32, u32)>,
+	max_net_trade_volume_limit_per_window: Option<(u32, u32)>,
 }
 
 impl Default for ExtBuilder {
@@ -479,6 +529,7 @@ impl Default for ExtBuilder {
 			max_net_trade_volume_limit_per_block: (2_000, 10_000),
 			max_add_liquidity_limit_per_block: Some((4_000, 10_000)),
 			max_remove_liquidity_limit_per_block: Some((2_000, 10_000)),
+			max_net_trade_volume_limit_per_window: None,
 		}
 	}
 }
@@ -517,6 +568,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_max_trade_volume_limit_per_window(mut self, value: Option<(u32, u32)>) -> Self {
+		self.max_net_trade_volume_limit_per_window = value;
+		self
+	}
+
 	pub fn with_token(
 		mut self,
 		asset_id: AssetId,
@@ -576,6 +632,12 @@ impl ExtBuilder {
 		MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| {
 			*v.borrow_mut() = self.max_remove_liquidity_limit_per_block;
 		});
+		MAX_NET_TRADE_VOLUME_LIMIT_PER_WINDOW.with(|v| {
+			*v.borrow_mut() = self.max_net_trade_volume_limit_per_window;
+		});
+		TRADING_RESUME_FAILS.with(|v| {
+			*v.borrow_mut() = false;
+		});
 
 		orml_tokens::GenesisConfig::<Test> {
 			balances: self
@@ -5,3 +5,4 @@ pub(crate) mod mock;
 mod omnipool;
 mod remove_liquidity_limit;
 mod trade_volume;
+mod trade_volume_window;
@@ -0,0 +1,331 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::traits::OnInitialize;
+use pallet_omnipool::types::Tradability;
+pub use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+const FIFTEEN_PERCENT: (u32, u32) = (1_500, 10_000);
+
+fn next_block() {
+	let current = System::block_number();
+	CircuitBreaker::on_finalize(current);
+	System::set_block_number(current + 1);
+	CircuitBreaker::on_initialize(current + 1);
+}
+
+fn omnipool_ext(window_limit: Option<(u32, u32)>) -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1_000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, DOT, 2_000_000 * ONE),
+			(LP1, ACA, 2_000_000 * ONE),
+			(TRADER, DOT, 2_000_000 * ONE),
+		])
+		.with_registered_asset(DOT)
+		.with_registered_asset(ACA)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(DOT, FixedU128::from_float(0.65), LP1, 10_000 * ONE)
+		.with_token(ACA, FixedU128::from_float(0.65), LP1, 10_000 * ONE)
+		.with_max_trade_volume_limit_per_block(TEN_PERCENT)
+		.with_max_trade_volume_limit_per_window(window_limit)
+		.build()
+}
+
+#[test]
+fn rolling_volume_should_include_weighted_volume_of_previous_window() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let mut window = TradeVolumeWindow::<Test>::new(1, 1_000);
+		assert_ok!(window.update_amounts(800, 0));
+
+		// Act
+		window.roll(11, WINDOW_LENGTH, 1_000);
+
+		// Assert
+		assert_eq!(window.start, 11);
+		assert_eq!(window.previous_volume_in, 800);
+		assert_eq!(window.rolling_volume(11, WINDOW_LENGTH).volume_in, 800);
+		assert_eq!(window.rolling_volume(16, WINDOW_LENGTH).volume_in, 400);
+		assert_eq!(window.rolling_volume(21, WINDOW_LENGTH).volume_in, 0);
+	});
+}
+
+#[test]
+fn roll_should_drop_previous_volume_when_windows_do_not_overlap() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let mut window = TradeVolumeWindow::<Test>::new(1, 1_000);
+		assert_ok!(window.update_amounts(800, 100));
+
+		// Act
+		window.roll(25, WINDOW_LENGTH, 2_000);
+
+		// Assert
+		assert_eq!(
+			window,
+			TradeVolumeWindow::<Test> {
+				start: 25,
+				volume_in: 0,
+				volume_out: 0,
+				previous_volume_in: 0,
+				previous_volume_out: 0,
+				limit: 2_000,
+			}
+		);
+	});
+}
+
+#[test]
+fn trade_volume_window_should_not_be_tracked_when_limit_is_not_set() {
+	omnipool_ext(None).execute_with(|| {
+		// Act
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 800 * ONE, 0));
+
+		// Assert
+		assert_eq!(CircuitBreaker::trade_volume_window_per_asset(DOT), None);
+		assert_eq!(CircuitBreaker::trade_volume_window_per_asset(ACA), None);
+	});
+}
+
+#[test]
+fn trade_volume_window_should_accumulate_volume_across_blocks() {
+	omnipool_ext(Some(FIFTEEN_PERCENT)).execute_with(|| {
+		// Arrange
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 500 * ONE, 0));
+		next_block();
+
+		// Act
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 500 * ONE, 0));
+
+		// Assert
+		let window = CircuitBreaker::trade_volume_window_per_asset(DOT).unwrap();
+		assert_eq!(window.start, 1);
+		assert_eq!(window.volume_in, 1_000 * ONE);
+		assert_eq!(window.volume_out, 0);
+		assert_eq!(window.limit, 1_500 * ONE);
+		assert_eq!(CircuitBreaker::halted_assets(DOT), None);
+	});
+}
+
+#[test]
+fn trading_should_be_halted_when_window_limit_is_exceeded_over_consecutive_blocks() {
+	omnipool_ext(Some(FIFTEEN_PERCENT)).execute_with(|| {
+		// Arrange
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 800 * ONE, 0));
+		next_block();
+
+		// Act
+		// still within the per block limit
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 800 * ONE, 0));
+
+		// Assert
+		let halted_until = 2 + HALT_COOLDOWN;
+		assert_eq!(
+			CircuitBreaker::halted_assets(DOT),
+			Some((halted_until, Tradability::default()))
+		);
+		assert_eq!(CircuitBreaker::trading_resumes(halted_until, DOT), Some(()));
+		assert_eq!(CircuitBreaker::trade_volume_window_per_asset(DOT), None);
+		assert_eq!(
+			Omnipool::assets(DOT).unwrap().tradable,
+			Tradability::ADD_LIQUIDITY | Tradability::REMOVE_LIQUIDITY
+		);
+		assert!(System::events().iter().any(|record| record.event
+			== crate::Event::TradingHalted {
+				asset_id: DOT,
+				until: halted_until,
+			}
+			.into()));
+
+		assert_noop!(
+			Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, ONE, 0),
+			pallet_omnipool::Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn trading_should_be_resumed_when_cooldown_expires() {
+	omnipool_ext(Some(FIFTEEN_PERCENT)).execute_with(|| {
+		// Arrange
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 800 * ONE, 0));
+		next_block();
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 800 * ONE, 0));
+		let (halted_until, _) = CircuitBreaker::halted_assets(DOT).unwrap();
+
+		// Act
+		while System::block_number() < halted_until - 1 {
+			next_block();
+		}
+		assert!(CircuitBreaker::halted_assets(DOT).is_some());
+		next_block();
+
+		// Assert
+		assert_eq!(CircuitBreaker::halted_assets(DOT), None);
+		assert_eq!(CircuitBreaker::trading_resumes(halted_until, DOT), None);
+		assert_eq!(Omnipool::assets(DOT).unwrap().tradable, Tradability::default());
+		expect_events(vec![crate::Event::TradingResumed { asset_id: DOT }.into()]);
+
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, ONE, 0));
+	});
+}
+
+#[test]
+fn resume_trading_should_work_when_called_by_technical_origin() {
+	omnipool_ext(Some(FIFTEEN_PERCENT)).execute_with(|| {
+		// Arrange
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 800 * ONE, 0));
+		next_block();
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 800 * ONE, 0));
+
+		let (halted_until, _) = CircuitBreaker::halted_assets(DOT).unwrap();
+
+		// Act
+		assert_ok!(CircuitBreaker::resume_trading(RuntimeOrigin::root(), DOT));
+
+		// Assert
+		assert_eq!(CircuitBreaker::halted_assets(DOT), None);
+		assert_eq!(CircuitBreaker::trading_resumes(halted_until, DOT), None);
+		assert_eq!(Omnipool::assets(DOT).unwrap().tradable, Tradability::default());
+		expect_events(vec![crate::Event::TradingResumed { asset_id: DOT }.into()]);
+	});
+}
+
+#[test]
+fn trading_should_be_resumed_to_tradable_state_before_halt() {
+	omnipool_ext(Some(FIFTEEN_PERCENT)).execute_with(|| {
+		// Arrange
+		let tradable = Tradability::SELL | Tradability::ADD_LIQUIDITY | Tradability::REMOVE_LIQUIDITY;
+		assert_ok!(Omnipool::set_asset_tradable_state(RuntimeOrigin::root(), DOT, tradable));
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 800 * ONE, 0));
+		next_block();
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 800 * ONE, 0));
+		let (halted_until, _) = CircuitBreaker::halted_assets(DOT).unwrap();
+
+		// Act
+		while System::block_number() < halted_until {
+			next_block();
+		}
+
+		// Assert
+		assert_eq!(CircuitBreaker::halted_assets(DOT), None);
+		assert_eq!(Omnipool::assets(DOT).unwrap().tradable, tradable);
+	});
+}
+
+#[test]
+fn asset_should_stay_halted_when_trading_can_not_be_resumed() {
+	omnipool_ext(Some(FIFTEEN_PERCENT)).execute_with(|| {
+		// Arrange
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 800 * ONE, 0));
+		next_block();
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 800 * ONE, 0));
+		let (halted_until, state) = CircuitBreaker::halted_assets(DOT).unwrap();
+		TRADING_RESUME_FAILS.with(|v| *v.borrow_mut() = true);
+
+		// Act
+		while System::block_number() < halted_until {
+			next_block();
+		}
+
+		// Assert
+		assert_eq!(CircuitBreaker::halted_assets(DOT), Some((halted_until, state)));
+		assert_eq!(CircuitBreaker::trading_resumes(halted_until, DOT), None);
+		assert!(!System::events()
+			.iter()
+			.any(|record| record.event == crate::Event::TradingResumed { asset_id: DOT }.into()));
+
+		TRADING_RESUME_FAILS.with(|v| *v.borrow_mut() = false);
+		assert_ok!(CircuitBreaker::resume_trading(RuntimeOrigin::root(), DOT));
+		assert_eq!(CircuitBreaker::halted_assets(DOT), None);
+	});
+}
+
+#[test]
+fn resume_trading_should_fail_when_not_called_by_technical_origin() {
+	omnipool_ext(Some(FIFTEEN_PERCENT)).execute_with(|| {
+		// Arrange
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 800 * ONE, 0));
+		next_block();
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 800 * ONE, 0));
+
+		// Act & Assert
+		assert_noop!(
+			CircuitBreaker::resume_trading(RuntimeOrigin::signed(ALICE), DOT),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn resume_trading_should_fail_when_trading_is_not_halted() {
+	omnipool_ext(Some(FIFTEEN_PERCENT)).execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::resume_trading(RuntimeOrigin::root(), DOT),
+			Error::<Test>::TradingNotHalted
+		);
+	});
+}
+
+#[test]
+fn set_trade_volume_window_limit_should_store_new_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_eq!(CircuitBreaker::trade_volume_window_limit_per_asset(HDX), None);
+		let new_limit = Some((7, 100));
+
+		// Act
+		assert_ok!(CircuitBreaker::set_trade_volume_window_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			new_limit
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::trade_volume_window_limit_per_asset(HDX), new_limit);
+		expect_events(vec![crate::Event::TradeVolumeWindowLimitChanged {
+			asset_id: HDX,
+			trade_volume_limit: new_limit,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_trade_volume_window_limit_should_fail_when_setting_limit_for_omnipool_hub_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_trade_volume_window_limit(RuntimeOrigin::root(), LRNA, Some((7, 100))),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn set_trade_volume_window_limit_should_fail_if_limit_is_not_valid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_trade_volume_window_limit(RuntimeOrigin::root(), HDX, Some((0, 100))),
+			Error::<Test>::InvalidLimitValue
+		);
+	});
+}
@@ -0,0 +1,57 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use frame_support::pallet_prelude::{DispatchError, DispatchResult, MaxEncodedLen, Parameter};
+use frame_support::weights::Weight;
+
+/// Handler used to halt and resume trading of an asset in the underlying AMM
+/// when a trade volume window limit is breached.
+pub trait TradingHaltHandler<AssetId> {
+	/// Trading state of an asset before the halt, which is restored when the trading is resumed.
+	type State: Parameter + MaxEncodedLen;
+
+	/// Disable trading of `asset_id` and return its previous trading state.
+	fn halt_trading(asset_id: AssetId) -> Result<Self::State, DispatchError>;
+
+	/// Re-enable trading of `asset_id` by restoring its trading state from before the halt.
+	fn resume_trading(asset_id: AssetId, state: Self::State) -> DispatchResult;
+
+	fn halt_trading_weight() -> Weight;
+
+	fn resume_trading_weight() -> Weight;
+}
+
+// Default implementation for no-op handler.
+impl<AssetId> TradingHaltHandler<AssetId> for () {
+	type State = ();
+
+	fn halt_trading(_asset_id: AssetId) -> Result<Self::State, DispatchError> {
+		Ok(())
+	}
+
+	fn resume_trading(_asset_id: AssetId, _state: Self::State) -> DispatchResult {
+		Ok(())
+	}
+
+	fn halt_trading_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn resume_trading_weight() -> Weight {
+		Weight::zero()
+	}
+}
@@ -61,6 +61,8 @@ pub trait WeightInfo {
 	fn ensure_pool_state_change_limit() -> Weight;
 	fn ensure_add_liquidity_limit() -> Weight;
 	fn ensure_remove_liquidity_limit() -> Weight;
+	fn set_trade_volume_window_limit() -> Weight;
+	fn resume_trading() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -164,13 +166,43 @@ impl WeightInfo for () {
 	/// Proof: `CircuitBreaker::AllowedTradeVolumeLimitPerAsset` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	/// Storage: `CircuitBreaker::TradeVolumeLimitPerAsset` (r:2 w:0)
 	/// Proof: `CircuitBreaker::TradeVolumeLimitPerAsset` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::HaltedAssets` (r:2 w:0)
+	/// Proof: `CircuitBreaker::HaltedAssets` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::TradeVolumeWindowLimitPerAsset` (r:2 w:0)
+	/// Proof: `CircuitBreaker::TradeVolumeWindowLimitPerAsset` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::TradeVolumeWindowPerAsset` (r:2 w:2)
+	/// Proof: `CircuitBreaker::TradeVolumeWindowPerAsset` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	fn ensure_pool_state_change_limit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `208`
-		//  Estimated: `6076`
+		//  Estimated: `6148`
 		// Minimum execution time: 18_243_000 picoseconds.
-		Weight::from_parts(18_729_000, 6076)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		Weight::from_parts(18_729_000, 6148)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `CircuitBreaker::TradeVolumeWindowLimitPerAsset` (r:0 w:1)
+	/// Proof: `CircuitBreaker::TradeVolumeWindowLimitPerAsset` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::TradeVolumeWindowPerAsset` (r:0 w:1)
+	/// Proof: `CircuitBreaker::TradeVolumeWindowPerAsset` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn set_trade_volume_window_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_412_000 picoseconds.
+		Weight::from_parts(13_705_000, 0).saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CircuitBreaker::HaltedAssets` (r:1 w:1)
+	/// Proof: `CircuitBreaker::HaltedAssets` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::TradingResumes` (r:0 w:1)
+	/// Proof: `CircuitBreaker::TradingResumes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn resume_trading() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3491`
+		// Minimum execution time: 16_031_000 picoseconds.
+		Weight::from_parts(16_372_000, 3491)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 }
@@ -180,7 +180,7 @@ impl<T: Config> Pallet<T> {
 }
 
 /// Converts the given binary data into ASCII-encoded hex. It will be twice the length.
-fn to_ascii_hex(data: &[u8]) -> Vec<u8> {
+pub fn to_ascii_hex(data: &[u8]) -> Vec<u8> {
 	let mut r = Vec::with_capacity(data.len() * 2);
 	let mut push_nibble = |n| r.push(if n < 10 { b'0' + n } else { b'a' - 10 + n });
 	for &b in data.iter() {
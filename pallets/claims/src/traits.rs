@@ -78,7 +78,7 @@ impl sp_std::fmt::Debug for EcdsaSignature {
 
 impl EcdsaSignature {
 	// Constructs the message that Ethereum RPC's `personal_sign` and `eth_sign` would sign.
-	fn ethereum_signable_message(what: &[u8], prefix: &'static [u8]) -> Vec<u8> {
+	pub fn ethereum_signable_message(what: &[u8], prefix: &'static [u8]) -> Vec<u8> {
 		//let prefix = T::Prefix::get();
 		let mut l = prefix.len() + what.len();
 		let mut rev = Vec::new();
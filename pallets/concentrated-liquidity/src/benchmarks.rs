@@ -0,0 +1,272 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::account;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use hydradx_traits::router::{PoolType, TradeExecution};
+use orml_traits::MultiCurrencyExtended;
+
+const ONE: Balance = 1_000_000_000_000;
+const ASSET_A: u32 = 2_000;
+const ASSET_B: u32 = 2_001;
+const TICK_SPACING: u32 = 10;
+
+fn fund<T: Config>(who: &T::AccountId, asset: T::AssetId, amount: Balance) -> DispatchResult
+where
+	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	T::Currency::update_balance(asset, who, amount as i128)
+}
+
+/// Create a pool at tick 0 with positions in nested ranges, so trades of benchmarks cross initialized ticks.
+fn create_pool_with_positions<T: Config>() -> Result<(PoolId, T::AssetId, T::AssetId), DispatchError>
+where
+	T::AssetId: From<u32>,
+	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let asset_a: T::AssetId = ASSET_A.into();
+	let asset_b: T::AssetId = ASSET_B.into();
+	T::BenchmarkHelper::register_asset(asset_a, 12)?;
+	T::BenchmarkHelper::register_asset(asset_b, 12)?;
+
+	let successful_origin = T::AuthorityOrigin::try_successful_origin().map_err(|_| DispatchError::BadOrigin)?;
+	crate::Pallet::<T>::create_pool(
+		successful_origin,
+		asset_a,
+		asset_b,
+		Permill::from_rational(3u32, 1000u32),
+		TICK_SPACING,
+		0,
+	)?;
+	let pool_id = NextPoolId::<T>::get() - 1;
+
+	let lp: T::AccountId = account("provider", 0, 1);
+	fund::<T>(&lp, asset_a, 1_000_000 * ONE)?;
+	fund::<T>(&lp, asset_b, 1_000_000 * ONE)?;
+	for i in 1..=5i32 {
+		let bound = i * 100 * TICK_SPACING as i32;
+		crate::Pallet::<T>::add_liquidity(
+			RawOrigin::Signed(lp.clone()).into(),
+			pool_id,
+			-bound,
+			bound,
+			1_000 * ONE,
+			1_000 * ONE,
+			0,
+		)?;
+	}
+
+	Ok((pool_id, asset_a, asset_b))
+}
+
+/// Create a pool at tick 0 with a full range position and `n` positions in nested ranges.
+/// Trades of benchmarks move the price below all nested ranges, so they cross exactly `n` initialized ticks.
+fn create_pool_with_crossable_ticks<T: Config>(n: u32) -> Result<(PoolId, T::AssetId, T::AssetId), DispatchError>
+where
+	T::AssetId: From<u32>,
+	T::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let asset_a: T::AssetId = ASSET_A.into();
+	let asset_b: T::AssetId = ASSET_B.into();
+	T::BenchmarkHelper::register_asset(asset_a, 12)?;
+	T::BenchmarkHelper::register_asset(asset_b, 12)?;
+
+	let successful_origin = T::AuthorityOrigin::try_successful_origin().map_err(|_| DispatchError::BadOrigin)?;
+	crate::Pallet::<T>::create_pool(
+		successful_origin,
+		asset_a,
+		asset_b,
+		Permill::from_rational(3u32, 1000u32),
+		TICK_SPACING,
+		0,
+	)?;
+	let pool_id = NextPoolId::<T>::get() - 1;
+
+	let lp: T::AccountId = account("provider", 0, 1);
+	fund::<T>(&lp, asset_a, 1_000_000 * ONE)?;
+	fund::<T>(&lp, asset_b, 1_000_000 * ONE)?;
+
+	let full_range = MAX_TICK - MAX_TICK % TICK_SPACING as i32;
+	crate::Pallet::<T>::add_liquidity(
+		RawOrigin::Signed(lp.clone()).into(),
+		pool_id,
+		-full_range,
+		full_range,
+		1_000 * ONE,
+		1_000 * ONE,
+		0,
+	)?;
+	for i in 1..=n as i32 {
+		let bound = i * 10 * TICK_SPACING as i32;
+		crate::Pallet::<T>::add_liquidity(
+			RawOrigin::Signed(lp.clone()).into(),
+			pool_id,
+			-bound,
+			bound,
+			ONE,
+			ONE,
+			0,
+		)?;
+	}
+
+	Ok((pool_id, asset_a, asset_b))
+}
+
+benchmarks! {
+	where_clause {  where T::AssetId: From<u32>,
+		T::Currency: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+		T: crate::pallet::Config,
+	}
+
+	create_pool {
+		let asset_a: T::AssetId = ASSET_A.into();
+		let asset_b: T::AssetId = ASSET_B.into();
+		T::BenchmarkHelper::register_asset(asset_a, 12)?;
+		T::BenchmarkHelper::register_asset(asset_b, 12)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, asset_a, asset_b, Permill::from_rational(3u32, 1000u32), TICK_SPACING, 0)
+	verify {
+		assert!(Pools::<T>::get(0).is_some());
+	}
+
+	add_liquidity {
+		let (pool_id, asset_a, asset_b) = create_pool_with_positions::<T>()?;
+		let caller: T::AccountId = account("caller", 0, 1);
+		fund::<T>(&caller, asset_a, 1_000 * ONE)?;
+		fund::<T>(&caller, asset_b, 1_000 * ONE)?;
+		let position_id = NextPositionId::<T>::get();
+	}: _(RawOrigin::Signed(caller.clone()), pool_id, -7_000, 7_000, 1_000 * ONE, 1_000 * ONE, 0)
+	verify {
+		assert!(Positions::<T>::get(position_id).is_some());
+	}
+
+	remove_liquidity {
+		let (pool_id, asset_a, asset_b) = create_pool_with_positions::<T>()?;
+		let caller: T::AccountId = account("caller", 0, 1);
+		fund::<T>(&caller, asset_a, 1_000 * ONE)?;
+		fund::<T>(&caller, asset_b, 1_000 * ONE)?;
+		let position_id = NextPositionId::<T>::get();
+		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(caller.clone()).into(), pool_id, -7_000, 7_000, 1_000 * ONE, 1_000 * ONE, 0)?;
+		let liquidity = Positions::<T>::get(position_id).unwrap().liquidity;
+
+		// accrue fees so they are paid out too
+		let trader: T::AccountId = account("trader", 0, 1);
+		fund::<T>(&trader, asset_a, 10 * ONE)?;
+		crate::Pallet::<T>::sell(RawOrigin::Signed(trader).into(), pool_id, asset_a, asset_b, 10 * ONE, 0)?;
+	}: _(RawOrigin::Signed(caller.clone()), position_id, liquidity, 0, 0)
+	verify {
+		assert!(Positions::<T>::get(position_id).is_none());
+	}
+
+	collect_fees {
+		let (pool_id, asset_a, asset_b) = create_pool_with_positions::<T>()?;
+		let caller: T::AccountId = account("caller", 0, 1);
+		fund::<T>(&caller, asset_a, 1_000 * ONE)?;
+		fund::<T>(&caller, asset_b, 1_000 * ONE)?;
+		let position_id = NextPositionId::<T>::get();
+		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(caller.clone()).into(), pool_id, -7_000, 7_000, 1_000 * ONE, 1_000 * ONE, 0)?;
+
+		let trader: T::AccountId = account("trader", 0, 1);
+		fund::<T>(&trader, asset_a, 10 * ONE)?;
+		fund::<T>(&trader, asset_b, 10 * ONE)?;
+		crate::Pallet::<T>::sell(RawOrigin::Signed(trader.clone()).into(), pool_id, asset_a, asset_b, 10 * ONE, 0)?;
+		crate::Pallet::<T>::sell(RawOrigin::Signed(trader).into(), pool_id, asset_b, asset_a, 10 * ONE, 0)?;
+	}: _(RawOrigin::Signed(caller.clone()), position_id)
+	verify {
+		assert!(T::Currency::free_balance(asset_a, &caller) > 0);
+		assert!(T::Currency::free_balance(asset_b, &caller) > 0);
+	}
+
+	sell {
+		let n in 0..T::MaxCrossedTicks::get();	// number of crossed ticks
+
+		let (pool_id, asset_a, asset_b) = create_pool_with_crossable_ticks::<T>(n)?;
+		let seller: T::AccountId = account("seller", 0, 1);
+		let amount_sell = 2_000 * ONE;
+		fund::<T>(&seller, asset_a, amount_sell)?;
+	}: _(RawOrigin::Signed(seller.clone()), pool_id, asset_a, asset_b, amount_sell, 0)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_a, &seller), 0);
+		assert!(Pools::<T>::get(pool_id).unwrap().tick < -(n as i32) * 10 * TICK_SPACING as i32);
+	}
+
+	buy {
+		let n in 0..T::MaxCrossedTicks::get();	// number of crossed ticks
+
+		let (pool_id, asset_a, asset_b) = create_pool_with_crossable_ticks::<T>(n)?;
+		let buyer: T::AccountId = account("buyer", 0, 1);
+		let amount_buy = 600 * ONE;
+		fund::<T>(&buyer, asset_a, 3_000 * ONE)?;
+	}: _(RawOrigin::Signed(buyer.clone()), pool_id, asset_b, asset_a, amount_buy, 3_000 * ONE)
+	verify {
+		assert_eq!(T::Currency::free_balance(asset_b, &buyer), amount_buy);
+		assert!(Pools::<T>::get(pool_id).unwrap().tick < -(n as i32) * 10 * TICK_SPACING as i32);
+	}
+
+	// worst case trades of router benchmarks cross `MaxCrossedTicks` ticks
+	router_execution_sell {
+		let c in 1..2;
+		let e in 0..1;	// if e == 1, execute_sell is executed
+
+		let (pool_id, asset_a, asset_b) = create_pool_with_crossable_ticks::<T>(T::MaxCrossedTicks::get())?;
+		let seller: T::AccountId = account("seller", 0, 1);
+		let amount_sell = 2_000 * ONE;
+		fund::<T>(&seller, asset_a, amount_sell)?;
+	}: {
+		for _ in 1..c {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_sell(PoolType::Concentrated(pool_id), asset_a, asset_b, amount_sell).is_ok());
+		}
+		if e != 0 {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_sell(RawOrigin::Signed(seller.clone()).into(), PoolType::Concentrated(pool_id), asset_a, asset_b, amount_sell, 0).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(asset_a, &seller), 0);
+		}
+	}
+
+	router_execution_buy {
+		let c in 1..2;
+		let e in 0..1;	// if e == 1, execute_buy is executed
+
+		let (pool_id, asset_a, asset_b) = create_pool_with_crossable_ticks::<T>(T::MaxCrossedTicks::get())?;
+		let buyer: T::AccountId = account("buyer", 0, 1);
+		let amount_buy = 600 * ONE;
+		fund::<T>(&buyer, asset_a, 3_000 * ONE)?;
+	}: {
+		for _ in 1..c {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_buy(PoolType::Concentrated(pool_id), asset_a, asset_b, amount_buy).is_ok());
+		}
+		if e != 0 {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_buy(RawOrigin::Signed(buyer.clone()).into(), PoolType::Concentrated(pool_id), asset_a, asset_b, amount_buy, 3_000 * ONE).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(asset_b, &buyer), amount_buy);
+		}
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
@@ -0,0 +1,1091 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Concentrated liquidity pallet
+//!
+//! Two-asset AMM where liquidity providers choose the price range their liquidity is used in.
+//!
+//! ## Overview
+//!
+//! Price range of a pool is divided into ticks, price at tick `i` is `1.0001^i`. A position provides liquidity
+//! in range `[tick_lower, tick_upper)` and earns trade fees only while the price of the pool is within the range.
+//! Bounds of a position must be divisible by tick spacing of the pool.
+//!
+//! Pools are created by `AuthorityOrigin`. Each pool is identified by a pool id, so multiple pools of the same
+//! asset pair with different fees can exist. Pools are tradable via `pallet_route_executor` as `PoolType::Concentrated(pool_id)`.
+//!
+//! Each position is represented by an NFT. Owner of the NFT can remove liquidity
+//! from the position and collect its fees. When all liquidity is removed, the NFT is burned.
+//!
+//! ### Hooks
+//!
+//! Trades and liquidity changes are reported to `AMMHandler`, which is used to update on-chain oracle.
+//!
+//! ### Terminology
+//!
+//! * **Tick** - price point of a pool, price at tick `i` is `1.0001^i`
+//! * **Liquidity** - virtual liquidity `L = sqrt(x * y)` of a position within its range
+//! * **Asset 0 / Asset 1** - pool assets ordered by asset id. Price of a pool is amount of asset 1 per unit of asset 0.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+
+use frame_support::pallet_prelude::{DispatchResult, Get};
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::{ensure, require_transactional, transactional, PalletId};
+use hydra_dx_math::concentrated::types::{FeeGrowth, Liquidity, SwapResult, Tick, TickLiquidity};
+use hydra_dx_math::concentrated::{
+	add_liquidity_delta, calculate_accrued_fees, calculate_amounts_for_liquidity, calculate_fee_growth_inside,
+	calculate_fee_growth_outside_after_cross, calculate_in_given_out, calculate_initial_fee_growth_outside,
+	calculate_liquidity_for_amounts, calculate_out_given_in, calculate_spot_price, sqrt_price_at_tick, MAX_TICK,
+	MIN_TICK,
+};
+use hydra_dx_math::ratio::Ratio;
+use hydra_dx_math::MathError;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::registry::Inspect as RegistryInspect;
+use hydradx_traits::{OnLiquidityChangedHandler, OnTradeHandler, Source};
+use orml_traits::MultiCurrency;
+pub use pallet::*;
+use sp_runtime::traits::{AccountIdConversion, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, Permill};
+use sp_std::prelude::*;
+
+mod trade_execution;
+pub mod types;
+pub mod weights;
+
+use crate::types::{Balance, InitializedTick, PoolId, PoolInfo, Position, PositionId, TickInfo};
+use weights::WeightInfo;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarks;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub use crate::types::BenchmarkHelper;
+
+/// Maximum tick spacing of a pool.
+pub const MAX_TICK_SPACING: u32 = 16_384;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier for the class of asset.
+		type AssetId: Member + Parameter + Ord + Default + Copy + MaxEncodedLen + TypeInfo;
+
+		/// Multi currency mechanism
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Balance>;
+
+		/// Asset registry mechanism to check if asset is registered.
+		type AssetInspection: RegistryInspect<AssetId = Self::AssetId>;
+
+		/// The origin which can create a new pool
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Pallet id - pool accounts are derived from pool id.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Collection id type
+		type CollectionId: TypeInfo + MaxEncodedLen;
+
+		/// Non fungible class id of positions
+		#[pallet::constant]
+		type NFTCollectionId: Get<Self::CollectionId>;
+
+		/// Non fungible handling - mint, burn, check owner
+		type NFTHandler: Mutate<Self::AccountId>
+			+ Create<Self::AccountId>
+			+ Inspect<Self::AccountId, ItemId = PositionId, CollectionId = Self::CollectionId>;
+
+		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
+		type DustAccountHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// Handler notified about trades and liquidity changes, e.g. on-chain oracle.
+		type AMMHandler: OnTradeHandler<Self::AssetId, Balance, Ratio>
+			+ OnLiquidityChangedHandler<Self::AssetId, Balance, Ratio>;
+
+		/// Source identifier used when reporting to `AMMHandler`.
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+
+		/// Minimum trading amount
+		#[pallet::constant]
+		type MinTradingLimit: Get<Balance>;
+
+		/// Maximum number of initialized ticks in a pool.
+		#[pallet::constant]
+		type MaxInitializedTicks: Get<u32>;
+
+		/// Maximum number of initialized ticks crossed by a single trade.
+		#[pallet::constant]
+		type MaxCrossedTicks: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+	}
+
+	/// Id of the next created pool.
+	#[pallet::storage]
+	#[pallet::getter(fn next_pool_id)]
+	pub type NextPoolId<T: Config> = StorageValue<_, PoolId, ValueQuery>;
+
+	/// Existing pools.
+	#[pallet::storage]
+	#[pallet::getter(fn pools)]
+	pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, PoolId, PoolInfo<T::AssetId>>;
+
+	/// Fee accounting of initialized ticks.
+	#[pallet::storage]
+	#[pallet::getter(fn ticks)]
+	pub type Ticks<T: Config> = StorageDoubleMap<_, Blake2_128Concat, PoolId, Twox64Concat, Tick, TickInfo>;
+
+	/// Initialized ticks of a pool, sorted in ascending order.
+	#[pallet::storage]
+	#[pallet::getter(fn initialized_ticks)]
+	pub type InitializedTicks<T: Config> =
+		StorageMap<_, Blake2_128Concat, PoolId, BoundedVec<InitializedTick, T::MaxInitializedTicks>, ValueQuery>;
+
+	/// Id of the next created position.
+	#[pallet::storage]
+	#[pallet::getter(fn next_position_id)]
+	pub type NextPositionId<T: Config> = StorageValue<_, PositionId, ValueQuery>;
+
+	/// Liquidity positions.
+	#[pallet::storage]
+	#[pallet::getter(fn positions)]
+	pub type Positions<T: Config> = StorageMap<_, Blake2_128Concat, PositionId, Position>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pool was created.
+		PoolCreated {
+			pool_id: PoolId,
+			asset_0: T::AssetId,
+			asset_1: T::AssetId,
+			fee: Permill,
+			tick_spacing: u32,
+			initial_tick: Tick,
+		},
+		/// A position was created and its NFT was minted to the owner.
+		PositionCreated {
+			position_id: PositionId,
+			owner: T::AccountId,
+			pool_id: PoolId,
+			tick_lower: Tick,
+			tick_upper: Tick,
+			liquidity: Liquidity,
+			amount_0: Balance,
+			amount_1: Balance,
+		},
+		/// Liquidity was removed from a position.
+		LiquidityRemoved {
+			position_id: PositionId,
+			owner: T::AccountId,
+			pool_id: PoolId,
+			liquidity: Liquidity,
+			amount_0: Balance,
+			amount_1: Balance,
+		},
+		/// All liquidity was removed from a position and its NFT was burned.
+		PositionDestroyed {
+			position_id: PositionId,
+			owner: T::AccountId,
+		},
+		/// Fees accrued by a position were transferred to its owner.
+		FeesCollected {
+			position_id: PositionId,
+			owner: T::AccountId,
+			amount_0: Balance,
+			amount_1: Balance,
+		},
+		/// Sell trade executed. Trade fee is included in amount_in.
+		SellExecuted {
+			who: T::AccountId,
+			pool_id: PoolId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			fee: Balance,
+		},
+		/// Buy trade executed. Trade fee is included in amount_in.
+		BuyExecuted {
+			who: T::AccountId,
+			pool_id: PoolId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			fee: Balance,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Creating a pool with same assets is not allowed.
+		SameAssets,
+		/// One or more assets are not registered in AssetRegistry.
+		AssetNotRegistered,
+		/// Tick spacing must be greater than zero and lower than or equal to `MAX_TICK_SPACING`.
+		InvalidTickSpacing,
+		/// Fee must be lower than 100%.
+		InvalidFee,
+		/// Tick is out of range or not divisible by tick spacing of the pool.
+		InvalidTick,
+		/// Lower tick of a position must be lower than upper tick.
+		InvalidTickRange,
+		/// Pool does not exist.
+		PoolNotFound,
+		/// Asset is not in the pool.
+		AssetNotInPool,
+		/// Position does not exist.
+		PositionNotFound,
+		/// Origin is not the owner of the position.
+		Forbidden,
+		/// Provided amounts result in zero liquidity.
+		ZeroLiquidity,
+		/// Position does not have enough liquidity.
+		InsufficientPositionLiquidity,
+		/// There is not enough liquidity in the pool to perform a trade.
+		InsufficientLiquidity,
+		/// Amount is less than the minimum trading amount configured.
+		InsufficientTradingAmount,
+		/// Balance of an asset is not sufficient to perform a trade.
+		InsufficientBalance,
+		/// Slippage protection.
+		SlippageLimit,
+		/// Maximum number of initialized ticks of the pool has been reached.
+		MaxInitializedTicksReached,
+		/// Trade crosses more initialized ticks than allowed in a single trade.
+		MaxCrossedTicksReached,
+		/// Maximum number of pools has been reached.
+		PoolIdOverflow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a concentrated liquidity pool of given assets.
+		///
+		/// Both assets must be registered in `T::AssetInspection`. Order of assets does not matter,
+		/// pool assets are always ordered by asset id.
+		///
+		/// Note that this does not seed the pool with liquidity. Use `add_liquidity` to create a position.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `asset_a`: first asset of the pool
+		/// - `asset_b`: second asset of the pool
+		/// - `fee`: trade fee
+		/// - `tick_spacing`: bounds of positions must be divisible by tick spacing
+		/// - `initial_tick`: initial price of the pool given as tick. Price is amount of asset 1 per unit of asset 0.
+		///
+		/// Emits `PoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+		#[transactional]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			asset_a: T::AssetId,
+			asset_b: T::AssetId,
+			fee: Permill,
+			tick_spacing: u32,
+			initial_tick: Tick,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_a != asset_b, Error::<T>::SameAssets);
+			ensure!(
+				T::AssetInspection::exists(asset_a) && T::AssetInspection::exists(asset_b),
+				Error::<T>::AssetNotRegistered
+			);
+			ensure!(
+				tick_spacing > 0 && tick_spacing <= MAX_TICK_SPACING,
+				Error::<T>::InvalidTickSpacing
+			);
+			ensure!(fee < Permill::one(), Error::<T>::InvalidFee);
+
+			let sqrt_price = sqrt_price_at_tick(initial_tick).map_err(|_| Error::<T>::InvalidTick)?;
+			let assets = if asset_a < asset_b {
+				(asset_a, asset_b)
+			} else {
+				(asset_b, asset_a)
+			};
+
+			let pool_id = NextPoolId::<T>::try_mutate(|next_id| -> Result<PoolId, DispatchError> {
+				let current_id = *next_id;
+				*next_id = next_id.checked_add(1).ok_or(Error::<T>::PoolIdOverflow)?;
+				Ok(current_id)
+			})?;
+
+			Pools::<T>::insert(
+				pool_id,
+				PoolInfo {
+					assets,
+					fee,
+					tick_spacing,
+					sqrt_price,
+					tick: initial_tick,
+					liquidity: 0,
+					fee_growth_global_0: FeeGrowth::zero(),
+					fee_growth_global_1: FeeGrowth::zero(),
+				},
+			);
+
+			T::DustAccountHandler::add_account(&Self::pool_account(pool_id))?;
+
+			Self::deposit_event(Event::PoolCreated {
+				pool_id,
+				asset_0: assets.0,
+				asset_1: assets.1,
+				fee,
+				tick_spacing,
+				initial_tick,
+			});
+
+			Ok(())
+		}
+
+		/// Provide liquidity to a pool in range `[tick_lower, tick_upper)`.
+		///
+		/// Maximum liquidity which can be provided by given amounts is added. Required amounts depend on
+		/// the current price of the pool - only asset 0 is required when the range is above the current price and
+		/// only asset 1 when the range is below the current price.
+		///
+		/// A new position is created and its NFT is minted to the origin.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `pool_id`: pool id
+		/// - `tick_lower`: lower bound of the range
+		/// - `tick_upper`: upper bound of the range
+		/// - `amount_0_max`: maximum amount of asset 0 to provide
+		/// - `amount_1_max`: maximum amount of asset 1 to provide
+		/// - `min_liquidity`: slippage limit. Minimum liquidity of the position.
+		///
+		/// Emits `PositionCreated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+							.saturating_add(T::AMMHandler::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			tick_lower: Tick,
+			tick_upper: Tick,
+			amount_0_max: Balance,
+			amount_1_max: Balance,
+			min_liquidity: Liquidity,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			Self::ensure_valid_range(&pool, tick_lower, tick_upper)?;
+
+			let sqrt_price_lower = sqrt_price_at_tick(tick_lower).map_err(Self::math_error)?;
+			let sqrt_price_upper = sqrt_price_at_tick(tick_upper).map_err(Self::math_error)?;
+
+			let liquidity = calculate_liquidity_for_amounts(
+				pool.sqrt_price,
+				sqrt_price_lower,
+				sqrt_price_upper,
+				amount_0_max,
+				amount_1_max,
+			)
+			.map_err(Self::math_error)?;
+			ensure!(!liquidity.is_zero(), Error::<T>::ZeroLiquidity);
+			ensure!(liquidity >= min_liquidity, Error::<T>::SlippageLimit);
+
+			let (amount_0, amount_1) =
+				calculate_amounts_for_liquidity(pool.sqrt_price, sqrt_price_lower, sqrt_price_upper, liquidity, true)
+					.map_err(Self::math_error)?;
+			ensure!(
+				amount_0 <= amount_0_max && amount_1 <= amount_1_max,
+				Error::<T>::SlippageLimit
+			);
+
+			let liquidity_delta = i128::try_from(liquidity).map_err(|_| ArithmeticError::Overflow)?;
+			let (fee_growth_inside_0, fee_growth_inside_1) =
+				Self::update_position_ticks(pool_id, &mut pool, tick_lower, tick_upper, liquidity_delta)?;
+
+			let pool_account = Self::pool_account(pool_id);
+			T::Currency::transfer(pool.assets.0, &who, &pool_account, amount_0)?;
+			T::Currency::transfer(pool.assets.1, &who, &pool_account, amount_1)?;
+
+			let position_id = Self::create_position_nft(&who)?;
+			Positions::<T>::insert(
+				position_id,
+				Position {
+					pool_id,
+					tick_lower,
+					tick_upper,
+					liquidity,
+					fee_growth_inside_0_last: fee_growth_inside_0,
+					fee_growth_inside_1_last: fee_growth_inside_1,
+				},
+			);
+			Pools::<T>::insert(pool_id, &pool);
+
+			Self::call_on_liquidity_changed_hook(pool_id, &pool, amount_0, amount_1)?;
+
+			Self::deposit_event(Event::PositionCreated {
+				position_id,
+				owner: who,
+				pool_id,
+				tick_lower,
+				tick_upper,
+				liquidity,
+				amount_0,
+				amount_1,
+			});
+
+			Ok(())
+		}
+
+		/// Remove liquidity from a position.
+		///
+		/// Origin receives assets corresponding to removed liquidity and all fees accrued by the position.
+		/// When all liquidity is removed, the position is destroyed and its NFT is burned.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the position NFT
+		/// - `position_id`: position id
+		/// - `liquidity`: liquidity to remove
+		/// - `min_amount_0`: slippage limit. Minimum amount of asset 0 to receive, excluding fees.
+		/// - `min_amount_1`: slippage limit. Minimum amount of asset 1 to receive, excluding fees.
+		///
+		/// Emits `LiquidityRemoved` and `FeesCollected` events when successful.
+		/// Emits `PositionDestroyed` event when all liquidity is removed.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+							.saturating_add(T::AMMHandler::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			position_id: PositionId,
+			liquidity: Liquidity,
+			min_amount_0: Balance,
+			min_amount_1: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut position = Self::ensure_position_owner(&who, position_id)?;
+			ensure!(!liquidity.is_zero(), Error::<T>::ZeroLiquidity);
+			ensure!(
+				liquidity <= position.liquidity,
+				Error::<T>::InsufficientPositionLiquidity
+			);
+
+			let pool_id = position.pool_id;
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			let sqrt_price_lower = sqrt_price_at_tick(position.tick_lower).map_err(Self::math_error)?;
+			let sqrt_price_upper = sqrt_price_at_tick(position.tick_upper).map_err(Self::math_error)?;
+			let (amount_0, amount_1) =
+				calculate_amounts_for_liquidity(pool.sqrt_price, sqrt_price_lower, sqrt_price_upper, liquidity, false)
+					.map_err(Self::math_error)?;
+			ensure!(
+				amount_0 >= min_amount_0 && amount_1 >= min_amount_1,
+				Error::<T>::SlippageLimit
+			);
+
+			let liquidity_delta = i128::try_from(liquidity).map_err(|_| ArithmeticError::Overflow)?;
+			let fee_growth_inside = Self::update_position_ticks(
+				pool_id,
+				&mut pool,
+				position.tick_lower,
+				position.tick_upper,
+				liquidity_delta.checked_neg().ok_or(ArithmeticError::Overflow)?,
+			)?;
+			let (fee_0, fee_1) = Self::accrue_fees(&mut position, fee_growth_inside)?;
+
+			let pool_account = Self::pool_account(pool_id);
+			T::Currency::transfer(
+				pool.assets.0,
+				&pool_account,
+				&who,
+				amount_0.checked_add(fee_0).ok_or(ArithmeticError::Overflow)?,
+			)?;
+			T::Currency::transfer(
+				pool.assets.1,
+				&pool_account,
+				&who,
+				amount_1.checked_add(fee_1).ok_or(ArithmeticError::Overflow)?,
+			)?;
+
+			position.liquidity = position
+				.liquidity
+				.checked_sub(liquidity)
+				.ok_or(ArithmeticError::Underflow)?;
+			Pools::<T>::insert(pool_id, &pool);
+
+			Self::call_on_liquidity_changed_hook(pool_id, &pool, amount_0, amount_1)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				position_id,
+				owner: who.clone(),
+				pool_id,
+				liquidity,
+				amount_0,
+				amount_1,
+			});
+			Self::deposit_event(Event::FeesCollected {
+				position_id,
+				owner: who.clone(),
+				amount_0: fee_0,
+				amount_1: fee_1,
+			});
+
+			if position.liquidity.is_zero() {
+				Positions::<T>::remove(position_id);
+				T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
+
+				Self::deposit_event(Event::PositionDestroyed {
+					position_id,
+					owner: who,
+				});
+			} else {
+				Positions::<T>::insert(position_id, position);
+			}
+
+			Ok(())
+		}
+
+		/// Collect fees accrued by a position.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the position NFT
+		/// - `position_id`: position id
+		///
+		/// Emits `FeesCollected` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::collect_fees())]
+		#[transactional]
+		pub fn collect_fees(origin: OriginFor<T>, position_id: PositionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut position = Self::ensure_position_owner(&who, position_id)?;
+			let pool = Pools::<T>::get(position.pool_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			let fee_growth_inside =
+				Self::fee_growth_inside(position.pool_id, &pool, position.tick_lower, position.tick_upper);
+			let (fee_0, fee_1) = Self::accrue_fees(&mut position, fee_growth_inside)?;
+
+			let pool_account = Self::pool_account(position.pool_id);
+			T::Currency::transfer(pool.assets.0, &pool_account, &who, fee_0)?;
+			T::Currency::transfer(pool.assets.1, &pool_account, &who, fee_1)?;
+
+			Positions::<T>::insert(position_id, position);
+
+			Self::deposit_event(Event::FeesCollected {
+				position_id,
+				owner: who,
+				amount_0: fee_0,
+				amount_1: fee_1,
+			});
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out`.
+		///
+		/// Parameters:
+		/// - `origin`: origin of the caller
+		/// - `pool_id`: pool id
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `amount_in`: Amount of asset sold, including fee
+		/// - `min_buy_amount`: Minimum amount required to receive
+		///
+		/// Trade can cross at most `MaxCrossedTicks` initialized ticks. Weight of ticks which were not crossed
+		/// is refunded.
+		///
+		/// Emits `SellExecuted` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell(T::MaxCrossedTicks::get())
+							.saturating_add(T::AMMHandler::on_trade_weight()))]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			min_buy_amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount_in >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			let (pool, result, zero_for_one) = Self::calculate_swap(pool_id, asset_in, asset_out, amount_in, true)?;
+			ensure!(result.amount_out >= min_buy_amount, Error::<T>::SlippageLimit);
+
+			Self::execute_swap(&who, pool_id, pool, &result, zero_for_one, asset_in, asset_out)?;
+
+			Self::deposit_event(Event::SellExecuted {
+				who,
+				pool_id,
+				asset_in,
+				asset_out,
+				amount_in: result.amount_in,
+				amount_out: result.amount_out,
+				fee: result.fee_amount,
+			});
+
+			Ok(Some(
+				<T as Config>::WeightInfo::sell(result.crossed_ticks.len() as u32)
+					.saturating_add(T::AMMHandler::on_trade_weight()),
+			)
+			.into())
+		}
+
+		/// Execute a swap of `asset_in` for exact amount of `asset_out`.
+		///
+		/// Parameters:
+		/// - `origin`: origin of the caller
+		/// - `pool_id`: pool id
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `amount_out`: Amount of asset to receive
+		/// - `max_sell_amount`: Maximum amount allowed to be sold, including fee
+		///
+		/// Trade can cross at most `MaxCrossedTicks` initialized ticks. Weight of ticks which were not crossed
+		/// is refunded.
+		///
+		/// Emits `BuyExecuted` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy(T::MaxCrossedTicks::get())
+							.saturating_add(T::AMMHandler::on_trade_weight()))]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			pool_id: PoolId,
+			asset_out: T::AssetId,
+			asset_in: T::AssetId,
+			amount_out: Balance,
+			max_sell_amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount_out >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let (pool, result, zero_for_one) = Self::calculate_swap(pool_id, asset_in, asset_out, amount_out, false)?;
+			ensure!(result.amount_in <= max_sell_amount, Error::<T>::SlippageLimit);
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= result.amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			Self::execute_swap(&who, pool_id, pool, &result, zero_for_one, asset_in, asset_out)?;
+
+			Self::deposit_event(Event::BuyExecuted {
+				who,
+				pool_id,
+				asset_in,
+				asset_out,
+				amount_in: result.amount_in,
+				amount_out: result.amount_out,
+				fee: result.fee_amount,
+			});
+
+			Ok(Some(
+				<T as Config>::WeightInfo::buy(result.crossed_ticks.len() as u32)
+					.saturating_add(T::AMMHandler::on_trade_weight()),
+			)
+			.into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account holding reserves of a pool.
+	pub fn pool_account(pool_id: PoolId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(pool_id)
+	}
+
+	/// Account owning the collection of position NFTs.
+	fn protocol_account() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	fn math_error(error: MathError) -> DispatchError {
+		match error {
+			MathError::InsufficientOutReserve => Error::<T>::InsufficientLiquidity.into(),
+			MathError::TickOutOfRange | MathError::PriceOutOfRange => Error::<T>::InvalidTick.into(),
+			_ => ArithmeticError::Overflow.into(),
+		}
+	}
+
+	fn ensure_valid_range(pool: &PoolInfo<T::AssetId>, tick_lower: Tick, tick_upper: Tick) -> DispatchResult {
+		ensure!(tick_lower < tick_upper, Error::<T>::InvalidTickRange);
+		ensure!(
+			tick_lower >= MIN_TICK && tick_upper <= MAX_TICK,
+			Error::<T>::InvalidTick
+		);
+		let spacing = pool.tick_spacing as i32;
+		ensure!(
+			tick_lower % spacing == 0 && tick_upper % spacing == 0,
+			Error::<T>::InvalidTick
+		);
+		Ok(())
+	}
+
+	fn ensure_position_owner(who: &T::AccountId, position_id: PositionId) -> Result<Position, DispatchError> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+		ensure!(
+			T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id).as_ref() == Some(who),
+			Error::<T>::Forbidden
+		);
+		Ok(position)
+	}
+
+	/// Mint an NFT of a new position to `owner`. Collection of positions is created if it does not exist yet.
+	fn create_position_nft(owner: &T::AccountId) -> Result<PositionId, DispatchError> {
+		let collection_id = T::NFTCollectionId::get();
+		if T::NFTHandler::collection_owner(&collection_id).is_none() {
+			T::NFTHandler::create_collection(&collection_id, &Self::protocol_account(), &Self::protocol_account())?;
+		}
+
+		let position_id = NextPositionId::<T>::try_mutate(|next_id| -> Result<PositionId, DispatchError> {
+			let current_id = *next_id;
+			*next_id = next_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			Ok(current_id)
+		})?;
+
+		T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &position_id, owner)?;
+
+		Ok(position_id)
+	}
+
+	/// Initialized ticks of a pool in the form expected by the math.
+	pub(crate) fn tick_liquidity(pool_id: PoolId) -> Vec<TickLiquidity> {
+		InitializedTicks::<T>::get(pool_id)
+			.into_iter()
+			.map(TickLiquidity::from)
+			.collect()
+	}
+
+	/// Fee growth of both assets inside range `[tick_lower, tick_upper)`.
+	fn fee_growth_inside(
+		pool_id: PoolId,
+		pool: &PoolInfo<T::AssetId>,
+		tick_lower: Tick,
+		tick_upper: Tick,
+	) -> (FeeGrowth, FeeGrowth) {
+		let lower = Ticks::<T>::get(pool_id, tick_lower).unwrap_or_default();
+		let upper = Ticks::<T>::get(pool_id, tick_upper).unwrap_or_default();
+
+		(
+			calculate_fee_growth_inside(
+				tick_lower,
+				tick_upper,
+				pool.tick,
+				pool.fee_growth_global_0,
+				lower.fee_growth_outside_0,
+				upper.fee_growth_outside_0,
+			),
+			calculate_fee_growth_inside(
+				tick_lower,
+				tick_upper,
+				pool.tick,
+				pool.fee_growth_global_1,
+				lower.fee_growth_outside_1,
+				upper.fee_growth_outside_1,
+			),
+		)
+	}
+
+	/// Calculate fees accrued by a position and record current fee growth inside its range.
+	fn accrue_fees(
+		position: &mut Position,
+		fee_growth_inside: (FeeGrowth, FeeGrowth),
+	) -> Result<(Balance, Balance), DispatchError> {
+		let fee_0 = calculate_accrued_fees(
+			position.liquidity,
+			fee_growth_inside.0,
+			position.fee_growth_inside_0_last,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?;
+		let fee_1 = calculate_accrued_fees(
+			position.liquidity,
+			fee_growth_inside.1,
+			position.fee_growth_inside_1_last,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?;
+
+		position.fee_growth_inside_0_last = fee_growth_inside.0;
+		position.fee_growth_inside_1_last = fee_growth_inside.1;
+
+		Ok((fee_0, fee_1))
+	}
+
+	/// Apply liquidity change of a position to its bounds and to the pool.
+	///
+	/// Returns fee growth inside the range of the position. It is calculated after bounds are initialized
+	/// and before bounds without any liquidity are cleared.
+	#[require_transactional]
+	fn update_position_ticks(
+		pool_id: PoolId,
+		pool: &mut PoolInfo<T::AssetId>,
+		tick_lower: Tick,
+		tick_upper: Tick,
+		liquidity_delta: i128,
+	) -> Result<(FeeGrowth, FeeGrowth), DispatchError> {
+		let lower_cleared = Self::update_tick(pool_id, pool, tick_lower, liquidity_delta, false)?;
+		let upper_cleared = Self::update_tick(pool_id, pool, tick_upper, liquidity_delta, true)?;
+
+		if tick_lower <= pool.tick && pool.tick < tick_upper {
+			pool.liquidity = add_liquidity_delta(pool.liquidity, liquidity_delta).map_err(Self::math_error)?;
+		}
+
+		let fee_growth_inside = Self::fee_growth_inside(pool_id, pool, tick_lower, tick_upper);
+
+		if lower_cleared {
+			Ticks::<T>::remove(pool_id, tick_lower);
+		}
+		if upper_cleared {
+			Ticks::<T>::remove(pool_id, tick_upper);
+		}
+
+		Ok(fee_growth_inside)
+	}
+
+	/// Update liquidity of a tick. Tick is initialized if it has not been used by any position yet.
+	///
+	/// Returns true if there is no liquidity left at the tick, so its fee accounting can be cleared.
+	fn update_tick(
+		pool_id: PoolId,
+		pool: &PoolInfo<T::AssetId>,
+		tick: Tick,
+		liquidity_delta: i128,
+		upper: bool,
+	) -> Result<bool, DispatchError> {
+		let mut info = Ticks::<T>::get(pool_id, tick).unwrap_or_else(|| TickInfo {
+			liquidity_gross: 0,
+			fee_growth_outside_0: calculate_initial_fee_growth_outside(tick, pool.tick, pool.fee_growth_global_0),
+			fee_growth_outside_1: calculate_initial_fee_growth_outside(tick, pool.tick, pool.fee_growth_global_1),
+		});
+		info.liquidity_gross = add_liquidity_delta(info.liquidity_gross, liquidity_delta).map_err(Self::math_error)?;
+
+		// liquidity is removed from the pool when price crosses upper bound of a position from left to right
+		let net_delta = if upper {
+			liquidity_delta.checked_neg().ok_or(ArithmeticError::Overflow)?
+		} else {
+			liquidity_delta
+		};
+
+		let cleared = info.liquidity_gross.is_zero();
+
+		InitializedTicks::<T>::try_mutate(pool_id, |ticks| -> DispatchResult {
+			match ticks.binary_search_by_key(&tick, |t| t.tick) {
+				Ok(idx) if cleared => {
+					ticks.remove(idx);
+				}
+				Ok(idx) => {
+					ticks[idx].liquidity_net = ticks[idx]
+						.liquidity_net
+						.checked_add(net_delta)
+						.ok_or(ArithmeticError::Overflow)?;
+				}
+				Err(idx) => {
+					ticks
+						.try_insert(
+							idx,
+							InitializedTick {
+								tick,
+								liquidity_net: net_delta,
+							},
+						)
+						.map_err(|_| Error::<T>::MaxInitializedTicksReached)?;
+				}
+			}
+			Ok(())
+		})?;
+
+		Ticks::<T>::insert(pool_id, tick, info);
+
+		Ok(cleared)
+	}
+
+	/// Calculate swap in a pool without any storage changes.
+	///
+	/// `amount` is amount in when `exact_in` is true, amount out otherwise.
+	pub(crate) fn calculate_swap(
+		pool_id: PoolId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+		exact_in: bool,
+	) -> Result<(PoolInfo<T::AssetId>, SwapResult, bool), DispatchError> {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let zero_for_one = pool
+			.zero_for_one(&asset_in, &asset_out)
+			.ok_or(Error::<T>::AssetNotInPool)?;
+		let ticks = Self::tick_liquidity(pool_id);
+
+		let result = if exact_in {
+			calculate_out_given_in(&pool.state(), &ticks, zero_for_one, amount, pool.fee)
+		} else {
+			calculate_in_given_out(&pool.state(), &ticks, zero_for_one, amount, pool.fee)
+		}
+		.map_err(Self::math_error)?;
+
+		ensure!(!result.amount_out.is_zero(), Error::<T>::InsufficientTradingAmount);
+		ensure!(
+			result.crossed_ticks.len() <= T::MaxCrossedTicks::get() as usize,
+			Error::<T>::MaxCrossedTicksReached
+		);
+
+		Ok((pool, result, zero_for_one))
+	}
+
+	/// Transfer assets of a calculated swap and update the pool and crossed ticks.
+	#[require_transactional]
+	fn execute_swap(
+		who: &T::AccountId,
+		pool_id: PoolId,
+		mut pool: PoolInfo<T::AssetId>,
+		result: &SwapResult,
+		zero_for_one: bool,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+	) -> DispatchResult {
+		let pool_account = Self::pool_account(pool_id);
+		T::Currency::transfer(asset_in, who, &pool_account, result.amount_in)?;
+		T::Currency::transfer(asset_out, &pool_account, who, result.amount_out)?;
+
+		let (fee_growth_global_in, fee_growth_global_other) = if zero_for_one {
+			(pool.fee_growth_global_0, pool.fee_growth_global_1)
+		} else {
+			(pool.fee_growth_global_1, pool.fee_growth_global_0)
+		};
+
+		for (tick, fee_growth_delta) in result.crossed_ticks.iter() {
+			Ticks::<T>::mutate(pool_id, tick, |maybe_info| {
+				if let Some(info) = maybe_info {
+					let fee_growth_in_at_cross = fee_growth_global_in.overflowing_add(*fee_growth_delta).0;
+					let (outside_in, outside_other) = if zero_for_one {
+						(&mut info.fee_growth_outside_0, &mut info.fee_growth_outside_1)
+					} else {
+						(&mut info.fee_growth_outside_1, &mut info.fee_growth_outside_0)
+					};
+					*outside_in = calculate_fee_growth_outside_after_cross(fee_growth_in_at_cross, *outside_in);
+					*outside_other = calculate_fee_growth_outside_after_cross(fee_growth_global_other, *outside_other);
+				}
+			});
+		}
+
+		let fee_growth_global_in = fee_growth_global_in.overflowing_add(result.fee_growth_delta).0;
+		if zero_for_one {
+			pool.fee_growth_global_0 = fee_growth_global_in;
+		} else {
+			pool.fee_growth_global_1 = fee_growth_global_in;
+		}
+		pool.sqrt_price = result.state.sqrt_price;
+		pool.tick = result.state.tick;
+		pool.liquidity = result.state.liquidity;
+		Pools::<T>::insert(pool_id, &pool);
+
+		let liquidity_in = T::Currency::total_balance(asset_in, &pool_account);
+		let liquidity_out = T::Currency::total_balance(asset_out, &pool_account);
+		// oracle does not accept empty reserves, which is a valid state when price is out of all positions
+		if !liquidity_in.is_zero() && !liquidity_out.is_zero() {
+			T::AMMHandler::on_trade(
+				T::OracleSource::get(),
+				asset_in,
+				asset_out,
+				result.amount_in,
+				result.amount_out,
+				liquidity_in,
+				liquidity_out,
+				Self::spot_price(&pool, asset_in),
+			)
+			.map_err(|(_, e)| e)?;
+		}
+
+		Ok(())
+	}
+
+	fn call_on_liquidity_changed_hook(
+		pool_id: PoolId,
+		pool: &PoolInfo<T::AssetId>,
+		amount_0: Balance,
+		amount_1: Balance,
+	) -> DispatchResult {
+		let pool_account = Self::pool_account(pool_id);
+		let liquidity_0 = T::Currency::total_balance(pool.assets.0, &pool_account);
+		let liquidity_1 = T::Currency::total_balance(pool.assets.1, &pool_account);
+
+		// oracle does not accept empty reserves, which is a valid state when price is out of all positions
+		if liquidity_0.is_zero() || liquidity_1.is_zero() {
+			return Ok(());
+		}
+
+		T::AMMHandler::on_liquidity_changed(
+			T::OracleSource::get(),
+			pool.assets.0,
+			pool.assets.1,
+			amount_0,
+			amount_1,
+			liquidity_0,
+			liquidity_1,
+			Self::spot_price(pool, pool.assets.0),
+		)
+		.map_err(|(_, e)| e)?;
+
+		Ok(())
+	}
+
+	/// Spot price of a pool as amount of `asset` per unit of the other asset of the pool.
+	pub fn spot_price(pool: &PoolInfo<T::AssetId>, asset: T::AssetId) -> Ratio {
+		let (n, d) = calculate_spot_price(pool.sqrt_price);
+		if asset == pool.assets.0 {
+			Ratio::new(d, n)
+		} else {
+			Ratio::new(n, d)
+		}
+	}
+}
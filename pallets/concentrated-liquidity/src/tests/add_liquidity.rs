@@ -0,0 +1,252 @@
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn add_liquidity_should_work_when_range_contains_current_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 1_000 * ONE), (ALICE, DAI, 1_000 * ONE)])
+		.with_pool(HDX, DAI, Permill::from_rational(3u32, 1000u32), 10, 0)
+		.build()
+		.execute_with(|| {
+			assert_ok!(ConcentratedLiquidity::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				-600,
+				600,
+				100 * ONE,
+				100 * ONE,
+				DEFAULT_LIQUIDITY,
+			));
+
+			assert_eq!(
+				ConcentratedLiquidity::positions(0).unwrap(),
+				Position {
+					pool_id: 0,
+					tick_lower: -600,
+					tick_upper: 600,
+					liquidity: DEFAULT_LIQUIDITY,
+					fee_growth_inside_0_last: FeeGrowth::zero(),
+					fee_growth_inside_1_last: FeeGrowth::zero(),
+				}
+			);
+			assert_eq!(pool(0).liquidity, DEFAULT_LIQUIDITY);
+			assert_eq!(
+				initialized_ticks(0),
+				vec![
+					InitializedTick {
+						tick: -600,
+						liquidity_net: DEFAULT_LIQUIDITY as i128,
+					},
+					InitializedTick {
+						tick: 600,
+						liquidity_net: -(DEFAULT_LIQUIDITY as i128),
+					},
+				]
+			);
+			assert_eq!(
+				ConcentratedLiquidity::ticks(0, -600).unwrap().liquidity_gross,
+				DEFAULT_LIQUIDITY
+			);
+			assert_eq!(
+				ConcentratedLiquidity::ticks(0, 600).unwrap().liquidity_gross,
+				DEFAULT_LIQUIDITY
+			);
+
+			assert_eq!(Tokens::free_balance(HDX, &pool_account(0)), 100 * ONE);
+			assert_eq!(Tokens::free_balance(DAI, &pool_account(0)), 100 * ONE);
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), 900 * ONE);
+			assert_eq!(Tokens::free_balance(DAI, &ALICE), 900 * ONE);
+
+			assert_eq!(position_owner(0), Some(ALICE));
+			assert_eq!(
+				position_collection_owner(),
+				Some(ConcentratedLiquidity::protocol_account())
+			);
+			assert_eq!(ConcentratedLiquidity::next_position_id(), 1);
+
+			expect_events(vec![Event::PositionCreated {
+				position_id: 0,
+				owner: ALICE,
+				pool_id: 0,
+				tick_lower: -600,
+				tick_upper: 600,
+				liquidity: DEFAULT_LIQUIDITY,
+				amount_0: 100 * ONE,
+				amount_1: 100 * ONE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn add_liquidity_should_transfer_only_asset_0_when_range_is_above_current_price() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			0,
+			600,
+			1200,
+			100 * ONE,
+			100 * ONE,
+			0,
+		));
+
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 900 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_000 * ONE);
+		// price is out of range, so in-range liquidity of the pool does not change
+		assert_eq!(pool(0).liquidity, DEFAULT_LIQUIDITY);
+		assert_eq!(position_owner(1), Some(BOB));
+	});
+}
+
+#[test]
+fn add_liquidity_should_transfer_only_asset_1_when_range_is_below_current_price() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			0,
+			-1200,
+			-600,
+			100 * ONE,
+			100 * ONE,
+			0,
+		));
+
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 1_000 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 900 * ONE);
+		assert_eq!(pool(0).liquidity, DEFAULT_LIQUIDITY);
+
+		// shared tick sums liquidity of both positions
+		let position = ConcentratedLiquidity::positions(1).unwrap();
+		assert_eq!(
+			initialized_ticks(0)[1],
+			InitializedTick {
+				tick: -600,
+				liquidity_net: DEFAULT_LIQUIDITY as i128 - position.liquidity as i128,
+			}
+		);
+		assert_eq!(
+			ConcentratedLiquidity::ticks(0, -600).unwrap().liquidity_gross,
+			DEFAULT_LIQUIDITY + position.liquidity
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_pool_does_not_exist() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(BOB), 1, -600, 600, ONE, ONE, 0),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_range_is_invalid() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(BOB), 0, 600, -600, ONE, ONE, 0),
+			Error::<Test>::InvalidTickRange
+		);
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(BOB), 0, 600, 600, ONE, ONE, 0),
+			Error::<Test>::InvalidTickRange
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_ticks_are_not_divisible_by_tick_spacing() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(BOB), 0, -605, 600, ONE, ONE, 0),
+			Error::<Test>::InvalidTick
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_ticks_are_out_of_range() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				0,
+				-600,
+				hydra_dx_math::concentrated::MAX_TICK + 10,
+				ONE,
+				ONE,
+				0
+			),
+			Error::<Test>::InvalidTick
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_liquidity_is_zero() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(BOB), 0, -600, 600, 0, ONE, 0),
+			Error::<Test>::ZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_liquidity_is_lower_than_min_liquidity() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				0,
+				-600,
+				600,
+				100 * ONE,
+				100 * ONE,
+				DEFAULT_LIQUIDITY + 1
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_balance_is_insufficient() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(CHARLIE), 0, -600, 600, 100 * ONE, 100 * ONE, 0),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_max_initialized_ticks_is_reached() {
+	let mut builder = default_pool();
+	// default position initializes 2 ticks, each of these positions 1 more
+	for i in 1..=(MaxInitializedTicks::get() as i32 - 2) {
+		builder = builder.with_position(BOB, 0, -600, 600 + i * 10, ONE, ONE);
+	}
+
+	builder.build().execute_with(|| {
+		assert_eq!(initialized_ticks(0).len() as u32, MaxInitializedTicks::get());
+
+		// reusing initialized ticks is still allowed
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			0,
+			-600,
+			600,
+			ONE,
+			ONE,
+			0
+		));
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(BOB), 0, -610, 600, ONE, ONE, 0),
+			Error::<Test>::MaxInitializedTicksReached
+		);
+	});
+}
@@ -0,0 +1,119 @@
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn collect_fees_should_transfer_fees_of_trades_to_owner() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			0,
+			HDX,
+			DAI,
+			10 * ONE,
+			0,
+		));
+
+		assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 0));
+
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 900 * ONE + 29_999_999_999);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 900 * ONE);
+		expect_events(vec![Event::FeesCollected {
+			position_id: 0,
+			owner: ALICE,
+			amount_0: 29_999_999_999,
+			amount_1: 0,
+		}
+		.into()]);
+
+		// fees can be collected only once
+		assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 0));
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 900 * ONE + 29_999_999_999);
+	});
+}
+
+#[test]
+fn collect_fees_should_distribute_fees_proportionally_to_liquidity_in_range() {
+	default_pool()
+		.with_position(BOB, 0, -600, 600, 100 * ONE, 100 * ONE)
+		.with_position(BOB, 0, 600, 1200, 100 * ONE, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(ConcentratedLiquidity::sell(
+				RuntimeOrigin::signed(BOB),
+				0,
+				HDX,
+				DAI,
+				10 * ONE,
+				0,
+			));
+
+			let alice_balance = Tokens::free_balance(HDX, &ALICE);
+			assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 0));
+			let alice_fee = Tokens::free_balance(HDX, &ALICE) - alice_balance;
+
+			let bob_balance = Tokens::free_balance(HDX, &BOB);
+			assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(BOB), 1));
+			let bob_fee = Tokens::free_balance(HDX, &BOB) - bob_balance;
+
+			assert_eq!(alice_fee, bob_fee);
+			assert!(alice_fee <= 15_000_000_000 && alice_fee >= 14_999_999_998);
+
+			// position out of range does not earn any fees
+			let bob_balance = Tokens::free_balance(HDX, &BOB);
+			assert_ok!(ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(BOB), 2));
+			assert_eq!(Tokens::free_balance(HDX, &BOB), bob_balance);
+		});
+}
+
+#[test]
+fn remove_liquidity_should_transfer_accrued_fees() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			0,
+			HDX,
+			DAI,
+			10 * ONE,
+			0,
+		));
+
+		let liquidity_removed_balance = Tokens::free_balance(HDX, &ALICE);
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			DEFAULT_LIQUIDITY,
+			0,
+			0,
+		));
+
+		expect_events(vec![Event::FeesCollected {
+			position_id: 0,
+			owner: ALICE,
+			amount_0: 29_999_999_999,
+			amount_1: 0,
+		}
+		.into()]);
+		assert!(Tokens::free_balance(HDX, &ALICE) > liquidity_removed_balance + 29_999_999_999);
+	});
+}
+
+#[test]
+fn collect_fees_should_fail_when_origin_is_not_owner() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn collect_fees_should_fail_when_position_does_not_exist() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(ALICE), 1),
+			Error::<Test>::PositionNotFound
+		);
+	});
+}
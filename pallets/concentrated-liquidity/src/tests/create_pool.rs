@@ -0,0 +1,151 @@
+use super::*;
+use frame_support::traits::Contains;
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::concentrated::{sqrt_price_at_tick, MAX_TICK};
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn create_pool_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		let fee = Permill::from_rational(3u32, 1000u32);
+
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			DAI,
+			HDX,
+			fee,
+			10,
+			100,
+		));
+
+		assert_eq!(
+			pool(0),
+			PoolInfo {
+				assets: (HDX, DAI),
+				fee,
+				tick_spacing: 10,
+				sqrt_price: sqrt_price_at_tick(100).unwrap(),
+				tick: 100,
+				liquidity: 0,
+				fee_growth_global_0: FeeGrowth::zero(),
+				fee_growth_global_1: FeeGrowth::zero(),
+			}
+		);
+		assert_eq!(ConcentratedLiquidity::next_pool_id(), 1);
+		assert!(Whitelist::contains(&pool_account(0)));
+
+		expect_events(vec![Event::PoolCreated {
+			pool_id: 0,
+			asset_0: HDX,
+			asset_1: DAI,
+			fee,
+			tick_spacing: 10,
+			initial_tick: 100,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_pool_should_allow_multiple_pools_of_same_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			HDX,
+			DAI,
+			Permill::from_percent(1),
+			100,
+			0,
+		));
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			HDX,
+			DAI,
+			Permill::from_rational(5u32, 10_000u32),
+			1,
+			0,
+		));
+
+		assert_eq!(pool(0).fee, Permill::from_percent(1));
+		assert_eq!(pool(1).fee, Permill::from_rational(5u32, 10_000u32));
+		assert_ne!(pool_account(0), pool_account(1));
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::signed(ALICE), HDX, DAI, Permill::from_percent(1), 10, 0),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_assets_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), HDX, HDX, Permill::from_percent(1), 10, 0),
+			Error::<Test>::SameAssets
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_asset_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), HDX, 1_000, Permill::from_percent(1), 10, 0),
+			Error::<Test>::AssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_tick_spacing_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), HDX, DAI, Permill::from_percent(1), 0, 0),
+			Error::<Test>::InvalidTickSpacing
+		);
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				DAI,
+				Permill::from_percent(1),
+				MAX_TICK_SPACING + 1,
+				0
+			),
+			Error::<Test>::InvalidTickSpacing
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_fee_is_too_high() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(RuntimeOrigin::root(), HDX, DAI, Permill::one(), 10, 0),
+			Error::<Test>::InvalidFee
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_initial_tick_is_out_of_range() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				DAI,
+				Permill::from_percent(1),
+				10,
+				MAX_TICK + 1
+			),
+			Error::<Test>::InvalidTick
+		);
+	});
+}
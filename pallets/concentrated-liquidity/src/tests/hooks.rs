@@ -0,0 +1,106 @@
+use super::*;
+use frame_support::assert_ok;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn add_liquidity_should_not_call_on_liquidity_changed_hook_when_pool_has_single_asset() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 1_000 * ONE), (ALICE, DAI, 1_000 * ONE)])
+		.with_pool(HDX, DAI, Permill::from_rational(3u32, 1000u32), 10, 0)
+		.build()
+		.execute_with(|| {
+			assert_ok!(ConcentratedLiquidity::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				600,
+				1200,
+				100 * ONE,
+				100 * ONE,
+				0,
+			));
+
+			assert_eq!(liquidity_hooks(), vec![]);
+		});
+}
+
+#[test]
+fn add_liquidity_should_call_on_liquidity_changed_hook() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			0,
+			-600,
+			600,
+			100 * ONE,
+			100 * ONE,
+			0,
+		));
+
+		let price = ConcentratedLiquidity::spot_price(&pool(0), HDX);
+		assert_eq!(price.n, price.d);
+		assert_eq!(
+			liquidity_hooks(),
+			vec![HookCall {
+				source: ORACLE_SOURCE,
+				asset_a: HDX,
+				asset_b: DAI,
+				amount_a: 100 * ONE,
+				amount_b: 100 * ONE,
+				liquidity_a: 200 * ONE,
+				liquidity_b: 200 * ONE,
+				price,
+			}]
+		);
+	});
+}
+
+#[test]
+fn sell_should_call_on_trade_hook() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			0,
+			HDX,
+			DAI,
+			10 * ONE,
+			0,
+		));
+
+		let pool = pool(0);
+		assert_eq!(
+			trade_hooks(),
+			vec![HookCall {
+				source: ORACLE_SOURCE,
+				asset_a: HDX,
+				asset_b: DAI,
+				amount_a: 10 * ONE,
+				amount_b: 9_940_710_341_290,
+				liquidity_a: 110 * ONE,
+				liquidity_b: 100 * ONE - 9_940_710_341_290,
+				price: ConcentratedLiquidity::spot_price(&pool, HDX),
+			}]
+		);
+	});
+}
+
+#[test]
+fn spot_price_should_be_amount_of_given_asset_per_unit_of_other_asset() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			0,
+			HDX,
+			DAI,
+			10 * ONE,
+			0,
+		));
+
+		let pool = pool(0);
+		let hdx_per_dai = ConcentratedLiquidity::spot_price(&pool, HDX);
+		let dai_per_hdx = ConcentratedLiquidity::spot_price(&pool, DAI);
+
+		// HDX was sold, so HDX is cheaper than DAI
+		assert!(hdx_per_dai.n > hdx_per_dai.d);
+		assert_eq!(hdx_per_dai, Ratio::new(dai_per_hdx.d, dai_per_hdx.n));
+	});
+}
@@ -0,0 +1,512 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for Concentrated liquidity pallet.
+#![allow(clippy::type_complexity)]
+
+use crate as pallet_concentrated_liquidity;
+use crate::Config;
+
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::traits::{Contains, Everything};
+use frame_support::weights::Weight;
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+	PalletId,
+};
+use frame_system::EnsureRoot;
+use hydra_dx_math::concentrated::types::Tick;
+use hydra_dx_math::ratio::Ratio;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{Inspect as InspectRegistry, OnLiquidityChangedHandler, OnTradeHandler, Source};
+use orml_traits::parameter_type_with_key;
+pub use orml_traits::MultiCurrency;
+use sp_core::crypto::AccountId32;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, DispatchError, DispatchResult, Permill,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+#[cfg(feature = "runtime-benchmarks")]
+use crate::types::BenchmarkHelper;
+use crate::types::{PoolId, PositionId};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type Balance = u128;
+pub type AssetId = u32;
+pub type AccountId = AccountId32;
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 2;
+pub const DOT: AssetId = 5;
+
+pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId = AccountId32::new([2u8; 32]);
+pub const CHARLIE: AccountId = AccountId32::new([3u8; 32]);
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const POSITION_COLLECTION_ID: u32 = 1111;
+
+pub const ORACLE_SOURCE: Source = *b"concentr";
+
+thread_local! {
+	pub static REGISTERED_ASSETS: RefCell<Vec<AssetId>> = RefCell::new(Vec::new());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+	pub static POSITIONS: RefCell<HashMap<PositionId, AccountId>> = RefCell::new(HashMap::default());
+	pub static COLLECTIONS: RefCell<HashMap<u32, AccountId>> = RefCell::new(HashMap::default());
+	pub static TRADE_HOOKS: RefCell<Vec<HookCall>> = RefCell::new(Vec::new());
+	pub static LIQUIDITY_HOOKS: RefCell<Vec<HookCall>> = RefCell::new(Vec::new());
+}
+
+/// Arguments of a call to `AMMHandler`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HookCall {
+	pub source: Source,
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+	pub amount_a: Balance,
+	pub amount_b: Balance,
+	pub liquidity_a: Balance,
+	pub liquidity_b: Balance,
+	pub price: Ratio,
+}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		ConcentratedLiquidity: pallet_concentrated_liquidity,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type DustRemovalWhitelist = Everything;
+}
+
+parameter_types! {
+	pub const ConcentratedLiquidityPalletId: PalletId = PalletId(*b"conliqid");
+	pub const PositionCollectionId: u32 = POSITION_COLLECTION_ID;
+	pub const OracleSource: Source = ORACLE_SOURCE;
+	pub const MinTradingLimit: Balance = 1_000;
+	pub const MaxInitializedTicks: u32 = 100;
+	pub const MaxCrossedTicks: u32 = 4;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type AssetInspection = DummyRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type PalletId = ConcentratedLiquidityPalletId;
+	type CollectionId = u32;
+	type NFTCollectionId = PositionCollectionId;
+	type NFTHandler = DummyNFT;
+	type DustAccountHandler = Whitelist;
+	type AMMHandler = MockAMMHandler;
+	type OracleSource = OracleSource;
+	type MinTradingLimit = MinTradingLimit;
+	type MaxInitializedTicks = MaxInitializedTicks;
+	type MaxCrossedTicks = MaxCrossedTicks;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+pub struct InitialPool {
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+	pub fee: Permill,
+	pub tick_spacing: u32,
+	pub initial_tick: Tick,
+}
+
+pub struct InitialPosition {
+	pub owner: AccountId,
+	pub pool_id: PoolId,
+	pub tick_lower: Tick,
+	pub tick_upper: Tick,
+	pub amount_0: Balance,
+	pub amount_1: Balance,
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	registered_assets: Vec<AssetId>,
+	pools: Vec<InitialPool>,
+	positions: Vec<InitialPosition>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		REGISTERED_ASSETS.with(|v| v.borrow_mut().clear());
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().clear());
+		POSITIONS.with(|v| v.borrow_mut().clear());
+		COLLECTIONS.with(|v| v.borrow_mut().clear());
+		TRADE_HOOKS.with(|v| v.borrow_mut().clear());
+		LIQUIDITY_HOOKS.with(|v| v.borrow_mut().clear());
+
+		Self {
+			endowed_accounts: vec![],
+			registered_assets: vec![HDX, DAI, DOT],
+			pools: vec![],
+			positions: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn with_pool(
+		mut self,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		fee: Permill,
+		tick_spacing: u32,
+		initial_tick: Tick,
+	) -> Self {
+		self.pools.push(InitialPool {
+			asset_a,
+			asset_b,
+			fee,
+			tick_spacing,
+			initial_tick,
+		});
+		self
+	}
+
+	pub fn with_position(
+		mut self,
+		owner: AccountId,
+		pool_id: PoolId,
+		tick_lower: Tick,
+		tick_upper: Tick,
+		amount_0: Balance,
+		amount_1: Balance,
+	) -> Self {
+		self.positions.push(InitialPosition {
+			owner,
+			pool_id,
+			tick_lower,
+			tick_upper,
+			amount_0,
+			amount_1,
+		});
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		REGISTERED_ASSETS.with(|v| v.borrow_mut().extend(self.registered_assets.iter().copied()));
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			for pool in self.pools {
+				assert_ok!(ConcentratedLiquidity::create_pool(
+					RuntimeOrigin::root(),
+					pool.asset_a,
+					pool.asset_b,
+					pool.fee,
+					pool.tick_spacing,
+					pool.initial_tick,
+				));
+			}
+
+			for position in self.positions {
+				assert_ok!(ConcentratedLiquidity::add_liquidity(
+					RuntimeOrigin::signed(position.owner),
+					position.pool_id,
+					position.tick_lower,
+					position.tick_upper,
+					position.amount_0,
+					position.amount_1,
+					0,
+				));
+			}
+
+			TRADE_HOOKS.with(|v| v.borrow_mut().clear());
+			LIQUIDITY_HOOKS.with(|v| v.borrow_mut().clear());
+
+			System::set_block_number(1);
+		});
+
+		r
+	}
+}
+
+pub struct DummyRegistry;
+
+impl InspectRegistry for DummyRegistry {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn is_sufficient(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains(&asset_id))
+	}
+
+	fn decimals(_id: Self::AssetId) -> Option<u8> {
+		unimplemented!()
+	}
+
+	fn asset_type(_id: Self::AssetId) -> Option<hydradx_traits::AssetKind> {
+		unimplemented!()
+	}
+
+	fn is_banned(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_name(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn existential_deposit(_id: Self::AssetId) -> Option<u128> {
+		unimplemented!()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(asset_id: AssetId, _decimals: u8) -> DispatchResult {
+		REGISTERED_ASSETS.with(|v| v.borrow_mut().push(asset_id));
+		Ok(())
+	}
+}
+
+pub struct Whitelist;
+
+impl Contains<AccountId> for Whitelist {
+	fn contains(account: &AccountId) -> bool {
+		DUSTER_WHITELIST.with(|v| v.borrow().contains(account))
+	}
+}
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(account.clone()));
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().retain(|x| x != account));
+		Ok(())
+	}
+}
+
+pub struct DummyNFT;
+
+impl Inspect<AccountId> for DummyNFT {
+	type ItemId = PositionId;
+	type CollectionId = u32;
+
+	fn owner(_collection: &Self::CollectionId, item: &Self::ItemId) -> Option<AccountId> {
+		POSITIONS.with(|v| v.borrow().get(item).cloned())
+	}
+
+	fn collection_owner(collection: &Self::CollectionId) -> Option<AccountId> {
+		COLLECTIONS.with(|v| v.borrow().get(collection).cloned())
+	}
+}
+
+impl Mutate<AccountId> for DummyNFT {
+	fn mint_into(_collection: &Self::CollectionId, item: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		POSITIONS.with(|v| v.borrow_mut().insert(*item, who.clone()));
+		Ok(())
+	}
+
+	fn burn(
+		_collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		POSITIONS.with(|v| v.borrow_mut().remove(item));
+		Ok(())
+	}
+}
+
+impl Create<AccountId> for DummyNFT {
+	fn create_collection(collection: &Self::CollectionId, who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		COLLECTIONS.with(|v| v.borrow_mut().insert(*collection, who.clone()));
+		Ok(())
+	}
+}
+
+/// Transfer NFT of a position to `to`.
+pub(crate) fn transfer_position(position_id: PositionId, to: AccountId) {
+	POSITIONS.with(|v| v.borrow_mut().insert(position_id, to));
+}
+
+pub(crate) fn position_owner(position_id: PositionId) -> Option<AccountId> {
+	POSITIONS.with(|v| v.borrow().get(&position_id).cloned())
+}
+
+pub(crate) fn position_collection_owner() -> Option<AccountId> {
+	COLLECTIONS.with(|v| v.borrow().get(&POSITION_COLLECTION_ID).cloned())
+}
+
+pub struct MockAMMHandler;
+
+impl OnTradeHandler<AssetId, Balance, Ratio> for MockAMMHandler {
+	fn on_trade(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+		price: Ratio,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		TRADE_HOOKS.with(|v| {
+			v.borrow_mut().push(HookCall {
+				source,
+				asset_a,
+				asset_b,
+				amount_a,
+				amount_b,
+				liquidity_a,
+				liquidity_b,
+				price,
+			})
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl OnLiquidityChangedHandler<AssetId, Balance, Ratio> for MockAMMHandler {
+	fn on_liquidity_changed(
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		amount_a: Balance,
+		amount_b: Balance,
+		liquidity_a: Balance,
+		liquidity_b: Balance,
+		price: Ratio,
+	) -> Result<Weight, (Weight, DispatchError)> {
+		LIQUIDITY_HOOKS.with(|v| {
+			v.borrow_mut().push(HookCall {
+				source,
+				asset_a,
+				asset_b,
+				amount_a,
+				amount_b,
+				liquidity_a,
+				liquidity_b,
+				price,
+			})
+		});
+		Ok(Weight::zero())
+	}
+
+	fn on_liquidity_changed_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub(crate) fn trade_hooks() -> Vec<HookCall> {
+	TRADE_HOOKS.with(|v| v.borrow().clone())
+}
+
+pub(crate) fn liquidity_hooks() -> Vec<HookCall> {
+	LIQUIDITY_HOOKS.with(|v| v.borrow().clone())
+}
+
+pub(crate) fn pool_account(pool_id: PoolId) -> AccountId {
+	ConcentratedLiquidity::pool_account(pool_id)
+}
+
+pub(crate) fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
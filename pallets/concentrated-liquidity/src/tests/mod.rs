@@ -0,0 +1,37 @@
+use crate::tests::mock::*;
+use crate::*;
+
+mod add_liquidity;
+mod collect_fees;
+mod create_pool;
+mod hooks;
+pub(crate) mod mock;
+mod remove_liquidity;
+mod trade_execution;
+mod trades;
+
+type Balance = u128;
+
+/// Liquidity of a position in range `[-600, 600)` provided with `100 * ONE` of both assets at tick 0.
+pub(crate) const DEFAULT_LIQUIDITY: Liquidity = 3_383_749_980_973_837;
+
+/// Pool of HDX and DAI at price 1 with 0.3% fee and ALICE's position in range `[-600, 600)`.
+pub(crate) fn default_pool() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 1_000 * ONE),
+			(ALICE, DAI, 1_000 * ONE),
+			(BOB, HDX, 1_000 * ONE),
+			(BOB, DAI, 1_000 * ONE),
+		])
+		.with_pool(HDX, DAI, Permill::from_rational(3u32, 1000u32), 10, 0)
+		.with_position(ALICE, 0, -600, 600, 100 * ONE, 100 * ONE)
+}
+
+pub(crate) fn pool(pool_id: PoolId) -> PoolInfo<AssetId> {
+	Pools::<Test>::get(pool_id).unwrap()
+}
+
+pub(crate) fn initialized_ticks(pool_id: PoolId) -> Vec<InitializedTick> {
+	InitializedTicks::<Test>::get(pool_id).into_inner()
+}
@@ -0,0 +1,194 @@
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn remove_liquidity_should_work_when_part_of_liquidity_is_removed() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			DEFAULT_LIQUIDITY / 2,
+			0,
+			0,
+		));
+
+		let position = ConcentratedLiquidity::positions(0).unwrap();
+		assert_eq!(position.liquidity, DEFAULT_LIQUIDITY - DEFAULT_LIQUIDITY / 2);
+		assert_eq!(pool(0).liquidity, position.liquidity);
+		assert_eq!(position_owner(0), Some(ALICE));
+		assert_eq!(
+			ConcentratedLiquidity::ticks(0, -600).unwrap().liquidity_gross,
+			position.liquidity
+		);
+
+		// amounts are rounded down in favour of the pool
+		let received_0 = Tokens::free_balance(HDX, &ALICE) - 900 * ONE;
+		let received_1 = Tokens::free_balance(DAI, &ALICE) - 900 * ONE;
+		assert!(received_0 <= 50 * ONE && received_0 >= 50 * ONE - 1);
+		assert!(received_1 <= 50 * ONE && received_1 >= 50 * ONE - 1);
+		assert_eq!(Tokens::free_balance(HDX, &pool_account(0)), 100 * ONE - received_0);
+		assert_eq!(Tokens::free_balance(DAI, &pool_account(0)), 100 * ONE - received_1);
+
+		expect_events(vec![Event::LiquidityRemoved {
+			position_id: 0,
+			owner: ALICE,
+			pool_id: 0,
+			liquidity: DEFAULT_LIQUIDITY / 2,
+			amount_0: received_0,
+			amount_1: received_1,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_destroy_position_when_all_liquidity_is_removed() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			0,
+			DEFAULT_LIQUIDITY,
+			99_999_999_999_999,
+			99_999_999_999_999,
+		));
+
+		assert_eq!(ConcentratedLiquidity::positions(0), None);
+		assert_eq!(position_owner(0), None);
+		assert_eq!(pool(0).liquidity, 0);
+		assert_eq!(initialized_ticks(0), vec![]);
+		assert_eq!(ConcentratedLiquidity::ticks(0, -600), None);
+		assert_eq!(ConcentratedLiquidity::ticks(0, 600), None);
+
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 999_999_999_999_999);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 999_999_999_999_999);
+		assert_eq!(Tokens::free_balance(HDX, &pool_account(0)), 1);
+		assert_eq!(Tokens::free_balance(DAI, &pool_account(0)), 1);
+
+		expect_events(vec![
+			Event::LiquidityRemoved {
+				position_id: 0,
+				owner: ALICE,
+				pool_id: 0,
+				liquidity: DEFAULT_LIQUIDITY,
+				amount_0: 99_999_999_999_999,
+				amount_1: 99_999_999_999_999,
+			}
+			.into(),
+			Event::PositionDestroyed {
+				position_id: 0,
+				owner: ALICE,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_keep_shared_tick_when_other_position_uses_it() {
+	default_pool()
+		.with_position(BOB, 0, -1200, -600, 100 * ONE, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			let bob_liquidity = ConcentratedLiquidity::positions(1).unwrap().liquidity;
+
+			assert_ok!(ConcentratedLiquidity::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				0,
+				DEFAULT_LIQUIDITY,
+				0,
+				0,
+			));
+
+			assert_eq!(
+				initialized_ticks(0),
+				vec![
+					InitializedTick {
+						tick: -1200,
+						liquidity_net: bob_liquidity as i128,
+					},
+					InitializedTick {
+						tick: -600,
+						liquidity_net: -(bob_liquidity as i128),
+					},
+				]
+			);
+			assert_eq!(
+				ConcentratedLiquidity::ticks(0, -600).unwrap().liquidity_gross,
+				bob_liquidity
+			);
+			assert_eq!(ConcentratedLiquidity::ticks(0, 600), None);
+		});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_origin_is_not_owner() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(BOB), 0, DEFAULT_LIQUIDITY, 0, 0),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_work_when_position_nft_was_transferred() {
+	default_pool().build().execute_with(|| {
+		transfer_position(0, BOB);
+
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), 0, DEFAULT_LIQUIDITY, 0, 0),
+			Error::<Test>::Forbidden
+		);
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(BOB),
+			0,
+			DEFAULT_LIQUIDITY,
+			0,
+			0
+		));
+
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 1_000 * ONE + 99_999_999_999_999);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_000 * ONE + 99_999_999_999_999);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_position_does_not_exist() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), 1, DEFAULT_LIQUIDITY, 0, 0),
+			Error::<Test>::PositionNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_liquidity_is_zero() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), 0, 0, 0, 0),
+			Error::<Test>::ZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_liquidity_exceeds_position_liquidity() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), 0, DEFAULT_LIQUIDITY + 1, 0, 0),
+			Error::<Test>::InsufficientPositionLiquidity
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_amount_is_lower_than_min_amount() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), 0, DEFAULT_LIQUIDITY, 100 * ONE, 0),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
@@ -0,0 +1,125 @@
+use super::*;
+use frame_support::assert_ok;
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use pretty_assertions::assert_eq;
+
+fn calculate_sell(pool_type: PoolType<AssetId>, amount: Balance) -> Result<Balance, ExecutorError<DispatchError>> {
+	<ConcentratedLiquidity as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_sell(
+		pool_type, HDX, DAI, amount,
+	)
+}
+
+fn calculate_buy(pool_type: PoolType<AssetId>, amount: Balance) -> Result<Balance, ExecutorError<DispatchError>> {
+	<ConcentratedLiquidity as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_buy(
+		pool_type, HDX, DAI, amount,
+	)
+}
+
+#[test]
+fn calculate_sell_should_return_amount_out() {
+	default_pool().build().execute_with(|| {
+		assert_eq!(
+			calculate_sell(PoolType::Concentrated(0), 10 * ONE),
+			Ok(9_940_710_341_290)
+		);
+	});
+}
+
+#[test]
+fn calculate_buy_should_return_amount_in() {
+	default_pool().build().execute_with(|| {
+		assert_eq!(
+			calculate_buy(PoolType::Concentrated(0), 10 * ONE),
+			Ok(10_059_820_068_005)
+		);
+	});
+}
+
+#[test]
+fn calculate_should_fail_when_pool_type_is_not_supported() {
+	default_pool().build().execute_with(|| {
+		assert_eq!(
+			calculate_sell(PoolType::XYK, 10 * ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			calculate_buy(PoolType::Omnipool, 10 * ONE),
+			Err(ExecutorError::NotSupported)
+		);
+	});
+}
+
+#[test]
+fn calculate_sell_should_fail_when_pool_does_not_exist() {
+	default_pool().build().execute_with(|| {
+		assert_eq!(
+			calculate_sell(PoolType::Concentrated(1), 10 * ONE),
+			Err(ExecutorError::Error(Error::<Test>::PoolNotFound.into()))
+		);
+	});
+}
+
+#[test]
+fn execute_sell_should_work() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(<ConcentratedLiquidity as TradeExecution<
+			RuntimeOrigin,
+			AccountId,
+			AssetId,
+			Balance,
+		>>::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Concentrated(0),
+			HDX,
+			DAI,
+			10 * ONE,
+			9_940_710_341_290,
+		));
+
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_000 * ONE + 9_940_710_341_290);
+	});
+}
+
+#[test]
+fn execute_buy_should_work() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(<ConcentratedLiquidity as TradeExecution<
+			RuntimeOrigin,
+			AccountId,
+			AssetId,
+			Balance,
+		>>::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::Concentrated(0),
+			HDX,
+			DAI,
+			10 * ONE,
+			10_059_820_068_005,
+		));
+
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 1_000 * ONE - 10_059_820_068_005);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_010 * ONE);
+	});
+}
+
+#[test]
+fn get_liquidity_depth_should_return_pool_reserve() {
+	default_pool().build().execute_with(|| {
+		assert_eq!(
+			<ConcentratedLiquidity as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::get_liquidity_depth(
+				PoolType::Concentrated(0),
+				DAI,
+				HDX,
+			),
+			Ok(100 * ONE)
+		);
+		assert_eq!(
+			<ConcentratedLiquidity as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::get_liquidity_depth(
+				PoolType::Concentrated(0),
+				DOT,
+				HDX,
+			),
+			Err(ExecutorError::Error(Error::<Test>::AssetNotInPool.into()))
+		);
+	});
+}
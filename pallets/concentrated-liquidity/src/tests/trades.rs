@@ -0,0 +1,270 @@
+use super::*;
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn sell_should_work_when_selling_asset_0() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			0,
+			HDX,
+			DAI,
+			10 * ONE,
+			9_940_710_341_290,
+		));
+
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 990 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_000 * ONE + 9_940_710_341_290);
+		assert_eq!(Tokens::free_balance(HDX, &pool_account(0)), 110 * ONE);
+		assert_eq!(
+			Tokens::free_balance(DAI, &pool_account(0)),
+			100 * ONE - 9_940_710_341_290
+		);
+
+		let pool = pool(0);
+		assert_eq!(pool.tick, -59);
+		assert_eq!(pool.liquidity, DEFAULT_LIQUIDITY);
+		assert!(pool.fee_growth_global_0 > FeeGrowth::zero());
+		assert_eq!(pool.fee_growth_global_1, FeeGrowth::zero());
+
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			pool_id: 0,
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 10 * ONE,
+			amount_out: 9_940_710_341_290,
+			fee: 30_000_000_000,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_work_when_selling_asset_1() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			0,
+			DAI,
+			HDX,
+			10 * ONE,
+			0,
+		));
+
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 990 * ONE);
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 1_000 * ONE + 9_940_710_341_290);
+
+		let pool = pool(0);
+		assert_eq!(pool.tick, 58);
+		assert_eq!(pool.fee_growth_global_0, FeeGrowth::zero());
+		assert!(pool.fee_growth_global_1 > FeeGrowth::zero());
+	});
+}
+
+#[test]
+fn buy_should_work() {
+	default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::buy(
+			RuntimeOrigin::signed(BOB),
+			0,
+			DAI,
+			HDX,
+			10 * ONE,
+			10_059_820_068_005,
+		));
+
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 1_000 * ONE - 10_059_820_068_005);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 1_010 * ONE);
+		assert_eq!(pool(0).tick, -60);
+
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			pool_id: 0,
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 10_059_820_068_005,
+			amount_out: 10 * ONE,
+			fee: 30_179_460_205,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_cross_tick_when_price_leaves_range_of_position() {
+	default_pool()
+		.with_position(BOB, 0, -1200, 1200, 100 * ONE, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			let bob_liquidity = ConcentratedLiquidity::positions(1).unwrap().liquidity;
+			assert_eq!(pool(0).liquidity, DEFAULT_LIQUIDITY + bob_liquidity);
+
+			assert_ok!(ConcentratedLiquidity::sell(
+				RuntimeOrigin::signed(BOB),
+				0,
+				HDX,
+				DAI,
+				150 * ONE,
+				0,
+			));
+
+			let pool = pool(0);
+			assert!(pool.tick < -600 && pool.tick >= -1200);
+			assert_eq!(pool.liquidity, bob_liquidity);
+
+			// fee growth outside of crossed tick flips to the growth below the new price
+			let crossed = ConcentratedLiquidity::ticks(0, -600).unwrap();
+			assert!(crossed.fee_growth_outside_0 > FeeGrowth::zero());
+			assert!(crossed.fee_growth_outside_0 < pool.fee_growth_global_0);
+
+			// selling back moves the price into the range of both positions again
+			assert_ok!(ConcentratedLiquidity::sell(
+				RuntimeOrigin::signed(BOB),
+				0,
+				DAI,
+				HDX,
+				150 * ONE,
+				0,
+			));
+			assert_eq!(
+				ConcentratedLiquidity::pools(0).unwrap().liquidity,
+				DEFAULT_LIQUIDITY + bob_liquidity
+			);
+		});
+}
+
+#[test]
+fn sell_should_fail_when_there_is_not_enough_liquidity() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, HDX, DAI, 1_000 * ONE, 0),
+			Error::<Test>::InsufficientLiquidity
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), 0, DAI, HDX, 100 * ONE, Balance::MAX),
+			Error::<Test>::InsufficientLiquidity
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_amount_is_lower_than_min_trading_limit() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, HDX, DAI, 999, 0),
+			Error::<Test>::InsufficientTradingAmount
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), 0, DAI, HDX, 999, Balance::MAX),
+			Error::<Test>::InsufficientTradingAmount
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_balance_is_insufficient() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(CHARLIE), 0, HDX, DAI, 10 * ONE, 0),
+			Error::<Test>::InsufficientBalance
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(CHARLIE), 0, DAI, HDX, 10 * ONE, Balance::MAX),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_asset_is_not_in_pool() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, HDX, DOT, 10 * ONE, 0),
+			Error::<Test>::AssetNotInPool
+		);
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, HDX, HDX, 10 * ONE, 0),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_pool_does_not_exist() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 1, HDX, DAI, 10 * ONE, 0),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn trades_should_fail_when_slippage_limit_is_exceeded() {
+	default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, HDX, DAI, 10 * ONE, 9_940_710_341_291),
+			Error::<Test>::SlippageLimit
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), 0, DAI, HDX, 10 * ONE, 10_059_820_068_004),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+/// Default pool with BOB's adjacent positions below the current price, so trades selling asset 0 cross
+/// one initialized tick every 120 ticks.
+fn pool_with_adjacent_positions() -> ExtBuilder {
+	let mut builder = default_pool();
+	for k in 1..=5 {
+		builder = builder.with_position(BOB, 0, -600 - 120 * k, -600 - 120 * (k - 1), 0, 10 * ONE);
+	}
+	builder
+}
+
+#[test]
+fn trades_should_work_when_crossed_ticks_are_within_limit() {
+	pool_with_adjacent_positions().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			0,
+			HDX,
+			DAI,
+			140 * ONE,
+			0,
+		));
+
+		assert!(pool(0).tick < -960 && pool(0).tick >= -1080);
+	});
+}
+
+#[test]
+fn trades_should_fail_when_crossed_ticks_exceed_limit() {
+	pool_with_adjacent_positions().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, HDX, DAI, 150 * ONE, 0),
+			Error::<Test>::MaxCrossedTicksReached
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), 0, DAI, HDX, 140 * ONE, Balance::MAX),
+			Error::<Test>::MaxCrossedTicksReached
+		);
+	});
+}
+
+#[test]
+fn trades_should_refund_weight_of_ticks_which_were_not_crossed() {
+	pool_with_adjacent_positions().build().execute_with(|| {
+		let sell_info = ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), 0, HDX, DAI, 110 * ONE, 0).unwrap();
+		assert_eq!(sell_info.actual_weight, Some(<() as WeightInfo>::sell(1)));
+	});
+
+	pool_with_adjacent_positions().build().execute_with(|| {
+		let buy_info =
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), 0, DAI, HDX, 10 * ONE, Balance::MAX).unwrap();
+		assert_eq!(buy_info.actual_weight, Some(<() as WeightInfo>::buy(0)));
+	});
+}
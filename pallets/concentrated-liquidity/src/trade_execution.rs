@@ -0,0 +1,94 @@
+use crate::types::Balance;
+use crate::{Config, Error, Pallet, Pools};
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use orml_traits::MultiCurrency;
+use sp_runtime::DispatchError;
+
+impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn calculate_sell(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated(pool_id) => {
+				let (_, result, _) = Self::calculate_swap(pool_id, asset_in, asset_out, amount_in, true)
+					.map_err(ExecutorError::Error)?;
+
+				Ok(result.amount_out)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn calculate_buy(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated(pool_id) => {
+				let (_, result, _) = Self::calculate_swap(pool_id, asset_in, asset_out, amount_out, false)
+					.map_err(ExecutorError::Error)?;
+
+				Ok(result.amount_in)
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_sell(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated(pool_id) => Self::sell(who, pool_id, asset_in, asset_out, amount_in, min_limit)
+				.map(|_| ())
+				.map_err(|e| ExecutorError::Error(e.error)),
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn execute_buy(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated(pool_id) => Self::buy(who, pool_id, asset_out, asset_in, amount_out, max_limit)
+				.map(|_| ())
+				.map_err(|e| ExecutorError::Error(e.error)),
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+
+	fn get_liquidity_depth(
+		pool_type: PoolType<T::AssetId>,
+		asset_a: T::AssetId,
+		_asset_b: T::AssetId,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		match pool_type {
+			PoolType::Concentrated(pool_id) => {
+				let pool =
+					Pools::<T>::get(pool_id).ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+				if !pool.contains(&asset_a) {
+					return Err(ExecutorError::Error(Error::<T>::AssetNotInPool.into()));
+				}
+
+				Ok(T::Currency::free_balance(asset_a, &Self::pool_account(pool_id)))
+			}
+			_ => Err(ExecutorError::NotSupported),
+		}
+	}
+}
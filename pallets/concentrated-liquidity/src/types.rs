@@ -0,0 +1,100 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use hydra_dx_math::concentrated::types::{FeeGrowth, Liquidity, PoolState, SqrtPrice, Tick, TickLiquidity};
+use scale_info::TypeInfo;
+use sp_core::RuntimeDebug;
+use sp_runtime::Permill;
+
+pub type Balance = u128;
+pub type PoolId = u32;
+pub type PositionId = u128;
+
+/// Concentrated liquidity pool.
+///
+/// Assets are ordered so that `assets.0 < assets.1`. Price of the pool is amount of asset 1 per unit of asset 0.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolInfo<AssetId> {
+	pub assets: (AssetId, AssetId),
+	/// Trade fee, distributed to positions in range.
+	pub fee: Permill,
+	/// Only ticks divisible by tick spacing can be used as position bounds.
+	pub tick_spacing: u32,
+	/// Current square root of price as Q64.96.
+	pub sqrt_price: SqrtPrice,
+	/// Greatest tick whose price is lower than or equal to the current price.
+	pub tick: Tick,
+	/// Liquidity of all positions in range.
+	pub liquidity: Liquidity,
+	/// Fees of asset 0 accumulated per unit of liquidity as Q128.128.
+	pub fee_growth_global_0: FeeGrowth,
+	/// Fees of asset 1 accumulated per unit of liquidity as Q128.128.
+	pub fee_growth_global_1: FeeGrowth,
+}
+
+impl<AssetId: PartialEq> PoolInfo<AssetId> {
+	pub(crate) fn state(&self) -> PoolState {
+		PoolState {
+			sqrt_price: self.sqrt_price,
+			tick: self.tick,
+			liquidity: self.liquidity,
+		}
+	}
+
+	/// Returns true if asset 0 is traded for asset 1, false if asset 1 is traded for asset 0,
+	/// and None if given assets are not assets of the pool.
+	pub(crate) fn zero_for_one(&self, asset_in: &AssetId, asset_out: &AssetId) -> Option<bool> {
+		if *asset_in == self.assets.0 && *asset_out == self.assets.1 {
+			Some(true)
+		} else if *asset_in == self.assets.1 && *asset_out == self.assets.0 {
+			Some(false)
+		} else {
+			None
+		}
+	}
+
+	pub(crate) fn contains(&self, asset: &AssetId) -> bool {
+		*asset == self.assets.0 || *asset == self.assets.1
+	}
+}
+
+/// Initialized tick of a pool.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct InitializedTick {
+	pub tick: Tick,
+	/// Liquidity added to the pool when price crosses the tick from left to right.
+	pub liquidity_net: i128,
+}
+
+impl From<InitializedTick> for TickLiquidity {
+	fn from(value: InitializedTick) -> Self {
+		TickLiquidity::new(value.tick, value.liquidity_net)
+	}
+}
+
+/// Fee accounting of an initialized tick.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TickInfo {
+	/// Total liquidity of positions using the tick as a bound.
+	pub liquidity_gross: Liquidity,
+	/// Fee growth of asset 0 on the other side of the tick than the current price.
+	pub fee_growth_outside_0: FeeGrowth,
+	/// Fee growth of asset 1 on the other side of the tick than the current price.
+	pub fee_growth_outside_1: FeeGrowth,
+}
+
+/// Liquidity position in range `[tick_lower, tick_upper)`, represented by an NFT.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Position {
+	pub pool_id: PoolId,
+	pub tick_lower: Tick,
+	pub tick_upper: Tick,
+	pub liquidity: Liquidity,
+	/// Fee growth of asset 0 inside the range when fees were last collected.
+	pub fee_growth_inside_0_last: FeeGrowth,
+	/// Fee growth of asset 1 inside the range when fees were last collected.
+	pub fee_growth_inside_1_last: FeeGrowth,
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> sp_runtime::DispatchResult;
+}
@@ -0,0 +1,33 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the DCA pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query DCA schedules.
+	pub trait DcaApi<AccountId, ScheduleInfo> where
+		AccountId: Codec,
+		ScheduleInfo: Codec,
+	{
+		/// Get all schedules of `owner` with their remaining budget, next execution block, retry count,
+		/// estimated transaction fee and estimated amounts of a single trade.
+		fn schedules(owner: AccountId) -> Vec<ScheduleInfo>;
+	}
+}
@@ -45,6 +45,10 @@
 //! 1. Price Stability Error: If the price difference between the short oracle price and the current price
 //! exceeds the specified threshold. The user can customize this threshold,
 //! or the default value from the pallet configuration will be used.
+//! Additionally, the user can specify price stability bounds for longer oracle periods (`oracle_bounds`).
+//! In this case the last block oracle price is compared with the oracle price of each specified period,
+//! and the execution is skipped if any of the differences exceeds its bound.
+//! The period which failed the check is reported in the `ExecutionFailed` event.
 //! 2. Slippage Error: If the minimum amount out (sell) or maximum amount in (buy) slippage limits are not reached.
 //! These limits are calculated based on the last block's oracle price and the user-specified slippage.
 //! If no slippage is specified, the default value from the pallet configuration will be used.
@@ -53,6 +57,11 @@
 //! If the number of retries reaches the maximum number of retries, the schedule will be permanently terminated.
 //! In the case of a successful trade, the retry counter is reset.
 //!
+//! A schedule can specify a limit price, which is the maximum price of the bought asset denominated in the sold asset.
+//! While the last block oracle price or the price of the trade is above the limit price,
+//! the execution is skipped and planned for the next period without consuming a retry.
+//! The transaction fee is still charged for skipped executions.
+//!
 //! If a trade fails due to other types of errors, the order is terminated without any retry logic.
 //!
 //! ## Terminating a Schedule
@@ -60,6 +69,14 @@
 //! Both users and technical origin can terminate a DCA schedule. However, users can only terminate schedules that they own.
 //!
 //! Once a schedule is terminated, it is completely and permanently removed from the blockchain.
+//!
+//! ## Managing a Schedule
+//!
+//! The owner of a schedule can pause and resume the execution of the schedule.
+//! A paused schedule keeps its reserved budget but is not planned for any block until it is resumed.
+//!
+//! The owner can also top up the budget of the schedule, and update its period, slippage,
+//! stability threshold and max retries without terminating and re-creating it.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -86,7 +103,7 @@ use orml_traits::{arithmetic::CheckedAdd, MultiCurrency, NamedMultiReservableCur
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
-use sp_runtime::traits::{CheckedMul, One};
+use sp_runtime::traits::{CheckedMul, One, Zero};
 use sp_runtime::{
 	traits::{BlockNumberProvider, Saturating},
 	ArithmeticError, BoundedVec, DispatchError, FixedPointNumber, FixedU128, Permill, Rounding,
@@ -98,6 +115,7 @@ use sp_std::{cmp::min, vec};
 #[cfg(test)]
 mod tests;
 
+pub mod migration;
 pub mod types;
 pub mod weights;
 
@@ -124,7 +142,10 @@ pub mod pallet {
 	use hydradx_traits::{NativePriceOracle, PriceOracle};
 	use orml_traits::NamedMultiReservableCurrency;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -148,7 +169,7 @@ pub mod pallet {
 					continue;
 				};
 
-				let weight_for_single_execution = Self::get_trade_weight(&schedule.order);
+				let weight_for_single_execution = Self::get_schedule_weight(&schedule);
 				weight.saturating_accrue(weight_for_single_execution);
 
 				if let Err(e) = Self::prepare_schedule(
@@ -158,7 +179,7 @@ pub mod pallet {
 					&schedule,
 					&mut randomness_generator,
 				) {
-					if e != Error::<T>::PriceUnstable.into() {
+					if e != Error::<T>::PriceUnstable.into() && e != Error::<T>::LimitPriceNotReached.into() {
 						Self::terminate_schedule(schedule_id, &schedule, e);
 					};
 					continue;
@@ -176,6 +197,13 @@ pub mod pallet {
 							Self::terminate_schedule(schedule_id, &schedule, err);
 						}
 					}
+					Err(error) if error == Error::<T>::LimitPriceNotReached.into() => {
+						if let Err(skip_error) =
+							Self::skip_schedule(schedule_id, &schedule, current_blocknumber, &mut randomness_generator)
+						{
+							Self::terminate_schedule(schedule_id, &schedule, skip_error);
+						}
+					}
 					Err(error) => {
 						Self::deposit_event(Event::TradeFailed {
 							id: schedule_id,
@@ -321,6 +349,15 @@ pub mod pallet {
 			who: T::AccountId,
 			error: DispatchError,
 		},
+		///The DCA execution is skipped as the price deviation for the oracle period exceeded the bound of the schedule
+		ExecutionFailed {
+			id: ScheduleId,
+			who: T::AccountId,
+			period: OraclePeriod,
+			error: DispatchError,
+		},
+		///The DCA execution is skipped as the price is above the limit price of the schedule
+		ExecutionSkipped { id: ScheduleId, who: T::AccountId },
 		///The DCA is terminated and completely removed from the chain
 		Terminated {
 			id: ScheduleId,
@@ -329,6 +366,18 @@ pub mod pallet {
 		},
 		///The DCA is completed and completely removed from the chain
 		Completed { id: ScheduleId, who: T::AccountId },
+		///The DCA is paused and not planned for execution until resumed
+		Paused { id: ScheduleId, who: T::AccountId },
+		///The paused DCA is resumed
+		Resumed { id: ScheduleId, who: T::AccountId },
+		///The budget of the DCA is topped up
+		ToppedUp {
+			id: ScheduleId,
+			who: T::AccountId,
+			amount: Balance,
+		},
+		///The parameters of the DCA are updated
+		ScheduleUpdated { id: ScheduleId, who: T::AccountId },
 		///Randomness generation failed possibly coming from missing data about relay chain
 		RandomnessGenerationFailed {
 			block: BlockNumberFor<T>,
@@ -368,6 +417,16 @@ pub mod pallet {
 		NoParentHashFound,
 		///Error that should not really happen only in case of invalid state of the schedule storage entries
 		InvalidState,
+		///Oracle bounds contain the last block period or a period specified more than once
+		InvalidOracleBounds,
+		///The price is above the limit price of the schedule, leading to skipping the execution
+		LimitPriceNotReached,
+		///The limit price of the schedule is zero
+		InvalidLimitPrice,
+		///The DCA schedule is already paused
+		ScheduleAlreadyPaused,
+		///The DCA schedule is not paused
+		ScheduleNotPaused,
 	}
 
 	/// Id sequencer for schedules
@@ -403,6 +462,17 @@ pub mod pallet {
 	pub type ScheduleIdsPerBlock<T: Config> =
 		StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, BoundedVec<ScheduleId, T::MaxSchedulePerBlock>, ValueQuery>;
 
+	/// Keep tracking of the block where the DCA schedule is planned to be executed
+	#[pallet::storage]
+	#[pallet::getter(fn schedule_execution_block)]
+	pub type ScheduleExecutionBlock<T: Config> =
+		StorageMap<_, Blake2_128Concat, ScheduleId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Keep tracking of the paused DCA schedules
+	#[pallet::storage]
+	#[pallet::getter(fn paused_schedules)]
+	pub type PausedSchedules<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, (), OptionQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Creates a new DCA (Dollar-Cost Averaging) schedule and plans the next execution
@@ -440,6 +510,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin.clone())?;
 			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+			ensure!(
+				Self::are_oracle_bounds_valid(&schedule.oracle_bounds),
+				Error::<T>::InvalidOracleBounds
+			);
+			ensure!(
+				schedule.limit_price.map_or(true, |limit_price| !limit_price.is_zero()),
+				Error::<T>::InvalidLimitPrice
+			);
 
 			let min_budget = Self::convert_native_amount_to_currency(
 				schedule.order.get_asset_in(),
@@ -450,7 +528,7 @@ pub mod pallet {
 				Error::<T>::TotalAmountIsSmallerThanMinBudget
 			);
 
-			let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
+			let transaction_fee = Self::get_schedule_transaction_fee(&schedule)?;
 
 			let amount_in = match schedule.order {
 				Order::Sell { amount_in, .. } => amount_in,
@@ -527,18 +605,13 @@ pub mod pallet {
 		/// Parameters:
 		/// - `origin`: schedule owner
 		/// - `schedule_id`: schedule id
-		/// - `next_execution_block`: block number where the schedule is planned.
 		///
 		/// Emits `Terminated` event when successful.
 		///
 		#[pallet::call_index(1)]
 		#[pallet::weight(<T as Config>::WeightInfo::terminate())]
 		#[transactional]
-		pub fn terminate(
-			origin: OriginFor<T>,
-			schedule_id: ScheduleId,
-			next_execution_block: Option<BlockNumberFor<T>>,
-		) -> DispatchResult {
+		pub fn terminate(origin: OriginFor<T>, schedule_id: ScheduleId) -> DispatchResult {
 			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
 
 			if T::TechnicalOrigin::ensure_origin(origin.clone()).is_err() {
@@ -548,37 +621,194 @@ pub mod pallet {
 
 			Self::try_unreserve_all(schedule_id, &schedule);
 
-			let next_execution_block = Self::get_next_execution_block(next_execution_block)?;
+			if !PausedSchedules::<T>::contains_key(schedule_id) {
+				Self::remove_schedule_id_from_execution_block(schedule_id)?;
+			}
 
-			//Remove schedule id from next execution block
-			ScheduleIdsPerBlock::<T>::try_mutate_exists(
-				next_execution_block,
-				|maybe_schedule_ids| -> DispatchResult {
-					let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+			Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
 
-					let index = schedule_ids
-						.binary_search(&schedule_id)
-						.map_err(|_| Error::<T>::ScheduleNotFound)?;
+			Self::deposit_event(Event::Terminated {
+				id: schedule_id,
+				who: schedule.owner,
+				error: Error::<T>::ManuallyTerminated.into(),
+			});
 
-					schedule_ids.remove(index);
+			Ok(())
+		}
 
-					if schedule_ids.is_empty() {
-						*maybe_schedule_ids = None;
-					}
-					Ok(())
-				},
-			)?;
+		/// Pauses a DCA schedule.
+		///
+		/// The schedule is removed from its planned execution block, but its remaining budget stays reserved.
+		/// A paused schedule is not executed until it is resumed.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		///
+		/// Emits `Paused` event when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::pause())]
+		#[transactional]
+		pub fn pause(origin: OriginFor<T>, schedule_id: ScheduleId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedule = Self::ensure_schedule_owner(&who, schedule_id)?;
+			ensure!(
+				!PausedSchedules::<T>::contains_key(schedule_id),
+				Error::<T>::ScheduleAlreadyPaused
+			);
 
-			Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
+			Self::remove_schedule_id_from_execution_block(schedule_id)?;
 
-			Self::deposit_event(Event::Terminated {
+			PausedSchedules::<T>::insert(schedule_id, ());
+
+			Self::deposit_event(Event::Paused {
 				id: schedule_id,
 				who: schedule.owner,
-				error: Error::<T>::ManuallyTerminated.into(),
 			});
 
 			Ok(())
 		}
+
+		/// Resumes a paused DCA schedule and plans the next execution for the specified block.
+		///
+		/// If the block is not specified, the execution is planned for the next block.
+		/// If the given block is full, the execution will be planned in the subsequent block.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `start_execution_block`: block number where the schedule should be executed next.
+		///
+		/// Emits `Resumed` and `ExecutionPlanned` events when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::resume())]
+		#[transactional]
+		pub fn resume(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			start_execution_block: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedule = Self::ensure_schedule_owner(&who, schedule_id)?;
+			ensure!(
+				PausedSchedules::<T>::take(schedule_id).is_some(),
+				Error::<T>::ScheduleNotPaused
+			);
+
+			let next_execution_block = Self::get_next_execution_block(start_execution_block)?;
+			let mut randomness_generator =
+				Self::get_randomness_generator(frame_system::Pallet::<T>::current_block_number(), Some(schedule_id));
+
+			Self::deposit_event(Event::Resumed {
+				id: schedule_id,
+				who: schedule.owner.clone(),
+			});
+
+			Self::plan_schedule_for_block(
+				&schedule.owner,
+				next_execution_block,
+				schedule_id,
+				&mut randomness_generator,
+			)?;
+
+			Ok(())
+		}
+
+		/// Tops up the budget of a DCA schedule.
+		///
+		/// The `amount` is reserved in the sold (`amount_in`) currency of the order
+		/// and added to both the remaining amount and the total amount of the schedule.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `amount`: amount to add to the budget
+		///
+		/// Emits `ToppedUp` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::top_up())]
+		#[transactional]
+		pub fn top_up(origin: OriginFor<T>, schedule_id: ScheduleId, amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut schedule = Self::ensure_schedule_owner(&who, schedule_id)?;
+
+			schedule.total_amount = schedule
+				.total_amount
+				.checked_add(amount)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			RemainingAmounts::<T>::try_mutate(schedule_id, |maybe_remaining_amount| -> DispatchResult {
+				let remaining_amount = maybe_remaining_amount
+					.as_mut()
+					.defensive_ok_or(Error::<T>::InvalidState)?;
+				*remaining_amount = remaining_amount.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
+				Ok(())
+			})?;
+
+			T::Currencies::reserve_named(&T::NamedReserveId::get(), schedule.order.get_asset_in(), &who, amount)?;
+
+			Schedules::<T>::insert(schedule_id, schedule);
+
+			Self::deposit_event(Event::ToppedUp {
+				id: schedule_id,
+				who,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Updates the parameters of a DCA schedule.
+		///
+		/// Only the specified parameters are updated.
+		/// The optional parameters of the schedule can be reset to the pallet defaults by specifying `Some(None)`.
+		/// The new period is applied when the schedule is planned after its next execution.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `period`: new period between two executions
+		/// - `slippage`: new slippage limit
+		/// - `stability_threshold`: new price stability threshold
+		/// - `max_retries`: new max number of retries
+		///
+		/// Emits `ScheduleUpdated` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_schedule())]
+		pub fn update_schedule(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			period: Option<BlockNumberFor<T>>,
+			slippage: Option<Option<Permill>>,
+			stability_threshold: Option<Option<Permill>>,
+			max_retries: Option<Option<u8>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut schedule = Self::ensure_schedule_owner(&who, schedule_id)?;
+
+			if let Some(period) = period {
+				schedule.period = period;
+			}
+			if let Some(slippage) = slippage {
+				schedule.slippage = slippage;
+			}
+			if let Some(stability_threshold) = stability_threshold {
+				schedule.stability_threshold = stability_threshold;
+			}
+			if let Some(max_retries) = max_retries {
+				schedule.max_retries = max_retries;
+			}
+
+			Schedules::<T>::insert(schedule_id, schedule);
+
+			Self::deposit_event(Event::ScheduleUpdated { id: schedule_id, who });
+
+			Ok(())
+		}
 	}
 }
 
@@ -623,6 +853,12 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		Self::take_transaction_fee_from_user(schedule_id, schedule, weight_for_dca_execution)?;
 
+		if Self::is_limit_price_not_reached(schedule) {
+			Self::skip_schedule(schedule_id, schedule, current_blocknumber, randomness_generator)?;
+
+			return Err(Error::<T>::LimitPriceNotReached.into());
+		}
+
 		if Self::is_price_unstable(schedule) {
 			Self::deposit_event(Event::TradeFailed {
 				id: schedule_id,
@@ -634,6 +870,18 @@ impl<T: Config> Pallet<T> {
 			return Err(Error::<T>::PriceUnstable.into());
 		}
 
+		if let Some(period) = Self::find_exceeded_oracle_bound(schedule) {
+			Self::deposit_event(Event::ExecutionFailed {
+				id: schedule_id,
+				who: schedule.owner.clone(),
+				period,
+				error: Error::<T>::PriceUnstable.into(),
+			});
+			Self::retry_schedule(schedule_id, schedule, current_blocknumber, randomness_generator)?;
+
+			return Err(Error::<T>::PriceUnstable.into());
+		}
+
 		Ok(())
 	}
 
@@ -670,6 +918,13 @@ impl<T: Config> Pallet<T> {
 				let last_trade = trade_amounts.last().defensive_ok_or(Error::<T>::InvalidState)?;
 				let amount_out = last_trade.amount_out;
 
+				if let Some(limit_price) = schedule.limit_price {
+					let max_amount_in = limit_price
+						.checked_mul_int(amount_out)
+						.ok_or(ArithmeticError::Overflow)?;
+					ensure!(amount_to_sell <= max_amount_in, Error::<T>::LimitPriceNotReached);
+				}
+
 				if *min_amount_out > last_block_slippage_min_limit {
 					ensure!(amount_out >= *min_amount_out, Error::<T>::TradeLimitReached);
 				} else {
@@ -711,6 +966,13 @@ impl<T: Config> Pallet<T> {
 					.checked_add(slippage_amount)
 					.ok_or(ArithmeticError::Overflow)?;
 
+				if let Some(limit_price) = schedule.limit_price {
+					let limit_amount_in = limit_price
+						.checked_mul_int(*amount_out)
+						.ok_or(ArithmeticError::Overflow)?;
+					ensure!(amount_in <= limit_amount_in, Error::<T>::LimitPriceNotReached);
+				}
+
 				if *max_amount_in < last_block_slippage_max_limit {
 					ensure!(amount_in <= *max_amount_in, Error::<T>::TradeLimitReached);
 				} else {
@@ -748,7 +1010,7 @@ impl<T: Config> Pallet<T> {
 
 		let remaining_amount: Balance =
 			RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
-		let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
+		let transaction_fee = Self::get_schedule_transaction_fee(schedule)?;
 		let min_amount_for_replanning = transaction_fee.saturating_mul(FEE_MULTIPLIER_FOR_MIN_TRADE_LIMIT);
 		if remaining_amount < min_amount_for_replanning || remaining_amount < T::MinimumTradingLimit::get() {
 			Self::complete_schedule(schedule_id, schedule);
@@ -810,14 +1072,46 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn skip_schedule(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		current_blocknumber: BlockNumberFor<T>,
+		randomness_generator: &mut StdRng,
+	) -> DispatchResult {
+		Self::deposit_event(Event::ExecutionSkipped {
+			id: schedule_id,
+			who: schedule.owner.clone(),
+		});
+
+		let next_execution_block = current_blocknumber
+			.checked_add(&schedule.period)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		Self::plan_schedule_for_block(&schedule.owner, next_execution_block, schedule_id, randomness_generator)
+	}
+
+	fn is_limit_price_not_reached(schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) -> bool {
+		let Some(limit_price) = schedule.limit_price else {
+			return false;
+		};
+
+		let route = &schedule.order.get_route_or_default::<T::RouteProvider>();
+
+		let Ok(last_block_price) = Self::get_price_from_oracle(route, OraclePeriod::LastBlock) else {
+			return true;
+		};
+
+		last_block_price > limit_price
+	}
+
 	fn is_price_unstable(schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) -> bool {
 		let route = &schedule.order.get_route_or_default::<T::RouteProvider>();
 
-		let Ok(last_block_price) = Self::get_price_from_last_block_oracle(route) else {
+		let Ok(last_block_price) = Self::get_price_from_oracle(route, OraclePeriod::LastBlock) else {
 			return true;
 		};
 
-		let Ok(price_from_short_oracle) = Self::get_price_from_short_oracle(route) else {
+		let Ok(price_from_short_oracle) = Self::get_price_from_oracle(route, OraclePeriod::Short) else {
 			return true;
 		};
 
@@ -825,9 +1119,35 @@ impl<T: Config> Pallet<T> {
 			.stability_threshold
 			.unwrap_or_else(T::MaxPriceDifferenceBetweenBlocks::get);
 
+		Self::is_price_difference_exceeded(last_block_price, price_from_short_oracle, max_allowed_diff)
+	}
+
+	/// Returns the first oracle period whose price deviates from the last block price more than allowed by the schedule.
+	/// Missing oracle data is treated as an exceeded bound.
+	fn find_exceeded_oracle_bound(
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+	) -> Option<OraclePeriod> {
+		let (first_period, _) = schedule.oracle_bounds.first()?;
+
+		let route = &schedule.order.get_route_or_default::<T::RouteProvider>();
+
+		let Ok(last_block_price) = Self::get_price_from_oracle(route, OraclePeriod::LastBlock) else {
+			return Some(*first_period);
+		};
+
+		schedule.oracle_bounds.iter().find_map(|(period, max_allowed_diff)| {
+			let Ok(price_for_period) = Self::get_price_from_oracle(route, *period) else {
+				return Some(*period);
+			};
+
+			Self::is_price_difference_exceeded(last_block_price, price_for_period, *max_allowed_diff).then_some(*period)
+		})
+	}
+
+	fn is_price_difference_exceeded(price_a: FixedU128, price_b: FixedU128, max_allowed_diff: Permill) -> bool {
 		let max_allowed = FixedU128::from(max_allowed_diff);
 
-		let Some(price_sum) = last_block_price.checked_add(&price_from_short_oracle) else {
+		let Some(price_sum) = price_a.checked_add(&price_b) else {
 			return true;
 		};
 
@@ -835,10 +1155,10 @@ impl<T: Config> Pallet<T> {
 			return true;
 		};
 
-		let diff = if last_block_price > price_from_short_oracle {
-			last_block_price.saturating_sub(price_from_short_oracle)
+		let diff = if price_a > price_b {
+			price_a.saturating_sub(price_b)
 		} else {
-			price_from_short_oracle.saturating_sub(last_block_price)
+			price_b.saturating_sub(price_a)
 		};
 
 		let Some(diff) = diff.checked_mul(&FixedU128::from(2)) else {
@@ -848,6 +1168,12 @@ impl<T: Config> Pallet<T> {
 		diff > max_allowed_difference
 	}
 
+	fn are_oracle_bounds_valid(oracle_bounds: &OracleBounds) -> bool {
+		oracle_bounds.iter().enumerate().all(|(i, (period, _))| {
+			*period != OraclePeriod::LastBlock && !oracle_bounds[..i].iter().any(|(other, _)| other == period)
+		})
+	}
+
 	fn get_amount_in_for_buy(amount_out: &Balance, route: &[Trade<T::AssetId>]) -> Result<Balance, DispatchError> {
 		let trade_amounts = T::RouteExecutor::calculate_buy_trade_amounts(route, *amount_out)?;
 
@@ -860,6 +1186,13 @@ impl<T: Config> Pallet<T> {
 		Self::convert_weight_to_fee(Self::get_trade_weight(order), order.get_asset_in())
 	}
 
+	/// Fee of a single execution of the schedule, including the oracle bounds checks.
+	pub fn get_schedule_transaction_fee(
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+	) -> Result<Balance, DispatchError> {
+		Self::convert_weight_to_fee(Self::get_schedule_weight(schedule), schedule.order.get_asset_in())
+	}
+
 	fn unallocate_amount(
 		schedule_id: ScheduleId,
 		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
@@ -980,6 +1313,7 @@ impl<T: Config> Pallet<T> {
 				.map_err(|_| Error::<T>::InvalidState)?;
 			Ok(())
 		})?;
+		ScheduleExecutionBlock::<T>::insert(schedule_id, next_free_block);
 
 		Self::deposit_event(Event::ExecutionPlanned {
 			id: schedule_id,
@@ -1016,7 +1350,7 @@ impl<T: Config> Pallet<T> {
 		amount: Balance,
 		slippage: Option<Permill>,
 	) -> Result<(Balance, Balance), DispatchError> {
-		let price = Self::get_price_from_last_block_oracle(route)?;
+		let price = Self::get_price_from_oracle(route, OraclePeriod::LastBlock)?;
 
 		let estimated_amount = price.checked_mul_int(amount).ok_or(ArithmeticError::Overflow)?;
 
@@ -1044,6 +1378,21 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	// returns weight of a single execution of the schedule
+	fn get_schedule_weight(schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) -> Weight {
+		Self::get_trade_weight(&schedule.order).saturating_add(Self::get_oracle_bounds_weight(schedule))
+	}
+
+	// every oracle bound requires reading the oracle entry of each hop of the route
+	fn get_oracle_bounds_weight(schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>) -> Weight {
+		if schedule.oracle_bounds.is_empty() {
+			return Weight::zero();
+		}
+		let route_length = schedule.order.get_route_or_default::<T::RouteProvider>().len() as u64;
+		let number_of_bounds = schedule.oracle_bounds.len() as u64;
+		T::DbWeight::get().reads(number_of_bounds.saturating_mul(route_length))
+	}
+
 	fn convert_native_amount_to_currency(
 		asset_id: T::AssetId,
 		asset_amount: Balance,
@@ -1060,19 +1409,8 @@ impl<T: Config> Pallet<T> {
 		Ok(amount)
 	}
 
-	fn get_price_from_last_block_oracle(route: &[Trade<T::AssetId>]) -> Result<FixedU128, DispatchError> {
-		let price =
-			T::OraclePriceProvider::price(route, OraclePeriod::LastBlock).ok_or(Error::<T>::CalculatingPriceError)?;
-
-		let price_from_rational =
-			FixedU128::checked_from_rational(price.n, price.d).ok_or(ArithmeticError::Overflow)?;
-
-		Ok(price_from_rational)
-	}
-
-	fn get_price_from_short_oracle(route: &[Trade<T::AssetId>]) -> Result<FixedU128, DispatchError> {
-		let price =
-			T::OraclePriceProvider::price(route, OraclePeriod::Short).ok_or(Error::<T>::CalculatingPriceError)?;
+	fn get_price_from_oracle(route: &[Trade<T::AssetId>], period: OraclePeriod) -> Result<FixedU128, DispatchError> {
+		let price = T::OraclePriceProvider::price(route, period).ok_or(Error::<T>::CalculatingPriceError)?;
 
 		let price_from_rational =
 			FixedU128::checked_from_rational(price.n, price.d).ok_or(ArithmeticError::Overflow)?;
@@ -1085,6 +1423,92 @@ impl<T: Config> Pallet<T> {
 		ScheduleOwnership::<T>::remove(owner, schedule_id);
 		RemainingAmounts::<T>::remove(schedule_id);
 		RetriesOnError::<T>::remove(schedule_id);
+		PausedSchedules::<T>::remove(schedule_id);
+		ScheduleExecutionBlock::<T>::remove(schedule_id);
+	}
+
+	fn ensure_schedule_owner(
+		who: &T::AccountId,
+		schedule_id: ScheduleId,
+	) -> Result<Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>, DispatchError> {
+		let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+		ensure!(*who == schedule.owner, Error::<T>::Forbidden);
+
+		Ok(schedule)
+	}
+
+	fn remove_schedule_id_from_execution_block(schedule_id: ScheduleId) -> DispatchResult {
+		let blocknumber = ScheduleExecutionBlock::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+
+		ScheduleIdsPerBlock::<T>::try_mutate_exists(blocknumber, |maybe_schedule_ids| -> DispatchResult {
+			let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+
+			let index = schedule_ids
+				.iter()
+				.position(|id| *id == schedule_id)
+				.ok_or(Error::<T>::ScheduleNotFound)?;
+
+			schedule_ids.remove(index);
+
+			if schedule_ids.is_empty() {
+				*maybe_schedule_ids = None;
+			}
+			Ok(())
+		})?;
+		ScheduleExecutionBlock::<T>::remove(schedule_id);
+
+		Ok(())
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns all schedules of `owner` with their remaining budget, next execution block, retry count,
+	/// estimated transaction fee and estimated amounts of a single trade.
+	pub fn schedules_of(owner: T::AccountId) -> Vec<ScheduleInfo<T::AccountId, T::AssetId, BlockNumberFor<T>>> {
+		ScheduleOwnership::<T>::iter_key_prefix(&owner)
+			.filter_map(|id| {
+				let schedule = Schedules::<T>::get(id)?;
+				let remaining_amount = RemainingAmounts::<T>::get(id).unwrap_or_default();
+				let (estimated_amount_in, estimated_amount_out) =
+					match Self::estimate_trade_amounts(&schedule.order, remaining_amount) {
+						Ok((amount_in, amount_out)) => (Some(amount_in), Some(amount_out)),
+						Err(_) => (None, None),
+					};
+
+				Some(ScheduleInfo {
+					id,
+					remaining_amount,
+					next_execution_block: ScheduleExecutionBlock::<T>::get(id),
+					retries: RetriesOnError::<T>::get(id),
+					paused: PausedSchedules::<T>::contains_key(id),
+					transaction_fee: Self::get_schedule_transaction_fee(&schedule).ok(),
+					estimated_amount_in,
+					estimated_amount_out,
+					schedule,
+				})
+			})
+			.collect()
+	}
+
+	fn estimate_trade_amounts(
+		order: &Order<T::AssetId>,
+		remaining_amount: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let route = order.get_route_or_default::<T::RouteProvider>();
+		match order {
+			Order::Sell { amount_in, .. } => {
+				let amount_to_sell = min(remaining_amount, *amount_in);
+				let trade_amounts = T::RouteExecutor::calculate_sell_trade_amounts(&route, amount_to_sell)?;
+				let last_trade = trade_amounts.last().ok_or(Error::<T>::InvalidState)?;
+
+				Ok((amount_to_sell, last_trade.amount_out))
+			}
+			Order::Buy { amount_out, .. } => {
+				let amount_in = Self::get_amount_in_for_buy(amount_out, &route)?;
+
+				Ok((amount_in, *amount_out))
+			}
+		}
 	}
 }
 
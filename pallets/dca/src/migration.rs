@@ -0,0 +1,407 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+	storage_alias,
+	traits::{Get, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+
+/// The log target.
+const TARGET: &str = "runtime::dca::migration";
+
+pub mod v0 {
+	use super::*;
+	use codec::{Decode, Encode};
+
+	/// Schedule without oracle bounds.
+	#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+	pub struct Schedule<AccountId, AssetId, BlockNumber> {
+		pub owner: AccountId,
+		pub period: BlockNumber,
+		pub total_amount: Balance,
+		pub max_retries: Option<u8>,
+		pub stability_threshold: Option<Permill>,
+		pub slippage: Option<Permill>,
+		pub order: Order<AssetId>,
+	}
+
+	#[storage_alias]
+	pub type Schedules<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		ScheduleId,
+		Schedule<<T as frame_system::Config>::AccountId, <T as Config>::AssetId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+}
+
+pub mod v1 {
+	use super::*;
+	use codec::{Decode, Encode};
+	use sp_runtime::Saturating;
+
+	/// Schedule with oracle bounds but without limit price.
+	#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+	pub struct Schedule<AccountId, AssetId, BlockNumber> {
+		pub owner: AccountId,
+		pub period: BlockNumber,
+		pub total_amount: Balance,
+		pub max_retries: Option<u8>,
+		pub stability_threshold: Option<Permill>,
+		pub oracle_bounds: OracleBounds,
+		pub slippage: Option<Permill>,
+		pub order: Order<AssetId>,
+	}
+
+	#[storage_alias]
+	pub type Schedules<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		ScheduleId,
+		Schedule<<T as frame_system::Config>::AccountId, <T as Config>::AssetId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Migrates schedules to schedules with empty oracle bounds.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "Storage version too high.");
+
+			let ids = v0::Schedules::<T>::iter_keys().collect::<Vec<ScheduleId>>();
+
+			log::info!(target: TARGET, "DCA migration v1: PRE checks successful!");
+
+			Ok(ids.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 0 {
+				log::info!(target: TARGET, "DCA migration to v1 skipped");
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v1 for DCA");
+
+			let mut translated = 0u64;
+			Schedules::<T>::translate::<v0::Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>, _>(
+				|_id, schedule| {
+					translated.saturating_inc();
+					Some(Schedule {
+						owner: schedule.owner,
+						period: schedule.period,
+						total_amount: schedule.total_amount,
+						max_retries: schedule.max_retries,
+						stability_threshold: schedule.stability_threshold,
+						oracle_bounds: OracleBounds::default(),
+						slippage: schedule.slippage,
+						order: schedule.order,
+					})
+				},
+			);
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+			let previous_ids = <Vec<ScheduleId> as codec::Decode>::decode(&mut state.as_slice()).unwrap();
+
+			for id in previous_ids.into_iter() {
+				let schedule = Schedules::<T>::get(id).expect("Migrated schedule should exist");
+				assert!(
+					schedule.oracle_bounds.is_empty(),
+					"Migrated schedule should not have oracle bounds!"
+				);
+			}
+
+			log::info!(target: TARGET, "DCA migration v1: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+	use sp_runtime::Saturating;
+
+	/// Migrates schedules to schedules without limit price.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Storage version too high.");
+
+			let ids = v1::Schedules::<T>::iter_keys().collect::<Vec<ScheduleId>>();
+
+			log::info!(target: TARGET, "DCA migration v2: PRE checks successful!");
+
+			Ok(ids.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 1 {
+				log::info!(target: TARGET, "DCA migration to v2 skipped");
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v2 for DCA");
+
+			let mut translated = 0u64;
+			crate::Schedules::<T>::translate::<v1::Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>, _>(
+				|_id, schedule| {
+					translated.saturating_inc();
+					Some(Schedule {
+						owner: schedule.owner,
+						period: schedule.period,
+						total_amount: schedule.total_amount,
+						max_retries: schedule.max_retries,
+						stability_threshold: schedule.stability_threshold,
+						oracle_bounds: schedule.oracle_bounds,
+						slippage: schedule.slippage,
+						limit_price: None,
+						order: schedule.order,
+					})
+				},
+			);
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2, "Unexpected storage version.");
+
+			let previous_ids = <Vec<ScheduleId> as codec::Decode>::decode(&mut state.as_slice()).unwrap();
+
+			for id in previous_ids.into_iter() {
+				let schedule = crate::Schedules::<T>::get(id).expect("Migrated schedule should exist");
+				assert!(
+					schedule.limit_price.is_none(),
+					"Migrated schedule should not have limit price!"
+				);
+			}
+
+			log::info!(target: TARGET, "DCA migration v2: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use super::*;
+	use sp_runtime::Saturating;
+
+	/// Indexes the planned execution block of every schedule.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2, "Storage version too high.");
+
+			let planned = ScheduleIdsPerBlock::<T>::iter()
+				.flat_map(|(block, ids)| ids.into_iter().map(move |id| (id, block)))
+				.collect::<Vec<(ScheduleId, BlockNumberFor<T>)>>();
+
+			log::info!(target: TARGET, "DCA migration v3: PRE checks successful!");
+
+			Ok(planned.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 2 {
+				log::info!(target: TARGET, "DCA migration to v3 skipped");
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v3 for DCA");
+
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+			for (block, ids) in ScheduleIdsPerBlock::<T>::iter() {
+				reads.saturating_inc();
+				for id in ids {
+					ScheduleExecutionBlock::<T>::insert(id, block);
+					writes.saturating_inc();
+				}
+			}
+
+			StorageVersion::new(3).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(reads.saturating_add(1), writes.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 3, "Unexpected storage version.");
+
+			let planned =
+				<Vec<(ScheduleId, BlockNumberFor<T>)> as codec::Decode>::decode(&mut state.as_slice()).unwrap();
+
+			for (id, block) in planned.into_iter() {
+				assert_eq!(
+					ScheduleExecutionBlock::<T>::get(id),
+					Some(block),
+					"Execution block of the schedule should be indexed!"
+				);
+			}
+
+			log::info!(target: TARGET, "DCA migration v3: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::create_bounded_vec;
+	use crate::tests::mock::*;
+	use hydradx_traits::router::{PoolType, Trade};
+
+	#[test]
+	fn migration_should_add_empty_oracle_bounds_to_schedules() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<Test>>();
+
+			let order = Order::Sell {
+				asset_in: HDX,
+				asset_out: BTC,
+				amount_in: ONE,
+				min_amount_out: Balance::MIN,
+				route: create_bounded_vec(vec![Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: BTC,
+				}]),
+			};
+			v0::Schedules::<Test>::insert(
+				0,
+				v0::Schedule {
+					owner: ALICE,
+					period: 100,
+					total_amount: 1000 * ONE,
+					max_retries: Some(5),
+					stability_threshold: Some(Permill::from_percent(5)),
+					slippage: None,
+					order: order.clone(),
+				},
+			);
+
+			v1::Migration::<Test>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::get::<Pallet<Test>>(), 1);
+			assert_eq!(
+				v1::Schedules::<Test>::get(0),
+				Some(v1::Schedule {
+					owner: ALICE,
+					period: 100,
+					total_amount: 1000 * ONE,
+					max_retries: Some(5),
+					stability_threshold: Some(Permill::from_percent(5)),
+					oracle_bounds: OracleBounds::default(),
+					slippage: None,
+					order,
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn migration_should_add_no_limit_price_to_schedules() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<Test>>();
+
+			let order = Order::Buy {
+				asset_in: HDX,
+				asset_out: BTC,
+				amount_out: ONE,
+				max_amount_in: 2 * ONE,
+				route: create_bounded_vec(vec![Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: BTC,
+				}]),
+			};
+			let oracle_bounds = OracleBounds::truncate_from(vec![(OraclePeriod::Short, Permill::from_percent(5))]);
+			v1::Schedules::<Test>::insert(
+				0,
+				v1::Schedule {
+					owner: ALICE,
+					period: 100,
+					total_amount: 1000 * ONE,
+					max_retries: None,
+					stability_threshold: None,
+					oracle_bounds: oracle_bounds.clone(),
+					slippage: Some(Permill::from_percent(5)),
+					order: order.clone(),
+				},
+			);
+
+			v2::Migration::<Test>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::get::<Pallet<Test>>(), 2);
+			assert_eq!(
+				crate::Schedules::<Test>::get(0),
+				Some(Schedule {
+					owner: ALICE,
+					period: 100,
+					total_amount: 1000 * ONE,
+					max_retries: None,
+					stability_threshold: None,
+					oracle_bounds,
+					slippage: Some(Permill::from_percent(5)),
+					limit_price: None,
+					order,
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn migration_should_index_execution_blocks_of_planned_schedules() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(2).put::<Pallet<Test>>();
+
+			ScheduleIdsPerBlock::<Test>::insert(600, BoundedVec::truncate_from(vec![0, 2]));
+			ScheduleIdsPerBlock::<Test>::insert(700, BoundedVec::truncate_from(vec![1]));
+
+			v3::Migration::<Test>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::get::<Pallet<Test>>(), 3);
+			assert_eq!(ScheduleExecutionBlock::<Test>::get(0), Some(600));
+			assert_eq!(ScheduleExecutionBlock::<Test>::get(1), Some(700));
+			assert_eq!(ScheduleExecutionBlock::<Test>::get(2), Some(600));
+			assert_eq!(ScheduleExecutionBlock::<Test>::get(3), None);
+		});
+	}
+}
@@ -33,7 +33,7 @@ use orml_traits::{parameter_type_with_key, GetByKey};
 use pallet_currencies::BasicCurrencyAdapter;
 use primitive_types::U128;
 use sp_core::H256;
-use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, ConstU32};
+use sp_runtime::traits::{AccountIdConversion, BlockNumberProvider, ConstU128, ConstU32, ConstU64};
 use sp_runtime::Perbill;
 use sp_runtime::Permill;
 use sp_runtime::{
@@ -151,6 +151,7 @@ impl pallet_ema_oracle::Config for Test {
 	type SupportedPeriods = SupportedPeriods;
 	type OracleWhitelist = Everything;
 	type MaxUniqueEntries = ConstU32<20>;
+	type MaxTwapCheckpoints = ConstU32<100>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type WeightInfo = ();
@@ -262,6 +263,10 @@ impl pallet_omnipool::Config for Test {
 	type MinWithdrawalFee = ();
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type ListingPriceOracle = ();
+	type ListingBond = ConstU128<0>;
+	type ListingChallengePeriod = ConstU64<0>;
+	type ListingWeightCap = AssetWeightCap;
 }
 
 pub struct WithdrawFeePriceOracle;
@@ -608,6 +613,9 @@ impl PriceOracle<AssetId> for PriceProviderMock {
 		if period == OraclePeriod::Short {
 			return Some(Ratio::new(80, 100));
 		}
+		if period == OraclePeriod::TenMinutes {
+			return Some(Ratio::new(70, 100));
+		}
 		Some(Ratio::new(88, 100))
 	}
 }
@@ -974,6 +982,8 @@ pub fn expect_dca_events(e: Vec<RuntimeEvent>) {
 				| RuntimeEvent::DCA(crate::Event::<Test>::ExecutionPlanned { .. })
 				| RuntimeEvent::DCA(crate::Event::<Test>::TradeExecuted { .. })
 				| RuntimeEvent::DCA(crate::Event::<Test>::TradeFailed { .. })
+				| RuntimeEvent::DCA(crate::Event::<Test>::ExecutionFailed { .. })
+				| RuntimeEvent::DCA(crate::Event::<Test>::ExecutionSkipped { .. })
 				| RuntimeEvent::DCA(crate::Event::<Test>::Terminated { .. })
 				| RuntimeEvent::DCA(crate::Event::<Test>::Completed { .. })
 		) {
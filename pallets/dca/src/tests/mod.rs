@@ -1,14 +1,18 @@
 use crate::tests::mock::*;
-use crate::{Balance, Order, Schedule, ScheduleId};
+use crate::{Balance, OracleBounds, Order, Schedule, ScheduleId};
 use hydradx_traits::router::PoolType;
 use hydradx_traits::router::Trade;
 use sp_runtime::traits::ConstU32;
-use sp_runtime::{BoundedVec, Permill};
+use sp_runtime::{BoundedVec, FixedU128, Permill};
 
 pub mod mock;
 pub mod on_initialize;
+pub mod pause;
 pub mod schedule;
+pub mod schedules_of;
 pub mod terminate;
+pub mod top_up;
+pub mod update_schedule;
 
 #[macro_export]
 macro_rules! assert_balance {
@@ -25,6 +29,8 @@ struct ScheduleBuilder {
 	pub max_retries: Option<Option<u8>>,
 	pub slippage: Option<Option<Permill>>,
 	pub stability_threshold: Option<Option<Permill>>,
+	pub oracle_bounds: Option<OracleBounds>,
+	pub limit_price: Option<Option<FixedU128>>,
 }
 
 impl ScheduleBuilder {
@@ -33,6 +39,8 @@ impl ScheduleBuilder {
 			owner: Some(ALICE),
 			period: Some(ONE_HUNDRED_BLOCKS),
 			stability_threshold: Some(None),
+			oracle_bounds: Some(OracleBounds::default()),
+			limit_price: Some(None),
 			slippage: Some(None),
 			total_amount: Some(1000 * ONE),
 			max_retries: Some(None),
@@ -75,6 +83,16 @@ impl ScheduleBuilder {
 		self
 	}
 
+	fn with_oracle_bounds(mut self, oracle_bounds: OracleBounds) -> ScheduleBuilder {
+		self.oracle_bounds = Some(oracle_bounds);
+		self
+	}
+
+	fn with_limit_price(mut self, limit_price: Option<FixedU128>) -> ScheduleBuilder {
+		self.limit_price = Some(limit_price);
+		self
+	}
+
 	fn with_slippage(mut self, slippage: Option<Permill>) -> ScheduleBuilder {
 		self.slippage = Some(slippage);
 		self
@@ -90,7 +108,9 @@ impl ScheduleBuilder {
 			owner: self.owner.unwrap(),
 			period: self.period.unwrap(),
 			stability_threshold: self.stability_threshold.unwrap(),
+			oracle_bounds: self.oracle_bounds.unwrap(),
 			slippage: self.slippage.unwrap(),
+			limit_price: self.limit_price.unwrap(),
 			total_amount: self.total_amount.unwrap(),
 			max_retries: self.max_retries.unwrap(),
 			order: self.order.unwrap(),
@@ -124,5 +144,6 @@ macro_rules! assert_that_schedule_has_been_removed_from_storages {
 		assert!(DCA::owner_of($owner, $schedule_id).is_none());
 		assert!(DCA::remaining_amounts($schedule_id).is_none());
 		assert_eq!(DCA::retries_on_error($schedule_id), 0);
+		assert!(DCA::schedule_execution_block($schedule_id).is_none());
 	};
 }
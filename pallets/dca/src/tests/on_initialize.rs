@@ -19,17 +19,18 @@ use crate::tests::*;
 use crate::{
 	assert_balance, assert_executed_buy_trades, assert_executed_sell_trades, assert_number_of_executed_buy_trades,
 	assert_number_of_executed_sell_trades, assert_scheduled_ids, assert_that_schedule_has_been_removed_from_storages,
-	Error, Event as DcaEvent, Order, Permill, ScheduleId,
+	Error, Event as DcaEvent, OracleBounds, Order, Permill, ScheduleId,
 };
 use frame_support::assert_ok;
 use frame_support::traits::OnInitialize;
 use hydradx_traits::router::PoolType;
 use hydradx_traits::router::PoolType::Omnipool;
+use hydradx_traits::OraclePeriod;
 use orml_traits::MultiCurrency;
 use orml_traits::MultiReservableCurrency;
 use orml_traits::NamedMultiReservableCurrency;
 use pretty_assertions::assert_eq;
-use sp_runtime::DispatchError;
+use sp_runtime::{DispatchError, FixedU128};
 use std::borrow::Borrow;
 use std::ops::RangeInclusive;
 
@@ -1908,6 +1909,281 @@ fn one_sell_dca_execution_should_be_rescheduled_when_price_diff_is_more_than_use
 		});
 }
 
+#[test]
+fn one_sell_dca_execution_should_be_rescheduled_when_price_diff_for_oracle_period_is_more_than_bound() {
+	let initial_alice_hdx_balance = 10000 * ONE;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, initial_alice_hdx_balance)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let amount_to_sell = ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_oracle_bounds(OracleBounds::truncate_from(vec![
+					(OraclePeriod::Short, Permill::from_percent(20)),
+					(OraclePeriod::TenMinutes, Permill::from_percent(10)),
+				]))
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(501)));
+			assert_eq!(total_amount, Currencies::reserved_balance(HDX, &ALICE));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_executed_sell_trades!(vec![]);
+			assert_eq!(
+				total_amount - SELL_DCA_FEE_IN_NATIVE,
+				Currencies::reserved_balance(HDX, &ALICE)
+			);
+
+			let schedule_id = 0;
+			assert_scheduled_ids!(511, vec![schedule_id]);
+			assert_eq!(DCA::retries_on_error(schedule_id), 1);
+			expect_dca_events(vec![
+				DcaEvent::ExecutionFailed {
+					id: schedule_id,
+					who: ALICE,
+					period: OraclePeriod::TenMinutes,
+					error: Error::<Test>::PriceUnstable.into(),
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 511,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn one_sell_dca_execution_should_be_executed_when_price_diff_for_oracle_periods_is_within_bounds() {
+	let initial_alice_hdx_balance = 10000 * ONE;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, initial_alice_hdx_balance)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let amount_to_sell = ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_oracle_bounds(OracleBounds::truncate_from(vec![
+					(OraclePeriod::Short, Permill::from_percent(20)),
+					(OraclePeriod::TenMinutes, Permill::from_percent(30)),
+				]))
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(501)));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(1);
+			assert_eq!(DCA::retries_on_error(0), 0);
+		});
+}
+
+#[test]
+fn one_sell_dca_execution_should_be_skipped_when_oracle_price_is_above_limit_price() {
+	let initial_alice_hdx_balance = 10000 * ONE;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, initial_alice_hdx_balance)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let amount_to_sell = ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_limit_price(Some(FixedU128::from_rational(80, 100)))
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(501)));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_executed_sell_trades!(vec![]);
+			assert_eq!(
+				total_amount - SELL_DCA_FEE_IN_NATIVE,
+				Currencies::reserved_balance(HDX, &ALICE)
+			);
+
+			let schedule_id = 0;
+			assert_scheduled_ids!(601, vec![schedule_id]);
+			assert_eq!(DCA::retries_on_error(schedule_id), 0);
+			expect_dca_events(vec![
+				DcaEvent::ExecutionSkipped {
+					id: schedule_id,
+					who: ALICE,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 601,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn one_sell_dca_execution_should_be_skipped_when_trade_price_is_above_limit_price() {
+	let initial_alice_hdx_balance = 10000 * ONE;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, initial_alice_hdx_balance)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+			set_sell_amount_out(ONE / 2);
+
+			let total_amount = 5 * ONE;
+			let amount_to_sell = ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_limit_price(Some(FixedU128::from(1)))
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(501)));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_executed_sell_trades!(vec![]);
+			assert_eq!(
+				total_amount - SELL_DCA_FEE_IN_NATIVE,
+				Currencies::reserved_balance(HDX, &ALICE)
+			);
+
+			let schedule_id = 0;
+			assert_scheduled_ids!(601, vec![schedule_id]);
+			assert_eq!(DCA::retries_on_error(schedule_id), 0);
+			expect_dca_events(vec![
+				DcaEvent::ExecutionSkipped {
+					id: schedule_id,
+					who: ALICE,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 601,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn one_sell_dca_execution_should_be_executed_when_price_is_below_limit_price() {
+	let initial_alice_hdx_balance = 10000 * ONE;
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, initial_alice_hdx_balance)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let amount_to_sell = ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_limit_price(Some(FixedU128::from(1)))
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(501)));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_number_of_executed_sell_trades!(1);
+			assert_eq!(DCA::retries_on_error(0), 0);
+		});
+}
+
 #[test]
 fn one_buy_dca_execution_should_be_rescheduled_when_price_diff_is_more_than_max_allowed() {
 	ExtBuilder::default()
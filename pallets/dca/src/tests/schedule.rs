@@ -19,13 +19,16 @@ use crate::assert_scheduled_ids;
 use crate::tests::create_bounded_vec_with_schedule_ids;
 use crate::tests::mock::*;
 use crate::tests::{create_bounded_vec, ScheduleBuilder};
-use crate::{Error, Event, Order};
+use crate::{Error, Event, OracleBounds, Order};
 use frame_support::{assert_noop, assert_ok};
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydradx_traits::router::{PoolType, Trade};
+use hydradx_traits::OraclePeriod;
 use orml_traits::NamedMultiReservableCurrency;
 use pretty_assertions::assert_eq;
+use sp_runtime::traits::Zero;
 use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::{FixedU128, Permill};
 use std::ops::RangeInclusive;
 use test_case::test_case;
 
@@ -484,6 +487,43 @@ fn schedule_should_fail_when_not_called_by_user() {
 	});
 }
 
+#[test_case(vec![(OraclePeriod::LastBlock, Permill::from_percent(5))])]
+#[test_case(vec![(OraclePeriod::TenMinutes, Permill::from_percent(5)), (OraclePeriod::TenMinutes, Permill::from_percent(10))])]
+fn schedule_should_fail_when_oracle_bounds_are_invalid(oracle_bounds: Vec<(OraclePeriod, Permill)>) {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let schedule = ScheduleBuilder::new()
+				.with_oracle_bounds(OracleBounds::truncate_from(oracle_bounds))
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::InvalidOracleBounds
+			);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_limit_price_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let schedule = ScheduleBuilder::new().with_limit_price(Some(FixedU128::zero())).build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::InvalidLimitPrice
+			);
+		});
+}
+
 #[test_case(1)]
 #[test_case(499)]
 #[test_case(500)]
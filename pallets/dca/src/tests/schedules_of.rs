@@ -0,0 +1,153 @@
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::tests::mock::*;
+use crate::tests::on_initialize::set_to_blocknumber;
+use crate::tests::schedule::set_block_number;
+use crate::tests::*;
+use crate::types::ScheduleInfo;
+use crate::OracleBounds;
+use frame_support::assert_ok;
+use hydradx_traits::OraclePeriod;
+use pretty_assertions::assert_eq;
+use sp_runtime::Permill;
+
+#[test]
+fn schedules_of_should_return_schedules_of_owner_with_execution_state() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE), (BOB, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let total_amount = 100 * ONE;
+			let schedule = ScheduleBuilder::new().with_total_amount(total_amount).build();
+			let paused_schedule = ScheduleBuilder::new().with_total_amount(total_amount).build();
+			let bob_schedule = ScheduleBuilder::new().with_owner(BOB).build();
+
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::Some(600)
+			));
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				paused_schedule.clone(),
+				Option::Some(700)
+			));
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(BOB),
+				bob_schedule,
+				Option::Some(600)
+			));
+			assert_ok!(DCA::pause(RuntimeOrigin::signed(ALICE), 1));
+
+			//Act
+			let mut schedules = DCA::schedules_of(ALICE);
+			schedules.sort_by_key(|info| info.id);
+
+			//Assert
+			assert_eq!(
+				schedules,
+				vec![
+					ScheduleInfo {
+						id: 0,
+						schedule,
+						remaining_amount: total_amount,
+						next_execution_block: Some(600),
+						retries: 0,
+						paused: false,
+						transaction_fee: Some(BUY_DCA_FEE_IN_NATIVE),
+						estimated_amount_in: Some(CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY),
+						estimated_amount_out: Some(ONE),
+					},
+					ScheduleInfo {
+						id: 1,
+						schedule: paused_schedule,
+						remaining_amount: total_amount,
+						next_execution_block: None,
+						retries: 0,
+						paused: true,
+						transaction_fee: Some(BUY_DCA_FEE_IN_NATIVE),
+						estimated_amount_in: Some(CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY),
+						estimated_amount_out: Some(ONE),
+					},
+				]
+			);
+		});
+}
+
+#[test]
+fn schedules_of_should_return_empty_list_when_owner_has_no_schedules() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert!(DCA::schedules_of(ALICE).is_empty());
+	});
+}
+
+#[test]
+fn schedules_of_should_return_next_execution_block_of_replanned_schedule() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(501)));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			let schedules = DCA::schedules_of(ALICE);
+			assert_eq!(schedules.len(), 1);
+			assert_eq!(schedules[0].next_execution_block, Some(601));
+		});
+}
+
+#[test]
+fn schedules_of_should_return_transaction_fee_including_oracle_bounds() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_oracle_bounds(OracleBounds::truncate_from(vec![
+					(OraclePeriod::Short, Permill::from_percent(5)),
+					(OraclePeriod::TenMinutes, Permill::from_percent(10)),
+				]))
+				.build();
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::Some(600)
+			));
+
+			//Act
+			let schedules = DCA::schedules_of(ALICE);
+
+			//Assert
+			assert_eq!(schedules.len(), 1);
+			assert_eq!(
+				schedules[0].transaction_fee,
+				Some(DCA::get_schedule_transaction_fee(&schedule).unwrap())
+			);
+		});
+}
@@ -35,7 +35,7 @@ fn terminate_should_remove_schedule_from_storage() {
 			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
 
 			//Act
-			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id, Some(600)));
+			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id));
 
 			//Assert
 			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
@@ -62,7 +62,7 @@ fn terminate_should_terminate_schedule_planned_in_next_block_when_no_block_speci
 			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
 
 			//Act
-			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id, Option::None));
+			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id));
 
 			//Assert
 			expect_events(vec![Event::Terminated {
@@ -94,7 +94,7 @@ fn terminate_should_unreserve_all_named_reserved() {
 			);
 
 			//Act
-			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id, Some(600)));
+			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id));
 
 			//Assert
 			assert_eq!(
@@ -131,7 +131,7 @@ fn terminate_should_unreserve_all_named_reserved_only_for_single_dca_when_there_
 			);
 
 			//Act
-			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id, Some(600)));
+			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id));
 
 			//Assert
 			assert_eq!(
@@ -154,7 +154,7 @@ fn terminate_should_remove_planned_execution_when_there_is_only_single_execution
 			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
 
 			//Act
-			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id, Some(600)));
+			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id));
 
 			//Assert
 			assert!(DCA::schedule_ids_per_block(600).is_empty());
@@ -186,7 +186,7 @@ fn terminate_should_remove_planned_execution_when_there_are_multiple_planned_exe
 			));
 
 			//Act
-			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id, Some(block)));
+			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id));
 
 			//Assert
 			assert_scheduled_ids!(block, vec![1]);
@@ -206,7 +206,7 @@ fn terminate_should_pass_when_called_by_owner() {
 			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
 
 			//Act and assert
-			assert_ok!(DCA::terminate(RuntimeOrigin::signed(ALICE), schedule_id, Some(501)));
+			assert_ok!(DCA::terminate(RuntimeOrigin::signed(ALICE), schedule_id));
 		});
 }
 
@@ -224,7 +224,7 @@ fn terminate_should_fail_when_called_by_non_owner() {
 
 			//Act and assert
 			assert_noop!(
-				DCA::terminate(RuntimeOrigin::signed(BOB), schedule_id, Some(501)),
+				DCA::terminate(RuntimeOrigin::signed(BOB), schedule_id),
 				Error::<Test>::Forbidden
 			);
 		});
@@ -243,7 +243,7 @@ fn terminate_should_fail_when_called_by_non_signed() {
 			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
 
 			//Act and assert
-			assert_noop!(DCA::terminate(RuntimeOrigin::none(), schedule_id, Some(501)), BadOrigin);
+			assert_noop!(DCA::terminate(RuntimeOrigin::none(), schedule_id), BadOrigin);
 		});
 }
 
@@ -260,32 +260,12 @@ fn terminate_should_pass_when_called_by_technical_origin() {
 			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
 
 			//Act and assert
-			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id, Some(501)));
+			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id));
 		});
 }
 
 #[test]
-fn terminate_should_fail_when_no_planned_execution_in_block() {
-	ExtBuilder::default()
-		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
-		.build()
-		.execute_with(|| {
-			//Arrange
-			let schedule = ScheduleBuilder::new().build();
-			set_block_number(500);
-			let schedule_id = 0;
-			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
-
-			//Act and assert
-			assert_noop!(
-				DCA::terminate(RuntimeOrigin::root(), schedule_id, Some(9999)),
-				Error::<Test>::ScheduleNotFound
-			);
-		});
-}
-
-#[test]
-fn terminate_should_fail_when_there_is_planned_execution_in_block_not_not_for_schedule() {
+fn terminate_should_remove_schedule_from_its_indexed_execution_block() {
 	ExtBuilder::default()
 		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
 		.build()
@@ -302,11 +282,13 @@ fn terminate_should_fail_when_there_is_planned_execution_in_block_not_not_for_sc
 			));
 			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule2, Option::None));
 
-			//Act and assert
-			assert_noop!(
-				DCA::terminate(RuntimeOrigin::root(), schedule_id, Some(501)),
-				Error::<Test>::ScheduleNotFound
-			);
+			//Act
+			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id));
+
+			//Assert
+			assert!(DCA::schedule_ids_per_block(1000).is_empty());
+			assert_scheduled_ids!(501, vec![1]);
+			assert!(DCA::schedule_execution_block(schedule_id).is_none());
 		});
 }
 
@@ -318,7 +300,7 @@ fn terminate_should_fail_when_with_nonexisting_schedule() {
 		.execute_with(|| {
 			//Act and assert
 			assert_noop!(
-				DCA::terminate(RuntimeOrigin::root(), 999, Some(501)),
+				DCA::terminate(RuntimeOrigin::root(), 999),
 				Error::<Test>::ScheduleNotFound
 			);
 		});
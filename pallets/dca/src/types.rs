@@ -1,8 +1,9 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use hydradx_traits::router::{AssetPair, RouteProvider, Trade};
+use hydradx_traits::OraclePeriod;
 use scale_info::TypeInfo;
 use sp_runtime::traits::ConstU32;
-use sp_runtime::{BoundedVec, Permill};
+use sp_runtime::{BoundedVec, FixedU128, Permill};
 use sp_std::vec::Vec;
 
 pub type Balance = u128;
@@ -10,6 +11,10 @@ pub type ScheduleId = u32;
 pub type NamedReserveIdentifier = [u8; 8];
 
 const MAX_NUMBER_OF_TRADES: u32 = 5;
+pub const MAX_NUMBER_OF_ORACLE_BOUNDS: u32 = 4;
+
+/// Maximum allowed deviations between the last block oracle price and the oracle price of given periods.
+pub type OracleBounds = BoundedVec<(OraclePeriod, Permill), ConstU32<MAX_NUMBER_OF_ORACLE_BOUNDS>>;
 
 /// DCA schedule containing information to execute repeating orders.
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
@@ -28,12 +33,42 @@ pub struct Schedule<AccountId, AssetId, BlockNumber> {
 	/// The check is performed by comparing the spot price and short oracle price.
 	/// If not specified, the default pallet configuration `MaxPriceDifferenceBetweenBlocks` is used.
 	pub stability_threshold: Option<Permill>,
+	/// Additional price stability bounds per oracle period, e.g. `TenMinutes` or `Hour`.
+	/// Each bound is checked by comparing the last block oracle price with the oracle price of the period.
+	/// The execution is skipped and retried if any of the bounds is exceeded.
+	pub oracle_bounds: OracleBounds,
 	/// The slippage limit used to calculate the `min_amount_out` and `max_amount_in` trade limits.
 	pub slippage: Option<Permill>,
+	/// The maximum price of the bought (`asset_out`) asset denominated in the sold (`asset_in`) asset.
+	/// The execution is skipped, without consuming a retry, while the price is above the limit.
+	pub limit_price: Option<FixedU128>,
 	/// The order containing information to execute a specific trade by the router.
 	pub order: Order<AssetId>,
 }
 
+/// DCA schedule details with its execution state, returned by the runtime API.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo)]
+pub struct ScheduleInfo<AccountId, AssetId, BlockNumber> {
+	/// The id of the schedule.
+	pub id: ScheduleId,
+	/// The schedule details.
+	pub schedule: Schedule<AccountId, AssetId, BlockNumber>,
+	/// The remaining budget of the schedule.
+	pub remaining_amount: Balance,
+	/// The block in which the schedule is planned to be executed. `None` if the schedule is paused.
+	pub next_execution_block: Option<BlockNumber>,
+	/// The number of retries since the last successful trade.
+	pub retries: u8,
+	/// Whether the schedule is paused.
+	pub paused: bool,
+	/// The estimated fee charged for a single trade, in the sold (`amount_in`) currency.
+	pub transaction_fee: Option<Balance>,
+	/// The estimated amount sold in a single trade, simulated by the router.
+	pub estimated_amount_in: Option<Balance>,
+	/// The estimated amount bought in a single trade, simulated by the router.
+	pub estimated_amount_out: Option<Balance>,
+}
+
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
 pub enum Order<AssetId> {
 	Sell {
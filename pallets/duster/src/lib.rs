@@ -25,9 +25,19 @@ mod mock;
 mod tests;
 
 pub mod migration;
+pub mod traits;
 pub mod weights;
 
-use frame_support::{dispatch::DispatchResult, ensure, traits::Contains, traits::Get};
+use frame_support::{
+	dispatch::{DispatchResult, DispatchResultWithPostInfo},
+	ensure,
+	traits::Contains,
+	traits::Get,
+	weights::Weight,
+};
+use frame_system::RawOrigin;
+use hydradx_traits::router::{AmmTradeWeights, AmountInAndOut, AssetPair, PoolType, RouteProvider, RouterT, Trade};
+use sp_runtime::traits::{Saturating, Zero};
 
 use orml_traits::{
 	arithmetic::{Signed, SimpleArithmetic},
@@ -36,11 +46,17 @@ use orml_traits::{
 
 use frame_system::ensure_signed;
 
+use crate::traits::TokenAccountsIterator;
+use crate::weights::WeightInfo;
+
 use sp_std::convert::{TryFrom, TryInto};
 
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 
+/// Maximum number of trades in a route, as allowed by the router.
+pub const MAX_NUMBER_OF_TRADES: u32 = 5;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -72,8 +88,17 @@ pub mod pallet {
 	/// Account to send dust to.
 	pub type DustAccount<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn sweep_cursor)]
+	/// Last token account checked by the `on_idle` sweeper. Next sweep continues after this account.
+	pub type SweepCursor<T: Config> = StorageValue<_, (T::AccountId, T::CurrencyId), OptionQuery>;
+
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::sweep_dust(remaining_weight)
+		}
+	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -124,6 +149,35 @@ pub mod pallet {
 		/// The origin which can manage whiltelist.
 		type BlacklistUpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The origin which can convert collected dust to native currency.
+		type DustConversionOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Token accounts checked by the `on_idle` sweeper.
+		type TokenAccounts: TokenAccountsIterator<Self::AccountId, Self::CurrencyId>;
+
+		/// Router used to convert collected dust to native currency.
+		type RouteExecutor: RouterT<
+			Self::RuntimeOrigin,
+			Self::CurrencyId,
+			Self::Balance,
+			Trade<Self::CurrencyId>,
+			AmountInAndOut<Self::Balance>,
+		>;
+
+		/// Provides route used to convert collected dust to native currency.
+		type RouteProvider: RouteProvider<Self::CurrencyId>;
+
+		/// Weight information for the router trades.
+		type AmmTradeWeights: AmmTradeWeights<Trade<Self::CurrencyId>>;
+
+		/// Maximum number of accounts which can be dusted in one `dust_accounts` call.
+		#[pallet::constant]
+		type MaxAccountsPerBatch: Get<u32>;
+
+		/// Maximum number of token accounts checked by the `on_idle` sweeper in one block.
+		#[pallet::constant]
+		type MaxSweepAccountsPerBlock: Get<u32>;
+
 		/// Weight information for extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -185,6 +239,15 @@ pub mod pallet {
 
 		/// Reserve account is not set.
 		ReserveAccountNotSet,
+
+		/// Number of accounts exceeds `MaxAccountsPerBatch`.
+		TooManyAccounts,
+
+		/// None of the provided accounts could be dusted.
+		NothingDusted,
+
+		/// Native currency can't be converted to itself.
+		NotConvertible,
 	}
 
 	#[pallet::event]
@@ -198,6 +261,13 @@ pub mod pallet {
 
 		/// Account removed from non-dustable list.
 		Removed { who: T::AccountId },
+
+		/// Collected dust has been converted to native currency.
+		DustConverted {
+			currency_id: T::CurrencyId,
+			amount_in: T::Balance,
+			amount_out: T::Balance,
+		},
 	}
 
 	#[pallet::call]
@@ -212,26 +282,13 @@ pub mod pallet {
 		pub fn dust_account(origin: OriginFor<T>, account: T::AccountId, currency_id: T::CurrencyId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			ensure!(Self::blacklisted(&account).is_none(), Error::<T>::AccountBlacklisted);
-
-			let (dustable, dust) = Self::is_dustable(&account, currency_id);
-
-			ensure!(dust != T::Balance::from(0u32), Error::<T>::ZeroBalance);
-
-			ensure!(dustable, Error::<T>::BalanceSufficient);
-
 			// Error should never occur here
 			let dust_dest_account = Self::dust_dest_account().ok_or(Error::<T>::DustAccountNotSet)?;
 
-			Self::transfer_dust(&account, &dust_dest_account, currency_id, dust)?;
-
-			Self::deposit_event(Event::Dusted {
-				who: account,
-				amount: dust,
-			});
+			Self::do_dust_account(&account, currency_id, &dust_dest_account)?;
 
 			// Ignore the result, it fails - no problem.
-			let _ = Self::reward_duster(&who, currency_id, dust);
+			let _ = Self::reward_duster(&who, 1u32);
 
 			Ok(())
 		}
@@ -269,6 +326,102 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Dust multiple accounts in one call.
+		///
+		/// Accounts which can't be dusted ( blacklisted, zero or sufficient balance ) are skipped.
+		/// Fails with `NothingDusted` if none of the accounts has been dusted.
+		///
+		/// Caller is rewarded with chosen reward in native currency for each dusted account.
+		///
+		/// Parameters:
+		/// - `accounts`: list of `(account, currency)` pairs to dust. Max `MaxAccountsPerBatch` entries.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::dust_accounts(accounts.len() as u32))]
+		pub fn dust_accounts(origin: OriginFor<T>, accounts: Vec<(T::AccountId, T::CurrencyId)>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				accounts.len() <= T::MaxAccountsPerBatch::get() as usize,
+				Error::<T>::TooManyAccounts
+			);
+
+			// Error should never occur here
+			let dust_dest_account = Self::dust_dest_account().ok_or(Error::<T>::DustAccountNotSet)?;
+
+			let dusted = accounts
+				.iter()
+				.filter(|(account, currency_id)| {
+					Self::do_dust_account(account, *currency_id, &dust_dest_account).is_ok()
+				})
+				.count() as u32;
+
+			ensure!(dusted > 0, Error::<T>::NothingDusted);
+
+			// Ignore the result, it fails - no problem.
+			let _ = Self::reward_duster(&who, dusted);
+
+			Ok(())
+		}
+
+		/// Convert collected dust of given currency to native currency.
+		///
+		/// `amount` of `currency_id` is sold from dust account for native currency using route
+		/// provided by `RouteProvider`.
+		///
+		/// Route is not read to weigh the call - the longest route of the most expensive pool type is charged
+		/// and the difference to the weight of the used route is refunded.
+		///
+		/// Only `DustConversionOrigin` can perform this action.
+		///
+		/// Parameters:
+		/// - `currency_id`: currency to convert
+		/// - `amount`: amount of currency to sell
+		/// - `min_amount_out`: minimum amount of native currency to receive
+		///
+		/// Emits `DustConverted` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(Pallet::<T>::convert_dust_overhead_weight(*currency_id)
+			.saturating_add(Pallet::<T>::worst_case_sell_weight(*currency_id)))]
+		pub fn convert_dust(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+			min_amount_out: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			T::DustConversionOrigin::ensure_origin(origin)?;
+
+			let native_currency_id = T::NativeCurrencyId::get();
+			ensure!(currency_id != native_currency_id, Error::<T>::NotConvertible);
+			ensure!(!amount.is_zero(), Error::<T>::ZeroBalance);
+
+			let dust_dest_account = Self::dust_dest_account().ok_or(Error::<T>::DustAccountNotSet)?;
+
+			let route = T::RouteProvider::get_route(AssetPair::new(currency_id, native_currency_id));
+			let native_balance = T::MultiCurrency::free_balance(native_currency_id, &dust_dest_account);
+
+			let route_weight = T::AmmTradeWeights::sell_weight(&route);
+
+			T::RouteExecutor::sell(
+				RawOrigin::Signed(dust_dest_account.clone()).into(),
+				currency_id,
+				native_currency_id,
+				amount,
+				min_amount_out,
+				route,
+			)?;
+
+			let amount_out =
+				T::MultiCurrency::free_balance(native_currency_id, &dust_dest_account).saturating_sub(native_balance);
+
+			Self::deposit_event(Event::DustConverted {
+				currency_id,
+				amount_in: amount,
+				amount_out,
+			});
+
+			Ok(Some(Self::convert_dust_overhead_weight(currency_id).saturating_add(route_weight)).into())
+		}
 	}
 }
 impl<T: Config> Pallet<T> {
@@ -281,17 +434,123 @@ impl<T: Config> Pallet<T> {
 		(total < ed, total)
 	}
 
-	/// Send reward to account which did the dusting.
-	fn reward_duster(_duster: &T::AccountId, _currency_id: T::CurrencyId, _dust: T::Balance) -> DispatchResult {
+	/// Transfer dust of given account to `dust_dest_account` if the account can be dusted.
+	fn do_dust_account(
+		account: &T::AccountId,
+		currency_id: T::CurrencyId,
+		dust_dest_account: &T::AccountId,
+	) -> DispatchResult {
+		ensure!(Self::blacklisted(account).is_none(), Error::<T>::AccountBlacklisted);
+
+		let (dustable, dust) = Self::is_dustable(account, currency_id);
+
+		ensure!(dust != T::Balance::from(0u32), Error::<T>::ZeroBalance);
+
+		ensure!(dustable, Error::<T>::BalanceSufficient);
+
+		Self::transfer_dust(account, dust_dest_account, currency_id, dust)?;
+
+		Self::deposit_event(Event::Dusted {
+			who: account.clone(),
+			amount: dust,
+		});
+
+		Ok(())
+	}
+
+	/// Weight of dust conversion without the trade. The benchmark sells the dust in a single Omnipool trade.
+	fn convert_dust_overhead_weight(currency_id: T::CurrencyId) -> Weight {
+		T::WeightInfo::convert_dust().saturating_sub(T::AmmTradeWeights::sell_weight(&[Trade {
+			pool: PoolType::Omnipool,
+			asset_in: currency_id,
+			asset_out: T::NativeCurrencyId::get(),
+		}]))
+	}
+
+	/// Weight of the longest route of the most expensive pool type. Stableswap metapool trades are included,
+	/// as each of them is executed as two trades.
+	fn worst_case_sell_weight(currency_id: T::CurrencyId) -> Weight {
+		[
+			PoolType::Omnipool,
+			PoolType::LBP,
+			PoolType::Stableswap(currency_id),
+			PoolType::StableswapMetapool(currency_id, currency_id),
+			PoolType::XYK,
+			PoolType::Concentrated(0),
+		]
+		.into_iter()
+		.map(|pool| {
+			let route = sp_std::vec![
+				Trade {
+					pool,
+					asset_in: currency_id,
+					asset_out: T::NativeCurrencyId::get(),
+				};
+				MAX_NUMBER_OF_TRADES as usize
+			];
+			T::AmmTradeWeights::sell_weight(&route)
+		})
+		.fold(Weight::zero(), |max, weight| max.max(weight))
+	}
+
+	/// Send reward to account which did the dusting. Reward is paid for each dusted account.
+	fn reward_duster(duster: &T::AccountId, dusted_accounts: u32) -> DispatchResult {
 		// Error should never occur here
 		let reserve_account = Self::reward_account().ok_or(Error::<T>::ReserveAccountNotSet)?;
-		let reward = T::Reward::get();
+		let reward = T::Reward::get().saturating_mul(T::Balance::from(dusted_accounts));
 
-		T::MultiCurrency::transfer(T::NativeCurrencyId::get(), &reserve_account, _duster, reward)?;
+		T::MultiCurrency::transfer(T::NativeCurrencyId::get(), &reserve_account, duster, reward)?;
 
 		Ok(())
 	}
 
+	/// Check token accounts following the sweep cursor and dust those which are below minimum deposit.
+	///
+	/// Number of checked accounts is limited by `MaxSweepAccountsPerBlock` and by remaining ref time and proof size.
+	fn sweep_dust(remaining_weight: Weight) -> Weight {
+		let sweep_weight = T::WeightInfo::sweep_account();
+		if sweep_weight.ref_time().is_zero() {
+			return Weight::zero();
+		}
+		let base_weight = T::DbWeight::get().reads_writes(2, 1);
+		if remaining_weight.any_lt(base_weight) {
+			return Weight::zero();
+		}
+		let available_weight = remaining_weight.saturating_sub(base_weight);
+		let max_accounts_by_proof_size = available_weight
+			.proof_size()
+			.checked_div(sweep_weight.proof_size())
+			.unwrap_or(u64::MAX);
+		let max_accounts = (available_weight.ref_time() / sweep_weight.ref_time())
+			.min(max_accounts_by_proof_size)
+			.min(T::MaxSweepAccountsPerBlock::get() as u64);
+		if max_accounts.is_zero() {
+			return Weight::zero();
+		}
+
+		let Some(dust_dest_account) = Self::dust_dest_account() else {
+			return T::DbWeight::get().reads(1);
+		};
+
+		let mut checked = 0u64;
+		let mut last = None;
+		for (account, currency_id) in T::TokenAccounts::iter_from(Self::sweep_cursor()).take(max_accounts as usize) {
+			// accounts which can't be dusted are skipped
+			let _ = Self::do_dust_account(&account, currency_id, &dust_dest_account);
+			last = Some((account, currency_id));
+			checked.saturating_inc();
+		}
+
+		if checked < max_accounts {
+			// all accounts have been checked, start from the beginning next time
+			SweepCursor::<T>::kill();
+		} else {
+			SweepCursor::<T>::set(last);
+		}
+
+		sweep_weight.saturating_mul(checked).saturating_add(base_weight)
+	}
+
 	/// Transfer dust amount to selected DustAccount ( usually treasury)
 	fn transfer_dust(
 		from: &T::AccountId,
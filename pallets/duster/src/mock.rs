@@ -1,11 +1,14 @@
 use crate as duster;
 
 use frame_support::parameter_types;
-use frame_support::traits::{Everything, Nothing, OnKilledAccount};
+use frame_support::traits::{ConstU32, Everything, Nothing, OnKilledAccount};
+use hydradx_traits::router::{AmmTradeWeights, AmountInAndOut, AssetPair, PoolType, RouteProvider, RouterT, Trade};
+use orml_traits::MultiCurrency;
 
 use orml_traits::parameter_type_with_key;
 use pallet_currencies::BasicCurrencyAdapter;
 
+use crate::traits::TokenAccountsIterator;
 use crate::Config;
 use frame_system as system;
 
@@ -13,11 +16,13 @@ use sp_core::H256;
 
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, DispatchError, DispatchResult,
 };
 
+use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::weights::Weight;
-use frame_system::EnsureRoot;
+use frame_system::{ensure_signed, EnsureRoot};
+use sp_std::boxed::Box;
 use sp_std::cell::RefCell;
 use sp_std::vec::Vec;
 
@@ -28,6 +33,8 @@ type Amount = i128;
 
 type Block = frame_system::mocking::MockBlock<Test>;
 
+pub const TRADE_WEIGHT: u64 = 1_000_000;
+
 lazy_static::lazy_static! {
 pub static ref ALICE: AccountId = 100;
 pub static ref BOB: AccountId = 200;
@@ -66,6 +73,7 @@ parameter_types! {
 
 thread_local! {
 	pub static KILLED: RefCell<Vec<u64>> = RefCell::new(vec![]);
+	pub static CONVERSION_RATE: RefCell<Balance> = RefCell::new(2);
 }
 
 pub struct RecordKilled;
@@ -127,9 +135,136 @@ impl Config for Test {
 	type Reward = Reward;
 	type NativeCurrencyId = NativeCurrencyId;
 	type BlacklistUpdateOrigin = EnsureRoot<AccountId>;
+	type DustConversionOrigin = EnsureRoot<AccountId>;
+	type TokenAccounts = TokenAccounts;
+	type RouteExecutor = MockRouter;
+	type RouteProvider = MockRouteProvider;
+	type AmmTradeWeights = MockAmmTradeWeights;
+	type MaxAccountsPerBatch = ConstU32<5>;
+	type MaxSweepAccountsPerBlock = ConstU32<3>;
 	type WeightInfo = ();
 }
 
+pub struct TokenAccounts;
+impl TokenAccountsIterator<AccountId, AssetId> for TokenAccounts {
+	fn iter_from(last: Option<(AccountId, AssetId)>) -> Box<dyn Iterator<Item = (AccountId, AssetId)>> {
+		match last {
+			Some((who, currency_id)) => {
+				Box::new(orml_tokens::Accounts::<Test>::iter_keys_from(orml_tokens::Accounts::<
+					Test,
+				>::hashed_key_for(
+					who, currency_id
+				)))
+			}
+			None => Box::new(orml_tokens::Accounts::<Test>::iter_keys()),
+		}
+	}
+}
+
+pub struct MockRouteProvider;
+impl RouteProvider<AssetId> for MockRouteProvider {}
+
+pub struct MockAmmTradeWeights;
+impl AmmTradeWeights<Trade<AssetId>> for MockAmmTradeWeights {
+	fn sell_weight(route: &[Trade<AssetId>]) -> Weight {
+		// Metapool trade is executed as two trades.
+		let trades = route
+			.iter()
+			.map(|trade| match trade.pool {
+				PoolType::StableswapMetapool(..) => 2,
+				_ => 1,
+			})
+			.sum::<u64>();
+		Weight::from_parts(TRADE_WEIGHT.saturating_mul(trades), 0)
+	}
+
+	fn buy_weight(route: &[Trade<AssetId>]) -> Weight {
+		Self::sell_weight(route)
+	}
+
+	fn calculate_buy_trade_amounts_weight(_route: &[Trade<AssetId>]) -> Weight {
+		Weight::zero()
+	}
+
+	fn sell_and_calculate_sell_trade_amounts_weight(_route: &[Trade<AssetId>]) -> Weight {
+		Weight::zero()
+	}
+
+	fn buy_and_calculate_buy_trade_amounts_weight(_route: &[Trade<AssetId>]) -> Weight {
+		Weight::zero()
+	}
+
+	fn set_route_weight(_route: &[Trade<AssetId>]) -> Weight {
+		Weight::zero()
+	}
+
+	fn force_insert_route_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Sells asset for `CONVERSION_RATE` times the amount of asset out.
+pub struct MockRouter;
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for MockRouter {
+	fn sell(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		let who = ensure_signed(origin)?;
+		let amount_out = amount_in * CONVERSION_RATE.with(|v| *v.borrow());
+		if amount_out < min_amount_out {
+			return Err(DispatchError::Other("TradingLimitReached"));
+		}
+		Currencies::withdraw(asset_in, &who, amount_in)?;
+		Currencies::deposit(asset_out, &who, amount_out)
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+
+	fn force_insert_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+}
+
 impl orml_tokens::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
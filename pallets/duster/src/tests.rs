@@ -1,12 +1,15 @@
 use super::*;
 use crate::mock::{
 	AssetId, Currencies, Duster, ExtBuilder, RuntimeEvent as TestEvent, RuntimeOrigin, System, Test, Tokens, ALICE,
-	BOB, DUSTER, KILLED, TREASURY,
+	BOB, DUSTER, KILLED, TRADE_WEIGHT, TREASURY,
 };
 
+use frame_support::dispatch::GetDispatchInfo;
+use frame_support::traits::Hooks;
 use frame_support::{assert_noop, assert_ok};
 
 use sp_runtime::traits::BadOrigin;
+use sp_runtime::DispatchError;
 
 #[test]
 fn dust_account_works() {
@@ -317,3 +320,224 @@ fn remove_nondustable_account_works() {
 			assert_ok!(Duster::dust_account(RuntimeOrigin::signed(*DUSTER), *ALICE, 0),);
 		});
 }
+
+#[test]
+fn dust_accounts_works_and_rewards_each_dusted_account() {
+	ExtBuilder::default()
+		.with_balance(*ALICE, 1, 100)
+		.with_balance(*BOB, 1, 200)
+		.with_balance(*BOB, 2, 100)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Duster::dust_accounts(
+				RuntimeOrigin::signed(*DUSTER),
+				vec![(*ALICE, 1), (*BOB, 1), (*BOB, 2), (*TREASURY, 1)]
+			));
+
+			assert_eq!(Tokens::free_balance(1, &*ALICE), 0);
+			assert_eq!(Tokens::free_balance(1, &*BOB), 0);
+			assert_eq!(Tokens::free_balance(2, &*BOB), 100);
+			assert_eq!(Tokens::free_balance(1, &*TREASURY), 300);
+
+			assert_eq!(Currencies::free_balance(0, &*DUSTER), 20_000);
+		});
+}
+
+#[test]
+fn dust_accounts_fails_when_no_account_is_dusted() {
+	ExtBuilder::default()
+		.with_balance(*ALICE, 1, 1_000_000)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Duster::dust_accounts(RuntimeOrigin::signed(*DUSTER), vec![(*ALICE, 1), (*BOB, 1)]),
+				Error::<Test>::NothingDusted
+			);
+		});
+}
+
+#[test]
+fn dust_accounts_fails_when_too_many_accounts_are_provided() {
+	ExtBuilder::default()
+		.with_balance(*ALICE, 1, 100)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Duster::dust_accounts(RuntimeOrigin::signed(*DUSTER), vec![(*ALICE, 1); 6]),
+				Error::<Test>::TooManyAccounts
+			);
+		});
+}
+
+#[test]
+fn on_idle_should_dust_accounts_below_min_deposit() {
+	ExtBuilder::default()
+		.with_balance(*ALICE, 1, 100)
+		.with_balance(*BOB, 1, 200)
+		.with_balance(*DUSTER, 1, 1_000_000)
+		.with_balance(*TREASURY, 1, 50)
+		.build()
+		.execute_with(|| {
+			// max 3 accounts are checked per block, 4 accounts need two blocks
+			Duster::on_idle(1, Weight::MAX);
+			Duster::on_idle(2, Weight::MAX);
+
+			assert_eq!(Tokens::free_balance(1, &*ALICE), 0);
+			assert_eq!(Tokens::free_balance(1, &*BOB), 0);
+			assert_eq!(Tokens::free_balance(1, &*DUSTER), 1_000_000);
+			// blacklisted
+			assert_eq!(Tokens::free_balance(1, &*TREASURY), 350);
+
+			// no reward for automatic dusting
+			assert_eq!(Currencies::free_balance(0, &*DUSTER), 0);
+		});
+}
+
+#[test]
+fn on_idle_should_check_limited_number_of_accounts_per_block() {
+	let accounts: Vec<u64> = (1..=6).collect();
+	let mut builder = ExtBuilder::default().with_balance(*TREASURY, 1, 50);
+	for account in accounts.iter() {
+		builder = builder.with_balance(*account, 1, 100);
+	}
+	builder.build().execute_with(|| {
+		let dusted = |accounts: &[u64]| {
+			accounts
+				.iter()
+				.filter(|account| Tokens::free_balance(1, *account) == 0)
+				.count()
+		};
+
+		// max 3 accounts are checked per block, one of them can be the blacklisted treasury
+		Duster::on_idle(1, Weight::MAX);
+		assert!(dusted(&accounts) <= 3);
+		assert!(Duster::sweep_cursor().is_some());
+
+		Duster::on_idle(2, Weight::MAX);
+		Duster::on_idle(3, Weight::MAX);
+		assert_eq!(dusted(&accounts), 6);
+		assert_eq!(Tokens::free_balance(1, &*TREASURY), 650);
+		assert!(Duster::sweep_cursor().is_none());
+	});
+}
+
+#[test]
+fn on_idle_should_not_dust_when_weight_is_not_sufficient() {
+	ExtBuilder::default()
+		.with_balance(*ALICE, 1, 100)
+		.build()
+		.execute_with(|| {
+			assert_eq!(Duster::on_idle(1, Weight::zero()), Weight::zero());
+
+			assert_eq!(Tokens::free_balance(1, &*ALICE), 100);
+			assert!(Duster::sweep_cursor().is_none());
+		});
+}
+
+#[test]
+fn on_idle_should_check_accounts_within_remaining_proof_size() {
+	let accounts: Vec<u64> = (1..=6).collect();
+	let mut builder = ExtBuilder::default();
+	for account in accounts.iter() {
+		builder = builder.with_balance(*account, 1, 100);
+	}
+	builder.build().execute_with(|| {
+		let sweep_weight = <Test as Config>::WeightInfo::sweep_account();
+		let remaining_weight = Weight::from_parts(u64::MAX, sweep_weight.proof_size());
+
+		let used_weight = Duster::on_idle(1, remaining_weight);
+
+		// ref time is sufficient for all accounts, but proof size only for one of them
+		assert_eq!(used_weight, sweep_weight);
+		assert_eq!(
+			accounts
+				.iter()
+				.filter(|account| Tokens::free_balance(1, *account) == 0)
+				.count(),
+			1
+		);
+		assert!(Duster::sweep_cursor().is_some());
+	});
+}
+
+#[test]
+fn convert_dust_should_refund_weight_of_worst_case_route() {
+	ExtBuilder::default()
+		.with_balance(*TREASURY, 1, 1_000)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let call = Call::<Test>::convert_dust {
+				currency_id: 1,
+				amount: 400,
+				min_amount_out: 0,
+			};
+			let charged = call.get_dispatch_info().weight;
+
+			// Act
+			let post_info = Duster::convert_dust(RuntimeOrigin::root(), 1, 400, 0).unwrap();
+
+			// Assert - worst case route has metapool trades only, route of the mock router provider has one trade
+			assert_eq!(
+				charged.saturating_sub(post_info.actual_weight.unwrap()),
+				Weight::from_parts(TRADE_WEIGHT * (2 * MAX_NUMBER_OF_TRADES as u64 - 1), 0)
+			);
+		});
+}
+
+#[test]
+fn convert_dust_works() {
+	let mut ext = ExtBuilder::default().with_balance(*TREASURY, 1, 1_000).build();
+	ext.execute_with(|| {
+		System::set_block_number(1);
+	});
+	ext.execute_with(|| {
+		assert_ok!(Duster::convert_dust(RuntimeOrigin::root(), 1, 400, 800));
+
+		assert_eq!(Tokens::free_balance(1, &*TREASURY), 600);
+		assert_eq!(Currencies::free_balance(0, &*TREASURY), 1_000_800);
+
+		expect_events(vec![Event::DustConverted {
+			currency_id: 1,
+			amount_in: 400,
+			amount_out: 800,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn convert_dust_fails_when_origin_is_not_allowed() {
+	ExtBuilder::default()
+		.with_balance(*TREASURY, 1, 1_000)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Duster::convert_dust(RuntimeOrigin::signed(*DUSTER), 1, 400, 0),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn convert_dust_fails_when_native_currency_is_converted() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Duster::convert_dust(RuntimeOrigin::root(), 0, 400, 0),
+			Error::<Test>::NotConvertible
+		);
+	});
+}
+
+#[test]
+fn convert_dust_fails_when_min_amount_out_is_not_reached() {
+	ExtBuilder::default()
+		.with_balance(*TREASURY, 1, 1_000)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Duster::convert_dust(RuntimeOrigin::root(), 1, 400, 801),
+				DispatchError::Other("TradingLimitReached")
+			);
+		});
+}
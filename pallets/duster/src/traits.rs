@@ -0,0 +1,32 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use sp_std::boxed::Box;
+
+/// Provides token accounts which are checked by the `on_idle` dust sweeper.
+pub trait TokenAccountsIterator<AccountId, CurrencyId> {
+	/// Iterate over `(account, currency)` pairs which follow `last` in storage order.
+	///
+	/// Iteration starts from the beginning if `last` is `None`.
+	fn iter_from(last: Option<(AccountId, CurrencyId)>) -> Box<dyn Iterator<Item = (AccountId, CurrencyId)>>;
+}
+
+impl<AccountId: 'static, CurrencyId: 'static> TokenAccountsIterator<AccountId, CurrencyId> for () {
+	fn iter_from(_last: Option<(AccountId, CurrencyId)>) -> Box<dyn Iterator<Item = (AccountId, CurrencyId)>> {
+		Box::new(sp_std::iter::empty())
+	}
+}
@@ -59,6 +59,9 @@ pub trait WeightInfo {
 	fn dust_account() -> Weight;
 	fn add_nondustable_account() -> Weight;
 	fn remove_nondustable_account() -> Weight;
+	fn dust_accounts(c: u32) -> Weight;
+	fn sweep_account() -> Weight;
+	fn convert_dust() -> Weight;
 }
 
 /// Weights for claims using the hydraDX node and recommended hardware.
@@ -107,6 +110,66 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Duster::DustAccount` (r:1 w:0)
+	/// Proof: `Duster::DustAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountBlacklist` (r:100 w:0)
+	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:100 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:200 w:200)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:100 w:100)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::RewardAccount` (r:1 w:0)
+	/// Proof: `Duster::RewardAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 100]`.
+	fn dust_accounts(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1471 + c * (289 ±0)`
+		//  Estimated: `3593 + c * (5166 ±0)`
+		// Minimum execution time: 97_102_000 picoseconds.
+		Weight::from_parts(26_814_220, 3593)
+			// Standard Error: 21_327
+			.saturating_add(Weight::from_parts(73_442_604, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 5166).saturating_mul(c.into()))
+	}
+	/// Storage: `Duster::SweepCursor` (r:1 w:1)
+	/// Proof: `Duster::SweepCursor` (`max_values`: Some(1), `max_size`: Some(36), added: 531, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::DustAccount` (r:1 w:0)
+	/// Proof: `Duster::DustAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountBlacklist` (r:1 w:0)
+	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn sweep_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2511`
+		//  Estimated: `6156`
+		// Minimum execution time: 78_213_000 picoseconds.
+		Weight::from_parts(79_032_000, 6156)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Duster::DustAccount` (r:1 w:0)
+	/// Proof: `Duster::DustAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Router::Routes` (r:1 w:0)
+	/// Proof: `Router::Routes` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn convert_dust() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1433`
+		//  Estimated: `3593`
+		// Minimum execution time: 24_510_000 picoseconds.
+		Weight::from_parts(25_008_000, 3593).saturating_add(T::DbWeight::get().reads(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -154,4 +217,64 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Duster::DustAccount` (r:1 w:0)
+	/// Proof: `Duster::DustAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountBlacklist` (r:100 w:0)
+	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:100 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:200 w:200)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:100 w:100)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::RewardAccount` (r:1 w:0)
+	/// Proof: `Duster::RewardAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// The range of component `c` is `[1, 100]`.
+	fn dust_accounts(c: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1471 + c * (289 ±0)`
+		//  Estimated: `3593 + c * (5166 ±0)`
+		// Minimum execution time: 97_102_000 picoseconds.
+		Weight::from_parts(26_814_220, 3593)
+			// Standard Error: 21_327
+			.saturating_add(Weight::from_parts(73_442_604, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(c.into())))
+			.saturating_add(Weight::from_parts(0, 5166).saturating_mul(c.into()))
+	}
+	/// Storage: `Duster::SweepCursor` (r:1 w:1)
+	/// Proof: `Duster::SweepCursor` (`max_values`: Some(1), `max_size`: Some(36), added: 531, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::DustAccount` (r:1 w:0)
+	/// Proof: `Duster::DustAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:2 w:2)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `Duster::AccountBlacklist` (r:1 w:0)
+	/// Proof: `Duster::AccountBlacklist` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn sweep_account() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2511`
+		//  Estimated: `6156`
+		// Minimum execution time: 78_213_000 picoseconds.
+		Weight::from_parts(79_032_000, 6156)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Duster::DustAccount` (r:1 w:0)
+	/// Proof: `Duster::DustAccount` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `Router::Routes` (r:1 w:0)
+	/// Proof: `Router::Routes` (`max_values`: None, `max_size`: Some(90), added: 2565, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn convert_dust() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1433`
+		//  Estimated: `3593`
+		// Minimum execution time: 24_510_000 picoseconds.
+		Weight::from_parts(25_008_000, 3593).saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
 }
@@ -0,0 +1,34 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the EMA oracle pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query time weighted average prices.
+	pub trait TwapApi<AssetId, BlockNumber, Price, Error> where
+		AssetId: Codec,
+		BlockNumber: Codec,
+		Price: Codec,
+		Error: Codec,
+	{
+		/// Get the time weighted average price of `asset_a` in `asset_b` reported by `source`
+		/// over the last `window` blocks.
+		fn twap(source: [u8; 8], asset_a: AssetId, asset_b: AssetId, window: BlockNumber) -> Result<Price, Error>;
+	}
+}
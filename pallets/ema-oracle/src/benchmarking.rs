@@ -379,5 +379,28 @@ benchmarks! {
 		}));
 	}
 
+	get_twap {
+		let max_checkpoints = <<T as Config>::MaxTwapCheckpoints as Get<u32>>::get();
+		let asset_a = 1_000;
+		let asset_b = asset_a + 500;
+		let price = Price::new(1_000_000_000_000_000, 2_000_000_000_000_000);
+
+		// fill the ring buffer with one checkpoint per block
+		for i in 1..=max_checkpoints {
+			frame_system::Pallet::<T>::set_block_number(i.into());
+			EmaOracle::<T>::update_twap(SOURCE, ordered_pair(asset_a, asset_b), price);
+		}
+		let block_num: BlockNumberFor<T> = max_checkpoints.saturating_add(1).into();
+		frame_system::Pallet::<T>::set_block_number(block_num);
+		let window: BlockNumberFor<T> = max_checkpoints.into();
+
+		let res = core::cell::RefCell::new(Err(OracleError::NotPresent));
+
+	}: { let _ = res.replace(EmaOracle::<T>::get_twap(asset_a, asset_b, window, SOURCE)); }
+	verify {
+		let twap = res.borrow().unwrap();
+		assert_eq!(twap.n.saturating_mul(2), twap.d);
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::new_test_ext(), crate::tests::Test);
 }
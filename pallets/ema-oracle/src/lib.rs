@@ -63,18 +63,28 @@
 //! and the price and liquidity to be constant) to the last block. Note: The most recent oracle
 //! values are always from the last block. This avoids e.g. sandwiching risks. If you want current
 //! prices you should use a spot price or similar.
+//!
+//! ### Time Weighted Average Prices
+//!
+//! Next to the EMA oracles the pallet tracks cumulative prices (à la Uniswap v2) for every
+//! combination of source and asset pair. At the end of each block with activity a checkpoint of
+//! the cumulative prices is written into a ring buffer of `MaxTwapCheckpoints` slots. The time
+//! weighted average price over any window of up to `MaxTwapCheckpoints` blocks is then calculated
+//! from the difference of two cumulative prices. It is exposed via the `AggregatedTwapOracle`
+//! trait. Like the EMA oracles, the values exclude trading data of the current block.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::pallet_prelude::*;
-use frame_support::sp_runtime::traits::{BlockNumberProvider, One, Zero};
+use frame_support::sp_runtime::traits::{BlockNumberProvider, CheckedSub, One, Zero};
 use frame_support::traits::Contains;
 use frame_system::pallet_prelude::BlockNumberFor;
+use hydra_dx_math::twap::time_weighted_average_price;
 use hydradx_traits::{
-	AggregatedEntry, AggregatedOracle, AggregatedPriceOracle, Liquidity, OnCreatePoolHandler,
+	AggregatedEntry, AggregatedOracle, AggregatedPriceOracle, AggregatedTwapOracle, Liquidity, OnCreatePoolHandler,
 	OnLiquidityChangedHandler, OnTradeHandler, OraclePeriod::*, Volume,
 };
-use sp_arithmetic::traits::Saturating;
+use sp_arithmetic::traits::{SaturatedConversion, Saturating};
 use sp_std::marker::PhantomData;
 use sp_std::prelude::*;
 
@@ -142,6 +152,11 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxUniqueEntries: Get<u32>;
 
+		/// Maximum number of TWAP checkpoints kept for each combination of source and asset pair.
+		/// At most one checkpoint is written per block, so this is also the maximum TWAP window.
+		#[pallet::constant]
+		type MaxTwapCheckpoints: Get<u32>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: BenchmarkHelper<AssetId>;
 	}
@@ -192,6 +207,31 @@ pub mod pallet {
 	pub type WhitelistedAssets<T: Config> =
 		StorageValue<_, BoundedBTreeSet<(Source, (AssetId, AssetId)), T::MaxUniqueEntries>, ValueQuery>;
 
+	/// State of the TWAP checkpoint ring buffer keyed by data source and involved asset ids.
+	#[pallet::storage]
+	pub type TwapAccumulators<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		Source,
+		Twox64Concat,
+		(AssetId, AssetId),
+		TwapAccumulator<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// TWAP checkpoint ring buffer keyed by data source, involved asset ids and slot index.
+	#[pallet::storage]
+	pub type TwapCheckpoints<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, Source>,
+			NMapKey<Twox64Concat, (AssetId, AssetId)>,
+			NMapKey<Twox64Concat, u32>,
+		),
+		TwapCheckpoint<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -221,6 +261,7 @@ pub mod pallet {
 				for period in T::SupportedPeriods::get() {
 					Pallet::<T>::update_oracle(source, ordered_pair(asset_a, asset_b), period, entry.clone());
 				}
+				Pallet::<T>::update_twap(source, ordered_pair(asset_a, asset_b), entry.price);
 			}
 		}
 	}
@@ -241,6 +282,10 @@ pub mod pallet {
 				T::MaxUniqueEntries::get() > 0,
 				"At least one trade should be possible per block."
 			);
+			assert!(
+				T::MaxTwapCheckpoints::get() > 0,
+				"At least one TWAP checkpoint should be kept."
+			);
 		}
 	}
 
@@ -264,7 +309,8 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_oracle())]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_oracle()
+			.saturating_add(T::DbWeight::get().writes(T::MaxTwapCheckpoints::get().saturating_add(1).into())))]
 		pub fn remove_oracle(origin: OriginFor<T>, source: Source, assets: (AssetId, AssetId)) -> DispatchResult {
 			T::AuthorityOrigin::ensure_origin(origin)?;
 
@@ -284,6 +330,8 @@ pub mod pallet {
 				});
 				Oracles::<T>::remove((source, assets, period));
 			}
+			TwapAccumulators::<T>::remove(source, assets);
+			let _ = TwapCheckpoints::<T>::clear_prefix((source, assets), T::MaxTwapCheckpoints::get(), None);
 
 			Self::deposit_event(Event::RemovedFromWhitelist { source, assets });
 
@@ -370,9 +418,124 @@ impl<T: Config> Pallet<T> {
 			// As we use (the old value of) the `LastBlock` entry to update the other oracles it
 			// gets updated last.
 			Self::update_oracle(src, assets, LastBlock, oracle_entry.clone());
+			Self::update_twap(src, assets, oracle_entry.price);
 		}
 	}
 
+	/// Write a new TWAP checkpoint with `price` for the given source and assets at the current block.
+	///
+	/// Replaces the most recent checkpoint if it was written in the current block already.
+	pub(crate) fn update_twap(src: Source, assets: (AssetId, AssetId), price: Price) {
+		let block = T::BlockNumberProvider::current_block_number();
+		let max_checkpoints = T::MaxTwapCheckpoints::get();
+		TwapAccumulators::<T>::mutate(src, assets, |maybe_accumulator| {
+			let mut accumulator = maybe_accumulator.take().unwrap_or_default();
+			let checkpoint = if accumulator.count.is_zero() {
+				Some(TwapCheckpoint {
+					price,
+					block,
+					..Default::default()
+				})
+			} else {
+				accumulator.last.next(price, block)
+			};
+			let Some(checkpoint) = checkpoint else {
+				log::warn!(
+					target: LOG_TARGET,
+					"Updating TWAP checkpoint ({src:?}, {assets:?}) failed. Defaulting to previous value."
+				);
+				debug_assert!(false, "Updating TWAP checkpoint should not fail.");
+				*maybe_accumulator = Some(accumulator);
+				return;
+			};
+
+			let index = if !accumulator.count.is_zero() && accumulator.last.block == block {
+				accumulator
+					.next_index
+					.checked_sub(1)
+					.unwrap_or(max_checkpoints.saturating_sub(1))
+			} else {
+				let index = accumulator.next_index;
+				accumulator.next_index = index.saturating_add(1) % max_checkpoints;
+				accumulator.count = accumulator.count.saturating_add(1).min(max_checkpoints);
+				index
+			};
+			TwapCheckpoints::<T>::insert((src, assets, index), checkpoint.clone());
+			accumulator.last = checkpoint;
+			*maybe_accumulator = Some(accumulator);
+		});
+	}
+
+	/// Return the most recent TWAP checkpoint at or before `block`.
+	///
+	/// Uses binary search on the ring buffer, so at most `log2(MaxTwapCheckpoints) + 1` checkpoints are read.
+	fn find_twap_checkpoint(
+		src: Source,
+		assets: (AssetId, AssetId),
+		accumulator: &TwapAccumulator<BlockNumberFor<T>>,
+		block: BlockNumberFor<T>,
+	) -> Option<TwapCheckpoint<BlockNumberFor<T>>> {
+		if accumulator.last.block <= block {
+			return Some(accumulator.last.clone());
+		}
+		let max_checkpoints = T::MaxTwapCheckpoints::get();
+		let oldest = accumulator
+			.next_index
+			.saturating_add(max_checkpoints)
+			.saturating_sub(accumulator.count)
+			% max_checkpoints;
+		let checkpoint_at =
+			|i: u32| TwapCheckpoints::<T>::get((src, assets, oldest.saturating_add(i) % max_checkpoints));
+
+		let mut found = checkpoint_at(0).filter(|checkpoint| checkpoint.block <= block)?;
+		// invariant: checkpoint `low` is at or before `block`, checkpoint `high` is after `block`
+		let (mut low, mut high) = (0, accumulator.count.saturating_sub(1));
+		while high.saturating_sub(low) > 1 {
+			let mid = low.saturating_add(high) / 2;
+			let checkpoint = checkpoint_at(mid)?;
+			if checkpoint.block <= block {
+				low = mid;
+				found = checkpoint;
+			} else {
+				high = mid;
+			}
+		}
+		Some(found)
+	}
+
+	/// Return the time weighted average prices of the given source and assets over the last
+	/// `window` blocks as a tuple of the price and the inverted price.
+	///
+	/// The window ends at the current block, so trading data of the current block is excluded.
+	fn get_twap_prices(
+		src: Source,
+		assets: (AssetId, AssetId),
+		window: BlockNumberFor<T>,
+	) -> Result<(Price, Price), OracleError> {
+		if window.is_zero() || window > T::MaxTwapCheckpoints::get().into() {
+			return Err(OracleError::InvalidWindow);
+		}
+		let accumulator = TwapAccumulators::<T>::get(src, assets).ok_or(OracleError::NotPresent)?;
+		let now = T::BlockNumberProvider::current_block_number();
+		let start = now.checked_sub(&window).ok_or(OracleError::InsufficientHistory)?;
+		let start_checkpoint =
+			Self::find_twap_checkpoint(src, assets, &accumulator, start).ok_or(OracleError::InsufficientHistory)?;
+
+		let (start_price, start_inverted_price) = start_checkpoint
+			.cumulative_prices_at(start)
+			.ok_or(OracleError::NotPresent)?;
+		let (end_price, end_inverted_price) = accumulator
+			.last
+			.cumulative_prices_at(now)
+			.ok_or(OracleError::NotPresent)?;
+
+		let blocks: u64 = window.saturated_into();
+		let price = time_weighted_average_price(start_price, end_price, blocks).ok_or(OracleError::NotPresent)?;
+		let inverted_price = time_weighted_average_price(start_inverted_price, end_inverted_price, blocks)
+			.ok_or(OracleError::NotPresent)?;
+		Ok((price, inverted_price))
+	}
+
 	/// Update the oracle of the given source, assets and period with `oracle_entry`.
 	fn update_oracle(
 		src: Source,
@@ -597,6 +760,10 @@ pub enum OracleError {
 	NotPresent,
 	/// The oracle is not defined if the asset ids are the same.
 	SameAsset,
+	/// The requested TWAP window is zero or longer than the maximum supported window.
+	InvalidWindow,
+	/// The recorded TWAP history does not cover the requested window.
+	InsufficientHistory,
 }
 
 impl<T: Config> AggregatedOracle<AssetId, Balance, BlockNumberFor<T>, Price> for Pallet<T> {
@@ -651,6 +818,34 @@ impl<T: Config> AggregatedPriceOracle<AssetId, BlockNumberFor<T>, Price> for Pal
 	}
 }
 
+impl<T: Config> AggregatedTwapOracle<AssetId, BlockNumberFor<T>, Price> for Pallet<T> {
+	type Error = OracleError;
+
+	/// Returns the time weighted average price of the given assets over the last `window` blocks.
+	/// Like `get_entry`, calling `get_twap(HDX, DOT, window, Omnipool)` returns the price
+	/// `HDX/DOT`, while `get_twap(DOT, HDX, window, Omnipool)` returns `DOT/HDX`.
+	fn get_twap(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		window: BlockNumberFor<T>,
+		source: Source,
+	) -> Result<Price, Self::Error> {
+		if asset_a == asset_b {
+			return Err(OracleError::SameAsset);
+		};
+		let (price, inverted_price) = Self::get_twap_prices(source, ordered_pair(asset_a, asset_b), window)?;
+		if (asset_a, asset_b) == ordered_pair(asset_a, asset_b) {
+			Ok(price)
+		} else {
+			Ok(inverted_price)
+		}
+	}
+
+	fn get_twap_weight() -> Weight {
+		T::WeightInfo::get_twap()
+	}
+}
+
 /// Oracle whitelist based on the pallet's storage.
 pub struct OracleWhitelist<T>(PhantomData<T>);
 impl<T: Config> Contains<(Source, AssetId, AssetId)> for OracleWhitelist<T> {
@@ -18,6 +18,7 @@
 mod add_and_remove_oracle;
 mod invariants;
 mod mock;
+mod twap;
 
 use super::*;
 pub use mock::{
@@ -0,0 +1,207 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+pub use mock::{EmaOracle, RuntimeOrigin, Test, DOT, HDX, INSUFFICIENT_ASSET};
+
+use frame_support::assert_ok;
+use pretty_assertions::assert_eq;
+
+/// Record a trade of HDX/DOT at `price` in `block` and finalize the block.
+fn trade_at(block: BlockNumber, price: Price) {
+	System::set_block_number(block);
+	assert_ok!(OnActivityHandler::<Test>::on_trade(
+		SOURCE, HDX, DOT, 1_000, 500, 2_000, 1_000, price
+	));
+	EmaOracle::on_finalize(block);
+}
+
+fn assert_price_eq(price: Price, expected: Price) {
+	assert_eq!(
+		price.n.checked_mul(expected.d),
+		expected.n.checked_mul(price.d),
+		"price {price:?} is not equal to {expected:?}"
+	);
+}
+
+#[test]
+fn twap_should_average_prices_over_window() {
+	new_test_ext().execute_with(|| {
+		trade_at(1, Price::new(2, 1));
+		trade_at(5, Price::new(4, 1));
+
+		System::set_block_number(9);
+		// 4 blocks at 2 and 4 blocks at 4
+		assert_price_eq(EmaOracle::get_twap(HDX, DOT, 8, SOURCE).unwrap(), Price::new(3, 1));
+		assert_price_eq(EmaOracle::get_twap(HDX, DOT, 2, SOURCE).unwrap(), Price::new(4, 1));
+	});
+}
+
+#[test]
+fn twap_should_average_inverted_prices_when_assets_are_reversed() {
+	new_test_ext().execute_with(|| {
+		trade_at(1, Price::new(2, 1));
+		trade_at(5, Price::new(4, 1));
+
+		System::set_block_number(9);
+		// 4 blocks at 1/2 and 4 blocks at 1/4
+		assert_price_eq(EmaOracle::get_twap(DOT, HDX, 8, SOURCE).unwrap(), Price::new(3, 8));
+	});
+}
+
+#[test]
+fn twap_should_exclude_trades_of_current_block() {
+	new_test_ext().execute_with(|| {
+		trade_at(1, Price::new(2, 1));
+
+		System::set_block_number(5);
+		assert_ok!(OnActivityHandler::<Test>::on_trade(
+			SOURCE,
+			HDX,
+			DOT,
+			1_000,
+			500,
+			2_000,
+			1_000,
+			Price::new(10, 1)
+		));
+
+		assert_price_eq(EmaOracle::get_twap(HDX, DOT, 4, SOURCE).unwrap(), Price::new(2, 1));
+	});
+}
+
+#[test]
+fn twap_should_keep_most_recent_checkpoints_when_buffer_is_full() {
+	new_test_ext().execute_with(|| {
+		let max_checkpoints = <<Test as Config>::MaxTwapCheckpoints as Get<u32>>::get();
+		for block in 1..=15 {
+			trade_at(block, Price::new(block.into(), 1));
+		}
+
+		let accumulator = TwapAccumulators::<Test>::get(SOURCE, ordered_pair(HDX, DOT)).unwrap();
+		assert_eq!(accumulator.count, max_checkpoints);
+		assert_eq!(accumulator.last.block, 15);
+
+		System::set_block_number(16);
+		// prices 6 to 15
+		assert_price_eq(
+			EmaOracle::get_twap(HDX, DOT, max_checkpoints.into(), SOURCE).unwrap(),
+			Price::new(21, 2),
+		);
+		// prices 12 to 15
+		assert_price_eq(EmaOracle::get_twap(HDX, DOT, 4, SOURCE).unwrap(), Price::new(27, 2));
+
+		System::set_block_number(15);
+		assert_eq!(
+			EmaOracle::get_twap(HDX, DOT, max_checkpoints.into(), SOURCE),
+			Err(OracleError::InsufficientHistory)
+		);
+	});
+}
+
+#[test]
+fn twap_should_fail_when_history_does_not_cover_window() {
+	new_test_ext().execute_with(|| {
+		trade_at(5, Price::new(2, 1));
+
+		System::set_block_number(7);
+		assert_eq!(
+			EmaOracle::get_twap(HDX, DOT, 4, SOURCE),
+			Err(OracleError::InsufficientHistory)
+		);
+		assert_price_eq(EmaOracle::get_twap(HDX, DOT, 2, SOURCE).unwrap(), Price::new(2, 1));
+	});
+}
+
+#[test]
+fn twap_should_fail_when_window_is_invalid() {
+	new_test_ext().execute_with(|| {
+		let max_checkpoints = <<Test as Config>::MaxTwapCheckpoints as Get<u32>>::get();
+		trade_at(1, Price::new(2, 1));
+
+		System::set_block_number(100);
+		assert_eq!(
+			EmaOracle::get_twap(HDX, DOT, 0, SOURCE),
+			Err(OracleError::InvalidWindow)
+		);
+		assert_eq!(
+			EmaOracle::get_twap(HDX, DOT, (max_checkpoints + 1).into(), SOURCE),
+			Err(OracleError::InvalidWindow)
+		);
+	});
+}
+
+#[test]
+fn twap_should_fail_when_oracle_is_not_present() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_eq!(EmaOracle::get_twap(HDX, DOT, 2, SOURCE), Err(OracleError::NotPresent));
+		assert_eq!(EmaOracle::get_twap(HDX, HDX, 2, SOURCE), Err(OracleError::SameAsset));
+	});
+}
+
+#[test]
+fn twap_should_be_initialized_from_genesis_data() {
+	ExtBuilder::default()
+		.with_initial_data(vec![(
+			SOURCE,
+			(HDX, DOT),
+			(1_000_000, 1).into(),
+			Liquidity::new(2_000_000, 2_000_000_000),
+		)])
+		.build()
+		.execute_with(|| {
+			assert_price_eq(
+				EmaOracle::get_twap(HDX, DOT, 1, SOURCE).unwrap(),
+				Price::new(1_000_000, 1),
+			);
+		});
+}
+
+#[test]
+fn remove_oracle_should_remove_twap_checkpoints() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_oracle(
+			RuntimeOrigin::root(),
+			SOURCE,
+			(HDX, INSUFFICIENT_ASSET)
+		));
+		System::set_block_number(5);
+		assert_ok!(OnActivityHandler::<Test>::on_trade(
+			SOURCE,
+			HDX,
+			INSUFFICIENT_ASSET,
+			1_000,
+			500,
+			2_000,
+			1_000,
+			Price::new(2, 1)
+		));
+		EmaOracle::on_finalize(5);
+		let assets = ordered_pair(HDX, INSUFFICIENT_ASSET);
+		assert!(TwapAccumulators::<Test>::get(SOURCE, assets).is_some());
+
+		assert_ok!(EmaOracle::remove_oracle(
+			RuntimeOrigin::root(),
+			SOURCE,
+			(HDX, INSUFFICIENT_ASSET)
+		));
+
+		assert!(TwapAccumulators::<Test>::get(SOURCE, assets).is_none());
+		assert!(TwapCheckpoints::<Test>::get((SOURCE, assets, 0)).is_none());
+	});
+}
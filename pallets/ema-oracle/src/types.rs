@@ -18,6 +18,7 @@
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::RuntimeDebug;
 use hydra_dx_math::ema::{calculate_new_by_integrating_incoming, update_outdated_to_current, EmaPrice};
+use hydra_dx_math::twap::{accumulate_price, CumulativePrice};
 use hydra_dx_math::types::Fraction;
 use hydradx_traits::{AggregatedEntry, Liquidity, Volume};
 use scale_info::TypeInfo;
@@ -227,3 +228,53 @@ impl<BlockNumber> From<(Price, Volume<Balance>, Liquidity<Balance>, BlockNumber)
 		}
 	}
 }
+
+/// A snapshot of the cumulative prices of an asset pair at `block`.
+///
+/// `price` is the price recorded at the end of `block`. It is valid from `block` on until the next
+/// checkpoint, so it is not yet included in the cumulative prices of this checkpoint.
+#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+pub struct TwapCheckpoint<BlockNumber> {
+	pub cumulative_price: CumulativePrice,
+	pub cumulative_inverted_price: CumulativePrice,
+	pub price: Price,
+	pub block: BlockNumber,
+}
+
+impl<BlockNumber> TwapCheckpoint<BlockNumber>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy + UniqueSaturatedInto<u64>,
+{
+	/// Return the cumulative prices (of the price and the inverted price) at `block`.
+	///
+	/// Returns `None` if `block` is older than `self` or the calculation fails.
+	pub fn cumulative_prices_at(&self, block: BlockNumber) -> Option<(CumulativePrice, CumulativePrice)> {
+		let blocks: u64 = block.checked_sub(&self.block)?.saturated_into();
+		Some((
+			accumulate_price(self.cumulative_price, self.price, blocks)?,
+			accumulate_price(self.cumulative_inverted_price, self.price.inverted(), blocks)?,
+		))
+	}
+
+	/// Return the checkpoint following `self` with the `price` recorded at `block`.
+	pub fn next(&self, price: Price, block: BlockNumber) -> Option<Self> {
+		let (cumulative_price, cumulative_inverted_price) = self.cumulative_prices_at(block)?;
+		Some(Self {
+			cumulative_price,
+			cumulative_inverted_price,
+			price,
+			block,
+		})
+	}
+}
+
+/// State of the ring buffer of `TwapCheckpoint`s of an asset pair.
+#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+pub struct TwapAccumulator<BlockNumber> {
+	/// The most recent checkpoint. Kept here so that it does not need to be read from the buffer.
+	pub last: TwapCheckpoint<BlockNumber>,
+	/// Index of the slot the next checkpoint is written to.
+	pub next_index: u32,
+	/// Number of checkpoints stored in the buffer.
+	pub count: u32,
+}
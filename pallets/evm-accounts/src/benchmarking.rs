@@ -20,8 +20,26 @@ use crate::Pallet as EVMAccounts;
 
 use frame_benchmarking::{account, benchmarks};
 use frame_system::RawOrigin;
+use sp_core::crypto::KeyTypeId;
 use sp_std::prelude::*;
 
+/// Sign the link message of `account` with a newly generated EVM key.
+fn sign_link_message<T: Config>(account: &T::AccountId) -> (EvmAddress, EvmSignature)
+where
+	T::AccountId: AsRef<[u8; 32]>,
+{
+	let key_type = KeyTypeId(*b"evma");
+	let public = sp_io::crypto::ecdsa_generate(key_type, None);
+	let message_hash = Pallet::<T>::link_evm_address_message_hash(account);
+	let signature = sp_io::crypto::ecdsa_sign_prehashed(key_type, &public, &message_hash).expect("Failed to sign");
+
+	let mut evm_signature: EvmSignature = [0u8; 65];
+	evm_signature.copy_from_slice(signature.as_ref());
+	let evm_address = Pallet::<T>::recover_evm_address(account, &evm_signature).expect("Invalid signature");
+
+	(evm_address, evm_signature)
+}
+
 benchmarks! {
 	where_clause {
 		where T::AccountId: AsRef<[u8; 32]> + frame_support::pallet_prelude::IsType<AccountId32>,
@@ -74,5 +92,29 @@ benchmarks! {
 		assert!(!ContractDeployer::<T>::contains_key(evm_address));
 	}
 
+	link_evm_address {
+		let user: T::AccountId = account("user", 0, 1);
+		let (evm_address, signature) = sign_link_message::<T>(&user);
+		assert!(!LinkedAccount::<T>::contains_key(evm_address));
+
+	}: _(RawOrigin::Signed(user.clone()), evm_address, signature)
+	verify {
+		assert_eq!(LinkedAccount::<T>::get(evm_address), Some(user));
+	}
+
+	unlink_evm_address {
+		let user: T::AccountId = account("user", 0, 1);
+		let (evm_address, signature) = sign_link_message::<T>(&user);
+
+		EVMAccounts::<T>::link_evm_address(RawOrigin::Signed(user.clone()).into(), evm_address, signature)?;
+
+		assert!(LinkedAccount::<T>::contains_key(evm_address));
+
+	}: _(RawOrigin::Signed(user.clone()))
+	verify {
+		assert!(!LinkedAccount::<T>::contains_key(evm_address));
+		assert!(!LinkedAddress::<T>::contains_key(user));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::ExtBuilder::default().build(), crate::mock::Test);
 }
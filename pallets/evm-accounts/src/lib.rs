@@ -36,6 +36,15 @@
 //! With binding, we store the last 12 bytes of the Substrate address. Then we can get the original
 //! Substrate address by concatenating these 12 bytes stored in the storage to the EVM address.
 //!
+//! ### Linking
+//! A user who already owns an EVM key can link its address to their Substrate account instead of binding
+//! the truncated address. The link is authorized by an EIP-712 signature of the Substrate account made
+//! with the EVM key, see `link_evm_address_message_hash`. A linked address is resolved to the Substrate
+//! account in the same way as a bound address. An address that was already used can be linked as well,
+//! the nonce of the Substrate account is raised to the nonce of the address so that the EVM transactions
+//! signed by the key before linking can't be replayed. Funds of the truncated account can be accessed again
+//! by unlinking the address.
+//!
 //! ### Smart contract deployment
 //! This pallet also allows granting a permission to deploy smart contracts.
 //! `ControllerOrigin` can add this permission to EVM addresses.
@@ -44,6 +53,8 @@
 //! ### Dispatchable Functions
 //!
 //! * `bind_evm_address` - Binds a Substrate address to EVM address.
+//! * `link_evm_address` - Links an EVM address to a Substrate address with a signature of the EVM key.
+//! * `unlink_evm_address` - Removes the link of an EVM address to the caller's Substrate address.
 //! * `add_contract_deployer` - Adds a permission to deploy smart contracts.
 //! * `remove_contract_deployer` - Removes a permission of whitelisted address to deploy smart contracts.
 //! * `renounce_contract_deployer` - Renounce caller's permission to deploy smart contracts.
@@ -52,11 +63,14 @@
 
 use frame_support::ensure;
 use frame_support::pallet_prelude::{DispatchResult, Get};
+use frame_support::sp_runtime::traits::UniqueSaturatedFrom;
 use hydradx_traits::evm::InspectEvmAccounts;
 use sp_core::{
 	crypto::{AccountId32, ByteArray},
 	H160, U256,
 };
+use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+use sp_std::vec::Vec;
 
 #[cfg(test)]
 mod mock;
@@ -72,6 +86,13 @@ pub use weights::WeightInfo;
 pub type Balance = u128;
 pub type EvmAddress = H160;
 pub type AccountIdLast12Bytes = [u8; 12];
+/// Signature of an EVM key, `r ++ s ++ v`.
+pub type EvmSignature = [u8; 65];
+
+/// Name of the EIP-712 domain of link messages.
+pub const EIP712_DOMAIN_NAME: &[u8] = b"HydraDX EVM Accounts";
+/// Version of the EIP-712 domain of link messages.
+pub const EIP712_DOMAIN_VERSION: &[u8] = b"1";
 
 pub trait EvmNonceProvider {
 	fn get_nonce(evm_address: H160) -> U256;
@@ -101,6 +122,10 @@ pub mod pallet {
 		/// Origin that can whitelist addresses for smart contract deployment.
 		type ControllerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// EVM chain id, part of the EIP-712 domain of link messages.
+		#[pallet::constant]
+		type ChainId: Get<u64>;
+
 		/// Weight information for extrinsic in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -110,6 +135,16 @@ pub mod pallet {
 	#[pallet::getter(fn account)]
 	pub(super) type AccountExtension<T: Config> = StorageMap<_, Blake2_128Concat, EvmAddress, AccountIdLast12Bytes>;
 
+	/// Maps an EVM address to the Substrate account it is linked to.
+	#[pallet::storage]
+	#[pallet::getter(fn linked_account)]
+	pub(super) type LinkedAccount<T: Config> = StorageMap<_, Blake2_128Concat, EvmAddress, T::AccountId>;
+
+	/// Maps a Substrate account to the EVM address linked to it.
+	#[pallet::storage]
+	#[pallet::getter(fn linked_address)]
+	pub(super) type LinkedAddress<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, EvmAddress>;
+
 	/// Whitelisted addresses that are allowed to deploy smart contracts.
 	#[pallet::storage]
 	pub(super) type ContractDeployer<T: Config> = StorageMap<_, Blake2_128Concat, EvmAddress, ()>;
@@ -123,6 +158,10 @@ pub mod pallet {
 		DeployerAdded { who: EvmAddress },
 		/// Deployer was removed.
 		DeployerRemoved { who: EvmAddress },
+		/// EVM address was linked to an account.
+		Linked { account: T::AccountId, address: EvmAddress },
+		/// EVM address was unlinked from an account.
+		Unlinked { account: T::AccountId, address: EvmAddress },
 	}
 
 	#[pallet::error]
@@ -136,6 +175,12 @@ pub mod pallet {
 		BoundAddressCannotBeUsed,
 		/// Address not whitelisted
 		AddressNotWhitelisted,
+		/// Signature is not a valid signature of the link message made by the EVM address
+		InvalidSignature,
+		/// Account has already linked an EVM address
+		AccountAlreadyLinked,
+		/// Account has no linked EVM address
+		AddressNotLinked,
 	}
 
 	#[pallet::hooks]
@@ -183,7 +228,7 @@ pub mod pallet {
 			// on the nonce. So it's better to prevent any confusion and throw an error when address is
 			// already bound.
 			ensure!(
-				!AccountExtension::<T>::contains_key(evm_address),
+				!AccountExtension::<T>::contains_key(evm_address) && !LinkedAccount::<T>::contains_key(evm_address),
 				Error::<T>::AddressAlreadyBound
 			);
 
@@ -260,6 +305,116 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Links an EVM address to the caller's Substrate address.
+		/// After linking, the EVM converts the address to the caller's Substrate address, the same way as a bound
+		/// address. Any existing EVM key can be linked, including keys that were already used. The nonce of the caller's
+		/// account is raised to the nonce of the EVM address if it is lower.
+		///
+		/// The link has to be authorized by the EVM key, which signs the EIP-712 message
+		/// `LinkEvmAddress(bytes32 account)` in the domain `EIP712Domain(string name,string version,uint256 chainId)`.
+		/// See `link_evm_address_message_hash`.
+		///
+		/// Parameters:
+		/// - `origin`: Substrate account linking the address
+		/// - `address`: EVM address to link
+		/// - `signature`: signature of the link message made by the EVM key of `address`
+		///
+		/// Emits `Linked` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::link_evm_address())]
+		pub fn link_evm_address(origin: OriginFor<T>, address: EvmAddress, signature: EvmSignature) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Self::recover_evm_address(&who, &signature) == Some(address),
+				Error::<T>::InvalidSignature
+			);
+
+			ensure!(
+				!AccountExtension::<T>::contains_key(address) && !LinkedAccount::<T>::contains_key(address),
+				Error::<T>::AddressAlreadyBound
+			);
+			ensure!(
+				!LinkedAddress::<T>::contains_key(&who),
+				Error::<T>::AccountAlreadyLinked
+			);
+
+			// After linking, the EVM nonce of the address is the nonce of the caller's account.
+			// It must not be lower than before, otherwise already executed EVM transactions could be replayed.
+			let evm_nonce = T::EvmNonceProvider::get_nonce(address);
+			let evm_nonce = T::Nonce::unique_saturated_from(u128::try_from(evm_nonce).unwrap_or(u128::MAX));
+			frame_system::Account::<T>::mutate(&who, |account| {
+				if account.nonce < evm_nonce {
+					account.nonce = evm_nonce;
+				}
+			});
+
+			<LinkedAccount<T>>::insert(address, who.clone());
+			<LinkedAddress<T>>::insert(&who, address);
+
+			Self::deposit_event(Event::Linked { account: who, address });
+
+			Ok(())
+		}
+
+		/// Removes the link of an EVM address to the caller's Substrate address.
+		/// After unlinking, the EVM converts the address to its truncated Substrate address again.
+		///
+		/// Parameters:
+		/// - `origin`: Substrate account which linked the address
+		///
+		/// Emits `Unlinked` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::unlink_evm_address())]
+		pub fn unlink_evm_address(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let address = <LinkedAddress<T>>::take(&who).ok_or(Error::<T>::AddressNotLinked)?;
+			<LinkedAccount<T>>::remove(address);
+
+			Self::deposit_event(Event::Unlinked { account: who, address });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T>
+where
+	T::AccountId: AsRef<[u8; 32]>,
+{
+	/// EIP-712 hash of the message which authorizes linking of an EVM address to `account`.
+	pub fn link_evm_address_message_hash(account: &T::AccountId) -> [u8; 32] {
+		let domain_type_hash = keccak_256(b"EIP712Domain(string name,string version,uint256 chainId)");
+		let mut chain_id = [0u8; 32];
+		U256::from(T::ChainId::get()).to_big_endian(&mut chain_id);
+
+		let mut domain = Vec::with_capacity(4 * 32);
+		domain.extend_from_slice(&domain_type_hash);
+		domain.extend_from_slice(&keccak_256(EIP712_DOMAIN_NAME));
+		domain.extend_from_slice(&keccak_256(EIP712_DOMAIN_VERSION));
+		domain.extend_from_slice(&chain_id);
+		let domain_separator = keccak_256(&domain);
+
+		let message_type_hash = keccak_256(b"LinkEvmAddress(bytes32 account)");
+		let mut message = Vec::with_capacity(2 * 32);
+		message.extend_from_slice(&message_type_hash);
+		message.extend_from_slice(account.as_ref());
+		let message_hash = keccak_256(&message);
+
+		let mut digest = Vec::with_capacity(2 + 2 * 32);
+		digest.extend_from_slice(b"\x19\x01");
+		digest.extend_from_slice(&domain_separator);
+		digest.extend_from_slice(&message_hash);
+		keccak_256(&digest)
+	}
+
+	/// Recover the EVM address which signed the link message of `account`.
+	pub fn recover_evm_address(account: &T::AccountId, signature: &EvmSignature) -> Option<EvmAddress> {
+		let message_hash = Self::link_evm_address_message_hash(account);
+		let public_key = secp256k1_ecdsa_recover(signature, &message_hash).ok()?;
+		Some(EvmAddress::from_slice(&keccak_256(&public_key)[12..]))
 	}
 }
 
@@ -287,10 +442,10 @@ where
 		AccountId32::from(data).into()
 	}
 
-	/// Return the Substrate address bound to the EVM account. If not bound, returns `None`.
+	/// Return the Substrate address bound or linked to the EVM account. If not bound, returns `None`.
 	fn bound_account_id(evm_address: EvmAddress) -> Option<T::AccountId> {
 		let Some(last_12_bytes) = AccountExtension::<T>::get(evm_address) else {
-			return None;
+			return LinkedAccount::<T>::get(evm_address);
 		};
 		let mut data: [u8; 32] = [0u8; 32];
 		data[..20].copy_from_slice(evm_address.0.as_ref());
@@ -12,6 +12,7 @@ use frame_support::traits::Everything;
 use frame_system::EnsureRoot;
 use orml_traits::parameter_type_with_key;
 pub use sp_core::{H160, H256};
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 use std::cell::RefCell;
 use std::collections::HashMap;
 
@@ -24,6 +25,7 @@ pub const ONE: Balance = 1_000_000_000_000;
 pub const INITIAL_BALANCE: Balance = 1_000_000_000_000 * ONE;
 
 pub const ALICE: AccountId = AccountId::new([1; 32]);
+pub const BOB: AccountId = AccountId::new([2; 32]);
 
 pub const HDX: AssetId = 0;
 
@@ -61,6 +63,7 @@ impl Config for Test {
 	type FeeMultiplier = sp_core::ConstU32<10>;
 	type EvmNonceProvider = EvmNonceProviderMock;
 	type ControllerOrigin = EnsureRoot<AccountId>;
+	type ChainId = sp_core::ConstU64<222_222>;
 	type WeightInfo = ();
 }
 
@@ -137,6 +140,7 @@ impl ExtBuilder {
 		.unwrap();
 
 		let mut r: sp_io::TestExternalities = t.into();
+		r.register_extension(KeystoreExt::new(MemoryKeystore::new()));
 		r.execute_with(|| System::set_block_number(1));
 		r
 	}
@@ -149,6 +153,14 @@ impl ExtBuilder {
 		});
 		self
 	}
+
+	pub fn with_non_zero_nonce_of_address(self, evm_address: H160) -> Self {
+		NONCE.with(|v| {
+			let mut m = v.borrow_mut();
+			m.insert(evm_address, U256::one());
+		});
+		self
+	}
 }
 
 pub fn expect_events(e: Vec<RuntimeEvent>) {
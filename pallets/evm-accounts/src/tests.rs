@@ -146,3 +146,211 @@ fn renounce_contract_deployer_should_remove_address_from_the_storage() {
 		assert_ok!(EVMAccounts::renounce_contract_deployer(RuntimeOrigin::signed(ALICE)));
 	});
 }
+
+// Well known development keys.
+const EVM_KEY_1: [u8; 32] = hex!["ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80"];
+const EVM_ADDRESS_1: [u8; 20] = hex!["f39fd6e51aad88f6f4ce6ab8827279cfffb92266"];
+const EVM_KEY_2: [u8; 32] = hex!["59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d"];
+const EVM_ADDRESS_2: [u8; 20] = hex!["70997970c51812dc3a010c7d01b50e0d17dc79c8"];
+
+fn sign_link_message(evm_key: [u8; 32], account: &AccountId) -> EvmSignature {
+	use sp_core::Pair;
+	let pair = sp_core::ecdsa::Pair::from_seed(&evm_key);
+	let signature = pair.sign_prehashed(&EVMAccounts::link_evm_address_message_hash(account));
+	let mut evm_signature: EvmSignature = [0u8; 65];
+	evm_signature.copy_from_slice(signature.as_ref());
+	evm_signature
+}
+
+#[test]
+fn link_evm_address_should_link_address_when_signature_is_valid() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let evm_address = H160::from(EVM_ADDRESS_1);
+		let signature = sign_link_message(EVM_KEY_1, &ALICE);
+
+		// Act
+		assert_ok!(EVMAccounts::link_evm_address(
+			RuntimeOrigin::signed(ALICE),
+			evm_address,
+			signature
+		));
+
+		// Assert
+		assert_eq!(EVMAccounts::linked_account(evm_address), Some(ALICE));
+		assert_eq!(EVMAccounts::linked_address(ALICE), Some(evm_address));
+		assert_eq!(EVMAccounts::bound_account_id(evm_address), Some(ALICE));
+		assert_eq!(EVMAccounts::account_id(evm_address), ALICE);
+
+		expect_events(vec![Event::Linked {
+			account: ALICE,
+			address: evm_address,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn link_evm_address_should_fail_when_message_is_signed_for_different_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		let signature = sign_link_message(EVM_KEY_1, &BOB);
+
+		assert_noop!(
+			EVMAccounts::link_evm_address(RuntimeOrigin::signed(ALICE), H160::from(EVM_ADDRESS_1), signature),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn link_evm_address_should_work_when_address_was_already_used() {
+	ExtBuilder::default()
+		.with_non_zero_nonce_of_address(H160::from(EVM_ADDRESS_1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let evm_address = H160::from(EVM_ADDRESS_1);
+			let signature = sign_link_message(EVM_KEY_1, &ALICE);
+
+			// Act
+			assert_ok!(EVMAccounts::link_evm_address(
+				RuntimeOrigin::signed(ALICE),
+				evm_address,
+				signature
+			));
+
+			// Assert
+			assert_eq!(EVMAccounts::linked_account(evm_address), Some(ALICE));
+			assert_eq!(System::account_nonce(ALICE), 1);
+		});
+}
+
+#[test]
+fn link_evm_address_should_not_decrease_nonce_of_account() {
+	ExtBuilder::default()
+		.with_non_zero_nonce_of_address(H160::from(EVM_ADDRESS_1))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let evm_address = H160::from(EVM_ADDRESS_1);
+			let signature = sign_link_message(EVM_KEY_1, &ALICE);
+			for _ in 0..5 {
+				System::inc_account_nonce(ALICE);
+			}
+
+			// Act
+			assert_ok!(EVMAccounts::link_evm_address(
+				RuntimeOrigin::signed(ALICE),
+				evm_address,
+				signature
+			));
+
+			// Assert
+			assert_eq!(System::account_nonce(ALICE), 5);
+		});
+}
+
+#[test]
+fn link_evm_address_should_fail_when_message_is_signed_by_different_key() {
+	ExtBuilder::default().build().execute_with(|| {
+		let signature = sign_link_message(EVM_KEY_2, &ALICE);
+
+		assert_noop!(
+			EVMAccounts::link_evm_address(RuntimeOrigin::signed(ALICE), H160::from(EVM_ADDRESS_1), signature),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn link_evm_address_should_fail_when_address_is_linked_to_other_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let evm_address = H160::from(EVM_ADDRESS_1);
+		assert_ok!(EVMAccounts::link_evm_address(
+			RuntimeOrigin::signed(ALICE),
+			evm_address,
+			sign_link_message(EVM_KEY_1, &ALICE)
+		));
+
+		// Act & Assert
+		assert_noop!(
+			EVMAccounts::link_evm_address(
+				RuntimeOrigin::signed(BOB),
+				evm_address,
+				sign_link_message(EVM_KEY_1, &BOB)
+			),
+			Error::<Test>::AddressAlreadyBound
+		);
+	});
+}
+
+#[test]
+fn link_evm_address_should_fail_when_account_has_already_linked_address() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(EVMAccounts::link_evm_address(
+			RuntimeOrigin::signed(ALICE),
+			H160::from(EVM_ADDRESS_1),
+			sign_link_message(EVM_KEY_1, &ALICE)
+		));
+
+		// Act & Assert
+		assert_noop!(
+			EVMAccounts::link_evm_address(
+				RuntimeOrigin::signed(ALICE),
+				H160::from(EVM_ADDRESS_2),
+				sign_link_message(EVM_KEY_2, &ALICE)
+			),
+			Error::<Test>::AccountAlreadyLinked
+		);
+	});
+}
+
+#[test]
+fn unlink_evm_address_should_remove_link() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let evm_address = H160::from(EVM_ADDRESS_1);
+		assert_ok!(EVMAccounts::link_evm_address(
+			RuntimeOrigin::signed(ALICE),
+			evm_address,
+			sign_link_message(EVM_KEY_1, &ALICE)
+		));
+
+		// Act
+		assert_ok!(EVMAccounts::unlink_evm_address(RuntimeOrigin::signed(ALICE)));
+
+		// Assert
+		assert_eq!(EVMAccounts::linked_account(evm_address), None);
+		assert_eq!(EVMAccounts::linked_address(ALICE), None);
+		assert_eq!(EVMAccounts::bound_account_id(evm_address), None);
+		assert_eq!(
+			EVMAccounts::account_id(evm_address),
+			EVMAccounts::truncated_account_id(evm_address)
+		);
+
+		expect_events(vec![Event::Unlinked {
+			account: ALICE,
+			address: evm_address,
+		}
+		.into()]);
+
+		// the address can be linked to other account after unlinking
+		assert_ok!(EVMAccounts::link_evm_address(
+			RuntimeOrigin::signed(BOB),
+			evm_address,
+			sign_link_message(EVM_KEY_1, &BOB)
+		));
+	});
+}
+
+#[test]
+fn unlink_evm_address_should_fail_when_address_is_not_linked() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			EVMAccounts::unlink_evm_address(RuntimeOrigin::signed(ALICE)),
+			Error::<Test>::AddressNotLinked
+		);
+	});
+}
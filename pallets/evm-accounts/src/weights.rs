@@ -52,6 +52,8 @@ pub trait WeightInfo {
 	fn add_contract_deployer() -> Weight;
 	fn remove_contract_deployer() -> Weight;
 	fn renounce_contract_deployer() -> Weight;
+	fn link_evm_address() -> Weight;
+	fn unlink_evm_address() -> Weight;
 }
 
 pub struct HydraWeight<T>(PhantomData<T>);
@@ -103,6 +105,34 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 		// Minimum execution time: 13_153_000 picoseconds.
 		Weight::from_parts(13_380_000, 0).saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `EVMAccounts::AccountExtension` (r:1 w:0)
+	/// Proof: `EVMAccounts::AccountExtension` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::LinkedAccount` (r:1 w:1)
+	/// Proof: `EVMAccounts::LinkedAccount` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::LinkedAddress` (r:1 w:1)
+	/// Proof: `EVMAccounts::LinkedAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn link_evm_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3533`
+		// Minimum execution time: 61_215_000 picoseconds.
+		Weight::from_parts(61_742_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `EVMAccounts::LinkedAddress` (r:1 w:1)
+	/// Proof: `EVMAccounts::LinkedAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::LinkedAccount` (r:0 w:1)
+	/// Proof: `EVMAccounts::LinkedAccount` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn unlink_evm_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `169`
+		//  Estimated: `3533`
+		// Minimum execution time: 19_874_000 picoseconds.
+		Weight::from_parts(20_241_000, 3533)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
 
 // For backwards compatibility and tests
@@ -153,4 +183,32 @@ impl WeightInfo for () {
 		// Minimum execution time: 13_153_000 picoseconds.
 		Weight::from_parts(13_380_000, 0).saturating_add(RocksDbWeight::get().writes(1))
 	}
+	/// Storage: `EVMAccounts::AccountExtension` (r:1 w:0)
+	/// Proof: `EVMAccounts::AccountExtension` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::LinkedAccount` (r:1 w:1)
+	/// Proof: `EVMAccounts::LinkedAccount` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::LinkedAddress` (r:1 w:1)
+	/// Proof: `EVMAccounts::LinkedAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn link_evm_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `3533`
+		// Minimum execution time: 61_215_000 picoseconds.
+		Weight::from_parts(61_742_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `EVMAccounts::LinkedAddress` (r:1 w:1)
+	/// Proof: `EVMAccounts::LinkedAddress` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	/// Storage: `EVMAccounts::LinkedAccount` (r:0 w:1)
+	/// Proof: `EVMAccounts::LinkedAccount` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
+	fn unlink_evm_address() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `169`
+		//  Estimated: `3533`
+		// Minimum execution time: 19_874_000 picoseconds.
+		Weight::from_parts(20_241_000, 3533)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
 }
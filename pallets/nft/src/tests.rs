@@ -767,6 +767,39 @@ fn create_typed_collection_should_work_without_deposit_when_deposit_is_not_requi
 	});
 }
 
+#[test]
+fn create_typed_collection_should_work_for_concentrated_liquidity_positions() {
+	ExtBuilder.build().execute_with(|| {
+		assert_ok!(NFTPallet::create_typed_collection(
+			ACCOUNT_WITH_NO_BALANCE,
+			COLLECTION_ID_0,
+			CollectionType::ConcentratedLiquidity,
+			None,
+		));
+		assert_ok!(NFTPallet::mint_into(&COLLECTION_ID_0, &ITEM_ID_0, &BOB));
+
+		assert!(
+			<NFTPallet as Inspect<<Test as frame_system::Config>::AccountId>>::can_transfer(
+				&COLLECTION_ID_0,
+				&ITEM_ID_0
+			)
+		);
+		assert_noop!(
+			NFTPallet::create_collection(
+				RuntimeOrigin::signed(ALICE),
+				COLLECTION_ID_1,
+				CollectionType::ConcentratedLiquidity,
+				Default::default()
+			),
+			Error::<Test>::NotPermitted
+		);
+		assert_noop!(
+			NFTPallet::burn(RuntimeOrigin::signed(BOB), COLLECTION_ID_0, ITEM_ID_0),
+			Error::<Test>::NotPermitted
+		);
+	});
+}
+
 #[test]
 fn create_typed_collection_should_work_with_reserved_id() {
 	ExtBuilder.build().execute_with(|| {
@@ -49,6 +49,7 @@ pub enum CollectionType {
 	#[default]
 	Marketplace = 0_isize,
 	LiquidityMining = 1_isize,
+	ConcentratedLiquidity = 2_isize,
 }
 
 pub trait NftPermission<InnerCollectionType> {
@@ -76,7 +77,7 @@ impl NftPermission<CollectionType> for NftPermissions {
 	fn can_transfer(collection_type: &CollectionType) -> bool {
 		matches!(
 			*collection_type,
-			CollectionType::Marketplace | CollectionType::LiquidityMining
+			CollectionType::Marketplace | CollectionType::LiquidityMining | CollectionType::ConcentratedLiquidity
 		)
 	}
 
@@ -252,6 +252,7 @@ impl pallet_ema_oracle::Config for Test {
 	type SupportedPeriods = SupportedPeriods;
 	type OracleWhitelist = Everything;
 	type MaxUniqueEntries = ConstU32<20>;
+	type MaxTwapCheckpoints = ConstU32<100>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type WeightInfo = ();
@@ -295,6 +296,10 @@ impl pallet_omnipool::Config for Test {
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type ListingPriceOracle = ();
+	type ListingBond = ConstU128<0>;
+	type ListingChallengePeriod = ConstU64<0>;
+	type ListingWeightCap = AssetWeightCap;
 }
 
 pub struct ExtBuilder {
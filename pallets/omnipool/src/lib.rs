@@ -74,13 +74,37 @@
 //!
 //! * `add_token` - Adds token to the pool. Initial liquidity must be transffered to pool account prior to calling add_token.
 //! * `add_liquidity` - Adds liquidity of selected asset to the pool. Mints corresponding position NFT.
+//! * `add_liquidity_with_limit` - Same as `add_liquidity` with minimum amount of shares received.
 //! * `remove_liquidity` - Removes liquidity of selected position from the pool. Partial withdrawals are allowed.
+//! * `remove_liquidity_with_limit` - Same as `remove_liquidity` with minimum amounts of asset and hub asset received.
 //! * `sell` - Trades an asset in for asset out by selling given amount of asset in.
 //! * `buy` - Trades an asset in for asset out by buying given amount of asset out.
 //! * `set_asset_tradable_state` - Updates asset's tradable state with new flags. This allows/forbids asset operation such SELL,BUY,ADD or  REMOVE liquidtityy.
 //! * `refund_refused_asset` - Refunds the initial liquidity amount sent to pool account prior to add_token if the token has been refused to be added.
 //! * `sacrifice_position` - Destroys a position and position's shares become protocol's shares.
 //! * `withdraw_protocol_liquidity` - Withdraws protocol's liquidity from the pool. Used to withdraw liquidity from sacrificed position.
+//! * `remove_token` - Removes frozen token from the pool once all its shares are owned by protocol.
+//! * `propose_listing` - Proposes permissionless listing of a token. Initial liquidity and bond are transferred to listing account.
+//! * `veto_listing` - Rejects proposed listing during its challenge period.
+//! * `finalize_listing` - Adds token of proposed listing to the pool once its challenge period is over.
+//! * `merge_positions` - Merges two positions of the same asset into one. Price of the merged position is share-weighted average of the positions' prices.
+//! * `split_position` - Moves part of the position's shares into a new position with the same price.
+//!
+//! ### Permissionless listing
+//!
+//! Any account can propose a token to be listed by providing its initial liquidity and a bond in native asset.
+//! The listing can be vetoed by `TechnicalOrigin` until the end of the challenge period, in which case
+//! the liquidity is returned to proposer and the bond is either returned or slashed.
+//!
+//! After the challenge period, anyone can finalize the listing. The initial price is derived from the listing
+//! price oracle ( eg. XYK pool oracle ) and the token is added with `ListingWeightCap` weight cap.
+//! `AuthorityOrigin` can add the token by `add_token` any time, which cancels the listing and returns
+//! the liquidity and the bond to proposer.
+//! If liquidity of the token in the oracle source is lower than the initial liquidity, the token can only be bought
+//! and its liquidity removed until `TechnicalOrigin` updates its tradable state.
+//!
+//! Listed token is removed the same way as any other token - frozen and, once the positions are sacrificed, removed
+//! by `remove_token`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -89,7 +113,7 @@ use frame_support::require_transactional;
 use frame_support::PalletId;
 use frame_support::{ensure, transactional};
 use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, One};
-use sp_runtime::traits::{CheckedAdd, CheckedSub, Zero};
+use sp_runtime::traits::{CheckedAdd, CheckedMul, CheckedSub, Zero};
 use sp_std::ops::{Add, Sub};
 use sp_std::prelude::*;
 
@@ -109,8 +133,9 @@ pub mod traits;
 pub mod types;
 pub mod weights;
 
-use crate::traits::{AssetInfo, OmnipoolHooks};
-use crate::types::{AssetReserveState, AssetState, Balance, Position, SimpleImbalance, Tradability};
+use crate::traits::{AssetInfo, ListingPriceProvider, OmnipoolHooks};
+use crate::types::{AssetReserveState, AssetState, Balance, Listing, Position, Price, SimpleImbalance, Tradability};
+use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -121,8 +146,8 @@ pub type NFTCollectionIdOf<T> =
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use crate::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks, ShouldAllow};
-	use crate::types::{Position, Price, Tradability};
+	use crate::traits::{AssetInfo, ExternalPriceProvider, ListingPriceProvider, OmnipoolHooks, ShouldAllow};
+	use crate::types::{Listing, Position, Price, Tradability};
 	use codec::HasCompact;
 	use frame_support::pallet_prelude::*;
 	use frame_support::traits::DefensiveOption;
@@ -130,6 +155,7 @@ pub mod pallet {
 	use hydra_dx_math::ema::EmaPrice;
 	use hydra_dx_math::omnipool::types::{BalanceUpdate, I129};
 	use orml_traits::GetByKey;
+	use sp_runtime::traits::Saturating;
 	use sp_runtime::ArithmeticError;
 
 	#[pallet::pallet]
@@ -225,6 +251,21 @@ pub mod pallet {
 
 		/// Oracle price provider. Provides price for given asset. Used in remove liquidity to support calculation of dynamic withdrawal fee.
 		type ExternalPriceOracle: ExternalPriceProvider<Self::AssetId, EmaPrice, Error = DispatchError>;
+
+		/// Price provider for permissionless listing. Provides price of an asset in native asset and its liquidity.
+		type ListingPriceOracle: ListingPriceProvider<Self::AssetId, Balance, EmaPrice>;
+
+		/// Bond in native asset required to propose a listing.
+		#[pallet::constant]
+		type ListingBond: Get<Balance>;
+
+		/// Number of blocks during which a proposed listing can be vetoed.
+		#[pallet::constant]
+		type ListingChallengePeriod: Get<BlockNumberFor<Self>>;
+
+		/// Weight cap of an asset added by permissionless listing.
+		#[pallet::constant]
+		type ListingWeightCap: Get<Permill>;
 	}
 
 	#[pallet::storage]
@@ -259,6 +300,12 @@ pub mod pallet {
 	/// Position ids sequencer
 	pub(super) type NextPositionId<T: Config> = StorageValue<_, T::PositionItemId, ValueQuery>;
 
+	#[pallet::storage]
+	/// Proposed permissionless listings.
+	#[pallet::getter(fn listings)]
+	pub(super) type Listings<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, Listing<T::AccountId, BlockNumberFor<T>>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -356,6 +403,29 @@ pub mod pallet {
 
 		/// Asset's weight cap has been updated.
 		AssetWeightCapUpdated { asset_id: T::AssetId, cap: Permill },
+
+		/// Permissionless listing of an asset has been proposed.
+		ListingProposed {
+			asset_id: T::AssetId,
+			proposer: T::AccountId,
+			amount: Balance,
+			bond: Balance,
+			challenge_period_end: BlockNumberFor<T>,
+		},
+
+		/// Proposed listing has been vetoed.
+		ListingVetoed {
+			asset_id: T::AssetId,
+			proposer: T::AccountId,
+			bond_slashed: bool,
+		},
+
+		/// Proposed listing has been finalized and asset added to Omnipool.
+		ListingFinalized {
+			asset_id: T::AssetId,
+			proposer: T::AccountId,
+			tradable: Tradability,
+		},
 	}
 
 	#[pallet::error]
@@ -423,6 +493,20 @@ pub mod pallet {
 		ZeroAmountOut,
 		/// Existential deposit of asset is not available.
 		ExistentialDepositNotAvailable,
+		/// Slippage protection - amount of shares or assets is below the provided limit.
+		SlippageLimit,
+		/// Listing of the asset has already been proposed.
+		ListingAlreadyProposed,
+		/// Listing of the asset has not been found.
+		ListingNotFound,
+		/// Challenge period of the listing is not over yet.
+		ChallengePeriodNotOver,
+		/// Challenge period of the listing is over.
+		ChallengePeriodOver,
+		/// Listing price oracle does not provide price of the asset or the price is not stable.
+		ListingPriceNotAvailable,
+		/// Only different positions of the same asset can be merged.
+		PositionsNotMergeable,
 	}
 
 	#[pallet::call]
@@ -441,10 +525,15 @@ pub mod pallet {
 		/// - `position_owner`: account id for which share are distributed in form on NFT
 		/// - `weight_cap`: asset weight cap
 		///
+		/// If there is a proposed listing of the asset, the listing is cancelled and its initial liquidity and bond
+		/// are returned to the proposer.
+		///
 		/// Emits `TokenAdded` event when successful.
 		///
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::add_token().saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()))]
+		#[pallet::weight(<T as Config>::WeightInfo::add_token()
+			.saturating_add(<T as Config>::WeightInfo::veto_listing())
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()))]
 		#[transactional]
 		pub fn add_token(
 			origin: OriginFor<T>,
@@ -455,99 +544,21 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::AuthorityOrigin::ensure_origin(origin.clone())?;
 
-			ensure!(!Assets::<T>::contains_key(asset), Error::<T>::AssetAlreadyAdded);
-
-			ensure!(T::AssetRegistry::exists(asset), Error::<T>::AssetNotRegistered);
-
-			ensure!(initial_price > FixedU128::zero(), Error::<T>::InvalidInitialAssetPrice);
-
-			// ensure collection is created, we can simply ignore the error if it was already created.
-			let _ = T::NFTHandler::create_collection(
-				&T::NFTCollectionId::get(),
-				&Self::protocol_account(),
-				&Self::protocol_account(),
-			);
+			if let Some(listing) = Listings::<T>::take(asset) {
+				Self::cancel_listing(asset, listing, false)?;
+			}
 
 			let amount = T::Currency::free_balance(asset, &Self::protocol_account());
 
-			let ed = T::AssetRegistry::existential_deposit(asset).ok_or(Error::<T>::ExistentialDepositNotAvailable)?;
-			let minimum_pool_liquidity = ed.saturating_mul(20);
-
-			ensure!(ed > 0 && amount >= minimum_pool_liquidity, Error::<T>::MissingBalance);
-
-			let hub_reserve = initial_price.checked_mul_int(amount).ok_or(ArithmeticError::Overflow)?;
-
-			// Initial state of asset
-			let state = AssetState::<Balance> {
-				hub_reserve,
-				shares: amount,
-				protocol_shares: Balance::zero(),
-				cap: FixedU128::from(weight_cap).into_inner(),
-				tradable: Tradability::default(),
-			};
-
-			let lp_position = Position::<Balance, T::AssetId> {
-				asset_id: asset,
-				amount,
-				shares: amount,
-				price: (initial_price.into_inner(), FixedU128::DIV),
-			};
-
-			let instance_id = Self::create_and_mint_position_instance(&position_owner)?;
-
-			<Positions<T>>::insert(instance_id, lp_position);
-
-			Self::deposit_event(Event::PositionCreated {
-				position_id: instance_id,
-				owner: position_owner,
+			Self::do_add_token(
+				origin,
 				asset,
+				initial_price,
+				weight_cap,
+				Tradability::default(),
+				position_owner,
 				amount,
-				shares: amount,
-				price: initial_price,
-			});
-
-			let current_imbalance = <HubAssetImbalance<T>>::get();
-			let current_hub_asset_liquidity =
-				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
-
-			let delta_imbalance = hydra_dx_math::omnipool::calculate_delta_imbalance(
-				hub_reserve,
-				I129 {
-					value: current_imbalance.value,
-					negative: current_imbalance.negative,
-				},
-				current_hub_asset_liquidity,
 			)
-			.ok_or(ArithmeticError::Overflow)?;
-
-			Self::update_imbalance(BalanceUpdate::Decrease(delta_imbalance))?;
-
-			let delta_hub_reserve = BalanceUpdate::Increase(hub_reserve);
-			Self::update_hub_asset_liquidity(&delta_hub_reserve)?;
-
-			let reserve = T::Currency::free_balance(asset, &Self::protocol_account());
-
-			let reserve_state: AssetReserveState<_> = (state.clone(), reserve).into();
-			let changes = AssetStateChange {
-				delta_hub_reserve,
-				delta_reserve: BalanceUpdate::Increase(reserve),
-				delta_shares: BalanceUpdate::Increase(amount),
-				delta_protocol_shares: BalanceUpdate::Increase(Balance::zero()),
-			};
-			T::OmnipoolHooks::on_liquidity_changed(
-				origin,
-				AssetInfo::new(asset, &AssetReserveState::default(), &reserve_state, &changes, false),
-			)?;
-
-			<Assets<T>>::insert(asset, state);
-
-			Self::deposit_event(Event::TokenAdded {
-				asset_id: asset,
-				initial_amount: amount,
-				initial_price,
-			});
-
-			Ok(())
 		}
 
 		/// Add liquidity of asset `asset` in quantity `amount` to Omnipool
@@ -577,6 +588,33 @@ pub mod pallet {
 		)]
 		#[transactional]
 		pub fn add_liquidity(origin: OriginFor<T>, asset: T::AssetId, amount: Balance) -> DispatchResult {
+			Self::add_liquidity_with_limit(origin, asset, amount, Balance::zero())
+		}
+
+		/// Add liquidity of asset `asset` in quantity `amount` to Omnipool with slippage protection.
+		///
+		/// Same as `add_liquidity` but fails with `SlippageLimit` error if the amount of shares
+		/// the position receives is lower than `min_shares_limit`.
+		///
+		/// Parameters:
+		/// - `asset`: The identifier of the new asset added to the pool. Must be already in the pool
+		/// - `amount`: Amount of asset added to omnipool
+		/// - `min_shares_limit`: Minimum amount of shares to receive
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		///
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()
+			.saturating_add(T::ExternalPriceOracle::get_price_weight()))
+		)]
+		#[transactional]
+		pub fn add_liquidity_with_limit(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			amount: Balance,
+			min_shares_limit: Balance,
+		) -> DispatchResult {
 			let who = ensure_signed(origin.clone())?;
 
 			ensure!(
@@ -622,6 +660,11 @@ pub mod pallet {
 			)
 			.ok_or(ArithmeticError::Overflow)?;
 
+			ensure!(
+				*state_changes.asset.delta_shares >= min_shares_limit,
+				Error::<T>::SlippageLimit
+			);
+
 			let new_asset_state = asset_state
 				.clone()
 				.delta_update(&state_changes.asset)
@@ -719,6 +762,32 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			position_id: T::PositionItemId,
 			amount: Balance,
+		) -> DispatchResult {
+			Self::remove_liquidity_with_limit(origin, position_id, amount, Balance::zero(), Balance::zero())
+		}
+
+		/// Remove liquidity of asset `asset` in quantity `amount` from Omnipool with slippage protection.
+		///
+		/// Same as `remove_liquidity` but fails with `SlippageLimit` error if the amount of asset
+		/// received is lower than `min_limit` or the amount of hub asset received is lower than `min_hub_limit`.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which liquidity is removed from.
+		/// - `amount`: Amount of shares removed from omnipool
+		/// - `min_limit`: Minimum amount of asset to receive
+		/// - `min_hub_limit`: Minimum amount of hub asset to receive
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		///
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity().saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn remove_liquidity_with_limit(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			amount: Balance,
+			min_limit: Balance,
+			min_hub_limit: Balance,
 		) -> DispatchResult {
 			let who = ensure_signed(origin.clone())?;
 
@@ -785,6 +854,13 @@ pub mod pallet {
 			)
 			.ok_or(ArithmeticError::Overflow)?;
 
+			ensure!(
+				*state_changes.asset.delta_reserve >= min_limit,
+				Error::<T>::SlippageLimit
+			);
+
+			ensure!(state_changes.lp_hub_amount >= min_hub_limit, Error::<T>::SlippageLimit);
+
 			let new_asset_state = asset_state
 				.clone()
 				.delta_update(&state_changes.asset)
@@ -1574,6 +1650,323 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Propose permissionless listing of an asset.
+		///
+		/// Initial liquidity `amount` of the asset and `ListingBond` of native asset are transferred
+		/// from origin to the listing account.
+		///
+		/// Listing price oracle must provide price of the asset, otherwise `ListingPriceNotAvailable` error is returned.
+		///
+		/// The listing can be vetoed by `TechnicalOrigin` within `ListingChallengePeriod` blocks.
+		///
+		/// Parameters:
+		/// - `origin`: signed origin of the proposer
+		/// - `asset`: The identifier of the asset. Must be registered in Asset registry
+		/// - `amount`: initial liquidity of the asset
+		///
+		/// Emits `ListingProposed` event when successful.
+		///
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::propose_listing()
+			.saturating_add(T::ListingPriceOracle::get_price_and_liquidity_weight()))]
+		#[transactional]
+		pub fn propose_listing(origin: OriginFor<T>, asset: T::AssetId, amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(asset != T::HubAssetId::get(), Error::<T>::NotAllowed);
+			ensure!(!Assets::<T>::contains_key(asset), Error::<T>::AssetAlreadyAdded);
+			ensure!(!Listings::<T>::contains_key(asset), Error::<T>::ListingAlreadyProposed);
+			ensure!(T::AssetRegistry::exists(asset), Error::<T>::AssetNotRegistered);
+
+			let ed = T::AssetRegistry::existential_deposit(asset).ok_or(Error::<T>::ExistentialDepositNotAvailable)?;
+			ensure!(
+				ed > 0 && amount >= ed.saturating_mul(20),
+				Error::<T>::InsufficientLiquidity
+			);
+
+			// Fail early if the asset cannot be priced
+			Self::listing_price_and_tradability(asset, amount)?;
+
+			let bond = T::ListingBond::get();
+			T::Currency::transfer(asset, &who, &Self::listing_account(), amount)?;
+			T::Currency::transfer(T::HdxAssetId::get(), &who, &Self::listing_account(), bond)?;
+
+			let challenge_period_end =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::ListingChallengePeriod::get());
+
+			Listings::<T>::insert(
+				asset,
+				Listing {
+					proposer: who.clone(),
+					amount,
+					bond,
+					challenge_period_end,
+				},
+			);
+
+			Self::deposit_event(Event::ListingProposed {
+				asset_id: asset,
+				proposer: who,
+				amount,
+				bond,
+				challenge_period_end,
+			});
+
+			Ok(())
+		}
+
+		/// Veto proposed listing of an asset.
+		///
+		/// Initial liquidity is returned to the proposer. Bond is burned if `slash_bond` is true,
+		/// otherwise it is returned to the proposer as well.
+		///
+		/// Listing can be vetoed only until the end of its challenge period.
+		///
+		/// Only `TechnicalOrigin` can perform this call.
+		///
+		/// Parameters:
+		/// - `asset`: The identifier of the asset of the listing
+		/// - `slash_bond`: whether bond of the proposer should be slashed
+		///
+		/// Emits `ListingVetoed` event when successful.
+		///
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::veto_listing())]
+		#[transactional]
+		pub fn veto_listing(origin: OriginFor<T>, asset: T::AssetId, slash_bond: bool) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			let listing = Listings::<T>::take(asset).ok_or(Error::<T>::ListingNotFound)?;
+
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= listing.challenge_period_end,
+				Error::<T>::ChallengePeriodOver
+			);
+
+			Self::cancel_listing(asset, listing, slash_bond)
+		}
+
+		/// Finalize proposed listing of an asset after its challenge period.
+		///
+		/// Asset is added to Omnipool at price provided by listing price oracle with `ListingWeightCap` weight cap.
+		/// Position NFT token is minted for the proposer and bond is returned.
+		///
+		/// If liquidity of the asset in the oracle source is lower than the initial liquidity, asset is allowed only to be
+		/// bought and its liquidity removed.
+		///
+		/// Can be called by any signed origin.
+		///
+		/// Parameters:
+		/// - `asset`: The identifier of the asset of the listing
+		///
+		/// Emits `ListingFinalized` and `TokenAdded` events when successful.
+		///
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::finalize_listing()
+			.saturating_add(T::ListingPriceOracle::get_price_and_liquidity_weight())
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn finalize_listing(origin: OriginFor<T>, asset: T::AssetId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let listing = Listings::<T>::take(asset).ok_or(Error::<T>::ListingNotFound)?;
+
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > listing.challenge_period_end,
+				Error::<T>::ChallengePeriodNotOver
+			);
+
+			let (initial_price, tradable) = Self::listing_price_and_tradability(asset, listing.amount)?;
+
+			T::Currency::transfer(
+				asset,
+				&Self::listing_account(),
+				&Self::protocol_account(),
+				listing.amount,
+			)?;
+			T::Currency::transfer(
+				T::HdxAssetId::get(),
+				&Self::listing_account(),
+				&listing.proposer,
+				listing.bond,
+			)?;
+
+			// Token is added on behalf of the protocol, the same way as by `add_token`, so that liquidity limits
+			// of hooks are not applied to the initial liquidity.
+			Self::do_add_token(
+				frame_system::RawOrigin::Root.into(),
+				asset,
+				initial_price,
+				T::ListingWeightCap::get(),
+				tradable,
+				listing.proposer.clone(),
+				listing.amount,
+			)?;
+
+			Self::deposit_event(Event::ListingFinalized {
+				asset_id: asset,
+				proposer: listing.proposer,
+				tradable,
+			});
+
+			Ok(())
+		}
+
+		/// Merge two LP positions of the same asset into one.
+		///
+		/// Shares and amounts of the positions are added up. Price of the merged position is share-weighted
+		/// average of the positions' prices, rounded in favor of the pool.
+		///
+		/// `other_position_id` is destroyed and its NFT is burned.
+		///
+		/// Only owner of both positions can perform this action.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which is kept and updated.
+		/// - `other_position_id`: The identifier of position which is merged into `position_id`.
+		///
+		/// Emits `PositionUpdated` and `PositionDestroyed` events when successful.
+		///
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::merge_positions())]
+		#[transactional]
+		pub fn merge_positions(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			other_position_id: T::PositionItemId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(position_id != other_position_id, Error::<T>::PositionsNotMergeable);
+
+			let position = Self::load_position(position_id, who.clone())?;
+			let other_position = Self::load_position(other_position_id, who.clone())?;
+
+			ensure!(
+				position.asset_id == other_position.asset_id,
+				Error::<T>::PositionsNotMergeable
+			);
+
+			let price = hydra_dx_math::omnipool::calculate_merged_position_price(
+				&(&position).into(),
+				&(&other_position).into(),
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			let merged_position = Position::<Balance, T::AssetId> {
+				asset_id: position.asset_id,
+				amount: position
+					.amount
+					.checked_add(other_position.amount)
+					.ok_or(ArithmeticError::Overflow)?,
+				shares: position
+					.shares
+					.checked_add(other_position.shares)
+					.ok_or(ArithmeticError::Overflow)?,
+				price,
+			};
+
+			<Positions<T>>::insert(position_id, &merged_position);
+
+			<Positions<T>>::remove(other_position_id);
+			T::NFTHandler::burn(&T::NFTCollectionId::get(), &other_position_id, Some(&who))?;
+
+			Self::deposit_event(Event::PositionUpdated {
+				position_id,
+				owner: who.clone(),
+				asset: merged_position.asset_id,
+				amount: merged_position.amount,
+				shares: merged_position.shares,
+				price: merged_position
+					.price_from_rational()
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			});
+
+			Self::deposit_event(Event::PositionDestroyed {
+				position_id: other_position_id,
+				owner: who,
+			});
+
+			Ok(())
+		}
+
+		/// Split `shares` of an LP position into a new position.
+		///
+		/// New position has the same price as the original position. Amount of the position is divided
+		/// proportionally to the shares, rounded down for the new position.
+		///
+		/// Only owner of position can perform this action.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which is split.
+		/// - `shares`: Amount of shares moved to the new position. Must be lower than shares of the position.
+		///
+		/// Amount of both resulting positions must be at least `MinimumPoolLiquidity`.
+		///
+		/// Emits `PositionUpdated` and `PositionCreated` events when successful.
+		///
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::split_position())]
+		#[transactional]
+		pub fn split_position(origin: OriginFor<T>, position_id: T::PositionItemId, shares: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(shares > Balance::zero(), Error::<T>::InvalidSharesAmount);
+
+			let position = Self::load_position(position_id, who.clone())?;
+
+			ensure!(shares < position.shares, Error::<T>::InsufficientShares);
+
+			let amount = hydra_dx_math::omnipool::calculate_split_position_amount(&(&position).into(), shares)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			let updated_position = Position::<Balance, T::AssetId> {
+				asset_id: position.asset_id,
+				amount: position.amount.checked_sub(amount).ok_or(ArithmeticError::Underflow)?,
+				shares: position.shares.checked_sub(shares).ok_or(ArithmeticError::Underflow)?,
+				price: position.price,
+			};
+			let new_position = Position::<Balance, T::AssetId> {
+				asset_id: position.asset_id,
+				amount,
+				shares,
+				price: position.price,
+			};
+
+			ensure!(
+				updated_position.amount >= T::MinimumPoolLiquidity::get()
+					&& new_position.amount >= T::MinimumPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidity
+			);
+
+			let price = position.price_from_rational().ok_or(ArithmeticError::DivisionByZero)?;
+
+			<Positions<T>>::insert(position_id, &updated_position);
+
+			let new_position_id = Self::create_and_mint_position_instance(&who)?;
+			<Positions<T>>::insert(new_position_id, &new_position);
+
+			Self::deposit_event(Event::PositionUpdated {
+				position_id,
+				owner: who.clone(),
+				asset: updated_position.asset_id,
+				amount: updated_position.amount,
+				shares: updated_position.shares,
+				price,
+			});
+
+			Self::deposit_event(Event::PositionCreated {
+				position_id: new_position_id,
+				owner: who,
+				asset: new_position.asset_id,
+				amount: new_position.amount,
+				shares: new_position.shares,
+				price,
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -1601,6 +1994,181 @@ impl<T: Config> Pallet<T> {
 		PalletId(*b"omnipool").into_account_truncating()
 	}
 
+	/// Add new token to omnipool with initial liquidity of pool's account balance of the token.
+	///
+	/// Position of `amount` is minted to `position_owner`. Shares of any balance above `amount` are owned by protocol.
+	#[require_transactional]
+	fn do_add_token(
+		origin: T::RuntimeOrigin,
+		asset: T::AssetId,
+		initial_price: Price,
+		weight_cap: Permill,
+		tradable: Tradability,
+		position_owner: T::AccountId,
+		amount: Balance,
+	) -> DispatchResult {
+		ensure!(!Assets::<T>::contains_key(asset), Error::<T>::AssetAlreadyAdded);
+
+		ensure!(T::AssetRegistry::exists(asset), Error::<T>::AssetNotRegistered);
+
+		ensure!(initial_price > FixedU128::zero(), Error::<T>::InvalidInitialAssetPrice);
+
+		// ensure collection is created, we can simply ignore the error if it was already created.
+		let _ = T::NFTHandler::create_collection(
+			&T::NFTCollectionId::get(),
+			&Self::protocol_account(),
+			&Self::protocol_account(),
+		);
+
+		let reserve = T::Currency::free_balance(asset, &Self::protocol_account());
+
+		let ed = T::AssetRegistry::existential_deposit(asset).ok_or(Error::<T>::ExistentialDepositNotAvailable)?;
+		let minimum_pool_liquidity = ed.saturating_mul(20);
+
+		ensure!(
+			ed > 0 && amount >= minimum_pool_liquidity && amount <= reserve,
+			Error::<T>::MissingBalance
+		);
+
+		let hub_reserve = initial_price
+			.checked_mul_int(reserve)
+			.ok_or(ArithmeticError::Overflow)?;
+		let protocol_shares = reserve.saturating_sub(amount);
+
+		// Initial state of asset
+		let state = AssetState::<Balance> {
+			hub_reserve,
+			shares: reserve,
+			protocol_shares,
+			cap: FixedU128::from(weight_cap).into_inner(),
+			tradable,
+		};
+
+		let lp_position = Position::<Balance, T::AssetId> {
+			asset_id: asset,
+			amount,
+			shares: amount,
+			price: (initial_price.into_inner(), FixedU128::DIV),
+		};
+
+		let instance_id = Self::create_and_mint_position_instance(&position_owner)?;
+
+		<Positions<T>>::insert(instance_id, lp_position);
+
+		Self::deposit_event(Event::PositionCreated {
+			position_id: instance_id,
+			owner: position_owner,
+			asset,
+			amount,
+			shares: amount,
+			price: initial_price,
+		});
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+		let current_hub_asset_liquidity = T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
+
+		let delta_imbalance = hydra_dx_math::omnipool::calculate_delta_imbalance(
+			hub_reserve,
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			current_hub_asset_liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Self::update_imbalance(BalanceUpdate::Decrease(delta_imbalance))?;
+
+		let delta_hub_reserve = BalanceUpdate::Increase(hub_reserve);
+		Self::update_hub_asset_liquidity(&delta_hub_reserve)?;
+
+		let reserve_state: AssetReserveState<_> = (state.clone(), reserve).into();
+		let changes = AssetStateChange {
+			delta_hub_reserve,
+			delta_reserve: BalanceUpdate::Increase(reserve),
+			delta_shares: BalanceUpdate::Increase(reserve),
+			delta_protocol_shares: BalanceUpdate::Increase(protocol_shares),
+		};
+		T::OmnipoolHooks::on_liquidity_changed(
+			origin,
+			AssetInfo::new(asset, &AssetReserveState::default(), &reserve_state, &changes, false),
+		)?;
+
+		<Assets<T>>::insert(asset, state);
+
+		Self::deposit_event(Event::TokenAdded {
+			asset_id: asset,
+			initial_amount: reserve,
+			initial_price,
+		});
+
+		Ok(())
+	}
+
+	/// Return initial liquidity of a listing to its proposer. Bond is burned if `slash_bond` is true,
+	/// otherwise it is returned to the proposer as well.
+	fn cancel_listing(
+		asset: T::AssetId,
+		listing: Listing<T::AccountId, BlockNumberFor<T>>,
+		slash_bond: bool,
+	) -> DispatchResult {
+		T::Currency::transfer(asset, &Self::listing_account(), &listing.proposer, listing.amount)?;
+
+		if slash_bond {
+			T::Currency::withdraw(T::HdxAssetId::get(), &Self::listing_account(), listing.bond)?;
+		} else {
+			T::Currency::transfer(
+				T::HdxAssetId::get(),
+				&Self::listing_account(),
+				&listing.proposer,
+				listing.bond,
+			)?;
+		}
+
+		Self::deposit_event(Event::ListingVetoed {
+			asset_id: asset,
+			proposer: listing.proposer,
+			bond_slashed: slash_bond,
+		});
+
+		Ok(())
+	}
+
+	/// Account which holds initial liquidity and bonds of proposed listings
+	pub fn listing_account() -> T::AccountId {
+		PalletId(*b"omnipool").into_sub_account_truncating(b"listing")
+	}
+
+	/// Calculate initial price of listed asset in hub asset and its tradable state.
+	///
+	/// Price of the asset in native asset is provided by listing price oracle and converted to hub asset
+	/// using current price of native asset in Omnipool.
+	fn listing_price_and_tradability(
+		asset: T::AssetId,
+		amount: Balance,
+	) -> Result<(Price, Tradability), DispatchError> {
+		let (oracle_price, liquidity) = T::ListingPriceOracle::get_price_and_liquidity(asset, T::HdxAssetId::get())
+			.map_err(|_| Error::<T>::ListingPriceNotAvailable)?;
+
+		let hdx_price = Self::load_asset_state(T::HdxAssetId::get())?
+			.price()
+			.ok_or(Error::<T>::InvalidOraclePrice)?;
+
+		let price = FixedU128::checked_from_rational(oracle_price.n, oracle_price.d)
+			.and_then(|p| p.checked_mul(&hdx_price))
+			.ok_or(Error::<T>::InvalidOraclePrice)?;
+
+		ensure!(price > FixedU128::zero(), Error::<T>::InvalidInitialAssetPrice);
+
+		let tradable = if liquidity >= amount {
+			Tradability::default()
+		} else {
+			Tradability::BUY | Tradability::REMOVE_LIQUIDITY
+		};
+
+		Ok((price, tradable))
+	}
+
 	/// Retrieve state of asset from the pool and its pool balance
 	pub fn load_asset_state(asset_id: T::AssetId) -> Result<AssetReserveState<Balance>, DispatchError> {
 		let state = <Assets<T>>::get(asset_id).ok_or(Error::<T>::AssetNotFound)?;
@@ -2005,6 +2573,28 @@ impl<T: Config> Pallet<T> {
 		Assets::<T>::contains_key(asset)
 	}
 
+	/// Update tradable state of an asset without origin checks.
+	///
+	/// Used by other pallets (e.g. circuit breaker) to disable or enable operations of an asset.
+	/// Emits `TradableStateUpdated` event if the state was changed.
+	pub fn update_asset_tradable_state(asset_id: T::AssetId, f: impl FnOnce(&mut Tradability)) -> DispatchResult {
+		Assets::<T>::try_mutate(asset_id, |maybe_asset| -> DispatchResult {
+			let asset_state = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+
+			let previous_state = asset_state.tradable;
+			f(&mut asset_state.tradable);
+
+			if asset_state.tradable != previous_state {
+				Self::deposit_event(Event::TradableStateUpdated {
+					asset_id,
+					state: asset_state.tradable,
+				});
+			}
+
+			Ok(())
+		})
+	}
+
 	/// Calls `on_trade_fee` hook and ensures that no more than the fee amount is transferred.
 	fn process_trade_fee(trader: &T::AccountId, asset: T::AssetId, amount: Balance) -> DispatchResult {
 		let account = Self::protocol_account();
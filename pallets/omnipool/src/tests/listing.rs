@@ -0,0 +1,310 @@
+use super::*;
+use crate::types::Listing;
+use frame_support::assert_noop;
+use hydra_dx_math::ema::EmaPrice;
+
+const TOKEN: AssetId = 1_000;
+
+fn listing_ext(oracle_liquidity: Balance) -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_registered_asset(TOKEN)
+		.add_endowed_accounts((LP1, TOKEN, 5000 * ONE))
+		.add_endowed_accounts((LP1, HDX, 1000 * ONE))
+		.with_listing_oracle(TOKEN, EmaPrice::new(65, 100), oracle_liquidity)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+}
+
+#[test]
+fn propose_listing_should_transfer_liquidity_and_bond_to_listing_account() {
+	listing_ext(10_000 * ONE).execute_with(|| {
+		assert_ok!(Omnipool::propose_listing(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE));
+
+		assert_balance!(LP1, TOKEN, 3000 * ONE);
+		assert_balance!(LP1, HDX, 900 * ONE);
+		assert_balance!(Omnipool::listing_account(), TOKEN, 2000 * ONE);
+		assert_balance!(Omnipool::listing_account(), HDX, ListingBond::get());
+
+		assert_eq!(
+			Omnipool::listings(TOKEN),
+			Some(Listing {
+				proposer: LP1,
+				amount: 2000 * ONE,
+				bond: ListingBond::get(),
+				challenge_period_end: 1 + LISTING_CHALLENGE_PERIOD,
+			})
+		);
+
+		expect_events(vec![Event::ListingProposed {
+			asset_id: TOKEN,
+			proposer: LP1,
+			amount: 2000 * ONE,
+			bond: ListingBond::get(),
+			challenge_period_end: 1 + LISTING_CHALLENGE_PERIOD,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn propose_listing_should_fail_when_oracle_price_is_not_available() {
+	ExtBuilder::default()
+		.with_registered_asset(TOKEN)
+		.add_endowed_accounts((LP1, TOKEN, 5000 * ONE))
+		.add_endowed_accounts((LP1, HDX, 1000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::propose_listing(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE),
+				Error::<Test>::ListingPriceNotAvailable
+			);
+		});
+}
+
+#[test]
+fn propose_listing_should_fail_when_asset_is_already_in_pool() {
+	listing_ext(10_000 * ONE).execute_with(|| {
+		assert_noop!(
+			Omnipool::propose_listing(RuntimeOrigin::signed(LP1), DAI, 2000 * ONE),
+			Error::<Test>::AssetAlreadyAdded
+		);
+	});
+}
+
+#[test]
+fn propose_listing_should_fail_when_listing_is_already_proposed() {
+	listing_ext(10_000 * ONE).execute_with(|| {
+		assert_ok!(Omnipool::propose_listing(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE));
+
+		assert_noop!(
+			Omnipool::propose_listing(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE),
+			Error::<Test>::ListingAlreadyProposed
+		);
+	});
+}
+
+#[test]
+fn add_token_should_cancel_listing_when_listing_is_proposed() {
+	listing_ext(10_000 * ONE).execute_with(|| {
+		assert_ok!(Omnipool::propose_listing(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE));
+
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(LP1),
+			Omnipool::protocol_account(),
+			TOKEN,
+			1000 * ONE
+		));
+
+		assert_ok!(Omnipool::add_token(
+			RuntimeOrigin::root(),
+			TOKEN,
+			FixedU128::from_float(0.65),
+			Permill::from_percent(100),
+			LP2
+		));
+
+		assert_balance!(LP1, TOKEN, 4000 * ONE);
+		assert_balance!(LP1, HDX, 1000 * ONE);
+		assert_balance!(Omnipool::listing_account(), TOKEN, 0);
+		assert_balance!(Omnipool::listing_account(), HDX, 0);
+		assert!(Omnipool::listings(TOKEN).is_none());
+		assert_eq!(Omnipool::assets(TOKEN).unwrap().shares, 1000 * ONE);
+
+		expect_events(vec![
+			Event::ListingVetoed {
+				asset_id: TOKEN,
+				proposer: LP1,
+				bond_slashed: false,
+			}
+			.into(),
+			Event::TokenAdded {
+				asset_id: TOKEN,
+				initial_amount: 1000 * ONE,
+				initial_price: FixedU128::from_float(0.65),
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn veto_listing_should_return_liquidity_and_bond_when_bond_is_not_slashed() {
+	listing_ext(10_000 * ONE).execute_with(|| {
+		assert_ok!(Omnipool::propose_listing(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE));
+
+		assert_ok!(Omnipool::veto_listing(RuntimeOrigin::root(), TOKEN, false));
+
+		assert_balance!(LP1, TOKEN, 5000 * ONE);
+		assert_balance!(LP1, HDX, 1000 * ONE);
+		assert!(Omnipool::listings(TOKEN).is_none());
+
+		expect_events(vec![Event::ListingVetoed {
+			asset_id: TOKEN,
+			proposer: LP1,
+			bond_slashed: false,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn veto_listing_should_burn_bond_when_bond_is_slashed() {
+	listing_ext(10_000 * ONE).execute_with(|| {
+		assert_ok!(Omnipool::propose_listing(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE));
+
+		assert_ok!(Omnipool::veto_listing(RuntimeOrigin::root(), TOKEN, true));
+
+		assert_balance!(LP1, TOKEN, 5000 * ONE);
+		assert_balance!(LP1, HDX, 900 * ONE);
+		assert_balance!(Omnipool::listing_account(), HDX, 0);
+	});
+}
+
+#[test]
+fn veto_listing_should_fail_when_origin_is_not_technical_origin() {
+	listing_ext(10_000 * ONE).execute_with(|| {
+		assert_ok!(Omnipool::propose_listing(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE));
+
+		assert_noop!(
+			Omnipool::veto_listing(RuntimeOrigin::signed(LP2), TOKEN, true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn veto_listing_should_fail_when_challenge_period_is_over() {
+	listing_ext(10_000 * ONE).execute_with(|| {
+		assert_ok!(Omnipool::propose_listing(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE));
+
+		System::set_block_number(2 + LISTING_CHALLENGE_PERIOD);
+
+		assert_noop!(
+			Omnipool::veto_listing(RuntimeOrigin::root(), TOKEN, true),
+			Error::<Test>::ChallengePeriodOver
+		);
+	});
+}
+
+#[test]
+fn finalize_listing_should_fail_when_challenge_period_is_not_over() {
+	listing_ext(10_000 * ONE).execute_with(|| {
+		assert_ok!(Omnipool::propose_listing(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE));
+
+		System::set_block_number(1 + LISTING_CHALLENGE_PERIOD);
+
+		assert_noop!(
+			Omnipool::finalize_listing(RuntimeOrigin::signed(LP2), TOKEN),
+			Error::<Test>::ChallengePeriodNotOver
+		);
+	});
+}
+
+#[test]
+fn finalize_listing_should_fail_when_listing_does_not_exist() {
+	listing_ext(10_000 * ONE).execute_with(|| {
+		assert_noop!(
+			Omnipool::finalize_listing(RuntimeOrigin::signed(LP2), TOKEN),
+			Error::<Test>::ListingNotFound
+		);
+	});
+}
+
+#[test]
+fn finalize_listing_should_add_token_at_oracle_price() {
+	listing_ext(10_000 * ONE).execute_with(|| {
+		assert_ok!(Omnipool::propose_listing(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE));
+
+		System::set_block_number(2 + LISTING_CHALLENGE_PERIOD);
+
+		let position_id = last_position_id();
+
+		assert_ok!(Omnipool::finalize_listing(RuntimeOrigin::signed(LP2), TOKEN));
+
+		assert_asset_state!(
+			TOKEN,
+			AssetReserveState {
+				reserve: 2000 * ONE,
+				hub_reserve: 1300 * ONE,
+				shares: 2000 * ONE,
+				protocol_shares: Balance::zero(),
+				cap: FixedU128::from(ListingWeightCap::get()).into_inner(),
+				tradable: Tradability::default(),
+			}
+		);
+
+		assert_eq!(get_mock_minted_position(position_id), Some(LP1));
+		assert_balance!(LP1, HDX, 1000 * ONE);
+		assert_balance!(Omnipool::listing_account(), TOKEN, 0);
+		assert!(Omnipool::listings(TOKEN).is_none());
+
+		expect_events(vec![
+			Event::TokenAdded {
+				asset_id: TOKEN,
+				initial_amount: 2000 * ONE,
+				initial_price: FixedU128::from_float(0.65),
+			}
+			.into(),
+			Event::ListingFinalized {
+				asset_id: TOKEN,
+				proposer: LP1,
+				tradable: Tradability::default(),
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn finalize_listing_should_mint_position_of_listed_amount_when_pool_account_holds_more_of_the_asset() {
+	listing_ext(10_000 * ONE).execute_with(|| {
+		assert_ok!(Omnipool::propose_listing(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE));
+
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(LP1),
+			Omnipool::protocol_account(),
+			TOKEN,
+			500 * ONE
+		));
+
+		System::set_block_number(2 + LISTING_CHALLENGE_PERIOD);
+
+		let position_id = last_position_id();
+
+		assert_ok!(Omnipool::finalize_listing(RuntimeOrigin::signed(LP2), TOKEN));
+
+		assert_asset_state!(
+			TOKEN,
+			AssetReserveState {
+				reserve: 2500 * ONE,
+				hub_reserve: 1625 * ONE,
+				shares: 2500 * ONE,
+				protocol_shares: 500 * ONE,
+				cap: FixedU128::from(ListingWeightCap::get()).into_inner(),
+				tradable: Tradability::default(),
+			}
+		);
+
+		let position = Positions::<Test>::get(position_id).unwrap();
+		assert_eq!(position.amount, 2000 * ONE);
+		assert_eq!(position.shares, 2000 * ONE);
+		assert_eq!(get_mock_minted_position(position_id), Some(LP1));
+	});
+}
+
+#[test]
+fn finalize_listing_should_restrict_tradability_when_oracle_liquidity_is_lower_than_listed_amount() {
+	listing_ext(1000 * ONE).execute_with(|| {
+		assert_ok!(Omnipool::propose_listing(RuntimeOrigin::signed(LP1), TOKEN, 2000 * ONE));
+
+		System::set_block_number(2 + LISTING_CHALLENGE_PERIOD);
+
+		assert_ok!(Omnipool::finalize_listing(RuntimeOrigin::signed(LP2), TOKEN));
+
+		assert_eq!(
+			Omnipool::assets(TOKEN).unwrap().tradable,
+			Tradability::BUY | Tradability::REMOVE_LIQUIDITY
+		);
+	});
+}
@@ -23,7 +23,7 @@ use std::collections::HashMap;
 
 use crate as pallet_omnipool;
 
-use crate::traits::ExternalPriceProvider;
+use crate::traits::{ExternalPriceProvider, ListingPriceProvider};
 use frame_support::traits::{ConstU128, Everything};
 use frame_support::weights::Weight;
 use frame_support::{
@@ -77,6 +77,7 @@ thread_local! {
 	pub static WITHDRAWAL_FEE: RefCell<Permill> = RefCell::new(Permill::from_percent(0));
 	pub static WITHDRAWAL_ADJUSTMENT: RefCell<(u32,u32, bool)> = RefCell::new((0u32,0u32, false));
 	pub static ON_TRADE_WITHDRAWAL: RefCell<Permill> = RefCell::new(Permill::from_percent(0));
+	pub static LISTING_ORACLE: RefCell<HashMap<AssetId, (EmaPrice, Balance)>> = RefCell::new(HashMap::default());
 }
 
 construct_runtime!(
@@ -172,8 +173,12 @@ parameter_types! {
 	pub MaxPriceDiff: Permill = MAX_PRICE_DIFF.with(|v| *v.borrow());
 	pub FourPercentDiff: Permill = Permill::from_percent(4);
 	pub MinWithdrawFee: Permill = WITHDRAWAL_FEE.with(|v| *v.borrow());
+	pub const ListingBond: Balance = 100 * ONE;
+	pub const ListingWeightCap: Permill = Permill::from_percent(5);
 }
 
+pub const LISTING_CHALLENGE_PERIOD: u64 = 10;
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type AssetId = AssetId;
@@ -200,6 +205,10 @@ impl Config for Test {
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type ListingPriceOracle = ListingOracle;
+	type ListingBond = ListingBond;
+	type ListingChallengePeriod = ConstU64<LISTING_CHALLENGE_PERIOD>;
+	type ListingWeightCap = ListingWeightCap;
 }
 
 pub struct ExtBuilder {
@@ -260,6 +269,9 @@ impl Default for ExtBuilder {
 		WITHDRAWAL_ADJUSTMENT.with(|v| {
 			*v.borrow_mut() = (0, 0, false);
 		});
+		LISTING_ORACLE.with(|v| {
+			v.borrow_mut().clear();
+		});
 
 		Self {
 			endowed_accounts: vec![
@@ -358,6 +370,13 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_listing_oracle(self, asset_id: AssetId, price: EmaPrice, liquidity: Balance) -> Self {
+		LISTING_ORACLE.with(|v| {
+			v.borrow_mut().insert(asset_id, (price, liquidity));
+		});
+		self
+	}
+
 	pub fn with_token(
 		mut self,
 		asset_id: AssetId,
@@ -624,6 +643,21 @@ impl ExternalPriceProvider<AssetId, EmaPrice> for WithdrawFeePriceOracle {
 	}
 }
 
+pub struct ListingOracle;
+
+impl ListingPriceProvider<AssetId, Balance, EmaPrice> for ListingOracle {
+	type Error = ();
+
+	fn get_price_and_liquidity(asset: AssetId, denomination: AssetId) -> Result<(EmaPrice, Balance), Self::Error> {
+		assert_eq!(denomination, HDX);
+		LISTING_ORACLE.with(|v| v.borrow().get(&asset).copied()).ok_or(())
+	}
+
+	fn get_price_and_liquidity_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 // Helper methods to work with Ema Price
 pub(super) fn round_to_rational((n, d): (U256, U256), rounding: Rounding) -> EmaPrice {
 	let shift = n.bits().max(d.bits()).saturating_sub(128);
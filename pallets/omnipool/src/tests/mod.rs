@@ -7,6 +7,7 @@ mod add_liquidity;
 mod add_token;
 mod buy;
 mod invariants;
+mod listing;
 mod remove_liquidity;
 mod sell;
 
@@ -122,3 +122,454 @@ fn sacrifice_position_should_emit_event_when_succesful() {
 			);
 		});
 }
+
+#[test]
+fn merge_positions_should_work_when_positions_have_same_price() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange - create two positions
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 200 * ONE));
+
+			// Act
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				other_position_id
+			));
+
+			// Assert
+			assert_eq!(
+				Positions::<Test>::get(position_id).unwrap(),
+				Position {
+					asset_id,
+					amount: 600 * ONE,
+					shares: 600 * ONE,
+					price: (1560 * ONE, 2400 * ONE),
+				}
+			);
+			assert!(Positions::<Test>::get(other_position_id).is_none());
+			assert_eq!(get_mock_minted_position(position_id), Some(LP1));
+			assert!(get_mock_minted_position(other_position_id).is_none());
+		});
+}
+
+#[test]
+fn merge_positions_should_use_weighted_price_when_positions_have_different_prices() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, asset_id, 5000 * ONE),
+			(LP2, asset_id, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange - create two positions at different prices
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_ok!(Omnipool::sell(
+				RuntimeOrigin::signed(LP2),
+				asset_id,
+				HDX,
+				100 * ONE,
+				10 * ONE
+			));
+
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 200 * ONE));
+
+			let position = Positions::<Test>::get(position_id).unwrap();
+			let other_position = Positions::<Test>::get(other_position_id).unwrap();
+			assert_ne!(position.price_from_rational(), other_position.price_from_rational());
+
+			// Act
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				other_position_id
+			));
+
+			// Assert
+			let merged_position = Positions::<Test>::get(position_id).unwrap();
+			assert_eq!(merged_position.amount, position.amount + other_position.amount);
+			assert_eq!(merged_position.shares, position.shares + other_position.shares);
+
+			let merged_price = merged_position.price_from_rational().unwrap();
+			let price = position.price_from_rational().unwrap();
+			let other_price = other_position.price_from_rational().unwrap();
+			assert!(merged_price <= price.max(other_price));
+			assert!(merged_price >= price.min(other_price));
+
+			assert!(Positions::<Test>::get(other_position_id).is_none());
+			assert!(get_mock_minted_position(other_position_id).is_none());
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_caller_is_not_owner_of_both_positions() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), asset_id, 200 * ONE));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+				Error::<Test>::Forbidden
+			);
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP2), position_id, other_position_id),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_positions_are_same() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, position_id),
+				Error::<Test>::PositionsNotMergeable
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_positions_are_of_different_assets() {
+	let asset_a: AssetId = 1_000;
+	let asset_b: AssetId = 2_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_a, 5000 * ONE))
+		.add_endowed_accounts((LP1, asset_b, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_a, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_b, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_a, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(asset_b, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_a, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_b, 400 * ONE));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+				Error::<Test>::PositionsNotMergeable
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_emit_events_when_succesful() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 200 * ONE));
+
+			// Act
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				other_position_id
+			));
+
+			// Assert
+			expect_events(vec![
+				crate::Event::PositionUpdated {
+					position_id,
+					owner: LP1,
+					asset: asset_id,
+					amount: 600 * ONE,
+					shares: 600 * ONE,
+					price: FixedU128::from_rational(1560, 2400),
+				}
+				.into(),
+				crate::Event::PositionDestroyed {
+					position_id: other_position_id,
+					owner: LP1,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn split_position_should_work_when_shares_are_lower_than_position_shares() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			// Act
+			let new_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				100 * ONE
+			));
+
+			// Assert
+			assert_eq!(
+				Positions::<Test>::get(position_id).unwrap(),
+				Position {
+					asset_id,
+					amount: 300 * ONE,
+					shares: 300 * ONE,
+					price: (1560 * ONE, 2400 * ONE),
+				}
+			);
+			assert_eq!(
+				Positions::<Test>::get(new_position_id).unwrap(),
+				Position {
+					asset_id,
+					amount: 100 * ONE,
+					shares: 100 * ONE,
+					price: (1560 * ONE, 2400 * ONE),
+				}
+			);
+			assert_eq!(get_mock_minted_position(new_position_id), Some(LP1));
+		});
+}
+
+#[test]
+fn split_position_should_restore_position_when_merged_back() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, asset_id, 5000 * ONE),
+			(LP2, asset_id, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange - create a position which shares differ from its amount
+			assert_ok!(Omnipool::sell(
+				RuntimeOrigin::signed(LP2),
+				asset_id,
+				HDX,
+				100 * ONE,
+				10 * ONE
+			));
+
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let position = Positions::<Test>::get(position_id).unwrap();
+
+			// Act
+			let new_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				position.shares / 3
+			));
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				new_position_id
+			));
+
+			// Assert
+			assert_eq!(Positions::<Test>::get(position_id).unwrap(), position);
+			assert!(Positions::<Test>::get(new_position_id).is_none());
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_shares_are_invalid() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 0),
+				Error::<Test>::InvalidSharesAmount
+			);
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 400 * ONE),
+				Error::<Test>::InsufficientShares
+			);
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 500 * ONE),
+				Error::<Test>::InsufficientShares
+			);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_resulting_position_is_below_minimum_liquidity() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_min_added_liquidity(150 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 100 * ONE),
+				Error::<Test>::InsufficientLiquidity
+			);
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 300 * ONE),
+				Error::<Test>::InsufficientLiquidity
+			);
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				200 * ONE
+			));
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_caller_is_not_position_owner() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			// Act & Assert
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP2), position_id, 100 * ONE),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn split_position_should_emit_events_when_succesful() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+			// Arrange
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			// Act
+			let new_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				100 * ONE
+			));
+
+			// Assert
+			expect_events(vec![
+				crate::Event::PositionUpdated {
+					position_id,
+					owner: LP1,
+					asset: asset_id,
+					amount: 300 * ONE,
+					shares: 300 * ONE,
+					price: FixedU128::from_rational(1560, 2400),
+				}
+				.into(),
+				crate::Event::PositionCreated {
+					position_id: new_position_id,
+					owner: LP1,
+					asset: asset_id,
+					amount: 100 * ONE,
+					shares: 100 * ONE,
+					price: FixedU128::from_rational(1560, 2400),
+				}
+				.into(),
+			]);
+		});
+}
@@ -247,6 +247,130 @@ fn lp_receives_lrna_when_price_is_higher() {
 		});
 }
 
+#[test]
+fn remove_liquidity_with_limit_should_work_when_received_amounts_are_above_limits() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP3, 1_000, 100 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+			(LP2, DAI, 50000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP3, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			let liq_added = 400 * ONE;
+
+			let current_position_id = <NextPositionId<Test>>::get();
+
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, liq_added));
+
+			assert_ok!(Omnipool::buy(
+				RuntimeOrigin::signed(LP2),
+				1_000,
+				DAI,
+				200 * ONE,
+				500000 * ONE
+			));
+
+			assert_ok!(Omnipool::remove_liquidity_with_limit(
+				RuntimeOrigin::signed(LP1),
+				current_position_id,
+				liq_added,
+				240 * ONE,
+				203_921_568_627_449,
+			));
+
+			assert_balance!(LP1, 1000, 4_840_000_000_000_000);
+			assert_balance!(LP1, LRNA, 203_921_568_627_449);
+			assert!(Positions::<Test>::get(current_position_id).is_none());
+		});
+}
+
+#[test]
+fn remove_liquidity_with_limit_should_fail_when_asset_amount_is_below_limit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP3, 1_000, 100 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+			(LP2, DAI, 50000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP3, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			let liq_added = 400 * ONE;
+
+			let current_position_id = <NextPositionId<Test>>::get();
+
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, liq_added));
+
+			assert_ok!(Omnipool::buy(
+				RuntimeOrigin::signed(LP2),
+				1_000,
+				DAI,
+				200 * ONE,
+				500000 * ONE
+			));
+
+			assert_noop!(
+				Omnipool::remove_liquidity_with_limit(
+					RuntimeOrigin::signed(LP1),
+					current_position_id,
+					liq_added,
+					240 * ONE + 1,
+					0,
+				),
+				Error::<Test>::SlippageLimit
+			);
+		});
+}
+
+#[test]
+fn remove_liquidity_with_limit_should_fail_when_hub_asset_amount_is_below_limit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP3, 1_000, 100 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+			(LP2, DAI, 50000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP3, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			let liq_added = 400 * ONE;
+
+			let current_position_id = <NextPositionId<Test>>::get();
+
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, liq_added));
+
+			assert_ok!(Omnipool::buy(
+				RuntimeOrigin::signed(LP2),
+				1_000,
+				DAI,
+				200 * ONE,
+				500000 * ONE
+			));
+
+			assert_noop!(
+				Omnipool::remove_liquidity_with_limit(
+					RuntimeOrigin::signed(LP1),
+					current_position_id,
+					liq_added,
+					0,
+					203_921_568_627_450,
+				),
+				Error::<Test>::SlippageLimit
+			);
+		});
+}
+
 #[test]
 fn remove_liquiduity_should_burn_lrna_when_amount_is_below_ed() {
 	let asset_id = 1_000;
@@ -115,6 +115,28 @@ pub trait ExternalPriceProvider<AssetId, Price> {
 	fn get_price_weight() -> Weight;
 }
 
+/// Provides price and liquidity depth of an asset which is not yet in Omnipool - eg. from XYK pool oracle.
+pub trait ListingPriceProvider<AssetId, Balance, Price> {
+	type Error;
+
+	/// Returns price of `asset` denominated in `denomination` asset and liquidity of `asset` in the price source.
+	fn get_price_and_liquidity(asset: AssetId, denomination: AssetId) -> Result<(Price, Balance), Self::Error>;
+
+	fn get_price_and_liquidity_weight() -> Weight;
+}
+
+impl<AssetId, Balance, Price> ListingPriceProvider<AssetId, Balance, Price> for () {
+	type Error = ();
+
+	fn get_price_and_liquidity(_asset: AssetId, _denomination: AssetId) -> Result<(Price, Balance), Self::Error> {
+		Err(())
+	}
+
+	fn get_price_and_liquidity_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 #[allow(clippy::result_unit_err)]
 pub trait ShouldAllow<AccountId, AssetId, Price> {
 	fn ensure_price(who: &AccountId, asset_a: AssetId, asset_b: AssetId, current_price: Price) -> Result<(), ()>;
@@ -88,6 +88,19 @@ where
 	}
 }
 
+/// Permissionless listing of an asset waiting for the end of its challenge period.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Listing<AccountId, BlockNumber> {
+	/// Account which proposed the listing and receives the LP position.
+	pub proposer: AccountId,
+	/// Initial liquidity of the asset.
+	pub amount: Balance,
+	/// Bond in native asset returned to proposer when listing is finalized.
+	pub bond: Balance,
+	/// Last block in which the listing can be vetoed.
+	pub challenge_period_end: BlockNumber,
+}
+
 /// Position in Omnipool represents a moment when LP provided liquidity of an asset at that moment’s price.
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct Position<Balance, AssetId> {
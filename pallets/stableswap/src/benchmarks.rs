@@ -19,7 +19,7 @@
 
 use super::*;
 
-use crate::types::AssetAmount;
+use crate::types::{AssetAmount, RateProvider};
 use frame_benchmarking::account;
 use frame_benchmarking::benchmarks;
 use frame_support::traits::EnsureOrigin;
@@ -248,6 +248,57 @@ benchmarks! {
 		assert_eq!(T::Currency::free_balance(asset_id_to_withdraw, &lp_provider), liquidity_added);
 	}
 
+	remove_liquidity{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let initial_liquidity = 1_000_000_000_000_000_000u128;
+		let liquidity_added = 300_000_000_000_000u128;
+
+		let mut initial: Vec<AssetAmount<T::AssetId>> = vec![];
+		let mut added_liquidity: Vec<AssetAmount<T::AssetId>> = vec![];
+		let mut min_amounts_out: Vec<AssetAmount<T::AssetId>> = vec![];
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+			T::Currency::update_balance(asset_id, &caller, initial_liquidity as i128)?;
+			T::Currency::update_balance(asset_id, &lp_provider, liquidity_added as i128)?;
+			initial.push(AssetAmount::new(asset_id, initial_liquidity));
+			added_liquidity.push(AssetAmount::new(asset_id, liquidity_added));
+			min_amounts_out.push(AssetAmount::new(asset_id, 1u128));
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+
+		let amplification = 100u16;
+		let trade_fee = Permill::from_percent(1);
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin,
+			pool_id,
+			asset_ids.clone(),
+			amplification,
+			trade_fee,
+		)?;
+
+		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(caller).into(),
+			pool_id,
+			initial,
+		)?;
+		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(),
+			pool_id,
+			added_liquidity
+		)?;
+
+		let shares = T::Currency::free_balance(pool_id, &lp_provider);
+	}: _(RawOrigin::Signed(lp_provider.clone()), pool_id, shares, min_amounts_out)
+	verify {
+		assert_eq!(T::Currency::free_balance(pool_id, &lp_provider), 0u128);
+		for asset_id in asset_ids {
+			assert!(T::Currency::free_balance(asset_id, &lp_provider) > 0u128);
+		}
+	}
+
 	sell{
 		let caller: T::AccountId = account("caller", 0, 1);
 		let lp_provider: T::AccountId = account("provider", 0, 1);
@@ -607,5 +658,134 @@ benchmarks! {
 		}
 	}
 
+	set_rate_provider{
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let asset_id: T::AssetId = *asset_ids.last().unwrap();
+		let peg: T::AssetId = *asset_ids.first().unwrap();
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+		let provider = Some(RateProvider::Oracle { peg, source: *b"omnipool" });
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, asset_id, provider.clone())
+	verify {
+		let pool = crate::Pallet::<T>::pools(pool_id).unwrap();
+		assert_eq!(pool.rate_providers[MAX_ASSETS_IN_POOL as usize - 1], provider);
+	}
+
+	set_external_rate{
+		let asset_id: T::AssetId = ASSET_ID_OFFSET.into();
+		let rate = (1_500_000_000_000_000_000u128, 1_000_000_000_000_000_000u128);
+		let successful_origin = T::UpdateRateOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(successful_origin, asset_id, rate)
+	verify {
+		assert_eq!(crate::Pallet::<T>::external_rate(asset_id), Some(rate));
+	}
+
+	schedule_fee_update{
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+
+		// Worst case is when fee is already changing
+		crate::Pallet::<T>::schedule_fee_update(successful_origin.clone(),
+			pool_id,
+			Permill::from_percent(2),
+			100u32.into(),
+			1000u32.into(),
+		)?;
+
+		System::<T>::set_block_number(500u32.into());
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, Permill::from_percent(3), 501u32.into(), 1000u32.into())
+	verify {
+		let pool = crate::Pallet::<T>::pools(pool_id).unwrap();
+		assert_eq!(pool.fee, Permill::from_percent(3));
+		assert!(pool.fee_ramp.is_some());
+	}
+
+	cancel_amplification_change{
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+
+		crate::Pallet::<T>::update_amplification(successful_origin.clone(),
+			pool_id,
+			1000,
+			100u32.into(),
+			1000u32.into(),
+		)?;
+
+		System::<T>::set_block_number(500u32.into());
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id)
+	verify {
+		let pool = crate::Pallet::<T>::pools(pool_id).unwrap();
+		assert_eq!(pool.initial_amplification, pool.final_amplification);
+	}
+
+	extend_amplification_change{
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+
+		crate::Pallet::<T>::update_amplification(successful_origin.clone(),
+			pool_id,
+			1000,
+			100u32.into(),
+			1000u32.into(),
+		)?;
+
+		System::<T>::set_block_number(500u32.into());
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, 2000u32.into())
+	verify {
+		let pool = crate::Pallet::<T>::pools(pool_id).unwrap();
+		assert_eq!(pool.final_block, 2000u32.into());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
@@ -29,6 +29,42 @@
 //!
 //! This is currently used to update on-chain oracle.
 //!
+//! ### Rate providers
+//!
+//! Pool assets are pegged 1:1 by default and reserves are normalized only by asset decimals.
+//! Yield-bearing assets, such as liquid staking tokens, drift away from the peg as they accrue yield.
+//! Such asset can be given a rate provider by `AuthorityOrigin` using `set_rate_provider`.
+//!
+//! Rate is the value of one unit of the asset expressed in the pool's peg. It can be sourced from:
+//! - oracle - price of the asset denominated in the peg asset. The price is a ratio of raw amounts, so the rated reserve
+//!   is normalized by decimals of the peg asset,
+//! - external value - set by `UpdateRateOrigin` via `set_external_rate`, eg. redemption rate sent via XCM from the asset's home chain.
+//!
+//! Reserves and amounts of the asset are scaled by the rate before the curve is evaluated, share prices are expressed in units of the asset.
+//! If rate of an asset is not available, all operations which need to evaluate the curve fail with `RateNotAvailable`.
+//! Liquidity can still be withdrawn proportionally by `remove_liquidity`, which does not evaluate the curve.
+//!
+//! Oracle rate provider can't use the pool's own oracle source (`OracleSource`) to price an asset in the pool's share
+//! or in another asset of the pool.
+//!
+//! ### Metapools
+//!
+//! A pool can contain share token of another pool, called base pool. Such pool is called a metapool.
+//! Assets of the base pool can be traded in the metapool via router as a single `StableswapMetapool(pool_id, base_pool_id)`
+//! trade. The trade is executed atomically as a trade in the base pool (adding or removing liquidity of a single asset)
+//! and a trade of the base pool share token in the metapool.
+//! The base pool is part of the route, so the trade can be weighed without looking up the base pool in storage.
+//!
+//! Oracle price of such trade is composed of the prices of the underlying trades.
+//! As the share token of the base pool accrues value, its rate can be provided by an oracle rate provider.
+//!
+//! ### Parameter changes
+//!
+//! Amplification and fee of a pool can be changed linearly over a range of blocks by `AuthorityOrigin`
+//! using `update_amplification` and `schedule_fee_update`. Current values are interpolated at the time of an operation.
+//! Scheduled change of amplification can be cancelled with `cancel_amplification_change` or prolonged with `extend_amplification_change`.
+//! `update_pool_fee` changes the fee immediately and cancels any scheduled fee change.
+//!
 //! ### Terminology
 //!
 //! * **LP** - liquidity provider
@@ -47,7 +83,7 @@
 //!
 //! LP is given certain amount of shares by minting a pool's share token.
 //!
-//! When LP decides to withdraw liquidity, it receives selected asset.
+//! When LP decides to withdraw liquidity, it receives selected asset or all pool assets in proportion to the pool reserves.
 //!
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -64,13 +100,17 @@ use sp_std::num::NonZeroU16;
 use sp_std::prelude::*;
 use sp_std::vec;
 
+pub mod migration;
 mod trade_execution;
 pub mod types;
 pub mod weights;
 
-use crate::types::{AssetAmount, Balance, PoolInfo, PoolState, StableswapHooks, Tradability};
+use crate::types::{AssetAmount, Balance, FeeRamp, PoolInfo, PoolState, RateProvider, StableswapHooks, Tradability};
+use hydra_dx_math::ema::EmaPrice;
 use hydra_dx_math::stableswap::types::AssetReserve;
+use hydradx_traits::oracle::{AggregatedPriceOracle, OraclePeriod, Source};
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::router::{PoolType, Trade};
 use orml_traits::MultiCurrency;
 use sp_std::collections::btree_map::BTreeMap;
 use weights::WeightInfo;
@@ -106,7 +146,10 @@ pub mod pallet {
 	use sp_runtime::Permill;
 	use sp_std::num::NonZeroU16;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -146,6 +189,21 @@ pub mod pallet {
 		/// Hooks are actions executed on add_liquidity, sell or buy.
 		type Hooks: StableswapHooks<Self::AssetId>;
 
+		/// Oracle providing rates of pool assets with oracle rate provider.
+		type RateOracle: AggregatedPriceOracle<Self::AssetId, BlockNumberFor<Self>, EmaPrice>;
+
+		/// Oracle period used to retrieve rates of pool assets.
+		#[pallet::constant]
+		type RateOraclePeriod: Get<OraclePeriod>;
+
+		/// Oracle source of pool's own trades and liquidity changes recorded by `Hooks`.
+		/// Rate of a pool asset can't be provided by this source, as the pool would price its assets itself.
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+
+		/// The origin which can set external rates of assets.
+		type UpdateRateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Minimum pool liquidity
 		#[pallet::constant]
 		type MinPoolLiquidity: Get<Balance>;
@@ -176,6 +234,11 @@ pub mod pallet {
 	pub type AssetTradability<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AssetId, Tradability, ValueQuery>;
 
+	/// Externally provided rates of assets as (numerator, denominator).
+	#[pallet::storage]
+	#[pallet::getter(fn external_rate)]
+	pub type ExternalRates<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, (Balance, Balance)>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -239,6 +302,34 @@ pub mod pallet {
 			start_block: BlockNumberFor<T>,
 			end_block: BlockNumberFor<T>,
 		},
+
+		/// Rate provider of a pool asset has been updated.
+		RateProviderUpdated {
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			provider: Option<RateProvider<T::AssetId>>,
+		},
+
+		/// External rate of an asset has been updated.
+		ExternalRateUpdated {
+			asset_id: T::AssetId,
+			rate: (Balance, Balance),
+		},
+
+		/// Fee of a pool has been scheduled to change.
+		FeeChanging {
+			pool_id: T::AssetId,
+			current_fee: Permill,
+			final_fee: Permill,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+		},
+
+		/// Scheduled amplification change of a pool has been cancelled.
+		AmplificationChangeCancelled {
+			pool_id: T::AssetId,
+			amplification: NonZeroU16,
+		},
 	}
 
 	#[pallet::error]
@@ -315,6 +406,21 @@ pub mod pallet {
 
 		/// Failed to retrieve asset decimals.
 		UnknownDecimals,
+
+		/// Failed to retrieve rate of an asset from its rate provider.
+		RateNotAvailable,
+
+		/// Rate numerator and denominator must be greater than zero.
+		InvalidRate,
+
+		/// New fee is equal to the current fee.
+		SameFee,
+
+		/// Amplification of the pool is not changing.
+		AmplificationNotChanging,
+
+		/// New end block must be after the end block of the scheduled change.
+		InvalidEndBlock,
 	}
 
 	#[pallet::call]
@@ -368,6 +474,8 @@ pub mod pallet {
 
 		/// Update pool's fee.
 		///
+		/// Fee is updated immediately. Scheduled fee change is cancelled.
+		///
 		/// if pool does not exist, `PoolNotFound` is returned.
 		///
 		/// Parameters:
@@ -386,6 +494,7 @@ pub mod pallet {
 				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
 
 				pool.fee = fee;
+				pool.fee_ramp = None;
 				Self::deposit_event(Event::FeeUpdated { pool_id, fee });
 				Ok(())
 			})
@@ -573,9 +682,7 @@ pub mod pallet {
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
 			let pool_account = Self::pool_account(pool_id);
-			let initial_reserves = pool
-				.reserves_with_decimals::<T>(&pool_account)
-				.ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = Self::pool_reserves(&pool, &pool_account)?;
 			let share_issuance = T::Currency::total_issuance(pool_id);
 
 			ensure!(
@@ -593,7 +700,7 @@ pub mod pallet {
 				asset_idx,
 				share_issuance,
 				amplification,
-				pool.fee,
+				Self::get_fee(&pool),
 			)
 			.ok_or(ArithmeticError::Overflow)?;
 
@@ -652,9 +759,7 @@ pub mod pallet {
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
 			let pool_account = Self::pool_account(pool_id);
-			let initial_reserves = pool
-				.reserves_with_decimals::<T>(&pool_account)
-				.ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = Self::pool_reserves(&pool, &pool_account)?;
 			let share_issuance = T::Currency::total_issuance(pool_id);
 			let amplification = Self::get_amplification(&pool);
 
@@ -665,7 +770,7 @@ pub mod pallet {
 				amount,
 				amplification,
 				share_issuance,
-				pool.fee,
+				Self::get_fee(&pool),
 			)
 			.ok_or(ArithmeticError::Overflow)?;
 
@@ -742,9 +847,7 @@ pub mod pallet {
 
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let pool_account = Self::pool_account(pool_id);
-			let initial_reserves = pool
-				.reserves_with_decimals::<T>(&pool_account)
-				.ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = Self::pool_reserves(&pool, &pool_account)?;
 
 			let (amount_out, fee_amount) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)?;
 			ensure!(amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
@@ -809,9 +912,7 @@ pub mod pallet {
 
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let pool_account = Self::pool_account(pool_id);
-			let initial_reserves = pool
-				.reserves_with_decimals::<T>(&pool_account)
-				.ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = Self::pool_reserves(&pool, &pool_account)?;
 
 			let (amount_in, fee_amount) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)?;
 
@@ -869,6 +970,336 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Remove liquidity from selected pool in exchange of all pool assets.
+		///
+		/// Share amount is burned and LP receives amount of each pool asset proportional to the pool reserves.
+		/// No fee is applied, the pool is not imbalanced by the withdrawal.
+		///
+		/// All pool assets must allow removing liquidity.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `pool_id`: Pool Id
+		/// - 'share_amount': amount of shares to withdraw
+		/// - 'min_amounts_out': minimum amounts of pool assets to receive. Assets not listed have no limit.
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			share_amount: Balance,
+			min_amounts_out: Vec<AssetAmount<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(share_amount > Balance::zero(), Error::<T>::InvalidAssetAmount);
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(
+				min_amounts_out.len() <= pool.assets.len(),
+				Error::<T>::MaxAssetsExceeded
+			);
+			for asset_id in pool.assets.iter() {
+				ensure!(
+					Self::is_asset_allowed(pool_id, *asset_id, Tradability::REMOVE_LIQUIDITY),
+					Error::<T>::NotAllowed
+				);
+			}
+
+			let current_share_balance = T::Currency::free_balance(pool_id, &who);
+			ensure!(current_share_balance >= share_amount, Error::<T>::InsufficientShares);
+			ensure!(
+				current_share_balance == share_amount
+					|| current_share_balance.saturating_sub(share_amount) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientShareBalance
+			);
+
+			let pool_account = Self::pool_account(pool_id);
+			// Proportional withdrawal does not evaluate the curve, so it does not depend on rates of pool assets
+			// and liquidity can be withdrawn even if a rate is not available.
+			let initial_reserves = pool
+				.reserves_with_decimals::<T>(&pool_account)
+				.ok_or(Error::<T>::UnknownDecimals)?;
+			let share_issuance = T::Currency::total_issuance(pool_id);
+
+			ensure!(
+				share_issuance == share_amount
+					|| share_issuance.saturating_sub(share_amount) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidityRemaining
+			);
+
+			let amounts_out =
+				hydra_dx_math::stableswap::calculate_liquidity_out(&initial_reserves, share_amount, share_issuance)
+					.ok_or(ArithmeticError::Overflow)?;
+
+			let amounts: Vec<AssetAmount<T::AssetId>> = pool
+				.assets
+				.iter()
+				.zip(amounts_out)
+				.map(|(asset_id, amount)| AssetAmount::new(*asset_id, amount))
+				.collect();
+
+			for min_amount_out in min_amounts_out.iter() {
+				let amount = amounts
+					.iter()
+					.find(|asset_amount| asset_amount.asset_id == min_amount_out.asset_id)
+					.ok_or(Error::<T>::AssetNotInPool)?;
+				ensure!(amount.amount >= min_amount_out.amount, Error::<T>::SlippageLimit);
+			}
+
+			let initial_reserves_with_rates = Self::pool_reserves(&pool, &pool_account).ok();
+
+			// Burn shares and transfer assets to user.
+			T::Currency::withdraw(pool_id, &who, share_amount)?;
+			for asset_amount in amounts.iter().filter(|asset_amount| !asset_amount.amount.is_zero()) {
+				T::Currency::transfer(asset_amount.asset_id, &pool_account, &who, asset_amount.amount)?;
+			}
+
+			// All done and updated. let's call the on_liquidity_changed hook.
+			// Share prices of an emptied pool are undefined, so the hook is not called when last shares are withdrawn.
+			// Share prices can't be calculated without rates either, so the hook is not called when a rate is not available.
+			if share_issuance != share_amount {
+				if let Some(initial_reserves) = initial_reserves_with_rates {
+					Self::call_on_liquidity_change_hook(pool_id, &initial_reserves, share_issuance)?;
+				}
+			}
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				pool_id,
+				who,
+				shares: share_amount,
+				amounts,
+				fee: Balance::zero(),
+			});
+
+			Ok(())
+		}
+
+		/// Set rate provider of a pool asset.
+		///
+		/// Reserves of the asset are scaled by the rate provided by the provider before the curve is evaluated.
+		/// `None` resets the asset to be pegged 1:1.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool id
+		/// - `asset_id`: pool asset
+		/// - `provider`: rate provider of the asset
+		///
+		/// Emits `RateProviderUpdated` event if successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_rate_provider())]
+		#[transactional]
+		pub fn set_rate_provider(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			provider: Option<RateProvider<T::AssetId>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+				let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+
+				if let Some(RateProvider::Oracle { peg, source }) = &provider {
+					ensure!(*peg != asset_id, Error::<T>::NotAllowed);
+					ensure!(
+						*source != T::OracleSource::get() || (*peg != pool_id && pool.find_asset(*peg).is_none()),
+						Error::<T>::NotAllowed
+					);
+				}
+
+				let rate_provider = pool
+					.rate_providers
+					.get_mut(asset_idx)
+					.ok_or(Error::<T>::AssetNotInPool)?;
+				*rate_provider = provider.clone();
+
+				Self::deposit_event(Event::RateProviderUpdated {
+					pool_id,
+					asset_id,
+					provider,
+				});
+				Ok(())
+			})
+		}
+
+		/// Set external rate of an asset.
+		///
+		/// The rate is used by pools in which the asset has `RateProvider::External` rate provider.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::UpdateRateOrigin
+		/// - `asset_id`: asset id
+		/// - `rate`: value of one unit of the asset expressed in the pool's peg as (numerator, denominator)
+		///
+		/// Emits `ExternalRateUpdated` event if successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_external_rate())]
+		pub fn set_external_rate(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			rate: (Balance, Balance),
+		) -> DispatchResult {
+			T::UpdateRateOrigin::ensure_origin(origin)?;
+
+			ensure!(!rate.0.is_zero() && !rate.1.is_zero(), Error::<T>::InvalidRate);
+
+			ExternalRates::<T>::insert(asset_id, rate);
+
+			Self::deposit_event(Event::ExternalRateUpdated { asset_id, rate });
+			Ok(())
+		}
+
+		/// Schedule linear change of pool's fee.
+		///
+		/// Fee changes linearly from current fee at `start_block` to `final_fee` at `end_block`.
+		/// Fee used by trades and liquidity operations is interpolated at the time of the operation.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		/// - `final_fee`: new desired pool fee
+		/// - `start_block`: block number when the fee starts to change
+		/// - `end_block`: block number when the fee reaches `final_fee`
+		///
+		/// Emits `FeeChanging` event if successful.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_fee_update())]
+		#[transactional]
+		pub fn schedule_fee_update(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			final_fee: Permill,
+			start_block: BlockNumberFor<T>,
+			end_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let current_block = T::BlockNumberProvider::current_block_number();
+			ensure!(
+				end_block > start_block && start_block >= current_block,
+				Error::<T>::PastBlock
+			);
+
+			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+				let current_fee = Self::get_fee(pool);
+
+				ensure!(current_fee != final_fee, Error::<T>::SameFee);
+
+				pool.fee = final_fee;
+				pool.fee_ramp = Some(FeeRamp {
+					initial_fee: current_fee,
+					initial_block: start_block,
+					final_block: end_block,
+				});
+
+				Self::deposit_event(Event::FeeChanging {
+					pool_id,
+					current_fee,
+					final_fee,
+					start_block,
+					end_block,
+				});
+				Ok(())
+			})
+		}
+
+		/// Cancel scheduled change of pool's amplification.
+		///
+		/// Amplification stays at its current value.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		///
+		/// Emits `AmplificationChangeCancelled` event if successful.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_amplification_change())]
+		#[transactional]
+		pub fn cancel_amplification_change(origin: OriginFor<T>, pool_id: T::AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let current_block = T::BlockNumberProvider::current_block_number();
+
+			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+				ensure!(
+					Self::is_amplification_changing(pool, current_block),
+					Error::<T>::AmplificationNotChanging
+				);
+
+				let amplification = NonZeroU16::new(Self::get_amplification(pool).saturated_into())
+					.ok_or(Error::<T>::InvalidAmplification)?;
+
+				pool.initial_amplification = amplification;
+				pool.final_amplification = amplification;
+				pool.initial_block = current_block;
+				pool.final_block = current_block;
+
+				Self::deposit_event(Event::AmplificationChangeCancelled { pool_id, amplification });
+				Ok(())
+			})
+		}
+
+		/// Extend scheduled change of pool's amplification.
+		///
+		/// Amplification changes linearly from its current value to the final amplification at `end_block`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		/// - `end_block`: new block number when the final amplification is reached
+		///
+		/// Emits `AmplificationChanging` event if successful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::extend_amplification_change())]
+		#[transactional]
+		pub fn extend_amplification_change(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			end_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let current_block = T::BlockNumberProvider::current_block_number();
+
+			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+				ensure!(
+					Self::is_amplification_changing(pool, current_block),
+					Error::<T>::AmplificationNotChanging
+				);
+				ensure!(end_block > pool.final_block, Error::<T>::InvalidEndBlock);
+
+				// Change continues from the current amplification if it has already started.
+				if current_block > pool.initial_block {
+					pool.initial_amplification = NonZeroU16::new(Self::get_amplification(pool).saturated_into())
+						.ok_or(Error::<T>::InvalidAmplification)?;
+					pool.initial_block = current_block;
+				}
+				pool.final_block = end_block;
+
+				Self::deposit_event(Event::AmplificationChanging {
+					pool_id,
+					current_amplification: pool.initial_amplification,
+					final_amplification: pool.final_amplification,
+					start_block: pool.initial_block,
+					end_block: pool.final_block,
+				});
+				Ok(())
+			})
+		}
 	}
 
 	#[pallet::hooks]
@@ -890,9 +1321,7 @@ impl<T: Config> Pallet<T> {
 		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
 
 		let pool_account = Self::pool_account(pool_id);
-		let initial_reserves = pool
-			.reserves_with_decimals::<T>(&pool_account)
-			.ok_or(Error::<T>::UnknownDecimals)?;
+		let initial_reserves = Self::pool_reserves(&pool, &pool_account)?;
 
 		ensure!(!initial_reserves[index_in].is_zero(), Error::<T>::InsufficientLiquidity);
 		ensure!(
@@ -907,7 +1336,7 @@ impl<T: Config> Pallet<T> {
 			index_out,
 			amount_in,
 			amplification,
-			pool.fee,
+			Self::get_fee(&pool),
 		)
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
@@ -926,9 +1355,7 @@ impl<T: Config> Pallet<T> {
 		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
 
 		let pool_account = Self::pool_account(pool_id);
-		let initial_reserves = pool
-			.reserves_with_decimals::<T>(&pool_account)
-			.ok_or(Error::<T>::UnknownDecimals)?;
+		let initial_reserves = Self::pool_reserves(&pool, &pool_account)?;
 
 		ensure!(
 			initial_reserves[index_out].amount > amount_out,
@@ -943,7 +1370,7 @@ impl<T: Config> Pallet<T> {
 			index_out,
 			amount_out,
 			amplification,
-			pool.fee,
+			Self::get_fee(&pool),
 		)
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
@@ -978,6 +1405,10 @@ impl<T: Config> Pallet<T> {
 			initial_block: block_number,
 			final_block: block_number,
 			fee,
+			rate_providers: vec![None; pool_assets.len()]
+				.try_into()
+				.map_err(|_| Error::<T>::MaxAssetsExceeded)?,
+			fee_ramp: None,
 		};
 		ensure!(pool.is_valid(), Error::<T>::IncorrectAssets);
 		ensure!(
@@ -1021,29 +1452,17 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let pool_account = Self::pool_account(pool_id);
-		let mut initial_reserves = Vec::with_capacity(pool.assets.len());
+		let initial_reserves = Self::pool_reserves(&pool, &pool_account)?;
 		let mut updated_reserves = Vec::with_capacity(pool.assets.len());
 		let mut added_amounts = Vec::with_capacity(pool.assets.len());
-		for pool_asset in pool.assets.iter() {
-			let decimals = Self::retrieve_decimals(*pool_asset).ok_or(Error::<T>::UnknownDecimals)?;
-			let reserve = T::Currency::free_balance(*pool_asset, &pool_account);
-			initial_reserves.push(AssetReserve {
-				amount: reserve,
-				decimals,
-			});
+		for (pool_asset, reserve) in pool.assets.iter().zip(initial_reserves.iter()) {
 			if let Some(liq_added) = added_assets.remove(pool_asset) {
-				let inc_reserve = reserve.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
-				updated_reserves.push(AssetReserve {
-					amount: inc_reserve,
-					decimals,
-				});
+				let inc_reserve = reserve.amount.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
+				updated_reserves.push(reserve.with_amount(inc_reserve));
 				added_amounts.push(liq_added);
 			} else {
 				ensure!(!reserve.is_zero(), Error::<T>::InvalidInitialLiquidity);
-				updated_reserves.push(AssetReserve {
-					amount: reserve,
-					decimals,
-				});
+				updated_reserves.push(*reserve);
 				added_amounts.push(0);
 			}
 		}
@@ -1057,7 +1476,7 @@ impl<T: Config> Pallet<T> {
 			&updated_reserves,
 			amplification,
 			share_issuance,
-			pool.fee,
+			Self::get_fee(&pool),
 		)
 		.ok_or(ArithmeticError::Overflow)?;
 
@@ -1098,9 +1517,7 @@ impl<T: Config> Pallet<T> {
 		let share_issuance = T::Currency::total_issuance(pool_id);
 		let amplification = Self::get_amplification(&pool);
 		let pool_account = Self::pool_account(pool_id);
-		let initial_reserves = pool
-			.reserves_with_decimals::<T>(&pool_account)
-			.ok_or(Error::<T>::UnknownDecimals)?;
+		let initial_reserves = Self::pool_reserves(&pool, &pool_account)?;
 
 		// Ensure that initial liquidity has been already provided
 		for reserve in initial_reserves.iter() {
@@ -1113,7 +1530,7 @@ impl<T: Config> Pallet<T> {
 			asset_idx,
 			share_issuance,
 			amplification,
-			pool.fee,
+			Self::get_fee(&pool),
 		)
 		.ok_or(ArithmeticError::Overflow)?;
 
@@ -1157,10 +1574,128 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	#[inline]
+	pub(crate) fn is_amplification_changing(
+		pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>,
+		current_block: BlockNumberFor<T>,
+	) -> bool {
+		pool.initial_amplification != pool.final_amplification && current_block < pool.final_block
+	}
+
+	#[inline]
+	pub(crate) fn get_fee(pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>) -> Permill {
+		match &pool.fee_ramp {
+			Some(ramp) => hydra_dx_math::stableswap::calculate_fee(
+				ramp.initial_fee,
+				pool.fee,
+				ramp.initial_block.saturated_into(),
+				ramp.final_block.saturated_into(),
+				T::BlockNumberProvider::current_block_number().saturated_into(),
+			),
+			None => pool.fee,
+		}
+	}
+
+	/// Split trade in a metapool, where one of the assets is in its base pool, into a trade in the metapool
+	/// and a trade in the base pool.
+	pub(crate) fn metapool_trades(
+		pool_id: T::AssetId,
+		base_pool_id: T::AssetId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+	) -> Result<[Trade<T::AssetId>; 2], DispatchError> {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let base_pool = Pools::<T>::get(base_pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		ensure!(pool.find_asset(base_pool_id).is_some(), Error::<T>::AssetNotInPool);
+
+		let is_pool_asset = |asset_id: T::AssetId| {
+			asset_id != base_pool_id && (asset_id == pool_id || pool.find_asset(asset_id).is_some())
+		};
+		let is_base_pool_asset = |asset_id: T::AssetId| base_pool.find_asset(asset_id).is_some();
+
+		if is_pool_asset(asset_in) && is_base_pool_asset(asset_out) {
+			return Ok([
+				Trade {
+					pool: PoolType::Stableswap(pool_id),
+					asset_in,
+					asset_out: base_pool_id,
+				},
+				Trade {
+					pool: PoolType::Stableswap(base_pool_id),
+					asset_in: base_pool_id,
+					asset_out,
+				},
+			]);
+		}
+
+		if is_base_pool_asset(asset_in) && is_pool_asset(asset_out) {
+			return Ok([
+				Trade {
+					pool: PoolType::Stableswap(base_pool_id),
+					asset_in,
+					asset_out: base_pool_id,
+				},
+				Trade {
+					pool: PoolType::Stableswap(pool_id),
+					asset_in: base_pool_id,
+					asset_out,
+				},
+			]);
+		}
+
+		Err(Error::<T>::AssetNotInPool.into())
+	}
+
 	#[inline]
 	pub(crate) fn retrieve_decimals(asset_id: T::AssetId) -> Option<u8> {
 		T::AssetInspection::decimals(asset_id)
 	}
+
+	/// Apply rate of an asset from given rate provider to the asset's reserve. Assets without rate provider are
+	/// pegged 1:1.
+	pub(crate) fn reserve_with_rate(
+		reserve: AssetReserve,
+		asset_id: T::AssetId,
+		provider: &Option<RateProvider<T::AssetId>>,
+	) -> Result<AssetReserve, DispatchError> {
+		let reserve = match provider {
+			None => return Ok(reserve),
+			Some(RateProvider::Oracle { peg, source }) => {
+				// Oracle price is the raw amount of peg asset per one raw unit of the asset. It already accounts for
+				// decimals of both assets, so the rated reserve is normalized by decimals of the peg asset.
+				let (price, _) = T::RateOracle::get_price(*peg, asset_id, T::RateOraclePeriod::get(), *source)
+					.map_err(|_| Error::<T>::RateNotAvailable)?;
+				let decimals = Self::retrieve_decimals(*peg).ok_or(Error::<T>::UnknownDecimals)?;
+				AssetReserve {
+					decimals,
+					..reserve.with_rate((price.n, price.d))
+				}
+			}
+			Some(RateProvider::External) => {
+				reserve.with_rate(ExternalRates::<T>::get(asset_id).ok_or(Error::<T>::RateNotAvailable)?)
+			}
+		};
+		ensure!(
+			!reserve.rate.0.is_zero() && !reserve.rate.1.is_zero(),
+			Error::<T>::RateNotAvailable
+		);
+		Ok(reserve)
+	}
+
+	/// Retrieve pool reserves with decimals and rates of pool assets.
+	pub(crate) fn pool_reserves(
+		pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>,
+		pool_account: &T::AccountId,
+	) -> Result<Vec<AssetReserve>, DispatchError> {
+		let reserves = pool
+			.reserves_with_decimals::<T>(pool_account)
+			.ok_or(Error::<T>::UnknownDecimals)?;
+		reserves
+			.into_iter()
+			.zip(pool.assets.iter().zip(pool.rate_providers.iter()))
+			.map(|(reserve, (asset_id, provider))| Self::reserve_with_rate(reserve, *asset_id, provider))
+			.collect()
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -1188,27 +1723,15 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
-		let mut initial_reserves = Vec::with_capacity(pool.assets.len());
+		let initial_reserves = Self::pool_reserves(&pool, &pool_account)?;
 		let mut updated_reserves = Vec::with_capacity(pool.assets.len());
-		for pool_asset in pool.assets.iter() {
-			let decimals = Self::retrieve_decimals(*pool_asset).ok_or(Error::<T>::UnknownDecimals)?;
-			let reserve = T::Currency::free_balance(*pool_asset, &pool_account);
-			initial_reserves.push(AssetReserve {
-				amount: reserve,
-				decimals,
-			});
+		for (pool_asset, reserve) in pool.assets.iter().zip(initial_reserves.iter()) {
 			if let Some(liq_added) = added_assets.remove(pool_asset) {
-				let inc_reserve = reserve.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
-				updated_reserves.push(AssetReserve {
-					amount: inc_reserve,
-					decimals,
-				});
+				let inc_reserve = reserve.amount.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
+				updated_reserves.push(reserve.with_amount(inc_reserve));
 			} else {
 				ensure!(!reserve.is_zero(), Error::<T>::InvalidInitialLiquidity);
-				updated_reserves.push(AssetReserve {
-					amount: reserve,
-					decimals,
-				});
+				updated_reserves.push(*reserve);
 			}
 		}
 
@@ -1219,7 +1742,7 @@ impl<T: Config> Pallet<T> {
 			&updated_reserves,
 			amplification,
 			share_issuance,
-			pool.fee,
+			Self::get_fee(&pool),
 		)
 		.ok_or(ArithmeticError::Overflow)?;
 
@@ -1259,9 +1782,7 @@ impl<T: Config> Pallet<T> {
 		let pool_account = Self::pool_account(pool_id);
 		let amplification = Self::get_amplification(&pool);
 		let share_issuance = T::Currency::total_issuance(pool_id);
-		let updated_reserves = pool
-			.reserves_with_decimals::<T>(&pool_account)
-			.ok_or(Error::<T>::UnknownDecimals)?;
+		let updated_reserves = Self::pool_reserves(&pool, &pool_account)?;
 		let share_prices = hydra_dx_math::stableswap::calculate_share_prices::<D_ITERATIONS>(
 			&updated_reserves,
 			amplification,
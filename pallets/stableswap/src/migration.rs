@@ -0,0 +1,298 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	pallet_prelude::*,
+	storage_alias,
+	traits::{OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+	BoundedVec,
+};
+
+/// The log target.
+const TARGET: &str = "runtime::stableswap::migration";
+
+pub mod v0 {
+	use super::*;
+
+	/// Pool properties without rate providers.
+	#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PoolInfo<AssetId, BlockNumber> {
+		pub assets: BoundedVec<AssetId, ConstU32<MAX_ASSETS_IN_POOL>>,
+		pub initial_amplification: NonZeroU16,
+		pub final_amplification: NonZeroU16,
+		pub initial_block: BlockNumber,
+		pub final_block: BlockNumber,
+		pub fee: Permill,
+	}
+
+	#[storage_alias]
+	pub type Pools<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as Config>::AssetId,
+		PoolInfo<<T as Config>::AssetId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+}
+
+pub mod v1 {
+	use super::*;
+	use sp_runtime::Saturating;
+
+	/// Pool properties without fee ramp.
+	#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub struct PoolInfo<AssetId, BlockNumber> {
+		pub assets: BoundedVec<AssetId, ConstU32<MAX_ASSETS_IN_POOL>>,
+		pub initial_amplification: NonZeroU16,
+		pub final_amplification: NonZeroU16,
+		pub initial_block: BlockNumber,
+		pub final_block: BlockNumber,
+		pub fee: Permill,
+		pub rate_providers: BoundedVec<Option<RateProvider<AssetId>>, ConstU32<MAX_ASSETS_IN_POOL>>,
+	}
+
+	#[storage_alias]
+	pub type Pools<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as Config>::AssetId,
+		PoolInfo<<T as Config>::AssetId, BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Migrates pools to pools with all assets pegged 1:1.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "Storage version too high.");
+
+			let ids = v0::Pools::<T>::iter_keys().collect::<Vec<T::AssetId>>();
+
+			log::info!(target: TARGET, "Stableswap migration v1: PRE checks successful!");
+
+			Ok(ids.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 0 {
+				log::info!(target: TARGET, "Stableswap migration to v1 skipped");
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v1 for Stableswap");
+
+			let mut translated = 0u64;
+			Pools::<T>::translate::<v0::PoolInfo<T::AssetId, BlockNumberFor<T>>, _>(|_pool_id, pool| {
+				translated.saturating_inc();
+				Some(v1::PoolInfo {
+					rate_providers: BoundedVec::truncate_from(vec![None; pool.assets.len()]),
+					assets: pool.assets,
+					initial_amplification: pool.initial_amplification,
+					final_amplification: pool.final_amplification,
+					initial_block: pool.initial_block,
+					final_block: pool.final_block,
+					fee: pool.fee,
+				})
+			});
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+			let previous_ids = <Vec<T::AssetId> as Decode>::decode(&mut state.as_slice()).unwrap();
+
+			for pool_id in previous_ids.into_iter() {
+				let pool = Pools::<T>::get(pool_id).expect("Migrated pool should exist");
+				assert_eq!(
+					pool.rate_providers.len(),
+					pool.assets.len(),
+					"Migrated pool should be valid!"
+				);
+				assert!(
+					pool.rate_providers.iter().all(|provider| provider.is_none()),
+					"Migrated pool should not have rate providers!"
+				);
+			}
+
+			log::info!(target: TARGET, "Stableswap migration v1: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+	use sp_runtime::Saturating;
+
+	/// Migrates pools to pools without scheduled fee change.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+			let ids = v1::Pools::<T>::iter_keys().collect::<Vec<T::AssetId>>();
+
+			log::info!(target: TARGET, "Stableswap migration v2: PRE checks successful!");
+
+			Ok(ids.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 1 {
+				log::info!(target: TARGET, "Stableswap migration to v2 skipped");
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v2 for Stableswap");
+
+			let mut translated = 0u64;
+			Pools::<T>::translate::<v1::PoolInfo<T::AssetId, BlockNumberFor<T>>, _>(|_pool_id, pool| {
+				translated.saturating_inc();
+				Some(PoolInfo {
+					assets: pool.assets,
+					initial_amplification: pool.initial_amplification,
+					final_amplification: pool.final_amplification,
+					initial_block: pool.initial_block,
+					final_block: pool.final_block,
+					fee: pool.fee,
+					rate_providers: pool.rate_providers,
+					fee_ramp: None,
+				})
+			});
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2, "Unexpected storage version.");
+
+			let previous_ids = <Vec<T::AssetId> as Decode>::decode(&mut state.as_slice()).unwrap();
+
+			for pool_id in previous_ids.into_iter() {
+				let pool = Pools::<T>::get(pool_id).expect("Migrated pool should exist");
+				assert!(pool.is_valid(), "Migrated pool should be valid!");
+				assert!(pool.fee_ramp.is_none(), "Migrated pool should not have fee ramp!");
+			}
+
+			log::info!(target: TARGET, "Stableswap migration v2: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::tests::mock::*;
+
+	const POOL_ID: AssetId = 100;
+	const ASSET_A: AssetId = 1;
+	const ASSET_B: AssetId = 2;
+
+	#[test]
+	fn migration_should_add_no_rate_providers_to_pools() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<Test>>();
+
+			let assets: BoundedVec<AssetId, ConstU32<MAX_ASSETS_IN_POOL>> =
+				BoundedVec::truncate_from(vec![ASSET_A, ASSET_B]);
+			v0::Pools::<Test>::insert(
+				POOL_ID,
+				v0::PoolInfo {
+					assets: assets.clone(),
+					initial_amplification: NonZeroU16::new(100).unwrap(),
+					final_amplification: NonZeroU16::new(200).unwrap(),
+					initial_block: 1,
+					final_block: 10,
+					fee: Permill::from_percent(1),
+				},
+			);
+
+			v1::Migration::<Test>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::get::<Pallet<Test>>(), 1);
+			assert_eq!(
+				v1::Pools::<Test>::get(POOL_ID),
+				Some(v1::PoolInfo {
+					assets,
+					initial_amplification: NonZeroU16::new(100).unwrap(),
+					final_amplification: NonZeroU16::new(200).unwrap(),
+					initial_block: 1,
+					final_block: 10,
+					fee: Permill::from_percent(1),
+					rate_providers: BoundedVec::truncate_from(vec![None, None]),
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn migration_should_add_no_fee_ramp_to_pools() {
+		ExtBuilder::default().build().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<Test>>();
+
+			let assets: BoundedVec<AssetId, ConstU32<MAX_ASSETS_IN_POOL>> =
+				BoundedVec::truncate_from(vec![ASSET_A, ASSET_B]);
+			v1::Pools::<Test>::insert(
+				POOL_ID,
+				v1::PoolInfo {
+					assets: assets.clone(),
+					initial_amplification: NonZeroU16::new(100).unwrap(),
+					final_amplification: NonZeroU16::new(200).unwrap(),
+					initial_block: 1,
+					final_block: 10,
+					fee: Permill::from_percent(1),
+					rate_providers: BoundedVec::truncate_from(vec![Some(RateProvider::External), None]),
+				},
+			);
+
+			v2::Migration::<Test>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::get::<Pallet<Test>>(), 2);
+			assert_eq!(
+				Pools::<Test>::get(POOL_ID),
+				Some(PoolInfo {
+					assets,
+					initial_amplification: NonZeroU16::new(100).unwrap(),
+					final_amplification: NonZeroU16::new(200).unwrap(),
+					initial_block: 1,
+					final_block: 10,
+					fee: Permill::from_percent(1),
+					rate_providers: BoundedVec::truncate_from(vec![Some(RateProvider::External), None]),
+					fee_ramp: None,
+				})
+			);
+		});
+	}
+}
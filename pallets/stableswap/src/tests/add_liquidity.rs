@@ -126,6 +126,8 @@ fn add_liquidity_should_work_when_initial_liquidity_has_been_provided() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -183,6 +185,8 @@ fn add_liquidity_should_work_when_order_is_not_sorted() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -240,6 +244,8 @@ fn add_liquidity_should_fail_when_providing_insufficient_liquidity() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -296,6 +302,8 @@ fn add_liquidity_should_work_when_providing_one_asset_only() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 4].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -351,6 +359,8 @@ fn add_liquidity_should_fail_when_providing_one_asset_not_in_pool() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 4].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -404,6 +414,8 @@ fn add_liquidity_should_fail_when_provided_list_contains_same_assets() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -506,6 +518,8 @@ fn add_liquidity_should_work_correctly() {
 				initial_block: 0,
 				final_block: 0,
 				//fee: Permill::from_float(0.0001),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 				fee: Permill::zero(),
 			},
 			InitialLiquidity {
@@ -557,6 +571,8 @@ fn add_liquidity_should_work_correctly_when_fee_is_applied() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_float(0.0001),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -607,6 +623,8 @@ fn add_liquidity_should_work_correctly_when_providing_exact_amount_of_shares() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -662,6 +680,8 @@ fn add_liquidity_should_apply_fee_when_providing_exact_amount_of_shares() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -716,6 +736,8 @@ fn add_liquidity_shares_should_fail_when_pool_is_empty() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -46,6 +46,8 @@ fn update_amplification_should_work_when_correct_params_are_provided() {
 					initial_block: 10,
 					final_block: 1000,
 					fee: Permill::from_percent(10),
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
 				}
 			);
 		});
@@ -181,6 +183,8 @@ fn update_amplification_should_work_when_current_change_is_in_progress() {
 					initial_block: 10,
 					final_block: 1000,
 					fee: Permill::from_percent(10),
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
 				}
 			);
 			System::set_block_number(500);
@@ -202,6 +206,8 @@ fn update_amplification_should_work_when_current_change_is_in_progress() {
 					initial_block: 501,
 					final_block: 1000,
 					fee: Permill::from_percent(10),
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
 				}
 			);
 		});
@@ -315,3 +321,190 @@ fn amplification_should_change_when_block_changes() {
 			}
 		});
 }
+
+#[test]
+fn cancel_amplification_change_should_keep_current_amplification() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			System::set_block_number(1);
+			assert_ok!(Stableswap::update_amplification(
+				RuntimeOrigin::root(),
+				pool_id,
+				1000,
+				10,
+				1010,
+			));
+
+			System::set_block_number(510);
+			assert_ok!(Stableswap::cancel_amplification_change(RuntimeOrigin::root(), pool_id));
+
+			let pool = <Pools<Test>>::get(pool_id).unwrap();
+			assert_eq!(pool.initial_amplification, NonZeroU16::new(550).unwrap());
+			assert_eq!(pool.final_amplification, NonZeroU16::new(550).unwrap());
+			assert_eq!(pool.initial_block, 510);
+			assert_eq!(pool.final_block, 510);
+
+			System::set_block_number(2000);
+			assert_eq!(crate::Pallet::<Test>::get_amplification(&pool), 550);
+		});
+}
+
+#[test]
+fn cancel_amplification_change_should_fail_when_amplification_is_not_changing() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			assert_noop!(
+				Stableswap::cancel_amplification_change(RuntimeOrigin::root(), pool_id),
+				Error::<Test>::AmplificationNotChanging
+			);
+
+			System::set_block_number(1);
+			assert_ok!(Stableswap::update_amplification(
+				RuntimeOrigin::root(),
+				pool_id,
+				1000,
+				10,
+				1010,
+			));
+
+			System::set_block_number(1010);
+			assert_noop!(
+				Stableswap::cancel_amplification_change(RuntimeOrigin::root(), pool_id),
+				Error::<Test>::AmplificationNotChanging
+			);
+		});
+}
+
+#[test]
+fn extend_amplification_change_should_continue_from_current_amplification() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			System::set_block_number(1);
+			assert_ok!(Stableswap::update_amplification(
+				RuntimeOrigin::root(),
+				pool_id,
+				1000,
+				10,
+				1010,
+			));
+
+			System::set_block_number(510);
+			assert_ok!(Stableswap::extend_amplification_change(
+				RuntimeOrigin::root(),
+				pool_id,
+				1410,
+			));
+
+			assert_eq!(
+				<Pools<Test>>::get(pool_id).unwrap(),
+				PoolInfo {
+					assets: vec![asset_a, asset_b].try_into().unwrap(),
+					initial_amplification: NonZeroU16::new(550).unwrap(),
+					final_amplification: NonZeroU16::new(1000).unwrap(),
+					initial_block: 510,
+					final_block: 1410,
+					fee: Permill::from_percent(10),
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
+				}
+			);
+
+			let pool = <Pools<Test>>::get(pool_id).unwrap();
+			System::set_block_number(960);
+			assert_eq!(crate::Pallet::<Test>::get_amplification(&pool), 775);
+		});
+}
+
+#[test]
+fn extend_amplification_change_should_fail_when_end_block_is_not_later() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			assert_noop!(
+				Stableswap::extend_amplification_change(RuntimeOrigin::root(), pool_id, 2000),
+				Error::<Test>::AmplificationNotChanging
+			);
+
+			System::set_block_number(1);
+			assert_ok!(Stableswap::update_amplification(
+				RuntimeOrigin::root(),
+				pool_id,
+				1000,
+				10,
+				1010,
+			));
+
+			System::set_block_number(510);
+			assert_noop!(
+				Stableswap::extend_amplification_change(RuntimeOrigin::root(), pool_id, 1010),
+				Error::<Test>::InvalidEndBlock
+			);
+		});
+}
@@ -0,0 +1,369 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, FeeRamp, PoolInfo};
+use crate::{Error, Pools};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Permill;
+use std::num::NonZeroU16;
+
+#[test]
+fn schedule_fee_update_should_work_when_correct_params_are_provided() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			System::set_block_number(2);
+
+			assert_ok!(Stableswap::schedule_fee_update(
+				RuntimeOrigin::root(),
+				pool_id,
+				Permill::from_percent(20),
+				10,
+				110,
+			));
+
+			assert_eq!(
+				<Pools<Test>>::get(pool_id).unwrap(),
+				PoolInfo {
+					assets: vec![asset_a, asset_b].try_into().unwrap(),
+					initial_amplification: NonZeroU16::new(100).unwrap(),
+					final_amplification: NonZeroU16::new(100).unwrap(),
+					initial_block: 0,
+					final_block: 0,
+					fee: Permill::from_percent(20),
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: Some(FeeRamp {
+						initial_fee: Permill::from_percent(10),
+						initial_block: 10,
+						final_block: 110,
+					}),
+				}
+			);
+		});
+}
+
+#[test]
+fn fee_should_change_when_block_changes() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			System::set_block_number(1);
+			assert_ok!(Stableswap::schedule_fee_update(
+				RuntimeOrigin::root(),
+				pool_id,
+				Permill::from_percent(20),
+				10,
+				110,
+			));
+
+			let pool = <Pools<Test>>::get(pool_id).unwrap();
+
+			System::set_block_number(5);
+			assert_eq!(Stableswap::get_fee(&pool), Permill::from_percent(10));
+
+			System::set_block_number(60);
+			assert_eq!(Stableswap::get_fee(&pool), Permill::from_percent(15));
+
+			System::set_block_number(110);
+			assert_eq!(Stableswap::get_fee(&pool), Permill::from_percent(20));
+
+			System::set_block_number(500);
+			assert_eq!(Stableswap::get_fee(&pool), Permill::from_percent(20));
+		});
+}
+
+#[test]
+fn schedule_fee_update_should_continue_from_current_fee_when_change_is_in_progress() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			System::set_block_number(1);
+			assert_ok!(Stableswap::schedule_fee_update(
+				RuntimeOrigin::root(),
+				pool_id,
+				Permill::from_percent(20),
+				10,
+				110,
+			));
+
+			System::set_block_number(60);
+			assert_ok!(Stableswap::schedule_fee_update(
+				RuntimeOrigin::root(),
+				pool_id,
+				Permill::from_percent(5),
+				60,
+				160,
+			));
+
+			let pool = <Pools<Test>>::get(pool_id).unwrap();
+			assert_eq!(pool.fee, Permill::from_percent(5));
+			assert_eq!(
+				pool.fee_ramp,
+				Some(FeeRamp {
+					initial_fee: Permill::from_percent(15),
+					initial_block: 60,
+					final_block: 160,
+				})
+			);
+		});
+}
+
+#[test]
+fn schedule_fee_update_should_fail_when_start_block_before_current_block() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			System::set_block_number(5000);
+
+			assert_noop!(
+				Stableswap::schedule_fee_update(RuntimeOrigin::root(), pool_id, Permill::from_percent(20), 10, 10_000),
+				Error::<Test>::PastBlock
+			);
+			assert_noop!(
+				Stableswap::schedule_fee_update(RuntimeOrigin::root(), pool_id, Permill::from_percent(20), 6000, 5500),
+				Error::<Test>::PastBlock
+			);
+		});
+}
+
+#[test]
+fn schedule_fee_update_should_fail_when_new_value_is_same_as_current_one() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			assert_noop!(
+				Stableswap::schedule_fee_update(RuntimeOrigin::root(), pool_id, Permill::from_percent(10), 10, 100),
+				Error::<Test>::SameFee
+			);
+		});
+}
+
+#[test]
+fn schedule_fee_update_should_fail_when_origin_is_not_authority() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			assert_noop!(
+				Stableswap::schedule_fee_update(
+					RuntimeOrigin::signed(ALICE),
+					pool_id,
+					Permill::from_percent(20),
+					10,
+					100
+				),
+				sp_runtime::DispatchError::BadOrigin
+			);
+		});
+}
+
+#[test]
+fn update_pool_fee_should_cancel_scheduled_fee_change() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, asset_a, 200 * ONE), (ALICE, asset_b, 200 * ONE)])
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			System::set_block_number(1);
+			assert_ok!(Stableswap::schedule_fee_update(
+				RuntimeOrigin::root(),
+				pool_id,
+				Permill::from_percent(20),
+				10,
+				110,
+			));
+
+			assert_ok!(Stableswap::update_pool_fee(
+				RuntimeOrigin::root(),
+				pool_id,
+				Permill::from_percent(3),
+			));
+
+			let pool = <Pools<Test>>::get(pool_id).unwrap();
+			assert_eq!(pool.fee, Permill::from_percent(3));
+			assert_eq!(pool.fee_ramp, None);
+
+			System::set_block_number(60);
+			assert_eq!(Stableswap::get_fee(&pool), Permill::from_percent(3));
+		});
+}
+
+#[test]
+fn sell_should_use_interpolated_fee_when_fee_is_changing() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool = |fee: Permill| PoolInfo::<AssetId, u64> {
+		assets: vec![asset_a, asset_b].try_into().unwrap(),
+		initial_amplification: NonZeroU16::new(100).unwrap(),
+		final_amplification: NonZeroU16::new(100).unwrap(),
+		initial_block: 0,
+		final_block: 0,
+		fee,
+		rate_providers: vec![None; 2].try_into().unwrap(),
+		fee_ramp: None,
+	};
+	let liquidity = || InitialLiquidity {
+		account: ALICE,
+		assets: vec![
+			AssetAmount::new(asset_a, 100 * ONE),
+			AssetAmount::new(asset_b, 100 * ONE),
+		],
+	};
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, asset_a, 200 * ONE),
+			(ALICE, asset_a, 200 * ONE),
+			(ALICE, asset_b, 200 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.with_pool(ALICE, pool(Permill::from_percent(0)), liquidity())
+		.with_pool(ALICE, pool(Permill::from_percent(5)), liquidity())
+		.build()
+		.execute_with(|| {
+			let ramping_pool_id = get_pool_id_at(0);
+			let fixed_pool_id = get_pool_id_at(1);
+
+			System::set_block_number(1);
+			assert_ok!(Stableswap::schedule_fee_update(
+				RuntimeOrigin::root(),
+				ramping_pool_id,
+				Permill::from_percent(10),
+				10,
+				110,
+			));
+
+			System::set_block_number(60);
+
+			assert_ok!(Stableswap::sell(
+				RuntimeOrigin::signed(BOB),
+				ramping_pool_id,
+				asset_a,
+				asset_b,
+				30 * ONE,
+				0,
+			));
+			let received_from_ramping_pool = Tokens::free_balance(asset_b, &BOB);
+
+			assert_ok!(Stableswap::sell(
+				RuntimeOrigin::signed(BOB),
+				fixed_pool_id,
+				asset_a,
+				asset_b,
+				30 * ONE,
+				0,
+			));
+			let received_from_fixed_pool = Tokens::free_balance(asset_b, &BOB) - received_from_ramping_pool;
+
+			assert!(received_from_ramping_pool > 0);
+			assert_eq!(received_from_ramping_pool, received_from_fixed_pool);
+		});
+}
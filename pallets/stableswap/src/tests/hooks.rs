@@ -29,6 +29,8 @@ fn add_liquidity_should_provide_correct_values_in_the_hook() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -99,6 +101,8 @@ fn add_liquidity_shares_should_provide_correct_values_in_the_hook() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -172,6 +176,8 @@ fn removing_liquidity_should_provide_correct_values_in_the_hook() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -229,6 +235,77 @@ fn removing_liquidity_should_provide_correct_values_in_the_hook() {
 		});
 }
 
+#[test]
+fn removing_liquidity_proportionally_should_provide_correct_values_in_the_hook() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, asset_a, 52425995641788588073263117),
+			(ALICE, asset_b, 52033213790329),
+			(ALICE, asset_c, 119135337044269),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 18)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 6)
+		.with_registered_asset("three".as_bytes().to_vec(), asset_c, 6)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b, asset_c].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(2000).unwrap(),
+				final_amplification: NonZeroU16::new(2000).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(1),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(asset_a, 52425995641788588073263117),
+					AssetAmount::new(asset_b, 52033213790329),
+					AssetAmount::new(asset_c, 119135337044269),
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+			Tokens::withdraw(pool_id, &ALICE, 5906657405945079804575283).unwrap();
+			let shares = 10_000_000_000_000_000_000_000_000;
+
+			// ACT
+			assert_ok!(Stableswap::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				shares,
+				vec![],
+			));
+
+			let (p, state) = last_liquidity_changed_hook_state().unwrap();
+			assert_eq!(p, pool_id);
+
+			assert_eq!(state.assets, vec![asset_a, asset_b, asset_c]);
+			assert_eq!(
+				state.before,
+				vec![52425995641788588073263117, 52033213790329, 119135337044269]
+			);
+			assert_eq!(
+				state.after,
+				vec![50017572604363388828197111, 49642834947357, 113662321476395]
+			);
+			assert_eq!(
+				state.delta,
+				vec![2408423037425199245066006, 2390378842972, 5473015567874]
+			);
+			assert_eq!(state.issuance_before, 217677687130232134753136480);
+			assert_eq!(state.issuance_after, 207677687130232134753136480);
+		});
+}
+
 #[test]
 fn withdraw_asset_amount_should_provide_correct_values_in_the_hook() {
 	let asset_a: AssetId = 1;
@@ -254,6 +331,8 @@ fn withdraw_asset_amount_should_provide_correct_values_in_the_hook() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -336,6 +415,8 @@ fn sell_should_provide_correct_values_in_the_hook() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -412,6 +493,8 @@ fn buy_should_provide_correct_values_in_the_hook() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
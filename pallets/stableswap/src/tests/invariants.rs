@@ -74,6 +74,8 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	vec![
@@ -147,6 +149,8 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	vec![
@@ -221,6 +225,8 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	vec![
@@ -295,6 +301,8 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(0),
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
 				},
 				InitialLiquidity{ account: ALICE, assets:
 				vec![
@@ -380,6 +388,8 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(0),
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
 				},
 				InitialLiquidity{ account: ALICE,
 					assets:	vec![
@@ -470,6 +480,8 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(0),
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
 				},
 				InitialLiquidity{ account: ALICE, assets:
 				vec![
@@ -582,6 +594,8 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(0),
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
 				},
 				InitialLiquidity{ account: ALICE, assets:
 				vec![
@@ -691,6 +705,8 @@ proptest! {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(0),
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
 				},
 				InitialLiquidity{ account: ALICE, assets:
 				vec![
@@ -816,12 +832,14 @@ proptest! {
 			.with_pool(
 				ALICE,
 				PoolInfo::<AssetId, u64> {
-					assets: pool_assets.try_into().unwrap(),
+					assets: pool_assets.clone().try_into().unwrap(),
 					initial_amplification: amplification,
 					final_amplification: amplification,
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_providers: vec![None; pool_assets.len()].try_into().unwrap(),
+					fee_ramp: None,
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	initial_liquidity,}
@@ -918,12 +936,14 @@ proptest! {
 			.with_pool(
 				ALICE,
 				PoolInfo::<AssetId, u64> {
-					assets: pool_assets.try_into().unwrap(),
+					assets: pool_assets.clone().try_into().unwrap(),
 					initial_amplification: amplification,
 					final_amplification: amplification,
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_providers: vec![None; pool_assets.len()].try_into().unwrap(),
+					fee_ramp: None,
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	initial_liquidity,}
@@ -1020,12 +1040,14 @@ proptest! {
 			.with_pool(
 				ALICE,
 				PoolInfo::<AssetId, u64> {
-					assets: pool_assets.try_into().unwrap(),
+					assets: pool_assets.clone().try_into().unwrap(),
 					initial_amplification: amplification,
 					final_amplification: amplification,
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_providers: vec![None; pool_assets.len()].try_into().unwrap(),
+					fee_ramp: None,
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	initial_liquidity,}
@@ -1126,12 +1148,14 @@ proptest! {
 			.with_pool(
 				ALICE,
 				PoolInfo::<AssetId, u64> {
-					assets: pool_assets.try_into().unwrap(),
+					assets: pool_assets.clone().try_into().unwrap(),
 					initial_amplification: amplification,
 					final_amplification: amplification,
 					initial_block: 0,
 					final_block: 0,
 					fee: trade_fee,
+					rate_providers: vec![None; pool_assets.len()].try_into().unwrap(),
+					fee_ramp: None,
 				},
 				InitialLiquidity{ account: ALICE,
 				assets:	initial_liquidity,}
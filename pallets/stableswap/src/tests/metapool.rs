@@ -0,0 +1,354 @@
+use crate::tests::mock::*;
+use crate::types::AssetAmount;
+use crate::{assert_balance, Error};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{ExecutorError, PoolType, Trade, TradeExecution, UnderlyingTrades};
+use sp_runtime::Permill;
+
+const BASE_POOL: AssetId = 100;
+const METAPOOL: AssetId = 200;
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const ASSET_C: AssetId = 3;
+
+fn metapool_ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, ASSET_A, 200 * ONE),
+			(ALICE, ASSET_B, 200 * ONE),
+			(ALICE, ASSET_C, 200 * ONE),
+			(BOB, ASSET_A, 200 * ONE),
+			(BOB, ASSET_C, 200 * ONE),
+		])
+		.with_registered_asset("base".as_bytes().to_vec(), BASE_POOL, 18)
+		.with_registered_asset("meta".as_bytes().to_vec(), METAPOOL, 18)
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_registered_asset("three".as_bytes().to_vec(), ASSET_C, 12)
+		.build();
+
+	ext.execute_with(|| {
+		assert_ok!(Stableswap::create_pool(
+			RuntimeOrigin::root(),
+			BASE_POOL,
+			vec![ASSET_A, ASSET_B],
+			100,
+			Permill::from_percent(0),
+		));
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			BASE_POOL,
+			vec![
+				AssetAmount::new(ASSET_A, 100 * ONE),
+				AssetAmount::new(ASSET_B, 100 * ONE),
+			]
+		));
+
+		assert_ok!(Stableswap::create_pool(
+			RuntimeOrigin::root(),
+			METAPOOL,
+			vec![ASSET_C, BASE_POOL],
+			100,
+			Permill::from_percent(0),
+		));
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			METAPOOL,
+			vec![
+				AssetAmount::new(ASSET_C, 100 * ONE),
+				AssetAmount::new(BASE_POOL, 100 * ONE * 1_000_000),
+			]
+		));
+	});
+
+	ext
+}
+
+#[test]
+fn sell_should_work_when_selling_base_pool_asset_in_metapool() {
+	metapool_ext().execute_with(|| {
+		let amount_in = 10 * ONE;
+		let shares =
+			Stableswap::calculate_sell(PoolType::Stableswap(BASE_POOL), ASSET_A, BASE_POOL, amount_in).unwrap();
+		let expected = Stableswap::calculate_sell(PoolType::Stableswap(METAPOOL), BASE_POOL, ASSET_C, shares).unwrap();
+
+		assert_eq!(
+			Stableswap::calculate_sell(
+				PoolType::StableswapMetapool(METAPOOL, BASE_POOL),
+				ASSET_A,
+				ASSET_C,
+				amount_in
+			),
+			Ok(expected)
+		);
+
+		assert_ok!(Stableswap::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::StableswapMetapool(METAPOOL, BASE_POOL),
+			ASSET_A,
+			ASSET_C,
+			amount_in,
+			expected,
+		));
+
+		assert_balance!(BOB, ASSET_A, 190 * ONE);
+		assert_balance!(BOB, ASSET_C, 200 * ONE + expected);
+		assert_balance!(BOB, BASE_POOL, 0);
+		assert_balance!(pool_account(BASE_POOL), ASSET_A, 110 * ONE);
+		assert_balance!(pool_account(METAPOOL), ASSET_C, 100 * ONE - expected);
+		assert_balance!(pool_account(METAPOOL), BASE_POOL, 100 * ONE * 1_000_000 + shares);
+	});
+}
+
+#[test]
+fn sell_should_work_when_buying_base_pool_asset_in_metapool() {
+	metapool_ext().execute_with(|| {
+		let amount_in = 10 * ONE;
+		let shares = Stableswap::calculate_sell(PoolType::Stableswap(METAPOOL), ASSET_C, BASE_POOL, amount_in).unwrap();
+		let expected = Stableswap::calculate_sell(PoolType::Stableswap(BASE_POOL), BASE_POOL, ASSET_B, shares).unwrap();
+
+		assert_eq!(
+			Stableswap::calculate_sell(
+				PoolType::StableswapMetapool(METAPOOL, BASE_POOL),
+				ASSET_C,
+				ASSET_B,
+				amount_in
+			),
+			Ok(expected)
+		);
+
+		assert_ok!(Stableswap::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::StableswapMetapool(METAPOOL, BASE_POOL),
+			ASSET_C,
+			ASSET_B,
+			amount_in,
+			expected,
+		));
+
+		assert_balance!(BOB, ASSET_C, 190 * ONE);
+		assert_balance!(BOB, ASSET_B, expected);
+		assert_balance!(BOB, BASE_POOL, 0);
+		assert_balance!(pool_account(BASE_POOL), ASSET_B, 100 * ONE - expected);
+		assert_balance!(pool_account(METAPOOL), ASSET_C, 110 * ONE);
+		assert_balance!(pool_account(METAPOOL), BASE_POOL, 100 * ONE * 1_000_000 - shares);
+	});
+}
+
+#[test]
+fn buy_should_work_when_selling_base_pool_asset_in_metapool() {
+	metapool_ext().execute_with(|| {
+		let amount_out = 5 * ONE;
+		let shares = Stableswap::calculate_buy(PoolType::Stableswap(METAPOOL), BASE_POOL, ASSET_C, amount_out).unwrap();
+		let expected = Stableswap::calculate_buy(PoolType::Stableswap(BASE_POOL), ASSET_A, BASE_POOL, shares).unwrap();
+
+		assert_eq!(
+			Stableswap::calculate_buy(
+				PoolType::StableswapMetapool(METAPOOL, BASE_POOL),
+				ASSET_A,
+				ASSET_C,
+				amount_out
+			),
+			Ok(expected)
+		);
+
+		assert_ok!(Stableswap::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::StableswapMetapool(METAPOOL, BASE_POOL),
+			ASSET_A,
+			ASSET_C,
+			amount_out,
+			expected,
+		));
+
+		assert_balance!(BOB, ASSET_A, 200 * ONE - expected);
+		assert_balance!(BOB, ASSET_C, 205 * ONE);
+		assert_balance!(BOB, BASE_POOL, 0);
+		assert_balance!(pool_account(BASE_POOL), ASSET_A, 100 * ONE + expected);
+		assert_balance!(pool_account(METAPOOL), ASSET_C, 95 * ONE);
+	});
+}
+
+#[test]
+fn buy_should_work_when_buying_base_pool_asset_in_metapool() {
+	metapool_ext().execute_with(|| {
+		let amount_out = 5 * ONE;
+		let shares =
+			Stableswap::calculate_buy(PoolType::Stableswap(BASE_POOL), BASE_POOL, ASSET_B, amount_out).unwrap();
+		let expected = Stableswap::calculate_buy(PoolType::Stableswap(METAPOOL), ASSET_C, BASE_POOL, shares).unwrap();
+
+		assert_eq!(
+			Stableswap::calculate_buy(
+				PoolType::StableswapMetapool(METAPOOL, BASE_POOL),
+				ASSET_C,
+				ASSET_B,
+				amount_out
+			),
+			Ok(expected)
+		);
+
+		assert_ok!(Stableswap::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::StableswapMetapool(METAPOOL, BASE_POOL),
+			ASSET_C,
+			ASSET_B,
+			amount_out,
+			expected,
+		));
+
+		assert_balance!(BOB, ASSET_C, 200 * ONE - expected);
+		assert_balance!(BOB, ASSET_B, 5 * ONE);
+		assert_balance!(BOB, BASE_POOL, 0);
+		assert_balance!(pool_account(BASE_POOL), ASSET_B, 95 * ONE);
+		assert_balance!(pool_account(METAPOOL), ASSET_C, 100 * ONE + expected);
+	});
+}
+
+#[test]
+fn metapool_sell_should_revert_both_trades_when_limit_is_not_reached() {
+	metapool_ext().execute_with(|| {
+		let amount_in = 10 * ONE;
+		let expected = Stableswap::calculate_sell(
+			PoolType::StableswapMetapool(METAPOOL, BASE_POOL),
+			ASSET_A,
+			ASSET_C,
+			amount_in,
+		)
+		.unwrap();
+
+		assert_noop!(
+			Stableswap::execute_sell(
+				RuntimeOrigin::signed(BOB),
+				PoolType::StableswapMetapool(METAPOOL, BASE_POOL),
+				ASSET_A,
+				ASSET_C,
+				amount_in,
+				expected + 1,
+			),
+			ExecutorError::Error(Error::<Test>::BuyLimitNotReached.into())
+		);
+	});
+}
+
+#[test]
+fn liquidity_depth_of_base_pool_asset_should_be_base_pool_reserve() {
+	metapool_ext().execute_with(|| {
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			BASE_POOL,
+			ASSET_A,
+			ASSET_B,
+			10 * ONE,
+			0,
+		));
+
+		assert_eq!(
+			Stableswap::get_liquidity_depth(PoolType::StableswapMetapool(METAPOOL, BASE_POOL), ASSET_A, METAPOOL),
+			Ok(110 * ONE)
+		);
+		assert_eq!(
+			Stableswap::get_liquidity_depth(PoolType::StableswapMetapool(METAPOOL, BASE_POOL), ASSET_C, METAPOOL),
+			Ok(100 * ONE)
+		);
+	});
+}
+
+#[test]
+fn metapool_trade_should_fail_when_asset_is_not_in_base_pool() {
+	metapool_ext().execute_with(|| {
+		// Base pool assets are not traded in the metapool without the metapool hop.
+		assert!(Stableswap::calculate_sell(PoolType::Stableswap(METAPOOL), ASSET_A, ASSET_C, 10 * ONE).is_err());
+
+		for (asset_in, asset_out) in [
+			(BASE_POOL, ASSET_C),
+			(BASE_POOL, ASSET_A),
+			(ASSET_A, ASSET_B),
+			(ASSET_C, METAPOOL),
+		] {
+			assert_eq!(
+				Stableswap::calculate_sell(
+					PoolType::StableswapMetapool(METAPOOL, BASE_POOL),
+					asset_in,
+					asset_out,
+					10 * ONE
+				),
+				Err(ExecutorError::Error(Error::<Test>::AssetNotInPool.into()))
+			);
+		}
+	});
+}
+
+#[test]
+fn metapool_trade_should_fail_when_base_pool_is_not_in_metapool() {
+	metapool_ext().execute_with(|| {
+		assert_eq!(
+			Stableswap::calculate_sell(
+				PoolType::StableswapMetapool(BASE_POOL, METAPOOL),
+				ASSET_C,
+				ASSET_A,
+				10 * ONE
+			),
+			Err(ExecutorError::Error(Error::<Test>::AssetNotInPool.into()))
+		);
+	});
+}
+
+#[test]
+fn underlying_trades_should_be_provided_only_for_metapool_trades() {
+	metapool_ext().execute_with(|| {
+		assert_eq!(
+			Stableswap::underlying_trades(&Trade {
+				pool: PoolType::StableswapMetapool(METAPOOL, BASE_POOL),
+				asset_in: ASSET_A,
+				asset_out: ASSET_C,
+			}),
+			Some(vec![
+				Trade {
+					pool: PoolType::Stableswap(BASE_POOL),
+					asset_in: ASSET_A,
+					asset_out: BASE_POOL,
+				},
+				Trade {
+					pool: PoolType::Stableswap(METAPOOL),
+					asset_in: BASE_POOL,
+					asset_out: ASSET_C,
+				},
+			])
+		);
+		assert_eq!(
+			Stableswap::underlying_trades(&Trade {
+				pool: PoolType::StableswapMetapool(METAPOOL, BASE_POOL),
+				asset_in: METAPOOL,
+				asset_out: ASSET_B,
+			}),
+			Some(vec![
+				Trade {
+					pool: PoolType::Stableswap(METAPOOL),
+					asset_in: METAPOOL,
+					asset_out: BASE_POOL,
+				},
+				Trade {
+					pool: PoolType::Stableswap(BASE_POOL),
+					asset_in: BASE_POOL,
+					asset_out: ASSET_B,
+				},
+			])
+		);
+
+		for (pool_id, asset_in, asset_out) in [
+			(BASE_POOL, ASSET_A, ASSET_B),
+			(METAPOOL, BASE_POOL, ASSET_C),
+			(METAPOOL, ASSET_A, ASSET_C),
+		] {
+			assert_eq!(
+				Stableswap::underlying_trades(&Trade {
+					pool: PoolType::Stableswap(pool_id),
+					asset_in,
+					asset_out,
+				}),
+				None
+			);
+		}
+	});
+}
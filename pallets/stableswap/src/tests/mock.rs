@@ -73,6 +73,7 @@ thread_local! {
 	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
 	pub static LAST_LIQUDITY_CHANGE_HOOK: RefCell<Option<(AssetId, PoolState<AssetId>)>> = RefCell::new(None);
 	pub static LAST_TRADE_HOOK: RefCell<Option<(AssetId, AssetId, AssetId, PoolState<AssetId>)>> = RefCell::new(None);
+	pub static ORACLE_RATES: RefCell<HashMap<(AssetId, AssetId), (Balance, Balance)>> = RefCell::new(HashMap::default());
 }
 
 construct_runtime!(
@@ -136,6 +137,8 @@ parameter_types! {
 	pub const MinimumLiquidity: Balance = 1000;
 	pub const MinimumTradingLimit: Balance = 1000;
 	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+	pub const RateOraclePeriod: OraclePeriod = OraclePeriod::Short;
+	pub const StableswapOracleSource: Source = *b"stablesw";
 }
 
 pub struct Whitelist;
@@ -180,6 +183,10 @@ impl Config for Test {
 	type BlockNumberProvider = System;
 	type DustAccountHandler = Whitelist;
 	type Hooks = DummyHookAdapter;
+	type RateOracle = DummyRateOracle;
+	type RateOraclePeriod = RateOraclePeriod;
+	type OracleSource = StableswapOracleSource;
+	type UpdateRateOrigin = EnsureRoot<AccountId>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = DummyRegistry;
 }
@@ -209,6 +216,9 @@ impl Default for ExtBuilder {
 		POOL_IDS.with(|v| {
 			v.borrow_mut().clear();
 		});
+		ORACLE_RATES.with(|v| {
+			v.borrow_mut().clear();
+		});
 		Self {
 			endowed_accounts: vec![],
 			registered_assets: vec![],
@@ -245,6 +255,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_oracle_rate(self, asset: AssetId, peg: AssetId, rate: (Balance, Balance)) -> Self {
+		set_oracle_rate(asset, peg, rate);
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
@@ -294,6 +309,17 @@ impl ExtBuilder {
 					v.borrow_mut().push(pool_id);
 				});
 
+				for (asset_id, provider) in pool.assets.iter().zip(pool.rate_providers.iter()) {
+					if provider.is_some() {
+						assert_ok!(Stableswap::set_rate_provider(
+							RuntimeOrigin::root(),
+							pool_id,
+							*asset_id,
+							provider.clone(),
+						));
+					}
+				}
+
 				if initial_liquid.assets.len() as u128 > Balance::zero() {
 					assert_ok!(Stableswap::add_liquidity(
 						RuntimeOrigin::signed(initial_liquid.account),
@@ -311,6 +337,8 @@ impl ExtBuilder {
 #[cfg(feature = "runtime-benchmarks")]
 use crate::types::BenchmarkHelper;
 use crate::types::{AssetAmount, PoolInfo, PoolState, StableswapHooks};
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::oracle::{AggregatedPriceOracle, OraclePeriod, Source};
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use hydradx_traits::{AccountIdFor, Inspect};
 use sp_runtime::traits::Zero;
@@ -435,3 +463,32 @@ pub(crate) fn last_liquidity_changed_hook_state() -> Option<(AssetId, PoolState<
 pub(crate) fn last_trade_hook_state() -> Option<(AssetId, AssetId, AssetId, PoolState<AssetId>)> {
 	LAST_TRADE_HOOK.with(|v| v.borrow().clone())
 }
+
+pub(crate) fn set_oracle_rate(asset: AssetId, peg: AssetId, rate: (Balance, Balance)) {
+	ORACLE_RATES.with(|v| {
+		v.borrow_mut().insert((asset, peg), rate);
+	});
+}
+
+pub struct DummyRateOracle;
+
+impl AggregatedPriceOracle<AssetId, u64, EmaPrice> for DummyRateOracle {
+	type Error = ();
+
+	// Price of `asset_b` denominated in `asset_a`.
+	fn get_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		_period: OraclePeriod,
+		_source: Source,
+	) -> Result<(EmaPrice, u64), Self::Error> {
+		let (n, d) = ORACLE_RATES
+			.with(|v| v.borrow().get(&(asset_b, asset_a)).copied())
+			.ok_or(())?;
+		Ok((EmaPrice::new(n, d), 1))
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
@@ -5,10 +5,13 @@ use sp_runtime::FixedU128;
 mod add_liquidity;
 mod amplification;
 mod creation;
+mod fee_ramp;
 mod hooks;
 mod invariants;
+mod metapool;
 pub(crate) mod mock;
 mod price;
+mod rate_providers;
 mod remove_liquidity;
 mod trades;
 mod update_pool;
@@ -29,6 +29,8 @@ fn test_spot_price_in_sell() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -96,6 +98,8 @@ fn test_spot_price_in_buy() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -159,6 +163,8 @@ fn test_share_price_in_add_remove_liquidity() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -232,6 +238,8 @@ fn test_share_price_in_add_shares_remove_liquidity() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -307,6 +315,8 @@ fn test_share_price_case() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
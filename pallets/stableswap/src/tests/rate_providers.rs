@@ -0,0 +1,348 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, PoolInfo, RateProvider};
+use crate::{assert_balance, Error, ExternalRates, Pools};
+use std::num::NonZeroU16;
+
+use frame_support::traits::Get;
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::stableswap::types::AssetReserve;
+use sp_runtime::traits::Zero;
+use sp_runtime::{DispatchError::BadOrigin, Permill};
+
+const VDOT: AssetId = 1;
+const DOT: AssetId = 2;
+
+const RATE_PRECISION: Balance = 1_000_000_000_000_000_000;
+
+// One vDOT is worth 1.5 DOT.
+const VDOT_RATE: (Balance, Balance) = (3 * RATE_PRECISION / 2, RATE_PRECISION);
+
+const ORACLE_PROVIDER: RateProvider<AssetId> = RateProvider::Oracle {
+	peg: DOT,
+	source: *b"omnipool",
+};
+
+fn lst_pool_ext(provider: Option<RateProvider<AssetId>>) -> sp_io::TestExternalities {
+	lst_pool_ext_with_vdot_decimals(provider, 12, VDOT_RATE)
+}
+
+// Oracle rate is a ratio of raw amounts, so it depends on decimals of vDOT.
+fn lst_pool_ext_with_vdot_decimals(
+	provider: Option<RateProvider<AssetId>>,
+	vdot_decimals: u8,
+	oracle_rate: (Balance, Balance),
+) -> sp_io::TestExternalities {
+	let vdot_one = 10u128.pow(vdot_decimals.into());
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, VDOT, 100 * vdot_one),
+			(BOB, DOT, 100 * ONE),
+			(ALICE, VDOT, 1_000_000 * vdot_one),
+			(ALICE, DOT, 1_500_000 * ONE),
+		])
+		.with_registered_asset("vdot".as_bytes().to_vec(), VDOT, vdot_decimals)
+		.with_registered_asset("dot".as_bytes().to_vec(), DOT, 12)
+		.with_oracle_rate(VDOT, DOT, oracle_rate)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![VDOT, DOT].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(1000).unwrap(),
+				final_amplification: NonZeroU16::new(1000).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(0),
+				rate_providers: vec![provider, None].try_into().unwrap(),
+				fee_ramp: None,
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(VDOT, 1_000_000 * vdot_one),
+					AssetAmount::new(DOT, 1_500_000 * ONE),
+				],
+			},
+		)
+		.build()
+}
+
+#[test]
+fn set_rate_provider_should_work_when_asset_is_in_pool() {
+	lst_pool_ext(None).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_rate_provider(
+			RuntimeOrigin::root(),
+			pool_id,
+			VDOT,
+			Some(ORACLE_PROVIDER),
+		));
+
+		assert_eq!(
+			Pools::<Test>::get(pool_id).unwrap().rate_providers.into_inner(),
+			vec![Some(ORACLE_PROVIDER), None]
+		);
+
+		assert_ok!(Stableswap::set_rate_provider(
+			RuntimeOrigin::root(),
+			pool_id,
+			VDOT,
+			None
+		));
+
+		assert_eq!(
+			Pools::<Test>::get(pool_id).unwrap().rate_providers.into_inner(),
+			vec![None, None]
+		);
+	});
+}
+
+#[test]
+fn set_rate_provider_should_fail_when_origin_is_not_authority() {
+	lst_pool_ext(None).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_rate_provider(RuntimeOrigin::signed(ALICE), pool_id, VDOT, Some(ORACLE_PROVIDER)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_rate_provider_should_fail_when_asset_is_not_in_pool() {
+	lst_pool_ext(None).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_rate_provider(RuntimeOrigin::root(), pool_id, HDX, Some(RateProvider::External)),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn set_rate_provider_should_fail_when_asset_is_its_own_peg() {
+	lst_pool_ext(None).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_rate_provider(RuntimeOrigin::root(), pool_id, DOT, Some(ORACLE_PROVIDER)),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn set_rate_provider_should_fail_when_oracle_source_is_the_pool_itself() {
+	lst_pool_ext(None).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_rate_provider(
+				RuntimeOrigin::root(),
+				pool_id,
+				VDOT,
+				Some(RateProvider::Oracle {
+					peg: pool_id,
+					source: StableswapOracleSource::get(),
+				})
+			),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			Stableswap::set_rate_provider(
+				RuntimeOrigin::root(),
+				pool_id,
+				VDOT,
+				Some(RateProvider::Oracle {
+					peg: DOT,
+					source: StableswapOracleSource::get(),
+				})
+			),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn set_external_rate_should_work_when_rate_is_valid() {
+	lst_pool_ext(None).execute_with(|| {
+		assert_ok!(Stableswap::set_external_rate(RuntimeOrigin::root(), VDOT, VDOT_RATE));
+
+		assert_eq!(ExternalRates::<Test>::get(VDOT), Some(VDOT_RATE));
+	});
+}
+
+#[test]
+fn set_external_rate_should_fail_when_rate_is_zero() {
+	lst_pool_ext(None).execute_with(|| {
+		assert_noop!(
+			Stableswap::set_external_rate(RuntimeOrigin::root(), VDOT, (0, RATE_PRECISION)),
+			Error::<Test>::InvalidRate
+		);
+		assert_noop!(
+			Stableswap::set_external_rate(RuntimeOrigin::root(), VDOT, (RATE_PRECISION, 0)),
+			Error::<Test>::InvalidRate
+		);
+	});
+}
+
+#[test]
+fn set_external_rate_should_fail_when_origin_is_not_allowed() {
+	lst_pool_ext(None).execute_with(|| {
+		assert_noop!(
+			Stableswap::set_external_rate(RuntimeOrigin::signed(ALICE), VDOT, VDOT_RATE),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn sell_should_apply_oracle_rate_when_asset_has_oracle_rate_provider() {
+	lst_pool_ext(Some(ORACLE_PROVIDER)).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			VDOT,
+			DOT,
+			ONE,
+			ONE + ONE / 2 - ONE / 1000,
+		));
+
+		assert_balance!(BOB, VDOT, 99 * ONE);
+		assert_balance!(BOB, DOT, 100 * ONE + 1_499_999_998_500);
+	});
+}
+
+#[test]
+fn sell_should_apply_oracle_rate_when_asset_and_peg_have_different_decimals() {
+	// Oracle price is a ratio of raw amounts - one vDOT (10 decimals) is worth 1.5 DOT (12 decimals).
+	let vdot_one = 10_000_000_000;
+	lst_pool_ext_with_vdot_decimals(Some(ORACLE_PROVIDER), 10, (150 * RATE_PRECISION, RATE_PRECISION)).execute_with(
+		|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_ok!(Stableswap::sell(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				VDOT,
+				DOT,
+				vdot_one,
+				ONE + ONE / 2 - ONE / 1000,
+			));
+
+			assert_balance!(BOB, VDOT, 99 * vdot_one);
+			assert_balance!(BOB, DOT, 100 * ONE + 1_499_999_998_500);
+		},
+	);
+}
+
+#[test]
+fn buy_should_apply_external_rate_when_asset_has_external_rate_provider() {
+	lst_pool_ext(None).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_external_rate(RuntimeOrigin::root(), VDOT, VDOT_RATE));
+		assert_ok!(Stableswap::set_rate_provider(
+			RuntimeOrigin::root(),
+			pool_id,
+			VDOT,
+			Some(RateProvider::External),
+		));
+
+		assert_ok!(Stableswap::buy(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			DOT,
+			VDOT,
+			3 * ONE,
+			2 * ONE + ONE / 1000,
+		));
+
+		assert_balance!(BOB, VDOT, 100 * ONE - 2_000_000_003_998);
+		assert_balance!(BOB, DOT, 103 * ONE);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_rate_is_not_available() {
+	lst_pool_ext(None).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_rate_provider(
+			RuntimeOrigin::root(),
+			pool_id,
+			VDOT,
+			Some(RateProvider::External),
+		));
+
+		assert_noop!(
+			Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, VDOT, DOT, ONE, 0),
+			Error::<Test>::RateNotAvailable
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_work_when_rate_is_not_available() {
+	lst_pool_ext(None).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_rate_provider(
+			RuntimeOrigin::root(),
+			pool_id,
+			VDOT,
+			Some(RateProvider::External),
+		));
+
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+		let share_issuance = Tokens::total_issuance(pool_id);
+
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares / 2,
+			vec![],
+		));
+
+		let amounts_out = hydra_dx_math::stableswap::calculate_liquidity_out(
+			&[
+				AssetReserve::new(1_000_000 * ONE, 12),
+				AssetReserve::new(1_500_000 * ONE, 12),
+			],
+			shares / 2,
+			share_issuance,
+		)
+		.unwrap();
+		assert!(!amounts_out[0].is_zero() && !amounts_out[1].is_zero());
+		assert_balance!(ALICE, VDOT, amounts_out[0]);
+		assert_balance!(ALICE, DOT, amounts_out[1]);
+	});
+}
+
+#[test]
+fn add_liquidity_should_apply_rate_when_asset_has_rate_provider() {
+	lst_pool_ext(Some(ORACLE_PROVIDER)).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			vec![AssetAmount::new(VDOT, 2 * ONE)],
+		));
+		let shares_for_vdot = Tokens::free_balance(pool_id, &BOB);
+
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			vec![AssetAmount::new(DOT, 3 * ONE)],
+		));
+		let shares_for_dot = Tokens::free_balance(pool_id, &BOB) - shares_for_vdot;
+
+		// 2 vDOT are worth 3 DOT, so both should be given approximately the same amount of shares.
+		assert!(shares_for_vdot.abs_diff(shares_for_dot) < shares_for_dot / 1_000_000);
+	});
+}
@@ -1,5 +1,5 @@
 use crate::tests::mock::*;
-use crate::types::{AssetAmount, PoolInfo};
+use crate::types::{AssetAmount, PoolInfo, Tradability};
 use crate::{assert_balance, Error};
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::Permill;
@@ -30,6 +30,8 @@ fn remove_liquidity_should_work_when_withdrawing_all_shares() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -98,6 +100,8 @@ fn remove_liquidity_should_apply_fee_when_withdrawing_all_shares() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -225,6 +229,8 @@ fn remove_liquidity_should_fail_when_requested_asset_not_in_pool() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -281,6 +287,8 @@ fn remove_liquidity_should_fail_when_remaining_shares_below_min_liquidity() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -346,6 +354,8 @@ fn verify_remove_liquidity_against_research_impl() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_float(0.003),
+				rate_providers: vec![None; 4].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -416,6 +426,8 @@ fn remove_liquidity_fail_when_desired_min_limit_is_not_reached() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -478,6 +490,8 @@ fn scenario_add_remove_with_different_decimals() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_float(0.0),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -545,6 +559,8 @@ fn scenario_sell_with_different_decimals() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_float(0.0),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -597,6 +613,8 @@ fn specific_scenario_to_verify_remove_liquidity() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -650,6 +668,8 @@ fn specific_scenario_to_verify_withdrawal_exact_amount() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -705,6 +725,8 @@ fn specific_scenario_to_verify_difference() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -757,6 +779,8 @@ fn scenario_3_trade() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_float(0.0001),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -810,6 +834,8 @@ fn removing_liquidity_with_exact_amount_should_work() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -879,6 +905,8 @@ fn removing_liquidity_should_not_give_more_assets() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::zero(),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -948,6 +976,8 @@ fn removing_liquidity_with_exact_amount_should_apply_fee() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(1),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -992,3 +1022,175 @@ fn removing_liquidity_with_exact_amount_should_apply_fee() {
 			assert_eq!(balance, 1988517979234162416);
 		});
 }
+
+fn proportional_pool_ext(fee: Permill) -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, 1, 100 * ONE),
+			(ALICE, 2, 200 * ONE),
+			(ALICE, 3, 300 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), 1, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), 2, 12)
+		.with_registered_asset("three".as_bytes().to_vec(), 3, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![1, 2, 3].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee,
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(1, 100 * ONE),
+					AssetAmount::new(2, 200 * ONE),
+					AssetAmount::new(3, 300 * ONE),
+				],
+			},
+		)
+		.build()
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_withdraw_all_assets_without_fee() {
+	proportional_pool_ext(Permill::from_percent(10)).execute_with(|| {
+		// Arrange
+		let pool_id = get_pool_id_at(0);
+		let pool_account = pool_account(pool_id);
+		let issuance = Tokens::total_issuance(pool_id);
+		let shares = issuance / 4;
+
+		// Act
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			vec![],
+		));
+
+		// Assert
+		let expected_a = 100 * ONE * shares / issuance;
+		let expected_b = 200 * ONE * shares / issuance;
+		let expected_c = 300 * ONE * shares / issuance;
+		assert_balance!(ALICE, 1, expected_a);
+		assert_balance!(ALICE, 2, expected_b);
+		assert_balance!(ALICE, 3, expected_c);
+		assert_balance!(ALICE, pool_id, issuance - shares);
+		assert_balance!(pool_account, 1, 100 * ONE - expected_a);
+		assert_balance!(pool_account, 2, 200 * ONE - expected_b);
+		assert_balance!(pool_account, 3, 300 * ONE - expected_c);
+	});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_empty_pool_when_withdrawing_all_shares() {
+	proportional_pool_ext(Permill::from_percent(10)).execute_with(|| {
+		// Arrange
+		let pool_id = get_pool_id_at(0);
+		let pool_account = pool_account(pool_id);
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+
+		// Act
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			vec![
+				AssetAmount::new(1, 100 * ONE),
+				AssetAmount::new(2, 200 * ONE),
+				AssetAmount::new(3, 300 * ONE),
+			],
+		));
+
+		// Assert
+		assert_balance!(ALICE, 1, 100 * ONE);
+		assert_balance!(ALICE, 2, 200 * ONE);
+		assert_balance!(ALICE, 3, 300 * ONE);
+		assert_balance!(ALICE, pool_id, 0u128);
+		assert_balance!(pool_account, 1, 0u128);
+		assert_balance!(pool_account, 2, 0u128);
+		assert_balance!(pool_account, 3, 0u128);
+		assert_eq!(Tokens::total_issuance(pool_id), 0u128);
+	});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_min_amount_out_is_not_reached() {
+	proportional_pool_ext(Permill::from_percent(0)).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let issuance = Tokens::total_issuance(pool_id);
+		let shares = issuance / 4;
+		let expected_b = 200 * ONE * shares / issuance;
+
+		assert_noop!(
+			Stableswap::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				shares,
+				vec![AssetAmount::new(2, expected_b + 1)],
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_min_amount_out_asset_is_not_in_pool() {
+	proportional_pool_ext(Permill::from_percent(0)).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::total_issuance(pool_id) / 4;
+
+		assert_noop!(
+			Stableswap::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				shares,
+				vec![AssetAmount::new(4, 0)],
+			),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_removing_liquidity_of_an_asset_is_not_allowed() {
+	proportional_pool_ext(Permill::from_percent(0)).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::total_issuance(pool_id) / 4;
+
+		assert_ok!(Stableswap::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			pool_id,
+			2,
+			Tradability::SELL | Tradability::BUY | Tradability::ADD_LIQUIDITY,
+		));
+
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(ALICE), pool_id, shares, vec![]),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_shares_are_insufficient() {
+	proportional_pool_ext(Permill::from_percent(0)).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(BOB), pool_id, shares, vec![]),
+			Error::<Test>::InsufficientShares
+		);
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(ALICE), pool_id, 0, vec![]),
+			Error::<Test>::InvalidAssetAmount
+		);
+	});
+}
@@ -23,6 +23,8 @@ fn sell_should_work_when_correct_input_provided() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -73,6 +75,8 @@ fn buy_should_work_when_correct_input_provided() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -124,6 +128,8 @@ fn sell_with_fee_should_work_when_correct_input_provided() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(10),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -172,6 +178,8 @@ fn sell_should_work_when_fee_is_small() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_rational(3u32, 1000u32),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -220,6 +228,8 @@ fn buy_should_work_when_fee_is_set() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(10),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -273,6 +283,8 @@ fn sell_should_fail_when_insufficient_amount_is_provided() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -359,6 +371,8 @@ fn buy_should_fail_when_insufficient_amount_is_provided() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -461,6 +475,8 @@ fn sell_should_work_when_pool_have_asset_with_various_decimals() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -520,6 +536,8 @@ fn buy_should_work_when_pool_have_asset_with_various_decimals() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 3].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -578,6 +596,8 @@ fn sell_should_work_when_assets_have_different_decimals() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -634,6 +654,8 @@ fn buy_should_work_when_assets_have_different_decimals() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -684,6 +706,8 @@ fn sell_should_fail_when_trading_same_assets() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -727,6 +751,8 @@ fn buy_should_fail_when_trading_same_assets() {
 				initial_block: 0,
 				final_block: 0,
 				fee: Permill::from_percent(0),
+				rate_providers: vec![None; 2].try_into().unwrap(),
+				fee_ramp: None,
 			},
 			InitialLiquidity {
 				account: ALICE,
@@ -41,6 +41,8 @@ fn update_pool_should_work_when_all_parames_are_updated() {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(10),
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
 				}
 			);
 		});
@@ -82,6 +84,8 @@ fn update_pool_should_work_when_only_fee_is_updated() {
 					initial_block: 0,
 					final_block: 0,
 					fee: Permill::from_percent(20),
+					rate_providers: vec![None; 2].try_into().unwrap(),
+					fee_ramp: None,
 				}
 			);
 		});
@@ -1,9 +1,13 @@
 use crate::types::AssetAmount;
 use crate::{Balance, Config, Error, Pallet, Pools, D_ITERATIONS, Y_ITERATIONS};
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use frame_support::transactional;
+use frame_system::ensure_signed;
+use hydradx_traits::router::{ExecutorError, PoolType, Trade, TradeExecution, UnderlyingTrades};
 use orml_traits::MultiCurrency;
-use sp_runtime::{ArithmeticError, DispatchError};
+use sp_runtime::traits::Zero;
+use sp_runtime::{ArithmeticError, DispatchError, DispatchResult};
 use sp_std::vec;
+use sp_std::vec::Vec;
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T> {
 	type Error = DispatchError;
@@ -15,6 +19,12 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 		amount_in: Balance,
 	) -> Result<Balance, ExecutorError<Self::Error>> {
 		match pool_type {
+			PoolType::StableswapMetapool(pool_id, base_pool_id) => {
+				let [first, second] =
+					Self::metapool_trades(pool_id, base_pool_id, asset_in, asset_out).map_err(ExecutorError::Error)?;
+				let amount = Self::calculate_sell(first.pool, first.asset_in, first.asset_out, amount_in)?;
+				Self::calculate_sell(second.pool, second.asset_in, second.asset_out, amount)
+			}
 			PoolType::Stableswap(pool_id) => {
 				if asset_in == pool_id {
 					let pool = Pools::<T>::get(pool_id)
@@ -23,17 +33,20 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 						.find_asset(asset_out)
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::AssetNotInPool.into()))?;
 					let pool_account = Self::pool_account(pool_id);
-					let balances = pool
-						.reserves_with_decimals::<T>(&pool_account)
-						.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+					let balances = Self::pool_reserves(&pool, &pool_account).map_err(ExecutorError::Error)?;
 					let share_issuance = T::Currency::total_issuance(pool_id);
 
 					let amplification = Self::get_amplification(&pool);
-					let (amount, _) = hydra_dx_math::stableswap::calculate_withdraw_one_asset::<
-						D_ITERATIONS,
-						Y_ITERATIONS,
-					>(&balances, amount_in, asset_idx, share_issuance, amplification, pool.fee)
-					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
+					let (amount, _) =
+						hydra_dx_math::stableswap::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+							&balances,
+							amount_in,
+							asset_idx,
+							share_issuance,
+							amplification,
+							Self::get_fee(&pool),
+						)
+						.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
 					Ok(amount)
 				} else if asset_out == pool_id {
@@ -65,6 +78,12 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 		amount_out: Balance,
 	) -> Result<Balance, ExecutorError<Self::Error>> {
 		match pool_type {
+			PoolType::StableswapMetapool(pool_id, base_pool_id) => {
+				let [first, second] =
+					Self::metapool_trades(pool_id, base_pool_id, asset_in, asset_out).map_err(ExecutorError::Error)?;
+				let amount = Self::calculate_buy(second.pool, second.asset_in, second.asset_out, amount_out)?;
+				Self::calculate_buy(first.pool, first.asset_in, first.asset_out, amount)
+			}
 			PoolType::Stableswap(pool_id) => {
 				if asset_out == pool_id {
 					//I wanna buy 500 shares, how much luqidity i need provide to get 500 shares
@@ -74,9 +93,7 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 						.find_asset(asset_in)
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::AssetNotInPool.into()))?;
 					let pool_account = Self::pool_account(pool_id);
-					let balances = pool
-						.reserves_with_decimals::<T>(&pool_account)
-						.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+					let balances = Self::pool_reserves(&pool, &pool_account).map_err(ExecutorError::Error)?;
 					let share_issuance = T::Currency::total_issuance(pool_id);
 					let amplification = Self::get_amplification(&pool);
 
@@ -86,7 +103,7 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 						asset_idx,
 						share_issuance,
 						amplification,
-						pool.fee,
+						Self::get_fee(&pool),
 					)
 					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
@@ -98,9 +115,7 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 						.find_asset(asset_out)
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::AssetNotInPool.into()))?;
 					let pool_account = Self::pool_account(pool_id);
-					let balances = pool
-						.reserves_with_decimals::<T>(&pool_account)
-						.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+					let balances = Self::pool_reserves(&pool, &pool_account).map_err(ExecutorError::Error)?;
 					let share_issuance = T::Currency::total_issuance(pool_id);
 					let amplification = Self::get_amplification(&pool);
 
@@ -113,7 +128,7 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 						amount_out,
 						amplification,
 						share_issuance,
-						pool.fee,
+						Self::get_fee(&pool),
 					)
 					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
@@ -138,6 +153,11 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 		min_limit: Balance,
 	) -> Result<(), ExecutorError<Self::Error>> {
 		match pool_type {
+			PoolType::StableswapMetapool(pool_id, base_pool_id) => {
+				let trades =
+					Self::metapool_trades(pool_id, base_pool_id, asset_in, asset_out).map_err(ExecutorError::Error)?;
+				Self::execute_metapool_sell(who, trades, amount_in, min_limit).map_err(ExecutorError::Error)
+			}
 			PoolType::Stableswap(pool_id) => {
 				if asset_in == pool_id {
 					Self::remove_liquidity_one_asset(who, pool_id, asset_out, amount_in, min_limit)
@@ -169,6 +189,11 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 		max_limit: Balance,
 	) -> Result<(), ExecutorError<Self::Error>> {
 		match pool_type {
+			PoolType::StableswapMetapool(pool_id, base_pool_id) => {
+				let trades =
+					Self::metapool_trades(pool_id, base_pool_id, asset_in, asset_out).map_err(ExecutorError::Error)?;
+				Self::execute_metapool_buy(who, trades, amount_out, max_limit).map_err(ExecutorError::Error)
+			}
 			PoolType::Stableswap(pool_id) => {
 				if asset_out == pool_id {
 					Self::add_liquidity_shares(who, pool_id, amount_out, asset_in, max_limit)
@@ -194,7 +219,96 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 				let pool_account = Self::pool_account(pool_id);
 				Ok(T::Currency::free_balance(asset_a, &pool_account))
 			}
+			PoolType::StableswapMetapool(pool_id, base_pool_id) => {
+				// Liquidity of a base pool asset traded in a metapool is in the base pool.
+				let base_pool = Pools::<T>::get(base_pool_id)
+					.ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+				let pool_id = if base_pool.find_asset(asset_a).is_some() {
+					base_pool_id
+				} else {
+					pool_id
+				};
+				let pool_account = Self::pool_account(pool_id);
+				Ok(T::Currency::free_balance(asset_a, &pool_account))
+			}
 			_ => Err(ExecutorError::NotSupported),
 		}
 	}
 }
+
+impl<T: Config> UnderlyingTrades<T::AssetId> for Pallet<T> {
+	fn underlying_trades(trade: &Trade<T::AssetId>) -> Option<Vec<Trade<T::AssetId>>> {
+		match trade.pool {
+			PoolType::StableswapMetapool(pool_id, base_pool_id) => {
+				Self::metapool_trades(pool_id, base_pool_id, trade.asset_in, trade.asset_out)
+					.ok()
+					.map(|trades| trades.to_vec())
+			}
+			_ => None,
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Sell in the first pool of a metapool trade and sell the received amount in the second pool.
+	#[transactional]
+	fn execute_metapool_sell(
+		who: T::RuntimeOrigin,
+		trades: [Trade<T::AssetId>; 2],
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> DispatchResult {
+		let [first, second] = trades;
+		let account = ensure_signed(who.clone())?;
+
+		let balance_before = T::Currency::free_balance(first.asset_out, &account);
+		Self::execute_sell(
+			who.clone(),
+			first.pool,
+			first.asset_in,
+			first.asset_out,
+			amount_in,
+			Balance::zero(),
+		)
+		.map_err(Self::executor_error)?;
+		let amount = T::Currency::free_balance(first.asset_out, &account)
+			.checked_sub(balance_before)
+			.ok_or(ArithmeticError::Underflow)?;
+
+		Self::execute_sell(who, second.pool, second.asset_in, second.asset_out, amount, min_limit)
+			.map_err(Self::executor_error)
+	}
+
+	/// Buy amount required by the second pool of a metapool trade in the first pool and buy `amount_out` in the second pool.
+	#[transactional]
+	fn execute_metapool_buy(
+		who: T::RuntimeOrigin,
+		trades: [Trade<T::AssetId>; 2],
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> DispatchResult {
+		let [first, second] = trades;
+
+		let amount = Self::calculate_buy(second.pool, second.asset_in, second.asset_out, amount_out)
+			.map_err(Self::executor_error)?;
+		Self::execute_buy(
+			who.clone(),
+			first.pool,
+			first.asset_in,
+			first.asset_out,
+			amount,
+			max_limit,
+		)
+		.map_err(Self::executor_error)?;
+
+		Self::execute_buy(who, second.pool, second.asset_in, second.asset_out, amount_out, amount)
+			.map_err(Self::executor_error)
+	}
+
+	fn executor_error(error: ExecutorError<DispatchError>) -> DispatchError {
+		match error {
+			ExecutorError::Error(error) => error,
+			ExecutorError::NotSupported => Error::<T>::PoolNotFound.into(),
+		}
+	}
+}
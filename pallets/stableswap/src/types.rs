@@ -13,16 +13,40 @@ use frame_support::traits::ConstU32;
 use frame_support::weights::Weight;
 use frame_support::BoundedVec;
 use hydra_dx_math::stableswap::types::AssetReserve;
+use hydradx_traits::oracle::Source;
 use orml_traits::MultiCurrency;
 use scale_info::TypeInfo;
 use sp_core::RuntimeDebug;
 use sp_runtime::DispatchResult;
 pub(crate) type Balance = u128;
 
+/// Provider of the rate of a yield-bearing pool asset.
+/// Rate is the value of one unit of the asset expressed in the pool's peg.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum RateProvider<AssetId> {
+	/// Rate is the oracle price of the asset denominated in `peg` asset.
+	Oracle { peg: AssetId, source: Source },
+	/// Rate is set by `UpdateRateOrigin`, eg. redemption rate sent via XCM from the asset's home chain.
+	External,
+}
+
+/// Scheduled linear change of pool's trade fee.
+/// Fee changes from `initial_fee` at `initial_block` to pool's `fee` at `final_block`.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct FeeRamp<BlockNumber> {
+	pub initial_fee: Permill,
+	pub initial_block: BlockNumber,
+	pub final_block: BlockNumber,
+}
+
 /// Pool properties for 2-asset pool (v1)
 /// `assets`: pool assets
 /// `amplification`: amp parameter
-/// `fee`: trade fee to be withdrawn on sell/buy
+/// `fee`: trade fee to be withdrawn on sell/buy, final fee if fee is changing
+/// `rate_providers`: rate provider of each pool asset, `None` if asset is pegged 1:1
+/// `fee_ramp`: scheduled change of trade fee
 #[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct PoolInfo<AssetId, BlockNumber> {
@@ -32,6 +56,8 @@ pub struct PoolInfo<AssetId, BlockNumber> {
 	pub initial_block: BlockNumber,
 	pub final_block: BlockNumber,
 	pub fee: Permill,
+	pub rate_providers: BoundedVec<Option<RateProvider<AssetId>>, ConstU32<MAX_ASSETS_IN_POOL>>,
+	pub fee_ramp: Option<FeeRamp<BlockNumber>>,
 }
 
 fn has_unique_elements<T>(iter: &mut T) -> bool
@@ -52,7 +78,9 @@ where
 	}
 
 	pub(crate) fn is_valid(&self) -> bool {
-		self.assets.len() >= 2 && has_unique_elements(&mut self.assets.iter())
+		self.assets.len() >= 2
+			&& has_unique_elements(&mut self.assets.iter())
+			&& self.rate_providers.len() == self.assets.len()
 	}
 
 	pub(crate) fn reserves_with_decimals<T: Config>(&self, account: &T::AccountId) -> Option<Vec<AssetReserve>>
@@ -64,10 +92,7 @@ where
 			.map(|asset| {
 				let reserve = T::Currency::free_balance((*asset).into(), account);
 				let decimals = Pallet::<T>::retrieve_decimals((*asset).into())?;
-				Some(AssetReserve {
-					amount: reserve,
-					decimals,
-				})
+				Some(AssetReserve::new(reserve, decimals))
 			})
 			.collect()
 	}
@@ -288,6 +288,7 @@ impl pallet_evm_accounts::Config for Test {
 	type EvmNonceProvider = EvmNonceProvider;
 	type FeeMultiplier = frame_support::traits::ConstU32<10>;
 	type ControllerOrigin = frame_system::EnsureRoot<AccountId>;
+	type ChainId = frame_support::traits::ConstU64<222_222>;
 	type WeightInfo = ();
 }
 
@@ -38,6 +38,30 @@ benchmarks! {
 	}:{
 		assert_ok!(crate::Pallet::<T>::unpause_transaction(origin, b"Balances".to_vec(), b"transfer".to_vec()));
 	}
+
+	pause_transaction_until {
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		let until: BlockNumberFor<T> = 10u32.into();
+	}: {
+		assert_ok!(crate::Pallet::<T>::pause_transaction_until(origin, b"Balances".to_vec(), b"transfer".to_vec(), until));
+	}
+
+	pause_asset_transaction {
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		let asset_id: T::AssetId = T::AssetId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes()).unwrap();
+		let until: BlockNumberFor<T> = 10u32.into();
+	}: {
+		assert_ok!(crate::Pallet::<T>::pause_asset_transaction(origin, b"Tokens".to_vec(), b"transfer".to_vec(), asset_id, Some(until)));
+	}
+
+	unpause_asset_transaction {
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+		let asset_id: T::AssetId = T::AssetId::decode(&mut sp_runtime::traits::TrailingZeroInput::zeroes()).unwrap();
+		crate::Pallet::<T>::pause_asset_transaction(origin, b"Tokens".to_vec(), b"transfer".to_vec(), asset_id, None)?;
+		let origin = T::UpdateOrigin::try_successful_origin().unwrap();
+	}:{
+		assert_ok!(crate::Pallet::<T>::unpause_asset_transaction(origin, b"Tokens".to_vec(), b"transfer".to_vec(), asset_id));
+	}
 }
 
 #[cfg(test)]
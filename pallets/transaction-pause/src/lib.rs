@@ -21,6 +21,7 @@
 
 use frame_support::{
 	pallet_prelude::*,
+	storage::StoragePrefixedMap,
 	traits::{CallMetadata, Contains, GetCallMetadata, PalletInfoAccess},
 	BoundedVec,
 };
@@ -37,12 +38,29 @@ pub mod weights;
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+/// Function name which pauses all calls of a pallet.
+pub const WILDCARD: &[u8] = b"*";
+
+/// Provides ids of assets touched by a call.
+///
+/// Used to pause calls only when they are executed with a specific asset.
+pub trait CallAssetsInspector<Call, AssetId> {
+	fn assets(call: &Call) -> Vec<AssetId>;
+}
+
+// Default implementation which does not inspect any call.
+impl<Call, AssetId> CallAssetsInspector<Call, AssetId> for () {
+	fn assets(_call: &Call) -> Vec<AssetId> {
+		Vec::new()
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
@@ -53,6 +71,10 @@ pub mod pallet {
 	pub const MAX_STR_LENGTH: u32 = 40;
 	pub type BoundedName = BoundedVec<u8, ConstU32<MAX_STR_LENGTH>>;
 
+	// max length of a raw storage key of a paused transaction
+	pub const MAX_CURSOR_LENGTH: u32 = 256;
+	pub type Cursor = BoundedVec<u8, ConstU32<MAX_CURSOR_LENGTH>>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -60,6 +82,12 @@ pub mod pallet {
 		/// The origin which may set filter.
 		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Asset id type used to pause calls for specific assets.
+		type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// Provides ids of assets touched by a call.
+		type CallAssetsInspector: CallAssetsInspector<<Self as frame_system::Config>::RuntimeCall, Self::AssetId>;
+
 		/// Weight information for the extrinsics in this module.
 		type WeightInfo: WeightInfo;
 	}
@@ -72,6 +100,8 @@ pub mod pallet {
 		InvalidCharacter,
 		/// pallet name or function name is too long
 		NameTooLong,
+		/// pause expiry block is in the past
+		InvalidExpiry,
 	}
 
 	#[pallet::event]
@@ -87,17 +117,60 @@ pub mod pallet {
 			pallet_name_bytes: Vec<u8>,
 			function_name_bytes: Vec<u8>,
 		},
+		/// Paused transaction until given block
+		TransactionPausedUntil {
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Vec<u8>,
+			until: BlockNumberFor<T>,
+		},
+		/// Paused transaction for given asset
+		AssetTransactionPaused {
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Vec<u8>,
+			asset_id: T::AssetId,
+			until: Option<BlockNumberFor<T>>,
+		},
+		/// Unpaused transaction for given asset
+		AssetTransactionUnpaused {
+			pallet_name_bytes: Vec<u8>,
+			function_name_bytes: Vec<u8>,
+			asset_id: T::AssetId,
+		},
 	}
 
 	/// The paused transaction map
 	///
-	/// map (PalletNameBytes, FunctionNameBytes) => Option<()>
+	/// map (PalletNameBytes, FunctionNameBytes) => Option<Option<ExpiryBlock>>
+	///
+	/// Transaction without expiry block is paused until unpaused.
+	/// `WILDCARD` function name pauses all calls of the pallet.
 	#[pallet::storage]
 	#[pallet::getter(fn paused_transactions)]
-	pub type PausedTransactions<T: Config> = StorageMap<_, Twox64Concat, (BoundedName, BoundedName), (), OptionQuery>;
+	pub type PausedTransactions<T: Config> =
+		StorageMap<_, Twox64Concat, (BoundedName, BoundedName), Option<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The paused transaction map for specific assets
+	///
+	/// map (PalletNameBytes, FunctionNameBytes, AssetId) => Option<Option<ExpiryBlock>>
+	#[pallet::storage]
+	#[pallet::getter(fn paused_asset_transactions)]
+	pub type PausedAssetTransactions<T: Config> =
+		StorageMap<_, Twox64Concat, (BoundedName, BoundedName, T::AssetId), Option<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Raw storage key of the last pause checked for expiry
+	///
+	/// Expired pauses are removed in `on_idle` as long as there is enough remaining weight. The next `on_idle`
+	/// continues after this key, `PausedTransactions` are checked before `PausedAssetTransactions`.
+	#[pallet::storage]
+	#[pallet::getter(fn expired_pauses_cursor)]
+	pub type ExpiredPausesCursor<T: Config> = StorageValue<_, Cursor, OptionQuery>;
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::remove_expired_pauses(n, remaining_weight)
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -106,19 +179,11 @@ pub mod pallet {
 		pub fn pause_transaction(origin: OriginFor<T>, pallet_name: Vec<u8>, function_name: Vec<u8>) -> DispatchResult {
 			T::UpdateOrigin::ensure_origin(origin)?;
 
-			let pallet_name_b = BoundedName::try_from(pallet_name.clone()).map_err(|_| Error::<T>::NameTooLong)?;
-			let function_name_b = BoundedName::try_from(function_name.clone()).map_err(|_| Error::<T>::NameTooLong)?;
-
-			// not allowed to pause calls of this pallet to ensure safe
-			let pallet_name_string = sp_std::str::from_utf8(&pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
-			ensure!(
-				pallet_name_string != <Self as PalletInfoAccess>::name(),
-				Error::<T>::CannotPause
-			);
+			let (pallet_name_b, function_name_b) = Self::validate_names(&pallet_name, &function_name)?;
 
-			PausedTransactions::<T>::mutate_exists((pallet_name_b, function_name_b), |maybe_paused| {
-				if maybe_paused.is_none() {
-					*maybe_paused = Some(());
+			PausedTransactions::<T>::mutate((pallet_name_b, function_name_b), |maybe_paused| {
+				if *maybe_paused != Some(None) {
+					*maybe_paused = Some(None);
 					Self::deposit_event(Event::TransactionPaused {
 						pallet_name_bytes: pallet_name,
 						function_name_bytes: function_name,
@@ -148,6 +213,234 @@ pub mod pallet {
 			};
 			Ok(())
 		}
+
+		/// Pause transaction until `until` block. The transaction is unpaused automatically when the block is reached.
+		///
+		/// `function_name` can be `WILDCARD` to pause all calls of the pallet.
+		///
+		/// Emits `TransactionPausedUntil` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::pause_transaction_until())]
+		pub fn pause_transaction_until(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+			until: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let (pallet_name_b, function_name_b) = Self::validate_names(&pallet_name, &function_name)?;
+			Self::ensure_valid_expiry(Some(until))?;
+
+			PausedTransactions::<T>::insert((pallet_name_b, function_name_b), Some(until));
+
+			Self::deposit_event(Event::TransactionPausedUntil {
+				pallet_name_bytes: pallet_name,
+				function_name_bytes: function_name,
+				until,
+			});
+			Ok(())
+		}
+
+		/// Pause transaction only when it is executed with `asset_id`.
+		///
+		/// Assets touched by a call are provided by `CallAssetsInspector`.
+		/// `function_name` can be `WILDCARD` to pause all calls of the pallet.
+		/// If `until` is set, the transaction is unpaused automatically when the block is reached.
+		///
+		/// Emits `AssetTransactionPaused` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::pause_asset_transaction())]
+		pub fn pause_asset_transaction(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+			asset_id: T::AssetId,
+			until: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let (pallet_name_b, function_name_b) = Self::validate_names(&pallet_name, &function_name)?;
+			Self::ensure_valid_expiry(until)?;
+
+			PausedAssetTransactions::<T>::insert((pallet_name_b, function_name_b, asset_id), until);
+
+			Self::deposit_event(Event::AssetTransactionPaused {
+				pallet_name_bytes: pallet_name,
+				function_name_bytes: function_name,
+				asset_id,
+				until,
+			});
+			Ok(())
+		}
+
+		/// Unpause transaction paused for `asset_id`.
+		///
+		/// Emits `AssetTransactionUnpaused` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::unpause_asset_transaction())]
+		pub fn unpause_asset_transaction(
+			origin: OriginFor<T>,
+			pallet_name: Vec<u8>,
+			function_name: Vec<u8>,
+			asset_id: T::AssetId,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let pallet_name_b = BoundedName::try_from(pallet_name.clone()).map_err(|_| Error::<T>::NameTooLong)?;
+			let function_name_b = BoundedName::try_from(function_name.clone()).map_err(|_| Error::<T>::NameTooLong)?;
+
+			if PausedAssetTransactions::<T>::take((&pallet_name_b, &function_name_b, asset_id)).is_some() {
+				Self::deposit_event(Event::AssetTransactionUnpaused {
+					pallet_name_bytes: pallet_name,
+					function_name_bytes: function_name,
+					asset_id,
+				});
+			};
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn validate_names(pallet_name: &[u8], function_name: &[u8]) -> Result<(BoundedName, BoundedName), DispatchError> {
+		let pallet_name_b = BoundedName::try_from(pallet_name.to_vec()).map_err(|_| Error::<T>::NameTooLong)?;
+		let function_name_b = BoundedName::try_from(function_name.to_vec()).map_err(|_| Error::<T>::NameTooLong)?;
+
+		// not allowed to pause calls of this pallet to ensure safe
+		let pallet_name_string = sp_std::str::from_utf8(pallet_name).map_err(|_| Error::<T>::InvalidCharacter)?;
+		ensure!(
+			pallet_name_string != <Self as PalletInfoAccess>::name(),
+			Error::<T>::CannotPause
+		);
+
+		Ok((pallet_name_b, function_name_b))
+	}
+
+	fn ensure_valid_expiry(until: Option<BlockNumberFor<T>>) -> DispatchResult {
+		if let Some(until) = until {
+			ensure!(
+				until > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidExpiry
+			);
+		}
+		Ok(())
+	}
+
+	/// Returns `true` if a pause with given expiry is still in effect.
+	fn is_active(until: Option<BlockNumberFor<T>>) -> bool {
+		until.map_or(true, |until| frame_system::Pallet::<T>::block_number() < until)
+	}
+
+	/// Returns `true` if the call identified by pallet and function name is paused,
+	/// either directly or by a pallet wildcard.
+	pub fn is_paused(pallet_name: &BoundedName, function_name: &BoundedName) -> bool {
+		let wildcard = BoundedName::truncate_from(WILDCARD.to_vec());
+		[function_name, &wildcard].into_iter().any(|function_name| {
+			PausedTransactions::<T>::get((pallet_name, function_name)).map_or(false, Self::is_active)
+		})
+	}
+
+	/// Returns `true` if the call identified by pallet and function name is paused for `asset_id`,
+	/// either directly or by a pallet wildcard.
+	pub fn is_paused_for_asset(pallet_name: &BoundedName, function_name: &BoundedName, asset_id: T::AssetId) -> bool {
+		let wildcard = BoundedName::truncate_from(WILDCARD.to_vec());
+		[function_name, &wildcard].into_iter().any(|function_name| {
+			PausedAssetTransactions::<T>::get((pallet_name, function_name, asset_id)).map_or(false, Self::is_active)
+		})
+	}
+
+	/// Remove expired pauses while there is enough remaining weight. Returns consumed weight.
+	///
+	/// Continues from `ExpiredPausesCursor` and stores the last checked pause in it when the remaining weight runs
+	/// out, so every pause is eventually checked.
+	fn remove_expired_pauses(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let read_weight = T::DbWeight::get().reads(1);
+		let remove_weight = T::DbWeight::get().writes(1);
+		// read and update of the cursor
+		let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+		if !consumed.all_lte(remaining_weight) {
+			return Weight::zero();
+		}
+		let can_consume = |consumed: Weight| {
+			consumed
+				.saturating_add(read_weight)
+				.saturating_add(remove_weight)
+				.all_lte(remaining_weight)
+		};
+		let is_expired = |until: Option<BlockNumberFor<T>>| until.map_or(false, |until| until <= now);
+
+		let cursor = ExpiredPausesCursor::<T>::get().map(|cursor| cursor.into_inner());
+		// cursor pointing to `PausedAssetTransactions` means all `PausedTransactions` were already checked
+		let asset_cursor = cursor
+			.clone()
+			.filter(|key| !key.starts_with(&PausedTransactions::<T>::final_prefix()));
+		let mut last_key = None;
+		let mut out_of_weight = false;
+
+		if asset_cursor.is_none() {
+			let iter = match cursor {
+				Some(key) => PausedTransactions::<T>::iter_from(key),
+				None => PausedTransactions::<T>::iter(),
+			};
+			let mut expired = Vec::new();
+			for (key, until) in iter {
+				if !can_consume(consumed) {
+					out_of_weight = true;
+					break;
+				}
+				consumed.saturating_accrue(read_weight);
+				last_key = Some(PausedTransactions::<T>::hashed_key_for(&key));
+				if is_expired(until) {
+					consumed.saturating_accrue(remove_weight);
+					expired.push(key);
+				}
+			}
+			for (pallet_name, function_name) in expired {
+				PausedTransactions::<T>::remove((&pallet_name, &function_name));
+				Self::deposit_event(Event::TransactionUnpaused {
+					pallet_name_bytes: pallet_name.into_inner(),
+					function_name_bytes: function_name.into_inner(),
+				});
+			}
+		}
+
+		if !out_of_weight {
+			let iter = match asset_cursor {
+				Some(key) => PausedAssetTransactions::<T>::iter_from(key),
+				None => PausedAssetTransactions::<T>::iter(),
+			};
+			let mut expired = Vec::new();
+			for (key, until) in iter {
+				if !can_consume(consumed) {
+					out_of_weight = true;
+					break;
+				}
+				consumed.saturating_accrue(read_weight);
+				last_key = Some(PausedAssetTransactions::<T>::hashed_key_for(&key));
+				if is_expired(until) {
+					consumed.saturating_accrue(remove_weight);
+					expired.push(key);
+				}
+			}
+			for (pallet_name, function_name, asset_id) in expired {
+				PausedAssetTransactions::<T>::remove((&pallet_name, &function_name, asset_id));
+				Self::deposit_event(Event::AssetTransactionUnpaused {
+					pallet_name_bytes: pallet_name.into_inner(),
+					function_name_bytes: function_name.into_inner(),
+					asset_id,
+				});
+			}
+		}
+
+		if !out_of_weight {
+			// all pauses were checked, next cleanup starts from the beginning
+			ExpiredPausesCursor::<T>::kill();
+		} else if let Some(cursor) = last_key.and_then(|key| Cursor::try_from(key).ok()) {
+			ExpiredPausesCursor::<T>::put(cursor);
+		}
+
+		consumed
 	}
 }
 
@@ -164,11 +457,16 @@ where
 
 		let pallet_name_b = BoundedName::try_from(pallet_name.as_bytes().to_vec());
 		let function_name_b = BoundedName::try_from(function_name.as_bytes().to_vec());
-		if pallet_name_b.is_err() || function_name_b.is_err() {
+		let (Ok(pallet_name_b), Ok(function_name_b)) = (pallet_name_b, function_name_b) else {
 			return false;
+		};
+
+		if Pallet::<T>::is_paused(&pallet_name_b, &function_name_b) {
+			return true;
 		}
 
-		// it's safe to call unwrap here thanks to the test above
-		PausedTransactions::<T>::contains_key((pallet_name_b.unwrap_or_default(), function_name_b.unwrap_or_default()))
+		T::CallAssetsInspector::assets(call)
+			.into_iter()
+			.any(|asset_id| Pallet::<T>::is_paused_for_asset(&pallet_name_b, &function_name_b, asset_id))
 	}
 }
@@ -23,7 +23,7 @@ use frame_support::{
 };
 
 /// The log target.
-const TARGET: &str = "runtime::transaction-pause::migration::v1";
+const TARGET: &str = "runtime::transaction-pause::migration";
 
 pub mod v0 {
 	use super::*;
@@ -35,6 +35,10 @@ pub mod v0 {
 pub mod v1 {
 	use super::*;
 
+	#[storage_alias]
+	pub type PausedTransactions<T: Config> =
+		StorageMap<Pallet<T>, Twox64Concat, (BoundedName, BoundedName), (), OptionQuery>;
+
 	pub struct Migration<T>(PhantomData<T>);
 
 	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
@@ -63,7 +67,7 @@ pub mod v1 {
 
 				match (pallet_name_b, function_name_b) {
 					(Ok(pallet), Ok(function)) => {
-						crate::PausedTransactions::<T>::insert((pallet, function), ());
+						PausedTransactions::<T>::insert((pallet, function), ());
 						weight.saturating_accrue(T::DbWeight::get().writes(1));
 					}
 					_ => log::info!(
@@ -85,7 +89,7 @@ pub mod v1 {
 
 			let previous_state = <Vec<(Vec<u8>, Vec<u8>)> as codec::Decode>::decode(&mut state.as_slice()).unwrap();
 
-			let new_state = crate::PausedTransactions::<T>::iter_keys()
+			let new_state = PausedTransactions::<T>::iter_keys()
 				.map(|v| (v.0.into_inner(), v.1.into_inner()))
 				.collect::<Vec<(Vec<u8>, Vec<u8>)>>();
 
@@ -103,6 +107,67 @@ pub mod v1 {
 	}
 }
 
+pub mod v2 {
+	use super::*;
+	use sp_runtime::Saturating;
+
+	/// Migrates paused transactions to values with optional expiry block.
+	/// All existing pauses are kept without expiry.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Storage version too high.");
+
+			let iter = v1::PausedTransactions::<T>::iter_keys();
+
+			log::info!(target: TARGET, "Transaction pause migration v2: PRE checks successful!");
+
+			Ok(iter.collect::<Vec<(BoundedName, BoundedName)>>().encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 1 {
+				log::info!(target: TARGET, "Transaction pause migration to v2 skipped");
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v2 for Transaction pause");
+
+			let mut translated = 0u64;
+			crate::PausedTransactions::<T>::translate::<(), _>(|_key, _| {
+				translated.saturating_inc();
+				Some(None)
+			});
+
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2, "Unexpected storage version.");
+
+			let previous_state =
+				<Vec<(BoundedName, BoundedName)> as codec::Decode>::decode(&mut state.as_slice()).unwrap();
+
+			for old_entry in previous_state.into_iter() {
+				assert_eq!(
+					crate::PausedTransactions::<T>::get(old_entry),
+					Some(None),
+					"Migrated storage entries don't match the entries prior migration!"
+				);
+			}
+
+			log::info!(target: TARGET, "Transaction pause migration v2: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
+
 #[cfg(test)]
 #[cfg(feature = "try-runtime")]
 mod test {
@@ -133,14 +198,14 @@ mod test {
 			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1);
 
 			assert_eq!(
-				crate::PausedTransactions::<T>::get((
+				v1::PausedTransactions::<T>::get((
 					BoundedName::try_from("first pallet".as_bytes().to_vec()).unwrap(),
 					BoundedName::try_from("first function".as_bytes().to_vec()).unwrap()
 				)),
 				Some(())
 			);
 			assert_eq!(
-				crate::PausedTransactions::<T>::get((
+				v1::PausedTransactions::<T>::get((
 					BoundedName::try_from("second pallet".as_bytes().to_vec()).unwrap(),
 					BoundedName::try_from("second function".as_bytes().to_vec()).unwrap()
 				)),
@@ -148,4 +213,24 @@ mod test {
 			);
 		});
 	}
+
+	#[test]
+	fn migration_to_v2_works() {
+		ExtBuilder.build().execute_with(|| {
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			let key = (
+				BoundedName::try_from("first pallet".as_bytes().to_vec()).unwrap(),
+				BoundedName::try_from("first function".as_bytes().to_vec()).unwrap(),
+			);
+			v1::PausedTransactions::<T>::insert(key.clone(), ());
+
+			let state = v2::Migration::<T>::pre_upgrade().unwrap();
+			let _w = v2::Migration::<T>::on_runtime_upgrade();
+			v2::Migration::<T>::post_upgrade(state).unwrap();
+
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 2);
+			assert_eq!(crate::PausedTransactions::<T>::get(key), Some(None));
+		});
+	}
 }
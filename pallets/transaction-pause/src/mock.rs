@@ -24,6 +24,7 @@ use super::*;
 use frame_support::{
 	construct_runtime, ord_parameter_types, parameter_types,
 	traits::{ConstU128, ConstU32, ConstU64, Everything, Nothing},
+	weights::constants::RocksDbWeight,
 };
 use frame_system::EnsureSignedBy;
 use orml_traits::parameter_type_with_key;
@@ -33,6 +34,7 @@ use sp_runtime::{traits::IdentityLookup, BuildStorage};
 pub type AccountId = u128;
 pub const ALICE: AccountId = 1;
 pub const AUSD: CurrencyId = 2;
+pub const DOT: CurrencyId = 3;
 
 pub type Amount = i128;
 pub type Balance = u128;
@@ -60,7 +62,7 @@ impl frame_system::Config for Runtime {
 	type AccountData = pallet_balances::AccountData<Balance>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
-	type DbWeight = ();
+	type DbWeight = RocksDbWeight;
 	type BaseCallFilter = Everything;
 	type SystemWeightInfo = ();
 	type SS58Prefix = ();
@@ -115,9 +117,23 @@ ord_parameter_types! {
 impl Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UpdateOrigin = EnsureSignedBy<One, AccountId>;
+	type AssetId = CurrencyId;
+	type CallAssetsInspector = TokensCallInspector;
 	type WeightInfo = ();
 }
 
+pub struct TokensCallInspector;
+
+impl CallAssetsInspector<RuntimeCall, CurrencyId> for TokensCallInspector {
+	fn assets(call: &RuntimeCall) -> Vec<CurrencyId> {
+		match call {
+			RuntimeCall::Tokens(orml_tokens::Call::transfer { currency_id, .. })
+			| RuntimeCall::Tokens(orml_tokens::Call::transfer_keep_alive { currency_id, .. }) => vec![*currency_id],
+			_ => vec![],
+		}
+	}
+}
+
 type Block = frame_system::mocking::MockBlock<Runtime>;
 
 construct_runtime!(
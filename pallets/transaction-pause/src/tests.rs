@@ -62,7 +62,7 @@ fn pause_transaction_work() {
 		}));
 		assert_eq!(
 			TransactionPause::paused_transactions((balances_b_str, transfer_b_str)),
-			Some(())
+			Some(None)
 		);
 
 		assert_noop!(
@@ -122,7 +122,7 @@ fn unpause_transaction_work() {
 		));
 		assert_eq!(
 			TransactionPause::paused_transactions((balances_b_str.clone(), transfer_b_str.clone())),
-			Some(())
+			Some(None)
 		);
 
 		assert_noop!(
@@ -195,3 +195,318 @@ fn paused_transaction_filter_work() {
 		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
 	});
 }
+
+#[test]
+fn pause_transaction_until_work() {
+	ExtBuilder.build().execute_with(|| {
+		let balances_b_str = BoundedName::try_from(b"Balances".to_vec()).unwrap();
+		let transfer_b_str = BoundedName::try_from(b"transfer".to_vec()).unwrap();
+
+		System::set_block_number(1);
+
+		assert_noop!(
+			TransactionPause::pause_transaction_until(
+				RuntimeOrigin::signed(5),
+				b"Balances".to_vec(),
+				b"transfer".to_vec(),
+				10
+			),
+			BadOrigin
+		);
+
+		assert_noop!(
+			TransactionPause::pause_transaction_until(
+				RuntimeOrigin::signed(1),
+				b"Balances".to_vec(),
+				b"transfer".to_vec(),
+				1
+			),
+			Error::<Runtime>::InvalidExpiry
+		);
+
+		assert_noop!(
+			TransactionPause::pause_transaction_until(
+				RuntimeOrigin::signed(1),
+				b"TransactionPause".to_vec(),
+				b"pause_transaction".to_vec(),
+				10
+			),
+			Error::<Runtime>::CannotPause
+		);
+
+		assert_ok!(TransactionPause::pause_transaction_until(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			10
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::TransactionPausedUntil {
+			pallet_name_bytes: b"Balances".to_vec(),
+			function_name_bytes: b"transfer".to_vec(),
+			until: 10,
+		}));
+		assert_eq!(
+			TransactionPause::paused_transactions((balances_b_str, transfer_b_str)),
+			Some(Some(10))
+		);
+	});
+}
+
+#[test]
+fn paused_transaction_filter_should_not_contain_transaction_when_pause_expired() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(TransactionPause::pause_transaction_until(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			10
+		));
+
+		System::set_block_number(9);
+		assert!(PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+
+		System::set_block_number(10);
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+	});
+}
+
+#[test]
+fn on_idle_should_remove_expired_pauses() {
+	ExtBuilder.build().execute_with(|| {
+		let balances_b_str = BoundedName::try_from(b"Balances".to_vec()).unwrap();
+		let tokens_b_str = BoundedName::try_from(b"Tokens".to_vec()).unwrap();
+		let transfer_b_str = BoundedName::try_from(b"transfer".to_vec()).unwrap();
+
+		System::set_block_number(1);
+
+		assert_ok!(TransactionPause::pause_transaction_until(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			10
+		));
+		assert_ok!(TransactionPause::pause_transaction(
+			RuntimeOrigin::signed(1),
+			b"Tokens".to_vec(),
+			b"transfer".to_vec(),
+		));
+		assert_ok!(TransactionPause::pause_asset_transaction(
+			RuntimeOrigin::signed(1),
+			b"Tokens".to_vec(),
+			b"transfer".to_vec(),
+			DOT,
+			Some(10)
+		));
+
+		// not expired yet
+		TransactionPause::on_idle(9, Weight::MAX);
+		assert_eq!(
+			TransactionPause::paused_transactions((balances_b_str.clone(), transfer_b_str.clone())),
+			Some(Some(10))
+		);
+
+		TransactionPause::on_idle(10, Weight::MAX);
+
+		assert_eq!(
+			TransactionPause::paused_transactions((balances_b_str, transfer_b_str.clone())),
+			None
+		);
+		assert_eq!(
+			TransactionPause::paused_asset_transactions((tokens_b_str.clone(), transfer_b_str.clone(), DOT)),
+			None
+		);
+		// pause without expiry is kept
+		assert_eq!(
+			TransactionPause::paused_transactions((tokens_b_str, transfer_b_str)),
+			Some(None)
+		);
+		System::assert_has_event(Event::TransactionPause(crate::Event::TransactionUnpaused {
+			pallet_name_bytes: b"Balances".to_vec(),
+			function_name_bytes: b"transfer".to_vec(),
+		}));
+		System::assert_has_event(Event::TransactionPause(crate::Event::AssetTransactionUnpaused {
+			pallet_name_bytes: b"Tokens".to_vec(),
+			function_name_bytes: b"transfer".to_vec(),
+			asset_id: DOT,
+		}));
+	});
+}
+
+#[test]
+fn on_idle_should_continue_removing_expired_pauses_from_cursor() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+
+		for pallet_name in [b"Balances".to_vec(), b"Tokens".to_vec(), b"Currencies".to_vec()] {
+			assert_ok!(TransactionPause::pause_transaction_until(
+				RuntimeOrigin::signed(1),
+				pallet_name,
+				b"transfer".to_vec(),
+				10
+			));
+		}
+		assert_ok!(TransactionPause::pause_asset_transaction(
+			RuntimeOrigin::signed(1),
+			b"Tokens".to_vec(),
+			b"transfer".to_vec(),
+			DOT,
+			Some(10)
+		));
+
+		// enough weight to update the cursor and to check and remove two pauses
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		let remaining_weight = db_weight
+			.reads_writes(1, 1)
+			.saturating_add(db_weight.reads_writes(1, 1).saturating_mul(2));
+
+		TransactionPause::on_idle(10, remaining_weight);
+		assert_eq!(PausedTransactions::<Runtime>::iter().count(), 1);
+		assert_eq!(PausedAssetTransactions::<Runtime>::iter().count(), 1);
+		assert!(TransactionPause::expired_pauses_cursor().is_some());
+
+		TransactionPause::on_idle(10, remaining_weight);
+		assert_eq!(PausedTransactions::<Runtime>::iter().count(), 0);
+		assert_eq!(PausedAssetTransactions::<Runtime>::iter().count(), 0);
+		assert_eq!(TransactionPause::expired_pauses_cursor(), None);
+	});
+}
+
+#[test]
+fn on_idle_should_not_remove_pauses_when_weight_is_not_enough_for_cursor() {
+	ExtBuilder.build().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(TransactionPause::pause_transaction_until(
+			RuntimeOrigin::signed(1),
+			b"Balances".to_vec(),
+			b"transfer".to_vec(),
+			10
+		));
+
+		let remaining_weight = <Runtime as frame_system::Config>::DbWeight::get().reads(1);
+
+		assert_eq!(TransactionPause::on_idle(10, remaining_weight), Weight::zero());
+		assert_eq!(PausedTransactions::<Runtime>::iter().count(), 1);
+	});
+}
+
+#[test]
+fn paused_transaction_filter_should_contain_all_pallet_calls_when_paused_with_wildcard() {
+	ExtBuilder.build().execute_with(|| {
+		assert_ok!(TransactionPause::pause_transaction(
+			RuntimeOrigin::signed(1),
+			b"Tokens".to_vec(),
+			WILDCARD.to_vec()
+		));
+
+		assert!(PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+		assert!(PausedTransactionFilter::<Runtime>::contains(
+			&mock::RuntimeCall::Tokens(orml_tokens::Call::transfer_all {
+				dest: ALICE,
+				currency_id: AUSD,
+				keep_alive: true,
+			})
+		));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+
+		assert_ok!(TransactionPause::unpause_transaction(
+			RuntimeOrigin::signed(1),
+			b"Tokens".to_vec(),
+			WILDCARD.to_vec()
+		));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+	});
+}
+
+#[test]
+fn pause_transaction_should_fail_when_pausing_this_pallet_with_wildcard() {
+	ExtBuilder.build().execute_with(|| {
+		assert_noop!(
+			TransactionPause::pause_transaction(
+				RuntimeOrigin::signed(1),
+				b"TransactionPause".to_vec(),
+				WILDCARD.to_vec()
+			),
+			Error::<Runtime>::CannotPause
+		);
+	});
+}
+
+#[test]
+fn pause_asset_transaction_work() {
+	ExtBuilder.build().execute_with(|| {
+		let tokens_b_str = BoundedName::try_from(b"Tokens".to_vec()).unwrap();
+		let transfer_b_str = BoundedName::try_from(b"transfer".to_vec()).unwrap();
+
+		System::set_block_number(1);
+
+		assert_noop!(
+			TransactionPause::pause_asset_transaction(
+				RuntimeOrigin::signed(5),
+				b"Tokens".to_vec(),
+				b"transfer".to_vec(),
+				DOT,
+				None
+			),
+			BadOrigin
+		);
+
+		assert_ok!(TransactionPause::pause_asset_transaction(
+			RuntimeOrigin::signed(1),
+			b"Tokens".to_vec(),
+			b"transfer".to_vec(),
+			DOT,
+			None
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::AssetTransactionPaused {
+			pallet_name_bytes: b"Tokens".to_vec(),
+			function_name_bytes: b"transfer".to_vec(),
+			asset_id: DOT,
+			until: None,
+		}));
+		assert_eq!(
+			TransactionPause::paused_asset_transactions((tokens_b_str.clone(), transfer_b_str.clone(), DOT)),
+			Some(None)
+		);
+
+		assert_ok!(TransactionPause::unpause_asset_transaction(
+			RuntimeOrigin::signed(1),
+			b"Tokens".to_vec(),
+			b"transfer".to_vec(),
+			DOT,
+		));
+		System::assert_last_event(Event::TransactionPause(crate::Event::AssetTransactionUnpaused {
+			pallet_name_bytes: b"Tokens".to_vec(),
+			function_name_bytes: b"transfer".to_vec(),
+			asset_id: DOT,
+		}));
+		assert_eq!(
+			TransactionPause::paused_asset_transactions((tokens_b_str, transfer_b_str, DOT)),
+			None
+		);
+	});
+}
+
+#[test]
+fn paused_transaction_filter_should_contain_only_calls_with_paused_asset() {
+	ExtBuilder.build().execute_with(|| {
+		let dot_transfer = &mock::RuntimeCall::Tokens(orml_tokens::Call::transfer {
+			dest: ALICE,
+			currency_id: DOT,
+			amount: 10,
+		});
+
+		assert_ok!(TransactionPause::pause_asset_transaction(
+			RuntimeOrigin::signed(1),
+			b"Tokens".to_vec(),
+			WILDCARD.to_vec(),
+			DOT,
+			None
+		));
+
+		assert!(PausedTransactionFilter::<Runtime>::contains(dot_transfer));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(TOKENS_TRANSFER));
+		assert!(!PausedTransactionFilter::<Runtime>::contains(BALANCE_TRANSFER));
+	});
+}
@@ -52,6 +52,9 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn pause_transaction() -> Weight;
 	fn unpause_transaction() -> Weight;
+	fn pause_transaction_until() -> Weight;
+	fn pause_asset_transaction() -> Weight;
+	fn unpause_asset_transaction() -> Weight;
 }
 
 /// Weights for module_transaction_pause using the Acala node and recommended hardware.
@@ -79,6 +82,37 @@ impl<T: frame_system::Config> WeightInfo for AcalaWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TransactionPause::PausedTransactions` (r:0 w:1)
+	/// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	fn pause_transaction_until() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_812_000 picoseconds.
+		Weight::from_parts(15_104_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TransactionPause::PausedAssetTransactions` (r:0 w:1)
+	/// Proof: `TransactionPause::PausedAssetTransactions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn pause_asset_transaction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_021_000 picoseconds.
+		Weight::from_parts(15_395_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TransactionPause::PausedAssetTransactions` (r:1 w:1)
+	/// Proof: `TransactionPause::PausedAssetTransactions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn unpause_asset_transaction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `164`
+		//  Estimated: `3563`
+		// Minimum execution time: 17_622_000 picoseconds.
+		Weight::from_parts(18_013_000, 3563)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -105,4 +139,35 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `TransactionPause::PausedTransactions` (r:0 w:1)
+	/// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	fn pause_transaction_until() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_812_000 picoseconds.
+		Weight::from_parts(15_104_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TransactionPause::PausedAssetTransactions` (r:0 w:1)
+	/// Proof: `TransactionPause::PausedAssetTransactions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn pause_asset_transaction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_021_000 picoseconds.
+		Weight::from_parts(15_395_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `TransactionPause::PausedAssetTransactions` (r:1 w:1)
+	/// Proof: `TransactionPause::PausedAssetTransactions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn unpause_asset_transaction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `164`
+		//  Estimated: `3563`
+		// Minimum execution time: 17_622_000 picoseconds.
+		Weight::from_parts(18_013_000, 3563)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -27,7 +27,7 @@ use polkadot_parachain::primitives::RelayChainBlockNumber;
 use sp_core::H256;
 use sp_runtime::traits::BlockNumberProvider;
 use sp_runtime::traits::Convert;
-use sp_runtime::traits::{ConstU128, ConstU32};
+use sp_runtime::traits::{ConstU128, ConstU32, ConstU64};
 use sp_runtime::DispatchResult;
 use sp_runtime::FixedU128;
 use sp_runtime::Permill;
@@ -253,6 +253,10 @@ impl pallet_omnipool::Config for Test {
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type ListingPriceOracle = ();
+	type ListingBond = ConstU128<0>;
+	type ListingChallengePeriod = ConstU64<0>;
+	type ListingWeightCap = AssetWeightCap;
 }
 use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
 use frame_support::weights::Weight;
@@ -18,14 +18,16 @@
 #![cfg(test)]
 
 use frame_support::{
+	dispatch::DispatchResultWithPostInfo,
 	instances::Instance1,
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, Everything, Nothing},
+	traits::{AsEnsureOriginWithArg, ConstU32, Everything, Nothing},
 	PalletId,
 };
 
 use frame_system as system;
 use frame_system::{EnsureRoot, EnsureSigned};
+use hydradx_traits::router::{AmountInAndOut, AssetPair, RouteProvider, RouterT, Trade};
 use hydradx_traits::{AssetPairAccountIdFor, Source};
 use orml_traits::parameter_type_with_key;
 use primitives::{
@@ -40,7 +42,7 @@ use pallet_nft::{CollectionType, NftPermissions};
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup},
-	BuildStorage,
+	BuildStorage, DispatchError, DispatchResult,
 };
 
 pub const UNITS: Balance = 1_000_000_000_000;
@@ -169,9 +171,73 @@ impl pallet_duster::Config for Test {
 	type Reward = ();
 	type NativeCurrencyId = BSXAssetId;
 	type BlacklistUpdateOrigin = EnsureRoot<AccountId>;
+	type DustConversionOrigin = EnsureRoot<AccountId>;
+	type TokenAccounts = ();
+	type RouteExecutor = DummyRouter;
+	type RouteProvider = DummyRouter;
+	type AmmTradeWeights = ();
+	type MaxAccountsPerBatch = ConstU32<10>;
+	type MaxSweepAccountsPerBlock = ConstU32<10>;
 	type WeightInfo = ();
 }
 
+pub struct DummyRouter;
+impl RouteProvider<AssetId> for DummyRouter {}
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for DummyRouter {
+	fn sell(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_in: Balance,
+		_min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		Err(DispatchError::Other("Not supported"))
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		Err(DispatchError::Other("Not supported"))
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		Err(DispatchError::Other("Not supported"))
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		Err(DispatchError::Other("Not supported"))
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		Err(DispatchError::Other("Not supported").into())
+	}
+
+	fn force_insert_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		Err(DispatchError::Other("Not supported").into())
+	}
+}
+
 parameter_types! {
 	pub const ReserveCollectionIdUpTo: u128 = 9999;
 }
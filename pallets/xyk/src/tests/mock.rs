@@ -103,6 +103,8 @@ parameter_types! {
 	pub const SequentialIdOffset: u32 = 1_000_000;
 	pub const StoreFees: Balance = 10 * ONE;
 	pub const FeesBeneficiarry: u64 = TREASURY;
+	#[derive(PartialEq, Debug)]
+	pub const RegistryMetadataValueLimit: u32 = 128;
 }
 
 impl pallet_asset_registry::Config for Test {
@@ -110,12 +112,18 @@ impl pallet_asset_registry::Config for Test {
 	type RegistryOrigin = EnsureSigned<AccountId>;
 	type Currency = Currency;
 	type UpdateOrigin = EnsureSigned<u64>;
+	type AttestOrigin = EnsureSigned<u64>;
 	type AssetId = AssetId;
 	type AssetNativeLocation = AssetLocation;
 	type StringLimit = RegistryStringLimit;
 	type MinStringLimit = MinRegistryStringLimit;
 	type SequentialIdStartAt = SequentialIdOffset;
 	type RegExternalWeightMultiplier = frame_support::traits::ConstU64<1>;
+	type DepositCurrency = Currency;
+	type DepositAssetId = NativeAssetId;
+	type MetadataDepositBase = frame_support::traits::ConstU128<0>;
+	type MetadataDepositPerByte = frame_support::traits::ConstU128<0>;
+	type MetadataValueLimit = RegistryMetadataValueLimit;
 	type WeightInfo = ();
 }
 
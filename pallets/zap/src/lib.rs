@@ -0,0 +1,411 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Zap pallet
+//!
+//! Single transaction liquidity provision from any asset.
+//!
+//! ## Overview
+//!
+//! Providing liquidity to Omnipool requires the exact asset of the Omnipool pool. Providing liquidity
+//! to an Omnipool asset backed by a stableswap pool requires adding liquidity to the stableswap pool first
+//! and then adding the received shares to Omnipool.
+//!
+//! `zap` does all of it in one atomic call:
+//!
+//! 1. sells the provided asset for the liquidity asset via the router. If no route is provided,
+//!    on-chain route is used. Swap is skipped if the provided asset is already the liquidity asset.
+//! 2. adds the liquidity asset to the stableswap pool, if the target is a stableswap pool.
+//! 3. adds the liquidity asset or the stableswap shares to Omnipool, if the target is in Omnipool.
+//! 4. deposits the new Omnipool position into the given yield farm, if requested.
+//!
+//! Intermediate steps are not slippage protected on their own. Instead, the call fails if the amount
+//! of shares received in the last liquidity step is lower than `min_shares`.
+//!
+//! Hub asset (LRNA) is never required - liquidity is provided only in the target asset.
+//!
+//! If no route is provided, the longest route of the most expensive pool type (stableswap metapool trades,
+//! each executed as two trades) is charged upfront and
+//! the difference to the weight of the on-chain route is refunded after the execution.
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use codec::{Decode, Encode};
+use frame_support::{
+	dispatch::DispatchResultWithPostInfo,
+	ensure,
+	pallet_prelude::{DispatchResult, Weight},
+	transactional,
+};
+use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use hydradx_traits::{
+	liquidity_mining::{GlobalFarmId, YieldFarmId},
+	oracle::AggregatedPriceOracle,
+	router::{AmmTradeWeights, AmountInAndOut, AssetPair, PoolType, RouteProvider, RouterT, Trade},
+};
+use orml_traits::MultiCurrency;
+use pallet_omnipool::traits::{ExternalPriceProvider, OmnipoolHooks};
+use pallet_omnipool::weights::WeightInfo as OmnipoolWeightInfo;
+use pallet_omnipool_liquidity_mining::weights::WeightInfo as LiquidityMiningWeightInfo;
+use pallet_stableswap::types::{AssetAmount, StableswapHooks};
+use pallet_stableswap::weights::WeightInfo as StableswapWeightInfo;
+use primitives::Balance;
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Zero, ArithmeticError, RuntimeDebug};
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// Maximum number of trades in a route, as allowed by the router.
+pub const MAX_NUMBER_OF_TRADES: u32 = 5;
+
+pub type AssetIdOf<T> = <T as pallet_omnipool::Config>::AssetId;
+pub type PositionItemIdOf<T> = <T as pallet_omnipool::Config>::PositionItemId;
+
+/// Pool which receives the liquidity.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum ZapTarget<AssetId> {
+	/// Liquidity of `asset` is added to Omnipool.
+	Omnipool { asset: AssetId },
+	/// Liquidity of `asset` is added to stableswap pool `pool_id`.
+	Stableswap { pool_id: AssetId, asset: AssetId },
+	/// Liquidity of `asset` is added to stableswap pool `pool_id` and received shares are added to Omnipool.
+	StableswapOmnipool { pool_id: AssetId, asset: AssetId },
+}
+
+impl<AssetId: Copy> ZapTarget<AssetId> {
+	/// Asset which the provided asset is swapped to.
+	pub fn liquidity_asset(&self) -> AssetId {
+		match self {
+			ZapTarget::Omnipool { asset }
+			| ZapTarget::Stableswap { asset, .. }
+			| ZapTarget::StableswapOmnipool { asset, .. } => *asset,
+		}
+	}
+
+	/// Stableswap pool which receives the liquidity, if any.
+	pub fn stableswap_pool(&self) -> Option<AssetId> {
+		match self {
+			ZapTarget::Omnipool { .. } => None,
+			ZapTarget::Stableswap { pool_id, .. } | ZapTarget::StableswapOmnipool { pool_id, .. } => Some(*pool_id),
+		}
+	}
+
+	/// Returns true if the liquidity ends up in an Omnipool position.
+	pub fn is_omnipool(&self) -> bool {
+		matches!(self, ZapTarget::Omnipool { .. } | ZapTarget::StableswapOmnipool { .. })
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_omnipool_liquidity_mining::Config
+		+ pallet_stableswap::Config<AssetId = AssetIdOf<Self>>
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Router used to swap the provided asset to the liquidity asset.
+		type RouteExecutor: RouterT<
+			Self::RuntimeOrigin,
+			AssetIdOf<Self>,
+			Balance,
+			Trade<AssetIdOf<Self>>,
+			AmountInAndOut<Balance>,
+		>;
+
+		/// Provides on-chain route if no route is specified.
+		type RouteProvider: RouteProvider<AssetIdOf<Self>>;
+
+		/// Weight information for the router trades.
+		type AmmTradeWeights: AmmTradeWeights<Trade<AssetIdOf<Self>>>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Liquidity was provided from `amount_in` of `asset_in`.
+		Zapped {
+			who: T::AccountId,
+			asset_in: AssetIdOf<T>,
+			amount_in: Balance,
+			target: ZapTarget<AssetIdOf<T>>,
+			shares: Balance,
+			position_id: Option<PositionItemIdOf<T>>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Provided amount is zero.
+		InvalidAssetAmount,
+
+		/// Route was provided but provided asset is already the liquidity asset.
+		InvalidRoute,
+
+		/// Received shares are lower than `min_shares`.
+		SlippageLimit,
+
+		/// Yield farm deposit is requested but the target is not in Omnipool.
+		YieldFarmNotSupported,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Provide liquidity to `target` from `amount_in` of any asset.
+		///
+		/// `asset_in` is sold for the liquidity asset of the target through the router, liquidity is
+		/// added to the target pool and, optionally, the new Omnipool position is deposited to a yield farm.
+		/// Everything is executed atomically.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider.
+		/// - `asset_in`: asset which is provided.
+		/// - `amount_in`: amount of `asset_in`.
+		/// - `target`: pool which receives the liquidity.
+		/// - `route`: route from `asset_in` to the liquidity asset. On-chain route is used if empty.
+		/// Must be empty if `asset_in` is the liquidity asset. Worst case route is charged if empty,
+		/// unused weight is refunded.
+		/// - `min_shares`: minimum amount of shares received in the last liquidity step.
+		/// - `farm_entry`: `(global_farm_id, yield_farm_id)` to deposit the Omnipool position to.
+		///
+		/// Emits `Zapped` event when successful.
+		///
+		#[pallet::call_index(0)]
+		#[pallet::weight(Pallet::<T>::zap_weight(*asset_in, target, route, farm_entry.is_some()))]
+		#[transactional]
+		pub fn zap(
+			origin: OriginFor<T>,
+			asset_in: AssetIdOf<T>,
+			amount_in: Balance,
+			target: ZapTarget<AssetIdOf<T>>,
+			route: Vec<Trade<AssetIdOf<T>>>,
+			min_shares: Balance,
+			farm_entry: Option<(GlobalFarmId, YieldFarmId)>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin.clone())?;
+
+			ensure!(amount_in > Balance::zero(), Error::<T>::InvalidAssetAmount);
+			ensure!(
+				farm_entry.is_none() || target.is_omnipool(),
+				Error::<T>::YieldFarmNotSupported
+			);
+
+			let liquidity_asset = target.liquidity_asset();
+
+			let route = if asset_in == liquidity_asset {
+				ensure!(route.is_empty(), Error::<T>::InvalidRoute);
+				route
+			} else {
+				Self::get_route_or_default(asset_in, liquidity_asset, route)
+			};
+
+			let amount = if asset_in == liquidity_asset {
+				amount_in
+			} else {
+				Self::received_amount(&who, liquidity_asset, || {
+					T::RouteExecutor::sell(
+						origin.clone(),
+						asset_in,
+						liquidity_asset,
+						amount_in,
+						Balance::zero(),
+						route.clone(),
+					)
+				})?
+			};
+
+			// Stableswap shares are provided to Omnipool instead of the liquidity asset.
+			let (asset, amount) = if let Some(pool_id) = target.stableswap_pool() {
+				let shares = Self::received_amount(&who, pool_id, || {
+					pallet_stableswap::Pallet::<T>::add_liquidity(
+						origin.clone(),
+						pool_id,
+						vec![AssetAmount::new(liquidity_asset, amount)],
+					)
+				})?;
+				(pool_id, shares)
+			} else {
+				(liquidity_asset, amount)
+			};
+
+			let (shares, position_id) = if target.is_omnipool() {
+				let position_id = pallet_omnipool::Pallet::<T>::next_position_id();
+
+				pallet_omnipool::Pallet::<T>::add_liquidity(origin.clone(), asset, amount)?;
+
+				let position = pallet_omnipool::Pallet::<T>::positions(position_id)
+					.ok_or(pallet_omnipool::Error::<T>::PositionNotFound)?;
+
+				(position.shares, Some(position_id))
+			} else {
+				(amount, None)
+			};
+
+			ensure!(shares >= min_shares, Error::<T>::SlippageLimit);
+
+			if let (Some(position_id), Some((global_farm_id, yield_farm_id))) = (position_id, farm_entry) {
+				pallet_omnipool_liquidity_mining::Pallet::<T>::deposit_shares(
+					origin,
+					global_farm_id,
+					yield_farm_id,
+					position_id,
+				)?;
+			}
+
+			Self::deposit_event(Event::Zapped {
+				who,
+				asset_in,
+				amount_in,
+				target,
+				shares,
+				position_id,
+			});
+
+			Ok(Some(Self::zap_weight(asset_in, &target, &route, farm_entry.is_some())).into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn get_route_or_default(
+		asset_in: AssetIdOf<T>,
+		asset_out: AssetIdOf<T>,
+		route: Vec<Trade<AssetIdOf<T>>>,
+	) -> Vec<Trade<AssetIdOf<T>>> {
+		if route.is_empty() {
+			T::RouteProvider::get_route(AssetPair::new(asset_in, asset_out))
+		} else {
+			route
+		}
+	}
+
+	/// Executes `f` and returns the amount of `asset` received by `who`.
+	fn received_amount(
+		who: &T::AccountId,
+		asset: AssetIdOf<T>,
+		f: impl FnOnce() -> DispatchResult,
+	) -> Result<Balance, sp_runtime::DispatchError> {
+		let balance_before = <T as pallet_omnipool::Config>::Currency::free_balance(asset, who);
+
+		f()?;
+
+		let balance_after = <T as pallet_omnipool::Config>::Currency::free_balance(asset, who);
+
+		balance_after
+			.checked_sub(balance_before)
+			.ok_or_else(|| ArithmeticError::Underflow.into())
+	}
+
+	/// Weight of zap without the liquidity steps.
+	fn zap_overhead_weight() -> Weight {
+		<T as Config>::WeightInfo::zap_into_omnipool()
+			.saturating_sub(<T as pallet_omnipool::Config>::WeightInfo::add_liquidity())
+	}
+
+	/// Weight of the swap. On-chain route is not read - if no route is provided, the longest route
+	/// of the most expensive pool type is used. Stableswap metapool trades are included, as each of them
+	/// is executed as two trades.
+	fn swap_weight(asset_in: AssetIdOf<T>, route: &[Trade<AssetIdOf<T>>]) -> Weight {
+		if !route.is_empty() {
+			return T::AmmTradeWeights::sell_weight(route);
+		}
+
+		[
+			PoolType::Omnipool,
+			PoolType::LBP,
+			PoolType::Stableswap(asset_in),
+			PoolType::StableswapMetapool(asset_in, asset_in),
+			PoolType::XYK,
+			PoolType::Concentrated(0),
+		]
+		.into_iter()
+		.map(|pool| {
+			let route = vec![
+				Trade {
+					pool,
+					asset_in,
+					asset_out: asset_in,
+				};
+				MAX_NUMBER_OF_TRADES as usize
+			];
+			T::AmmTradeWeights::sell_weight(&route)
+		})
+		.fold(Weight::zero(), |max, weight| max.max(weight))
+	}
+
+	fn zap_weight(
+		asset_in: AssetIdOf<T>,
+		target: &ZapTarget<AssetIdOf<T>>,
+		route: &[Trade<AssetIdOf<T>>],
+		deposit_to_farm: bool,
+	) -> Weight {
+		let mut weight = Self::zap_overhead_weight();
+
+		if asset_in != target.liquidity_asset() {
+			weight.saturating_accrue(Self::swap_weight(asset_in, route));
+		}
+
+		if target.stableswap_pool().is_some() {
+			weight.saturating_accrue(
+				<T as pallet_stableswap::Config>::WeightInfo::add_liquidity().saturating_add(
+					<T as pallet_stableswap::Config>::Hooks::on_liquidity_changed_weight(
+						pallet_stableswap::MAX_ASSETS_IN_POOL as usize,
+					),
+				),
+			);
+		}
+
+		if target.is_omnipool() {
+			weight.saturating_accrue(
+				<T as pallet_omnipool::Config>::WeightInfo::add_liquidity()
+					.saturating_add(<T as pallet_omnipool::Config>::OmnipoolHooks::on_liquidity_changed_weight())
+					.saturating_add(<T as pallet_omnipool::Config>::ExternalPriceOracle::get_price_weight()),
+			);
+		}
+
+		if deposit_to_farm {
+			weight.saturating_accrue(
+				<T as pallet_omnipool_liquidity_mining::Config>::WeightInfo::deposit_shares()
+					.saturating_add(<T as pallet_omnipool_liquidity_mining::Config>::PriceOracle::get_price_weight()),
+			);
+		}
+
+		weight
+	}
+}
@@ -0,0 +1,775 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as pallet_zap;
+use crate::*;
+
+use core::ops::RangeInclusive;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::num::NonZeroU16;
+
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate, Transfer};
+use frame_support::traits::{ConstU128, ConstU32, ConstU64, Contains, Everything};
+use frame_support::{assert_ok, construct_runtime, parameter_types, PalletId};
+use frame_system::EnsureRoot;
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::liquidity_mining::PriceAdjustment;
+use hydradx_traits::oracle::{OraclePeriod, Source};
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{AccountIdFor, AssetKind, Inspect as InspectRegistry};
+use orml_traits::{parameter_type_with_key, GetByKey};
+use pallet_ema_oracle::OracleError;
+use pallet_liquidity_mining::{GlobalFarmData, Instance1};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup},
+	BuildStorage, DispatchError, DispatchResult, FixedU128, Permill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+pub type AssetId = u32;
+pub type CollectionId = u128;
+pub type ItemId = u128;
+
+pub const HDX: AssetId = 0;
+pub const LRNA: AssetId = 1;
+pub const DAI: AssetId = 2;
+pub const DOT: AssetId = 1_000;
+pub const KSM: AssetId = 1_001;
+pub const USDT: AssetId = 1_002;
+pub const USDC: AssetId = 1_003;
+pub const STABLE_POOL: AssetId = 1_100;
+
+pub const LP1: AccountId = 1;
+pub const LP2: AccountId = 2;
+pub const ALICE: AccountId = 4;
+pub const GC: AccountId = 7;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const NATIVE_AMOUNT: Balance = 10_000 * ONE;
+
+pub const OMNIPOOL_COLLECTION_ID: u128 = 1_000;
+pub const LM_COLLECTION_ID: u128 = 1;
+
+// Weight of a single trade of the mocked router.
+pub const TRADE_WEIGHT: u64 = 1_000_000;
+
+thread_local! {
+	pub static NFTS: RefCell<HashMap<(CollectionId, ItemId), AccountId>> = RefCell::new(HashMap::default());
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u8>> = RefCell::new(HashMap::default());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+	pub static LAST_ROUTE: RefCell<Option<Vec<Trade<AssetId>>>> = RefCell::new(None);
+}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Tokens: orml_tokens,
+		Omnipool: pallet_omnipool,
+		Stableswap: pallet_stableswap,
+		WarehouseLM: pallet_liquidity_mining::<Instance1>,
+		OmnipoolMining: pallet_omnipool_liquidity_mining,
+		Zap: pallet_zap,
+	}
+);
+
+parameter_types! {
+	pub static MockBlockNumberProvider: u64 = 0;
+}
+
+impl BlockNumberProvider for MockBlockNumberProvider {
+	type BlockNumber = BlockNumber;
+
+	fn current_block_number() -> Self::BlockNumber {
+		System::block_number()
+	}
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type MaxHolds = ();
+	type RuntimeHoldReason = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Everything;
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const HDXAssetId: AssetId = HDX;
+	pub const LRNAAssetId: AssetId = LRNA;
+	pub const PositionCollectionId: CollectionId = OMNIPOOL_COLLECTION_ID;
+	pub const AssetWeightCap: Permill = Permill::from_percent(100);
+	pub const MinWithdrawFee: Permill = Permill::from_percent(0);
+}
+
+impl pallet_omnipool::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type PositionItemId = u128;
+	type Currency = Tokens;
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
+	type HubAssetId = LRNAAssetId;
+	type WeightInfo = ();
+	type HdxAssetId = HDXAssetId;
+	type NFTCollectionId = PositionCollectionId;
+	type NFTHandler = DummyNFT;
+	type AssetRegistry = DummyRegistry;
+	type MinimumTradingLimit = ConstU128<1000>;
+	type MinimumPoolLiquidity = ConstU128<1000>;
+	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type MaxInRatio = ConstU128<1>;
+	type MaxOutRatio = ConstU128<1>;
+	type CollectionId = u128;
+	type OmnipoolHooks = ();
+	type PriceBarrier = ();
+	type MinWithdrawalFee = MinWithdrawFee;
+	type ExternalPriceOracle = WithdrawFeePriceOracle;
+	type Fee = FeeProvider;
+	type ListingPriceOracle = ();
+	type ListingBond = ConstU128<0>;
+	type ListingChallengePeriod = ConstU64<0>;
+	type ListingWeightCap = AssetWeightCap;
+}
+
+parameter_types! {
+	pub const StableswapOracleSource: Source = *b"stablesw";
+	pub const RateOraclePeriod: OraclePeriod = OraclePeriod::Short;
+	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+}
+
+impl pallet_stableswap::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type ShareAccountId = AccountIdConstructor;
+	type AssetInspection = DummyRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type MinPoolLiquidity = ConstU128<1000>;
+	type AmplificationRange = AmplificationRange;
+	type MinTradingLimit = ConstU128<1000>;
+	type WeightInfo = ();
+	type BlockNumberProvider = System;
+	type DustAccountHandler = Whitelist;
+	type Hooks = ();
+	type RateOracle = DummyOracle;
+	type RateOraclePeriod = RateOraclePeriod;
+	type OracleSource = StableswapOracleSource;
+	type UpdateRateOrigin = EnsureRoot<AccountId>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+parameter_types! {
+	pub const LMPalletId: PalletId = PalletId(*b"TEST_lm_");
+	pub const LMCollectionId: CollectionId = LM_COLLECTION_ID;
+	pub const PeriodOracle: OraclePeriod = OraclePeriod::Day;
+	pub const OracleSource: Source = *b"omnipool";
+}
+
+impl pallet_omnipool_liquidity_mining::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Tokens;
+	type CreateOrigin = EnsureRoot<AccountId>;
+	type PalletId = LMPalletId;
+	type NFTCollectionId = LMCollectionId;
+	type NFTHandler = DummyNFT;
+	type LiquidityMiningHandler = WarehouseLM;
+	type OracleSource = OracleSource;
+	type OraclePeriod = PeriodOracle;
+	type PriceOracle = DummyOracle;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const WarehouseLMPalletId: PalletId = PalletId(*b"TEST_lm_");
+	pub const MinTotalFarmRewards: Balance = 1_000_000 * ONE;
+	pub const MinPlannedYieldingPeriods: BlockNumber = 100;
+	#[derive(PartialEq, Eq)]
+	pub const MaxEntriesPerDeposit: u32 = 5;
+	pub const MaxYieldFarmsPerGlobalFarm: u32 = 10;
+}
+
+impl pallet_liquidity_mining::Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type MultiCurrency = Tokens;
+	type PalletId = WarehouseLMPalletId;
+	type MinTotalFarmRewards = MinTotalFarmRewards;
+	type MinPlannedYieldingPeriods = MinPlannedYieldingPeriods;
+	type BlockNumberProvider = MockBlockNumberProvider;
+	type AmmPoolId = AssetId;
+	type MaxFarmEntriesPerDeposit = MaxEntriesPerDeposit;
+	type MaxYieldFarmsPerGlobalFarm = MaxYieldFarmsPerGlobalFarm;
+	type AssetRegistry = DummyRegistry;
+	type NonDustableWhitelistHandler = Whitelist;
+	type PriceAdjustment = DummyOracle;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RouteExecutor = MockRouter;
+	type RouteProvider = MockRouter;
+	type AmmTradeWeights = MockAmmTradeWeights;
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	registered_assets: Vec<(AssetId, u8)>,
+	init_pool: Option<(FixedU128, FixedU128)>,
+	pool_tokens: Vec<(AssetId, FixedU128, AccountId, Balance)>,
+	stable_pools: Vec<(AssetId, Vec<AssetId>, AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		// or set to original default value
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		NFTS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		DUSTER_WHITELIST.with(|v| {
+			v.borrow_mut().clear();
+		});
+		LAST_ROUTE.with(|v| {
+			*v.borrow_mut() = None;
+		});
+
+		Self {
+			endowed_accounts: vec![
+				(Omnipool::protocol_account(), DAI, 1000 * ONE),
+				(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			],
+			registered_assets: vec![(HDX, 12), (LRNA, 12), (DAI, 12)],
+			init_pool: None,
+			pool_tokens: vec![],
+			stable_pools: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn add_endowed_accounts(mut self, account: (AccountId, AssetId, Balance)) -> Self {
+		self.endowed_accounts.push(account);
+		self
+	}
+
+	pub fn with_registered_asset(mut self, asset: AssetId) -> Self {
+		self.registered_assets.push((asset, 12));
+		self
+	}
+
+	pub fn with_initial_pool(mut self, stable_price: FixedU128, native_price: FixedU128) -> Self {
+		self.init_pool = Some((stable_price, native_price));
+		self
+	}
+
+	pub fn with_token(
+		mut self,
+		asset_id: AssetId,
+		price: FixedU128,
+		position_owner: AccountId,
+		amount: Balance,
+	) -> Self {
+		self.pool_tokens.push((asset_id, price, position_owner, amount));
+		self
+	}
+
+	/// Creates stableswap pool before the Omnipool tokens are added. `provider` adds `amount` of each asset.
+	pub fn with_stableswap_pool(
+		mut self,
+		pool_id: AssetId,
+		assets: Vec<AssetId>,
+		provider: AccountId,
+		amount: Balance,
+	) -> Self {
+		self.stable_pools.push((pool_id, assets, provider, amount));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		REGISTERED_ASSETS.with(|v| {
+			self.registered_assets.iter().for_each(|(asset, decimals)| {
+				v.borrow_mut().insert(*asset, *decimals);
+			});
+		});
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+
+			for (pool_id, assets, provider, amount) in self.stable_pools {
+				assert_ok!(Stableswap::create_pool(
+					RuntimeOrigin::root(),
+					pool_id,
+					assets.clone(),
+					100,
+					Permill::zero(),
+				));
+				assert_ok!(Stableswap::add_liquidity(
+					RuntimeOrigin::signed(provider),
+					pool_id,
+					assets
+						.into_iter()
+						.map(|asset| AssetAmount::new(asset, amount))
+						.collect()
+				));
+			}
+
+			if let Some((stable_price, native_price)) = self.init_pool {
+				assert_ok!(Omnipool::add_token(
+					RuntimeOrigin::root(),
+					HDX,
+					native_price,
+					Permill::from_percent(100),
+					Omnipool::protocol_account(),
+				));
+				assert_ok!(Omnipool::add_token(
+					RuntimeOrigin::root(),
+					DAI,
+					stable_price,
+					Permill::from_percent(100),
+					Omnipool::protocol_account(),
+				));
+
+				for (asset_id, price, owner, amount) in self.pool_tokens {
+					assert_ok!(Tokens::transfer(
+						RuntimeOrigin::signed(owner),
+						Omnipool::protocol_account(),
+						asset_id,
+						amount
+					));
+					assert_ok!(Omnipool::add_token(
+						RuntimeOrigin::root(),
+						asset_id,
+						price,
+						Permill::from_percent(100),
+						owner
+					));
+				}
+			}
+		});
+
+		r
+	}
+}
+
+/// Router which sells through Omnipool and remembers the last route.
+pub struct MockRouter;
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for MockRouter {
+	fn sell(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		LAST_ROUTE.with(|v| {
+			*v.borrow_mut() = Some(route);
+		});
+		Omnipool::sell(origin, asset_in, asset_out, amount_in, min_amount_out)
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+
+	fn force_insert_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+}
+
+impl RouteProvider<AssetId> for MockRouter {}
+
+pub(crate) fn last_route() -> Option<Vec<Trade<AssetId>>> {
+	LAST_ROUTE.with(|v| v.borrow().clone())
+}
+
+pub struct MockAmmTradeWeights;
+
+impl AmmTradeWeights<Trade<AssetId>> for MockAmmTradeWeights {
+	fn sell_weight(route: &[Trade<AssetId>]) -> Weight {
+		// Metapool trade is executed as two trades.
+		let trades = route
+			.iter()
+			.map(|trade| match trade.pool {
+				PoolType::StableswapMetapool(..) => 2,
+				_ => 1,
+			})
+			.sum::<u64>();
+		Weight::from_parts(TRADE_WEIGHT.saturating_mul(trades), 0)
+	}
+
+	fn buy_weight(route: &[Trade<AssetId>]) -> Weight {
+		Self::sell_weight(route)
+	}
+
+	fn calculate_buy_trade_amounts_weight(_route: &[Trade<AssetId>]) -> Weight {
+		Weight::zero()
+	}
+
+	fn sell_and_calculate_sell_trade_amounts_weight(_route: &[Trade<AssetId>]) -> Weight {
+		Weight::zero()
+	}
+
+	fn buy_and_calculate_buy_trade_amounts_weight(_route: &[Trade<AssetId>]) -> Weight {
+		Weight::zero()
+	}
+
+	fn set_route_weight(_route: &[Trade<AssetId>]) -> Weight {
+		Weight::zero()
+	}
+
+	fn force_insert_route_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u128>> Inspect<AccountId> for DummyNFT {
+	type ItemId = ItemId;
+	type CollectionId = CollectionId;
+
+	fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<AccountId> {
+		NFTS.with(|v| v.borrow().get(&(*collection, *item)).map(|o| (*o).into()))
+	}
+}
+
+impl<AccountId: From<u128>> Create<AccountId> for DummyNFT {
+	fn create_collection(_collection: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<AccountId: From<u128> + Into<u128> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(collection: &Self::CollectionId, item: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		NFTS.with(|v| {
+			v.borrow_mut().insert((*collection, *item), (*who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		NFTS.with(|v| {
+			v.borrow_mut().remove(&(*collection, *item));
+		});
+		Ok(())
+	}
+}
+
+impl Transfer<AccountId> for DummyNFT {
+	fn transfer(collection: &Self::CollectionId, item: &Self::ItemId, destination: &AccountId) -> DispatchResult {
+		NFTS.with(|v| {
+			let mut m = v.borrow_mut();
+			let key = (*collection, *item);
+
+			if !m.contains_key(&key) {
+				return Err(DispatchError::Other("NFT not found"));
+			}
+
+			m.insert(key, *destination);
+
+			Ok(())
+		})
+	}
+}
+
+pub struct DummyRegistry;
+
+impl InspectRegistry for DummyRegistry {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn is_sufficient(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_type(_id: Self::AssetId) -> Option<AssetKind> {
+		unimplemented!()
+	}
+
+	fn decimals(asset_id: Self::AssetId) -> Option<u8> {
+		REGISTERED_ASSETS.with(|v| v.borrow().get(&asset_id).copied())
+	}
+
+	fn exists(asset_id: Self::AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn is_banned(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_name(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn existential_deposit(_id: Self::AssetId) -> Option<u128> {
+		Some(1u128)
+	}
+}
+
+impl GetByKey<AssetId, Balance> for DummyRegistry {
+	fn get(_key: &AssetId) -> Balance {
+		1_000_u128
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_stableswap::BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(asset_id, decimals);
+		});
+
+		Ok(())
+	}
+}
+
+pub struct AccountIdConstructor;
+
+impl AccountIdFor<AssetId> for AccountIdConstructor {
+	type AccountId = AccountId;
+
+	fn from_assets(asset: &AssetId, _identifier: Option<&[u8]>) -> Self::AccountId {
+		(*asset as u128) * 1000
+	}
+
+	fn name(asset: &AssetId, identifier: Option<&[u8]>) -> Vec<u8> {
+		let mut buf: Vec<u8> = if let Some(ident) = identifier {
+			ident.to_vec()
+		} else {
+			vec![]
+		};
+		buf.extend_from_slice(&(asset).to_le_bytes());
+
+		buf
+	}
+}
+
+pub struct DummyOracle;
+
+impl AggregatedPriceOracle<AssetId, BlockNumber, EmaPrice> for DummyOracle {
+	type Error = OracleError;
+
+	fn get_price(
+		_asset_a: AssetId,
+		asset_b: AssetId,
+		_period: OraclePeriod,
+		_source: Source,
+	) -> Result<(EmaPrice, BlockNumber), Self::Error> {
+		match asset_b {
+			KSM => Ok((
+				EmaPrice {
+					n: 650_000_000_000_000_000,
+					d: 1_000_000_000_000_000_000,
+				},
+				0,
+			)),
+			_ => Err(OracleError::NotPresent),
+		}
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl PriceAdjustment<GlobalFarmData<Test, Instance1>> for DummyOracle {
+	type Error = DispatchError;
+
+	type PriceAdjustment = FixedU128;
+
+	fn get(_global_farm: &GlobalFarmData<Test, Instance1>) -> Result<Self::PriceAdjustment, Self::Error> {
+		Ok(FixedU128::from_inner(500_000_000_000_000_000)) //0.5
+	}
+}
+
+pub struct Whitelist;
+
+impl Contains<AccountId> for Whitelist {
+	fn contains(account: &AccountId) -> bool {
+		DUSTER_WHITELIST.with(|v| v.borrow().contains(account))
+	}
+}
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		if Whitelist::contains(account) {
+			return Err(DispatchError::Other("Account is already in the whitelist"));
+		}
+
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| {
+			let mut v = v.borrow_mut();
+
+			let idx = v.iter().position(|x| *x == *account).unwrap();
+			v.remove(idx);
+
+			Ok(())
+		})
+	}
+}
+
+pub struct WithdrawFeePriceOracle;
+
+impl ExternalPriceProvider<AssetId, EmaPrice> for WithdrawFeePriceOracle {
+	type Error = DispatchError;
+
+	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Result<EmaPrice, Self::Error> {
+		assert_eq!(asset_a, LRNA);
+		let asset_state = Omnipool::load_asset_state(asset_b)?;
+		let price = EmaPrice::new(asset_state.hub_reserve, asset_state.reserve);
+		Ok(price)
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct FeeProvider;
+
+impl GetByKey<AssetId, (Permill, Permill)> for FeeProvider {
+	fn get(_: &AssetId) -> (Permill, Permill) {
+		(Permill::zero(), Permill::zero())
+	}
+}
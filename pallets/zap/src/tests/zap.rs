@@ -0,0 +1,452 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use frame_support::dispatch::GetDispatchInfo;
+use frame_support::traits::tokens::nonfungibles::Inspect;
+use pallet_omnipool::types::Position;
+use pretty_assertions::assert_eq;
+use sp_runtime::{traits::One, Perquintill};
+
+fn omnipool_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, KSM, 5000 * ONE))
+		.add_endowed_accounts((LP2, KSM, 1000 * ONE))
+		.add_endowed_accounts((LP2, DAI, 1000 * ONE))
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.build()
+}
+
+fn stableswap_ext(in_omnipool: bool) -> sp_io::TestExternalities {
+	let builder = ExtBuilder::default()
+		.add_endowed_accounts((ALICE, USDT, 1000 * ONE))
+		.add_endowed_accounts((ALICE, USDC, 1000 * ONE))
+		.add_endowed_accounts((LP2, USDT, 100 * ONE))
+		.with_registered_asset(USDT)
+		.with_registered_asset(USDC)
+		.with_registered_asset(STABLE_POOL)
+		.with_stableswap_pool(STABLE_POOL, vec![USDT, USDC], ALICE, 1000 * ONE);
+
+	if in_omnipool {
+		builder
+			.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+			.with_token(STABLE_POOL, FixedU128::from_float(0.5), ALICE, 1000 * ONE)
+			.build()
+	} else {
+		builder.build()
+	}
+}
+
+fn omnipool_trade(asset_in: AssetId, asset_out: AssetId) -> Trade<AssetId> {
+	Trade {
+		pool: PoolType::Omnipool,
+		asset_in,
+		asset_out,
+	}
+}
+
+#[test]
+fn zap_should_add_liquidity_to_omnipool_when_asset_in_is_omnipool_asset() {
+	omnipool_ext().execute_with(|| {
+		// Arrange
+		let position_id = pallet_omnipool::NextPositionId::<Test>::get();
+
+		// Act
+		assert_ok!(Zap::zap(
+			RuntimeOrigin::signed(LP2),
+			KSM,
+			100 * ONE,
+			ZapTarget::Omnipool { asset: KSM },
+			vec![],
+			100 * ONE,
+			None,
+		));
+
+		// Assert
+		assert_eq!(
+			pallet_omnipool::Positions::<Test>::get(position_id).unwrap(),
+			Position {
+				asset_id: KSM,
+				amount: 100 * ONE,
+				shares: 100 * ONE,
+				price: (1365 * ONE, 2100 * ONE),
+			}
+		);
+		assert_eq!(DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &position_id), Some(LP2));
+		assert_eq!(Tokens::free_balance(KSM, &LP2), 900 * ONE);
+		assert_eq!(last_route(), None);
+		assert_last_event!(Event::Zapped {
+			who: LP2,
+			asset_in: KSM,
+			amount_in: 100 * ONE,
+			target: ZapTarget::Omnipool { asset: KSM },
+			shares: 100 * ONE,
+			position_id: Some(position_id),
+		}
+		.into());
+	});
+}
+
+#[test]
+fn zap_should_sell_asset_in_for_omnipool_asset_when_assets_differ() {
+	omnipool_ext().execute_with(|| {
+		// Arrange
+		let position_id = pallet_omnipool::NextPositionId::<Test>::get();
+
+		// Act
+		assert_ok!(Zap::zap(
+			RuntimeOrigin::signed(LP2),
+			DAI,
+			100 * ONE,
+			ZapTarget::Omnipool { asset: KSM },
+			vec![],
+			0,
+			None,
+		));
+
+		// Assert
+		let position = pallet_omnipool::Positions::<Test>::get(position_id).unwrap();
+		assert_eq!(position.asset_id, KSM);
+		assert!(position.shares > 0);
+		assert_eq!(DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &position_id), Some(LP2));
+
+		// whole received amount is provided as liquidity
+		assert_eq!(Tokens::free_balance(DAI, &LP2), 900 * ONE);
+		assert_eq!(Tokens::free_balance(KSM, &LP2), 1000 * ONE);
+	});
+}
+
+#[test]
+fn zap_should_use_onchain_route_when_route_is_not_provided() {
+	omnipool_ext().execute_with(|| {
+		// Act
+		assert_ok!(Zap::zap(
+			RuntimeOrigin::signed(LP2),
+			DAI,
+			100 * ONE,
+			ZapTarget::Omnipool { asset: KSM },
+			vec![],
+			0,
+			None,
+		));
+
+		// Assert
+		assert_eq!(last_route(), Some(vec![omnipool_trade(DAI, KSM)]));
+	});
+}
+
+#[test]
+fn zap_should_use_provided_route_when_route_is_not_empty() {
+	omnipool_ext().execute_with(|| {
+		// Arrange
+		let route = vec![omnipool_trade(DAI, HDX), omnipool_trade(HDX, KSM)];
+
+		// Act
+		assert_ok!(Zap::zap(
+			RuntimeOrigin::signed(LP2),
+			DAI,
+			100 * ONE,
+			ZapTarget::Omnipool { asset: KSM },
+			route.clone(),
+			0,
+			None,
+		));
+
+		// Assert
+		assert_eq!(last_route(), Some(route));
+	});
+}
+
+#[test]
+fn zap_should_add_liquidity_to_stableswap_pool_when_target_is_stableswap() {
+	stableswap_ext(false).execute_with(|| {
+		// Act
+		assert_ok!(Zap::zap(
+			RuntimeOrigin::signed(LP2),
+			USDT,
+			100 * ONE,
+			ZapTarget::Stableswap {
+				pool_id: STABLE_POOL,
+				asset: USDT
+			},
+			vec![],
+			0,
+			None,
+		));
+
+		// Assert
+		let shares = Tokens::free_balance(STABLE_POOL, &LP2);
+		assert!(shares > 0);
+		assert_eq!(Tokens::free_balance(USDT, &LP2), 0);
+		assert_last_event!(Event::Zapped {
+			who: LP2,
+			asset_in: USDT,
+			amount_in: 100 * ONE,
+			target: ZapTarget::Stableswap {
+				pool_id: STABLE_POOL,
+				asset: USDT
+			},
+			shares,
+			position_id: None,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn zap_should_add_stableswap_shares_to_omnipool_when_target_is_stableswap_in_omnipool() {
+	stableswap_ext(true).execute_with(|| {
+		// Arrange
+		let position_id = pallet_omnipool::NextPositionId::<Test>::get();
+
+		// Act
+		assert_ok!(Zap::zap(
+			RuntimeOrigin::signed(LP2),
+			USDT,
+			100 * ONE,
+			ZapTarget::StableswapOmnipool {
+				pool_id: STABLE_POOL,
+				asset: USDT
+			},
+			vec![],
+			0,
+			None,
+		));
+
+		// Assert
+		let position = pallet_omnipool::Positions::<Test>::get(position_id).unwrap();
+		assert_eq!(position.asset_id, STABLE_POOL);
+		assert_eq!(DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &position_id), Some(LP2));
+		assert_eq!(Tokens::free_balance(USDT, &LP2), 0);
+		assert_eq!(Tokens::free_balance(STABLE_POOL, &LP2), 0);
+		assert_last_event!(Event::Zapped {
+			who: LP2,
+			asset_in: USDT,
+			amount_in: 100 * ONE,
+			target: ZapTarget::StableswapOmnipool {
+				pool_id: STABLE_POOL,
+				asset: USDT
+			},
+			shares: position.shares,
+			position_id: Some(position_id),
+		}
+		.into());
+	});
+}
+
+#[test]
+fn zap_should_deposit_position_to_yield_farm_when_farm_entry_is_provided() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, KSM, 5000 * ONE))
+		.add_endowed_accounts((LP2, KSM, 1000 * ONE))
+		.add_endowed_accounts((GC, HDX, 100_000_000 * ONE))
+		.with_registered_asset(KSM)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(KSM, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let global_farm_id = 1;
+			let yield_farm_id = 2;
+			let deposit_id = 1;
+			assert_ok!(OmnipoolMining::create_global_farm(
+				RuntimeOrigin::root(),
+				80_000_000 * ONE,
+				2_628_000,
+				1,
+				HDX,
+				GC,
+				Perquintill::from_float(0.000_000_15_f64),
+				1_000,
+				FixedU128::one(),
+			));
+			assert_ok!(OmnipoolMining::create_yield_farm(
+				RuntimeOrigin::signed(GC),
+				global_farm_id,
+				KSM,
+				FixedU128::one(),
+				None
+			));
+			let position_id = pallet_omnipool::NextPositionId::<Test>::get();
+
+			// Act
+			assert_ok!(Zap::zap(
+				RuntimeOrigin::signed(LP2),
+				KSM,
+				100 * ONE,
+				ZapTarget::Omnipool { asset: KSM },
+				vec![],
+				0,
+				Some((global_farm_id, yield_farm_id)),
+			));
+
+			// Assert
+			assert_eq!(
+				DummyNFT::owner(&OMNIPOOL_COLLECTION_ID, &position_id),
+				Some(OmnipoolMining::account_id())
+			);
+			assert_eq!(DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id), Some(LP2));
+			assert_eq!(
+				pallet_omnipool_liquidity_mining::OmniPositionId::<Test>::get(deposit_id),
+				Some(position_id)
+			);
+		});
+}
+
+#[test]
+fn zap_should_fail_when_amount_is_zero() {
+	omnipool_ext().execute_with(|| {
+		assert_noop!(
+			Zap::zap(
+				RuntimeOrigin::signed(LP2),
+				KSM,
+				0,
+				ZapTarget::Omnipool { asset: KSM },
+				vec![],
+				0,
+				None,
+			),
+			Error::<Test>::InvalidAssetAmount
+		);
+	});
+}
+
+#[test]
+fn zap_should_fail_when_route_is_provided_for_liquidity_asset() {
+	omnipool_ext().execute_with(|| {
+		assert_noop!(
+			Zap::zap(
+				RuntimeOrigin::signed(LP2),
+				KSM,
+				100 * ONE,
+				ZapTarget::Omnipool { asset: KSM },
+				vec![omnipool_trade(DAI, KSM)],
+				0,
+				None,
+			),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn zap_should_fail_when_farm_entry_is_provided_for_stableswap_target() {
+	stableswap_ext(false).execute_with(|| {
+		assert_noop!(
+			Zap::zap(
+				RuntimeOrigin::signed(LP2),
+				USDT,
+				100 * ONE,
+				ZapTarget::Stableswap {
+					pool_id: STABLE_POOL,
+					asset: USDT
+				},
+				vec![],
+				0,
+				Some((1, 2)),
+			),
+			Error::<Test>::YieldFarmNotSupported
+		);
+	});
+}
+
+#[test]
+fn zap_should_fail_when_received_shares_are_lower_than_min_shares() {
+	omnipool_ext().execute_with(|| {
+		assert_noop!(
+			Zap::zap(
+				RuntimeOrigin::signed(LP2),
+				KSM,
+				100 * ONE,
+				ZapTarget::Omnipool { asset: KSM },
+				vec![],
+				100 * ONE + 1,
+				None,
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn zap_should_refund_weight_of_worst_case_route_when_route_is_not_provided() {
+	omnipool_ext().execute_with(|| {
+		// Arrange
+		let call = Call::<Test>::zap {
+			asset_in: DAI,
+			amount_in: 100 * ONE,
+			target: ZapTarget::Omnipool { asset: KSM },
+			route: vec![],
+			min_shares: 0,
+			farm_entry: None,
+		};
+		let charged = call.get_dispatch_info().weight;
+
+		// Act
+		let post_info = Zap::zap(
+			RuntimeOrigin::signed(LP2),
+			DAI,
+			100 * ONE,
+			ZapTarget::Omnipool { asset: KSM },
+			vec![],
+			0,
+			None,
+		)
+		.unwrap();
+
+		// Assert - worst case route has metapool trades only, on-chain route has one trade
+		assert_eq!(
+			charged.saturating_sub(post_info.actual_weight.unwrap()),
+			Weight::from_parts(TRADE_WEIGHT * (2 * MAX_NUMBER_OF_TRADES as u64 - 1), 0)
+		);
+	});
+}
+
+#[test]
+fn zap_should_charge_weight_of_provided_route() {
+	omnipool_ext().execute_with(|| {
+		// Arrange
+		let route = vec![omnipool_trade(DAI, HDX), omnipool_trade(HDX, KSM)];
+		let call = Call::<Test>::zap {
+			asset_in: DAI,
+			amount_in: 100 * ONE,
+			target: ZapTarget::Omnipool { asset: KSM },
+			route: route.clone(),
+			min_shares: 0,
+			farm_entry: None,
+		};
+		let charged = call.get_dispatch_info().weight;
+
+		// Act
+		let post_info = Zap::zap(
+			RuntimeOrigin::signed(LP2),
+			DAI,
+			100 * ONE,
+			ZapTarget::Omnipool { asset: KSM },
+			route,
+			0,
+			None,
+		)
+		.unwrap();
+
+		// Assert
+		assert_eq!(post_info.actual_weight, Some(charged));
+	});
+}
@@ -22,7 +22,7 @@ use cumulus_primitives_core::relay_chain::Hash;
 use frame_support::{
 	sp_runtime::{
 		traits::{AtLeast32BitUnsigned, Convert, Get, MaybeSerializeDeserialize, Saturating, Zero},
-		ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, FixedPointOperand, FixedU128,
+		ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, FixedPointOperand, FixedU128, Permill,
 		SaturatedConversion,
 	},
 	traits::{Contains, LockIdentifier, OriginTrait},
@@ -34,23 +34,26 @@ use hydra_dx_math::{
 	omnipool::types::BalanceUpdate,
 	support::rational::{round_to_rational, round_u512_to_rational, Rounding},
 };
-use hydradx_traits::router::{AssetPair, PoolType, RouteProvider, Trade};
+use hydradx_traits::router::{AssetPair, PoolType, RouteProvider, Trade, UnderlyingTrades};
 use hydradx_traits::{
 	liquidity_mining::PriceAdjustment, AggregatedOracle, AggregatedPriceOracle, LockedBalance, NativePriceOracle,
 	OnLiquidityChangedHandler, OnTradeHandler, OraclePeriod, PriceOracle,
 };
 use orml_traits::GetByKey;
 use orml_xcm_support::{OnDepositFail, UnknownAsset as UnknownAssetT};
+use pallet_circuit_breaker::traits::TradingHaltHandler;
 use pallet_circuit_breaker::WeightInfo;
 use pallet_ema_oracle::{OnActivityHandler, OracleError, Price};
-use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
+use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, ListingPriceProvider, OmnipoolHooks};
+use pallet_omnipool::types::Tradability;
 use pallet_stableswap::types::{PoolState, StableswapHooks};
 use pallet_transaction_multi_payment::DepositFee;
 use polkadot_xcm::latest::prelude::*;
 use primitive_types::{U128, U512};
-use primitives::constants::chain::{STABLESWAP_SOURCE, XYK_SOURCE};
+use primitives::constants::chain::{CONCENTRATED_SOURCE, STABLESWAP_SOURCE, XYK_SOURCE};
 use primitives::{constants::chain::OMNIPOOL_SOURCE, AccountId, AssetId, Balance, BlockNumber, CollectionId};
 use sp_runtime::traits::BlockNumberProvider;
+use sp_std::vec;
 use sp_std::vec::Vec;
 use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, marker::PhantomData};
 use warehouse_liquidity_mining::GlobalFarmData;
@@ -69,23 +72,96 @@ pub mod xcm_execute_filter;
 mod tests;
 pub mod xyk;
 
+/// Swaps an asset that is not accepted as weight fee payment into one that is.
+///
+/// Used by `MultiCurrencyTrader` to let remote senders pay for execution with any tradable asset.
+pub trait SwapFeeAsset<AssetId> {
+	/// Asset which is bought to pay the fee.
+	fn fee_asset() -> AssetId;
+
+	/// Assets which are swapped first, in order of preference.
+	///
+	/// Offered assets which are not listed are tried afterwards, in the order they are offered.
+	fn preferred_assets() -> Vec<AssetId>;
+
+	/// Buy `amount_out` of the fee asset for at most `max_amount_in` of `asset_in`.
+	///
+	/// The bought fee asset is burned. Returns the amount of `asset_in` spent.
+	fn swap_to_fee_asset(asset_in: AssetId, amount_out: u128, max_amount_in: u128) -> Result<u128, DispatchError>;
+
+	/// Sell `amount_in` of the fee asset for `asset_out`.
+	///
+	/// The sold fee asset is minted and the received `asset_out` is burned. Returns the amount of `asset_out`
+	/// received.
+	fn swap_from_fee_asset(asset_out: AssetId, amount_in: u128) -> Result<u128, DispatchError>;
+
+	/// Weight of `swap_to_fee_asset`.
+	fn swap_to_fee_asset_weight() -> Weight;
+
+	/// Weight of `swap_from_fee_asset`.
+	fn swap_from_fee_asset_weight() -> Weight;
+}
+
+impl<AssetId: Default> SwapFeeAsset<AssetId> for () {
+	fn fee_asset() -> AssetId {
+		AssetId::default()
+	}
+
+	fn preferred_assets() -> Vec<AssetId> {
+		Vec::new()
+	}
+
+	fn swap_to_fee_asset(_asset_in: AssetId, _amount_out: u128, _max_amount_in: u128) -> Result<u128, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn swap_from_fee_asset(_asset_out: AssetId, _amount_in: u128) -> Result<u128, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn swap_to_fee_asset_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn swap_from_fee_asset_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+/// Weight bought with one asset and the amount of the asset paid for it.
+///
+/// If the fee was paid by swapping an offered asset to the fee asset, `swapped_from` is the location of the
+/// offered asset. Refunds are then swapped back and paid in that asset.
+#[derive(Debug)]
+struct FeePayment<Price> {
+	asset_loc: MultiLocation,
+	price: Price,
+	weight: Weight,
+	amount: u128,
+	swapped_from: Option<MultiLocation>,
+}
+
 /// Weight trader that accepts multiple assets as weight fee payment.
 ///
 /// It uses `WeightToFee` in combination with a `NativePriceOracle` to set the right price for weight.
-/// Keeps track of the assets used to pay for weight and can refund them one by one (interface only
-/// allows returning one asset per refund). Will pass any remaining assets on `Drop` to
-/// `TakeRevenue`.
+/// Keeps track of the assets used to pay for weight and the weight bought with each of them, so it can
+/// refund them one by one (interface only allows returning one asset per refund). Will pass any remaining
+/// assets on `Drop` to `TakeRevenue`.
+///
+/// Assets which are not accepted by the price oracle are swapped to the fee asset of `FeeSwap` if none
+/// of the offered assets is accepted. Refunds of such payments are swapped back to the offered asset.
 pub struct MultiCurrencyTrader<
 	AssetId,
 	Balance: FixedPointOperand + TryInto<u128>,
 	Price: FixedPointNumber,
 	ConvertWeightToFee: WeightToFee<Balance = Balance>,
 	AcceptedCurrencyPrices: NativePriceOracle<AssetId, Price>,
-	ConvertCurrency: Convert<MultiAsset, Option<AssetId>>,
+	ConvertCurrency: Convert<MultiAsset, Option<AssetId>> + Convert<AssetId, Option<MultiLocation>>,
 	Revenue: TakeRevenue,
+	FeeSwap: SwapFeeAsset<AssetId>,
 > {
 	weight: Weight,
-	paid_assets: BTreeMap<(MultiLocation, Price), u128>,
+	paid_assets: Vec<FeePayment<Price>>,
 	_phantom: PhantomData<(
 		AssetId,
 		Balance,
@@ -94,6 +170,7 @@ pub struct MultiCurrencyTrader<
 		AcceptedCurrencyPrices,
 		ConvertCurrency,
 		Revenue,
+		FeeSwap,
 	)>,
 }
 
@@ -103,22 +180,145 @@ impl<
 		Price: FixedPointNumber,
 		ConvertWeightToFee: WeightToFee<Balance = Balance>,
 		AcceptedCurrencyPrices: NativePriceOracle<AssetId, Price>,
-		ConvertCurrency: Convert<MultiAsset, Option<AssetId>>,
+		ConvertCurrency: Convert<MultiAsset, Option<AssetId>> + Convert<AssetId, Option<MultiLocation>>,
 		Revenue: TakeRevenue,
-	> MultiCurrencyTrader<AssetId, Balance, Price, ConvertWeightToFee, AcceptedCurrencyPrices, ConvertCurrency, Revenue>
+		FeeSwap: SwapFeeAsset<AssetId>,
+	>
+	MultiCurrencyTrader<
+		AssetId,
+		Balance,
+		Price,
+		ConvertWeightToFee,
+		AcceptedCurrencyPrices,
+		ConvertCurrency,
+		Revenue,
+		FeeSwap,
+	>
 {
-	/// Get the asset id of the first asset in `payment` and try to determine its price via the
-	/// price oracle.
-	fn get_asset_and_price(&mut self, payment: &Assets) -> Option<(MultiLocation, Price)> {
-		if let Some(asset) = payment.fungible_assets_iter().next() {
-			ConvertCurrency::convert(asset.clone())
-				.and_then(|currency| AcceptedCurrencyPrices::price(currency))
-				.and_then(|price| match asset.id {
-					Concrete(location) => Some((location, price)),
-					_ => None,
-				})
-		} else {
-			None
+	/// Convert `fee` to the amount of an asset with the given `price`.
+	fn fee_amount(fee: Balance, price: Price) -> Result<u128, XcmError> {
+		let converted_fee = price.checked_mul_int(fee).ok_or(XcmError::Overflow)?;
+		converted_fee.try_into().map_err(|_| XcmError::Overflow)
+	}
+
+	/// Track `amount` of asset at `asset_loc` paid for `weight`.
+	///
+	/// `swapped_from` is the location of the asset swapped to the asset at `asset_loc`, if any.
+	fn track_payment(
+		&mut self,
+		asset_loc: MultiLocation,
+		price: Price,
+		weight: Weight,
+		amount: u128,
+		swapped_from: Option<MultiLocation>,
+	) {
+		self.weight.saturating_accrue(weight);
+		match self
+			.paid_assets
+			.iter_mut()
+			.find(|p| p.asset_loc == asset_loc && p.price == price && p.swapped_from == swapped_from)
+		{
+			Some(payment) => {
+				payment.weight.saturating_accrue(weight);
+				payment.amount.saturating_accrue(amount);
+			}
+			None => self.paid_assets.push(FeePayment {
+				asset_loc,
+				price,
+				weight,
+				amount,
+				swapped_from,
+			}),
+		}
+	}
+
+	/// Try to pay `fee` with one of the accepted assets in `payment`.
+	///
+	/// Assets already used to pay for weight are preferred, so refunds are made in as few assets as possible.
+	fn buy_with_accepted_asset(&mut self, weight: Weight, fee: Balance, payment: &Assets) -> Result<Assets, XcmError> {
+		let mut candidates: Vec<MultiAsset> = payment.fungible_assets_iter().collect();
+		candidates.sort_by_key(|asset| !self.paid_assets.iter().any(|p| Concrete(p.asset_loc) == asset.id));
+
+		let mut error = XcmError::AssetNotFound;
+		for asset in candidates {
+			let Concrete(asset_loc) = asset.id else { continue };
+			let Some(price) = <ConvertCurrency as Convert<MultiAsset, Option<AssetId>>>::convert(asset.clone())
+				.and_then(AcceptedCurrencyPrices::price)
+			else {
+				continue;
+			};
+			let amount = match Self::fee_amount(fee, price) {
+				Ok(amount) => amount,
+				Err(e) => {
+					error = e;
+					continue;
+				}
+			};
+			match payment.checked_sub((Concrete(asset_loc), amount).into()) {
+				Ok(unused) => {
+					self.track_payment(asset_loc, price, weight, amount, None);
+					return Ok(unused);
+				}
+				Err(_) => error = XcmError::TooExpensive,
+			}
+		}
+		Err(error)
+	}
+
+	/// Try to pay `fee` by swapping one of the assets in `payment` to the fee asset of `FeeSwap`.
+	///
+	/// Assets preferred by `FeeSwap` are tried first.
+	fn buy_with_swapped_asset(&mut self, weight: Weight, fee: Balance, payment: &Assets) -> Result<Assets, XcmError> {
+		let fee_asset_loc = <ConvertCurrency as Convert<AssetId, Option<MultiLocation>>>::convert(FeeSwap::fee_asset())
+			.ok_or(XcmError::AssetNotFound)?;
+		let price = AcceptedCurrencyPrices::price(FeeSwap::fee_asset()).ok_or(XcmError::AssetNotFound)?;
+		let amount = Self::fee_amount(fee, price)?;
+
+		let preferred: Vec<MultiLocation> = FeeSwap::preferred_assets()
+			.into_iter()
+			.filter_map(<ConvertCurrency as Convert<AssetId, Option<MultiLocation>>>::convert)
+			.collect();
+		let mut candidates: Vec<MultiAsset> = payment.fungible_assets_iter().collect();
+		candidates.sort_by_key(|asset| {
+			preferred
+				.iter()
+				.position(|loc| Concrete(*loc) == asset.id)
+				.unwrap_or(preferred.len())
+		});
+
+		let mut error = XcmError::AssetNotFound;
+		for asset in candidates {
+			let Concrete(asset_loc) = asset.id else { continue };
+			let Fungible(max_amount_in) = asset.fun else { continue };
+			let Some(asset_in) = <ConvertCurrency as Convert<MultiAsset, Option<AssetId>>>::convert(asset.clone())
+			else {
+				continue;
+			};
+			let spent = match FeeSwap::swap_to_fee_asset(asset_in, amount, max_amount_in) {
+				Ok(spent) => spent,
+				Err(e) => {
+					log::trace!(target: "xcm::weight", "Could not swap {:?} to fee asset: {:?}", asset_loc, e);
+					error = Self::swap_error(e);
+					continue;
+				}
+			};
+			let unused = payment
+				.checked_sub((asset.id, spent).into())
+				.map_err(|_| XcmError::TooExpensive)?;
+			self.track_payment(fee_asset_loc, price, weight, amount, Some(asset_loc));
+			return Ok(unused);
+		}
+		Err(error)
+	}
+
+	/// Map the error of a failed fee asset swap to an XCM error.
+	fn swap_error(error: DispatchError) -> XcmError {
+		match error {
+			DispatchError::Arithmetic(_) => XcmError::Overflow,
+			DispatchError::Token(_) | DispatchError::Module(_) => XcmError::TooExpensive,
+			DispatchError::Unavailable => XcmError::AssetNotFound,
+			DispatchError::Other(reason) => XcmError::FailedToTransactAsset(reason),
+			_ => XcmError::FailedToTransactAsset("Fee asset swap failed"),
 		}
 	}
 }
@@ -129,10 +329,20 @@ impl<
 		Price: FixedPointNumber,
 		ConvertWeightToFee: WeightToFee<Balance = Balance>,
 		AcceptedCurrencyPrices: NativePriceOracle<AssetId, Price>,
-		ConvertCurrency: Convert<MultiAsset, Option<AssetId>>,
+		ConvertCurrency: Convert<MultiAsset, Option<AssetId>> + Convert<AssetId, Option<MultiLocation>>,
 		Revenue: TakeRevenue,
+		FeeSwap: SwapFeeAsset<AssetId>,
 	> WeightTrader
-	for MultiCurrencyTrader<AssetId, Balance, Price, ConvertWeightToFee, AcceptedCurrencyPrices, ConvertCurrency, Revenue>
+	for MultiCurrencyTrader<
+		AssetId,
+		Balance,
+		Price,
+		ConvertWeightToFee,
+		AcceptedCurrencyPrices,
+		ConvertCurrency,
+		Revenue,
+		FeeSwap,
+	>
 {
 	fn new() -> Self {
 		Self {
@@ -142,56 +352,68 @@ impl<
 		}
 	}
 
-	/// Will try to buy weight with the first asset in `payment`.
+	/// Will try to buy weight with an accepted asset in `payment` which covers the fee.
 	///
-	/// This is a reasonable strategy as the `BuyExecution` XCM instruction only passes one asset
-	/// per buy.
 	/// The fee is determined by `ConvertWeightToFee` in combination with the price determined by
 	/// `AcceptedCurrencyPrices`.
+	/// If none of the assets is accepted, the fee is paid by swapping an offered asset to the fee asset
+	/// of `FeeSwap`.
 	fn buy_weight(&mut self, weight: Weight, payment: Assets, _context: &XcmContext) -> Result<Assets, XcmError> {
 		log::trace!(
 			target: "xcm::weight", "MultiCurrencyTrader::buy_weight weight: {:?}, payment: {:?}",
 			weight, payment
 		);
-		let (asset_loc, price) = self.get_asset_and_price(&payment).ok_or(XcmError::AssetNotFound)?;
 		let fee = ConvertWeightToFee::weight_to_fee(&weight);
-		let converted_fee = price.checked_mul_int(fee).ok_or(XcmError::Overflow)?;
-		let amount: u128 = converted_fee.try_into().map_err(|_| XcmError::Overflow)?;
-		let required = (Concrete(asset_loc), amount).into();
-		let unused = payment.checked_sub(required).map_err(|_| XcmError::TooExpensive)?;
-		self.weight = self.weight.saturating_add(weight);
-		let key = (asset_loc, price);
-		match self.paid_assets.get_mut(&key) {
-			Some(v) => v.saturating_accrue(amount),
-			None => {
-				self.paid_assets.insert(key, amount);
-			}
+		match self.buy_with_accepted_asset(weight, fee, &payment) {
+			Err(XcmError::AssetNotFound) => self.buy_with_swapped_asset(weight, fee, &payment),
+			result => result,
 		}
-		Ok(unused)
 	}
 
 	/// Will refund up to `weight` from the first asset tracked by the trader.
+	///
+	/// At most the weight bought with that asset is refunded, the rest can be refunded by
+	/// subsequent calls.
+	/// If the weight was bought by swapping an offered asset, the refund is swapped back and paid in the
+	/// offered asset. Nothing is refunded if the swap fails.
 	fn refund_weight(&mut self, weight: Weight, _context: &XcmContext) -> Option<MultiAsset> {
 		log::trace!(
 			target: "xcm::weight", "MultiCurrencyTrader::refund_weight weight: {:?}, paid_assets: {:?}",
 			weight, self.paid_assets
 		);
-		let weight = weight.min(self.weight);
-		self.weight -= weight; // Will not underflow because of `min()` above.
-		let fee = ConvertWeightToFee::weight_to_fee(&weight);
-		if let Some(((asset_loc, price), amount)) = self.paid_assets.iter_mut().next() {
-			let converted_fee: u128 = price.saturating_mul_int(fee).saturated_into();
-			let refund = converted_fee.min(*amount);
-			*amount -= refund; // Will not underflow because of `min()` above.
-
-			let refund_asset = *asset_loc;
-			if amount.is_zero() {
-				let key = (*asset_loc, *price);
-				self.paid_assets.remove(&key);
-			}
-			Some((Concrete(refund_asset), refund).into())
+		let payment = self.paid_assets.first_mut()?;
+		let weight = weight.min(self.weight).min(payment.weight);
+		let refund = if weight == payment.weight {
+			payment.amount
 		} else {
+			let fee = ConvertWeightToFee::weight_to_fee(&weight);
+			let converted_fee: u128 = payment.price.saturating_mul_int(fee).saturated_into();
+			converted_fee.min(payment.amount)
+		};
+		let (refund_asset, refund_amount) = match payment.swapped_from {
+			Some(asset_loc) if !refund.is_zero() => {
+				let asset_out = <ConvertCurrency as Convert<MultiAsset, Option<AssetId>>>::convert(
+					(Concrete(asset_loc), refund).into(),
+				)?;
+				let amount_out = FeeSwap::swap_from_fee_asset(asset_out, refund)
+					.map_err(|e| log::trace!(target: "xcm::weight", "Could not swap refund: {:?}", e))
+					.ok()?;
+				(asset_loc, amount_out)
+			}
+			_ => (payment.asset_loc, refund),
+		};
+		// Will not underflow because of `min()` above.
+		self.weight -= weight;
+		payment.weight -= weight;
+		payment.amount -= refund;
+
+		if payment.weight.is_zero() || payment.amount.is_zero() {
+			self.paid_assets.remove(0);
+		}
+		if refund_amount.is_zero() {
 			None
+		} else {
+			Some((Concrete(refund_asset), refund_amount).into())
 		}
 	}
 }
@@ -204,14 +426,24 @@ impl<
 		Price: FixedPointNumber,
 		ConvertWeightToFee: WeightToFee<Balance = Balance>,
 		AcceptedCurrencyPrices: NativePriceOracle<AssetId, Price>,
-		ConvertCurrency: Convert<MultiAsset, Option<AssetId>>,
+		ConvertCurrency: Convert<MultiAsset, Option<AssetId>> + Convert<AssetId, Option<MultiLocation>>,
 		Revenue: TakeRevenue,
+		FeeSwap: SwapFeeAsset<AssetId>,
 	> Drop
-	for MultiCurrencyTrader<AssetId, Balance, Price, ConvertWeightToFee, AcceptedCurrencyPrices, ConvertCurrency, Revenue>
+	for MultiCurrencyTrader<
+		AssetId,
+		Balance,
+		Price,
+		ConvertWeightToFee,
+		AcceptedCurrencyPrices,
+		ConvertCurrency,
+		Revenue,
+		FeeSwap,
+	>
 {
 	fn drop(&mut self) {
-		for ((asset_loc, _), amount) in self.paid_assets.iter() {
-			Revenue::take_revenue((*asset_loc, *amount).into());
+		for payment in self.paid_assets.iter().filter(|p| !p.amount.is_zero()) {
+			Revenue::take_revenue((payment.asset_loc, payment.amount).into());
 		}
 	}
 }
@@ -323,6 +555,51 @@ where
 	}
 }
 
+/// Halts trading of an asset in the Omnipool by disabling its SELL and BUY operations.
+/// Adding and removing liquidity is not affected.
+///
+/// SELL and BUY operations which were enabled before the halt are enabled again when the trading is resumed.
+///
+/// Assets which are not in the Omnipool are ignored.
+pub struct OmnipoolTradingHalt<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> TradingHaltHandler<AssetId> for OmnipoolTradingHalt<Runtime>
+where
+	Runtime: pallet_omnipool::Config<AssetId = AssetId>,
+{
+	type State = Option<Tradability>;
+
+	fn halt_trading(asset_id: AssetId) -> Result<Self::State, DispatchError> {
+		let Some(asset_state) = pallet_omnipool::Pallet::<Runtime>::assets(asset_id) else {
+			return Ok(None);
+		};
+		pallet_omnipool::Pallet::<Runtime>::update_asset_tradable_state(asset_id, |state| {
+			state.remove(Tradability::SELL | Tradability::BUY)
+		})?;
+		Ok(Some(asset_state.tradable))
+	}
+
+	fn resume_trading(asset_id: AssetId, state: Self::State) -> DispatchResult {
+		let Some(previous) = state else {
+			return Ok(());
+		};
+		if !pallet_omnipool::Pallet::<Runtime>::exists(asset_id) {
+			return Ok(());
+		}
+		pallet_omnipool::Pallet::<Runtime>::update_asset_tradable_state(asset_id, |state| {
+			state.insert(previous & (Tradability::SELL | Tradability::BUY))
+		})
+	}
+
+	fn halt_trading_weight() -> Weight {
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1)
+	}
+
+	fn resume_trading_weight() -> Weight {
+		<Runtime as frame_system::Config>::DbWeight::get().reads_writes(1, 1)
+	}
+}
+
 /// Passes on trade and liquidity data from the omnipool to the oracle.
 pub struct OmnipoolHookAdapter<Origin, NativeAsset, Lrna, Runtime>(PhantomData<(Origin, NativeAsset, Lrna, Runtime)>);
 
@@ -462,7 +739,11 @@ where
 		let w1 = OnActivityHandler::<Runtime>::on_trade_weight().saturating_mul(2);
 		let w2 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_pool_state_change_limit();
 		let w3 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_trade_limit_entry();
-		w1.saturating_add(w2).saturating_add(w3)
+		// halting trading of an asset schedules its resume
+		let w4 = <Runtime as pallet_circuit_breaker::Config>::TradingHaltHandler::halt_trading_weight()
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().writes(1))
+			.saturating_mul(2);
+		w1.saturating_add(w2).saturating_add(w3).saturating_add(w4)
 	}
 
 	fn on_trade_fee(
@@ -516,24 +797,84 @@ where
 	}
 }
 
-pub struct OraclePriceProvider<AssetId, AggregatedPriceGetter, Lrna>(
-	PhantomData<(AssetId, AggregatedPriceGetter, Lrna)>,
+/// Passes ema oracle price and liquidity of given source to the omnipool listing.
+///
+/// Price of the `LongPeriod` oracle is used only if it does not differ from the price of the `ShortPeriod` oracle
+/// by more than `MaxPriceDifference`, so that a short-lived manipulation of the source pool can not set the listing price.
+/// Liquidity is the lower one of both periods.
+pub struct EmaOracleListingAdapter<ShortPeriod, LongPeriod, MaxPriceDifference, Source, Runtime>(
+	PhantomData<(ShortPeriod, LongPeriod, MaxPriceDifference, Source, Runtime)>,
+);
+
+impl<ShortPeriod, LongPeriod, MaxPriceDifference, Source, Runtime> ListingPriceProvider<AssetId, Balance, Price>
+	for EmaOracleListingAdapter<ShortPeriod, LongPeriod, MaxPriceDifference, Source, Runtime>
+where
+	ShortPeriod: Get<OraclePeriod>,
+	LongPeriod: Get<OraclePeriod>,
+	MaxPriceDifference: Get<Permill>,
+	Source: Get<hydradx_traits::Source>,
+	Runtime: pallet_ema_oracle::Config,
+{
+	type Error = ();
+
+	fn get_price_and_liquidity(asset: AssetId, denomination: AssetId) -> Result<(Price, Balance), Self::Error> {
+		// price of (denomination, asset) pair is amount of denomination per one unit of asset
+		let short =
+			pallet_ema_oracle::Pallet::<Runtime>::get_entry(denomination, asset, ShortPeriod::get(), Source::get())
+				.map_err(|_| ())?;
+		let long =
+			pallet_ema_oracle::Pallet::<Runtime>::get_entry(denomination, asset, LongPeriod::get(), Source::get())
+				.map_err(|_| ())?;
+
+		let short_price = FixedU128::checked_from_rational(short.price.n, short.price.d).ok_or(())?;
+		let long_price = FixedU128::checked_from_rational(long.price.n, long.price.d).ok_or(())?;
+
+		let max_allowed_difference = FixedU128::from(MaxPriceDifference::get())
+			.checked_mul(&short_price.checked_add(&long_price).ok_or(())?)
+			.ok_or(())?;
+
+		let diff = if short_price >= long_price {
+			short_price.saturating_sub(long_price)
+		} else {
+			long_price.saturating_sub(short_price)
+		};
+		let diff = diff.checked_mul(&FixedU128::from(2)).ok_or(())?;
+
+		ensure!(diff <= max_allowed_difference, ());
+
+		Ok((long.price, short.liquidity.b.min(long.liquidity.b)))
+	}
+
+	fn get_price_and_liquidity_weight() -> Weight {
+		pallet_ema_oracle::Pallet::<Runtime>::get_entry_weight().saturating_mul(2)
+	}
+}
+
+pub struct OraclePriceProvider<AssetId, AggregatedPriceGetter, Lrna, Underlying>(
+	PhantomData<(AssetId, AggregatedPriceGetter, Lrna, Underlying)>,
 );
 
-impl<AssetId, AggregatedPriceGetter, Lrna> PriceOracle<AssetId>
-	for OraclePriceProvider<AssetId, AggregatedPriceGetter, Lrna>
+impl<AssetId, AggregatedPriceGetter, Lrna, Underlying> PriceOracle<AssetId>
+	for OraclePriceProvider<AssetId, AggregatedPriceGetter, Lrna, Underlying>
 where
 	u32: From<AssetId>,
 	AggregatedPriceGetter: AggregatedPriceOracle<AssetId, BlockNumber, EmaPrice, Error = OracleError>,
 	Lrna: Get<AssetId>,
+	Underlying: UnderlyingTrades<AssetId>,
 	AssetId: Clone + Copy,
 {
 	type Price = EmaPrice;
 
 	/// We calculate prices for trade (in a route) then making the product of them
+	/// Trades which go through multiple pools, eg. stableswap metapool trades, are priced by their underlying trades.
 	fn price(route: &[Trade<AssetId>], period: OraclePeriod) -> Option<EmaPrice> {
+		let route: Vec<Trade<AssetId>> = route
+			.iter()
+			.flat_map(|trade| Underlying::underlying_trades(trade).unwrap_or_else(|| vec![*trade]))
+			.collect();
+
 		let mut prices: Vec<EmaPrice> = Vec::with_capacity(route.len());
-		for trade in route {
+		for trade in route.iter() {
 			let asset_a = trade.asset_in;
 			let asset_b = trade.asset_out;
 			let price = match trade.pool {
@@ -598,6 +939,15 @@ where
 						Err(_) => return None,
 					}
 				}
+				PoolType::Concentrated(_) => {
+					let price_result = AggregatedPriceGetter::get_price(asset_a, asset_b, period, CONCENTRATED_SOURCE);
+
+					match price_result {
+						Ok(price) => price.0,
+						Err(OracleError::SameAsset) => EmaPrice::from(1),
+						Err(_) => return None,
+					}
+				}
 				_ => return None,
 			};
 
@@ -18,7 +18,7 @@
 use crate::*;
 use codec::{Decode, Encode};
 use frame_support::{weights::IdentityFee, BoundedVec};
-use sp_runtime::{traits::One, DispatchResult, FixedU128};
+use sp_runtime::{traits::One, DispatchError, DispatchResult, FixedU128, TokenError};
 use sp_std::cell::RefCell;
 use sp_std::collections::btree_set::BTreeSet;
 
@@ -31,6 +31,8 @@ const CORE_ASSET_ID: AssetId = 0;
 const TEST_ASSET_ID: AssetId = 123;
 const CHEAP_ASSET_ID: AssetId = 420;
 const OVERFLOW_ASSET_ID: AssetId = 1_000;
+const SWAPPABLE_ASSET_ID: AssetId = 777;
+const PREFERRED_SWAPPABLE_ASSET_ID: AssetId = 778;
 
 /// Mock price oracle which returns prices for the hard-coded assets.
 struct MockOracle;
@@ -50,7 +52,12 @@ struct MockConvert;
 impl Convert<AssetId, Option<MultiLocation>> for MockConvert {
 	fn convert(id: AssetId) -> Option<MultiLocation> {
 		match id {
-			CORE_ASSET_ID | TEST_ASSET_ID | CHEAP_ASSET_ID | OVERFLOW_ASSET_ID => {
+			CORE_ASSET_ID
+			| TEST_ASSET_ID
+			| CHEAP_ASSET_ID
+			| OVERFLOW_ASSET_ID
+			| SWAPPABLE_ASSET_ID
+			| PREFERRED_SWAPPABLE_ASSET_ID => {
 				let junction = Junction::from(BoundedVec::try_from(id.encode()).unwrap());
 				Some(polkadot_xcm::v3::MultiLocation::new(0, X1(junction)))
 			}
@@ -69,7 +76,12 @@ impl Convert<MultiLocation, Option<AssetId>> for MockConvert {
 				if let Ok(currency_id) = AssetId::decode(&mut &data[..]) {
 					// we currently have only one native asset
 					match currency_id {
-						CORE_ASSET_ID | TEST_ASSET_ID | CHEAP_ASSET_ID | OVERFLOW_ASSET_ID => Some(currency_id),
+						CORE_ASSET_ID
+						| TEST_ASSET_ID
+						| CHEAP_ASSET_ID
+						| OVERFLOW_ASSET_ID
+						| SWAPPABLE_ASSET_ID
+						| PREFERRED_SWAPPABLE_ASSET_ID => Some(currency_id),
 						_ => None,
 					}
 				} else {
@@ -94,6 +106,51 @@ impl Convert<MultiAsset, Option<AssetId>> for MockConvert {
 	}
 }
 
+thread_local! {
+	pub static SWAP_FROM_FEE_ASSET_FAILS: RefCell<bool> = RefCell::new(false);
+}
+
+/// Mock swap which trades the core asset for the swappable assets at the price of 2 swappable assets per core asset.
+struct MockFeeSwap;
+impl SwapFeeAsset<AssetId> for MockFeeSwap {
+	fn fee_asset() -> AssetId {
+		CORE_ASSET_ID
+	}
+
+	fn preferred_assets() -> Vec<AssetId> {
+		vec![PREFERRED_SWAPPABLE_ASSET_ID]
+	}
+
+	fn swap_to_fee_asset(asset_in: AssetId, amount_out: u128, max_amount_in: u128) -> Result<u128, DispatchError> {
+		let amount_in = amount_out * 2;
+		if !matches!(asset_in, SWAPPABLE_ASSET_ID | PREFERRED_SWAPPABLE_ASSET_ID) {
+			Err(DispatchError::Other("swap failed"))
+		} else if amount_in > max_amount_in {
+			Err(DispatchError::Token(TokenError::FundsUnavailable))
+		} else {
+			Ok(amount_in)
+		}
+	}
+
+	fn swap_from_fee_asset(asset_out: AssetId, amount_in: u128) -> Result<u128, DispatchError> {
+		if matches!(asset_out, SWAPPABLE_ASSET_ID | PREFERRED_SWAPPABLE_ASSET_ID)
+			&& !SWAP_FROM_FEE_ASSET_FAILS.with(|f| *f.borrow())
+		{
+			Ok(amount_in * 2)
+		} else {
+			Err(DispatchError::Other("swap failed"))
+		}
+	}
+
+	fn swap_to_fee_asset_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn swap_from_fee_asset_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 thread_local! {
 	pub static TAKEN_REVENUE: RefCell<BTreeSet<MultiAsset>> = RefCell::new(BTreeSet::new());
 	pub static EXPECTED_REVENUE: RefCell<BTreeSet<MultiAsset>> = RefCell::new(BTreeSet::new());
@@ -173,7 +230,7 @@ impl DepositFee<AccountId, AssetId, Balance> for ExpectDeposit {
 fn can_buy_weight() {
 	ExpectRevenue::reset();
 	type Trader =
-		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ExpectRevenue>;
+		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ExpectRevenue, ()>;
 
 	let core_id = MockConvert::convert(CORE_ASSET_ID).unwrap();
 	let test_id = MockConvert::convert(TEST_ASSET_ID).unwrap();
@@ -215,7 +272,7 @@ fn can_buy_weight() {
 fn can_buy_twice() {
 	ExpectRevenue::reset();
 	type Trader =
-		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ExpectRevenue>;
+		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ExpectRevenue, ()>;
 
 	let core_id = MockConvert::convert(CORE_ASSET_ID).unwrap();
 
@@ -245,7 +302,7 @@ fn can_buy_twice() {
 
 #[test]
 fn cannot_buy_with_too_few_tokens() {
-	type Trader = MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ()>;
+	type Trader = MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, (), ()>;
 
 	let core_id = MockConvert::convert(CORE_ASSET_ID).unwrap();
 
@@ -263,7 +320,7 @@ fn cannot_buy_with_too_few_tokens() {
 
 #[test]
 fn cannot_buy_with_unknown_token() {
-	type Trader = MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ()>;
+	type Trader = MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, (), ()>;
 
 	let unknown_token = Junction::from(BoundedVec::try_from(9876u32.encode()).unwrap());
 
@@ -280,7 +337,7 @@ fn cannot_buy_with_unknown_token() {
 
 #[test]
 fn cannot_buy_with_non_fungible() {
-	type Trader = MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ()>;
+	type Trader = MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, (), ()>;
 
 	let unknown_token = Junction::from(BoundedVec::try_from(9876u32.encode()).unwrap());
 
@@ -309,6 +366,7 @@ fn overflow_errors() {
 		MockOracle,
 		MockConvert,
 		(),
+		(),
 	>;
 
 	let overflow_id = MockConvert::convert(OVERFLOW_ASSET_ID).unwrap();
@@ -332,7 +390,7 @@ fn refunds_first_asset_completely() {
 	ExpectRevenue::reset();
 
 	type Trader =
-		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ExpectRevenue>;
+		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ExpectRevenue, ()>;
 
 	let core_id = MockConvert::convert(CORE_ASSET_ID).unwrap();
 
@@ -358,7 +416,7 @@ fn refunds_first_asset_completely() {
 
 #[test]
 fn does_not_refund_if_empty() {
-	type Trader = MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ()>;
+	type Trader = MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, (), ()>;
 
 	let mut trader = Trader::new();
 	let ctx = XcmContext {
@@ -375,7 +433,7 @@ fn needs_multiple_refunds_for_multiple_currencies() {
 	ExpectRevenue::reset();
 
 	type Trader =
-		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ExpectRevenue>;
+		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ExpectRevenue, ()>;
 
 	let core_id = MockConvert::convert(CORE_ASSET_ID).unwrap();
 	let test_id = MockConvert::convert(TEST_ASSET_ID).unwrap();
@@ -407,6 +465,310 @@ fn needs_multiple_refunds_for_multiple_currencies() {
 	ExpectRevenue::expect_no_revenue();
 }
 
+#[test]
+fn buys_weight_with_accepted_asset_when_unknown_asset_is_offered_as_well() {
+	ExpectRevenue::reset();
+	type Trader =
+		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ExpectRevenue, ()>;
+
+	let unknown_token = Junction::from(BoundedVec::try_from(9876u32.encode()).unwrap());
+	let test_id = MockConvert::convert(TEST_ASSET_ID).unwrap();
+
+	{
+		let mut trader = Trader::new();
+		let ctx = XcmContext {
+			origin: None,
+			message_id: XcmHash::default(),
+			topic: None,
+		};
+
+		let unknown_payment: MultiAsset = (Concrete(unknown_token.into()), 1_000_000).into();
+		let test_payment: MultiAsset = (Concrete(test_id), 500_000).into();
+		let mut payment = Assets::new();
+		payment.subsume(unknown_payment.clone());
+		payment.subsume(test_payment.clone());
+
+		let res = dbg!(trader.buy_weight(Weight::from_parts(1_000_000, 0), payment, &ctx));
+		assert_eq!(
+			res.expect("buy_weight should succeed because test asset is accepted"),
+			unknown_payment.into()
+		);
+		ExpectRevenue::register_expected_asset(test_payment);
+	}
+	ExpectRevenue::expect_revenue();
+}
+
+#[test]
+fn buys_weight_with_accepted_asset_which_covers_the_fee() {
+	ExpectRevenue::reset();
+	type Trader =
+		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ExpectRevenue, ()>;
+
+	let core_id = MockConvert::convert(CORE_ASSET_ID).unwrap();
+	let test_id = MockConvert::convert(TEST_ASSET_ID).unwrap();
+
+	{
+		let mut trader = Trader::new();
+		let ctx = XcmContext {
+			origin: None,
+			message_id: XcmHash::default(),
+			topic: None,
+		};
+
+		let core_payment: MultiAsset = (Concrete(core_id), 69).into();
+		let test_payment: MultiAsset = (Concrete(test_id), 500_000).into();
+		let mut payment = Assets::new();
+		payment.subsume(core_payment.clone());
+		payment.subsume(test_payment.clone());
+
+		let res = dbg!(trader.buy_weight(Weight::from_parts(1_000_000, 0), payment, &ctx));
+		assert_eq!(
+			res.expect("buy_weight should succeed because test asset covers the fee"),
+			core_payment.into()
+		);
+		ExpectRevenue::register_expected_asset(test_payment);
+	}
+	ExpectRevenue::expect_revenue();
+}
+
+#[test]
+fn prefers_asset_already_used_for_payment() {
+	ExpectRevenue::reset();
+	type Trader =
+		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ExpectRevenue, ()>;
+
+	let core_id = MockConvert::convert(CORE_ASSET_ID).unwrap();
+	let test_id = MockConvert::convert(TEST_ASSET_ID).unwrap();
+
+	{
+		let mut trader = Trader::new();
+		let ctx = XcmContext {
+			origin: None,
+			message_id: XcmHash::default(),
+			topic: None,
+		};
+
+		let test_payment: MultiAsset = (Concrete(test_id), 500_000).into();
+		let res = dbg!(trader.buy_weight(Weight::from_parts(1_000_000, 0), test_payment.into(), &ctx));
+		assert!(res
+			.expect("buy_weight should succeed because payment == 0.5 * weight")
+			.is_empty());
+
+		let core_payment: MultiAsset = (Concrete(core_id), 1_000_000).into();
+		let test_payment: MultiAsset = (Concrete(test_id), 500_000).into();
+		let mut payment = Assets::new();
+		payment.subsume(core_payment.clone());
+		payment.subsume(test_payment);
+
+		let res = dbg!(trader.buy_weight(Weight::from_parts(1_000_000, 0), payment, &ctx));
+		assert_eq!(
+			res.expect("buy_weight should succeed because test asset covers the fee"),
+			core_payment.into()
+		);
+		ExpectRevenue::register_expected_asset((Concrete(test_id), 1_000_000).into());
+	}
+	ExpectRevenue::expect_revenue();
+}
+
+#[test]
+fn refund_is_limited_to_weight_bought_with_the_refunded_asset() {
+	ExpectRevenue::reset();
+
+	type Trader =
+		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, ExpectRevenue, ()>;
+
+	let core_id = MockConvert::convert(CORE_ASSET_ID).unwrap();
+	let test_id = MockConvert::convert(TEST_ASSET_ID).unwrap();
+
+	{
+		let mut trader = Trader::new();
+		let ctx = XcmContext {
+			origin: None,
+			message_id: XcmHash::default(),
+			topic: None,
+		};
+
+		let weight = Weight::from_parts(1_000_000, 0);
+		let core_payment: MultiAsset = (Concrete(core_id), 1_000_000).into();
+		let res = dbg!(trader.buy_weight(weight, core_payment.clone().into(), &ctx));
+		assert!(res
+			.expect("buy_weight should succeed because payment == weight")
+			.is_empty());
+
+		let test_payment: MultiAsset = (Concrete(test_id), 500_000).into();
+		let res = dbg!(trader.buy_weight(weight, test_payment.into(), &ctx));
+		assert!(res
+			.expect("buy_weight should succeed because payment == 0.5 * weight")
+			.is_empty());
+
+		assert_eq!(
+			trader.refund_weight(Weight::from_parts(1_500_000, 0), &ctx),
+			Some(core_payment)
+		);
+		assert_eq!(
+			trader.refund_weight(Weight::from_parts(500_000, 0), &ctx),
+			Some((Concrete(test_id), 250_000).into())
+		);
+		ExpectRevenue::register_expected_asset((Concrete(test_id), 250_000).into());
+	}
+	ExpectRevenue::expect_revenue();
+}
+
+#[test]
+fn buys_weight_by_swapping_asset_which_is_not_accepted() {
+	ExpectRevenue::reset();
+	type Trader = MultiCurrencyTrader<
+		AssetId,
+		Balance,
+		Price,
+		IdentityFee<Balance>,
+		MockOracle,
+		MockConvert,
+		ExpectRevenue,
+		MockFeeSwap,
+	>;
+
+	let core_id = MockConvert::convert(CORE_ASSET_ID).unwrap();
+	let swappable_id = MockConvert::convert(SWAPPABLE_ASSET_ID).unwrap();
+
+	{
+		let mut trader = Trader::new();
+		let ctx = XcmContext {
+			origin: None,
+			message_id: XcmHash::default(),
+			topic: None,
+		};
+
+		let weight = Weight::from_parts(1_000_000, 0);
+		let payment: MultiAsset = (Concrete(swappable_id), 5_000_000).into();
+		let res = dbg!(trader.buy_weight(weight, payment.into(), &ctx));
+		assert_eq!(
+			res.expect("buy_weight should succeed because swappable asset can be swapped to core asset"),
+			MultiAsset::from((Concrete(swappable_id), 3_000_000)).into()
+		);
+
+		assert_eq!(
+			trader.refund_weight(Weight::from_parts(400_000, 0), &ctx),
+			Some((Concrete(swappable_id), 800_000).into())
+		);
+		ExpectRevenue::register_expected_asset((Concrete(core_id), 600_000).into());
+	}
+	ExpectRevenue::expect_revenue();
+}
+
+#[test]
+fn swaps_preferred_asset_first() {
+	ExpectRevenue::reset();
+	type Trader = MultiCurrencyTrader<
+		AssetId,
+		Balance,
+		Price,
+		IdentityFee<Balance>,
+		MockOracle,
+		MockConvert,
+		ExpectRevenue,
+		MockFeeSwap,
+	>;
+
+	let swappable_id = MockConvert::convert(SWAPPABLE_ASSET_ID).unwrap();
+	let preferred_id = MockConvert::convert(PREFERRED_SWAPPABLE_ASSET_ID).unwrap();
+
+	let mut trader = Trader::new();
+	let ctx = XcmContext {
+		origin: None,
+		message_id: XcmHash::default(),
+		topic: None,
+	};
+
+	let swappable_payment: MultiAsset = (Concrete(swappable_id), 5_000_000).into();
+	let mut payment = Assets::new();
+	payment.subsume(swappable_payment.clone());
+	payment.subsume((Concrete(preferred_id), 2_000_000).into());
+
+	let res = dbg!(trader.buy_weight(Weight::from_parts(1_000_000, 0), payment, &ctx));
+	assert_eq!(
+		res.expect("buy_weight should succeed because preferred asset can be swapped to core asset"),
+		swappable_payment.into()
+	);
+}
+
+#[test]
+fn does_not_refund_when_swapping_refund_fails() {
+	ExpectRevenue::reset();
+	SWAP_FROM_FEE_ASSET_FAILS.with(|f| *f.borrow_mut() = true);
+	type Trader = MultiCurrencyTrader<
+		AssetId,
+		Balance,
+		Price,
+		IdentityFee<Balance>,
+		MockOracle,
+		MockConvert,
+		ExpectRevenue,
+		MockFeeSwap,
+	>;
+
+	let core_id = MockConvert::convert(CORE_ASSET_ID).unwrap();
+	let swappable_id = MockConvert::convert(SWAPPABLE_ASSET_ID).unwrap();
+
+	{
+		let mut trader = Trader::new();
+		let ctx = XcmContext {
+			origin: None,
+			message_id: XcmHash::default(),
+			topic: None,
+		};
+
+		let payment: MultiAsset = (Concrete(swappable_id), 2_000_000).into();
+		let res = dbg!(trader.buy_weight(Weight::from_parts(1_000_000, 0), payment.into(), &ctx));
+		assert!(res
+			.expect("buy_weight should succeed because swappable asset can be swapped to core asset")
+			.is_empty());
+
+		assert_eq!(trader.refund_weight(Weight::from_parts(400_000, 0), &ctx), None);
+		ExpectRevenue::register_expected_asset((Concrete(core_id), 1_000_000).into());
+	}
+	ExpectRevenue::expect_revenue();
+	SWAP_FROM_FEE_ASSET_FAILS.with(|f| *f.borrow_mut() = false);
+}
+
+#[test]
+fn cannot_buy_by_swapping_when_swap_fails() {
+	type Trader =
+		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, (), MockFeeSwap>;
+
+	let swappable_id = MockConvert::convert(SWAPPABLE_ASSET_ID).unwrap();
+
+	let mut trader = Trader::new();
+	let ctx = XcmContext {
+		origin: None,
+		message_id: XcmHash::default(),
+		topic: None,
+	};
+	let payment: MultiAsset = (Concrete(swappable_id), 1_000_000).into();
+
+	let res = dbg!(trader.buy_weight(Weight::from_parts(1_000_000, 0), payment.into(), &ctx));
+	assert_eq!(res, Err(XcmError::TooExpensive));
+}
+
+#[test]
+fn cannot_buy_by_swapping_unknown_token() {
+	type Trader =
+		MultiCurrencyTrader<AssetId, Balance, Price, IdentityFee<Balance>, MockOracle, MockConvert, (), MockFeeSwap>;
+
+	let unknown_token = Junction::from(BoundedVec::try_from(9876u32.encode()).unwrap());
+
+	let mut trader = Trader::new();
+	let ctx = XcmContext {
+		origin: None,
+		message_id: XcmHash::default(),
+		topic: None,
+	};
+	let payment: MultiAsset = (Concrete(unknown_token.into()), 1_000_000).into();
+
+	let res = dbg!(trader.buy_weight(Weight::from_parts(1_000_000, 0), payment.into(), &ctx));
+	assert_eq!(res, Err(XcmError::AssetNotFound));
+}
+
 #[test]
 fn revenue_goes_to_fee_receiver() {
 	ExpectDeposit::reset();
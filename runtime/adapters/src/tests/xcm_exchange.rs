@@ -2,20 +2,60 @@ use crate::tests::mock::AccountId;
 use crate::tests::mock::AssetId as CurrencyId;
 use crate::tests::mock::*;
 use crate::tests::mock::{DAI, HDX, NATIVE_AMOUNT};
-use crate::xcm_exchange::XcmAssetExchanger;
+use crate::xcm_exchange::{OmnipoolFeeSwap, XcmAssetExchanger};
+use crate::SwapFeeAsset;
 use frame_support::{assert_noop, assert_ok, parameter_types};
+use hydra_dx_math::ema::EmaPrice;
 use hydradx_traits::router::{AssetPair, PoolType, Trade};
+use hydradx_traits::{OraclePeriod, PriceOracle};
 use orml_traits::MultiCurrency;
+use pallet_omnipool::types::Tradability;
 use polkadot_xcm::latest::prelude::*;
 use pretty_assertions::assert_eq;
 use sp_runtime::traits::Convert;
-use sp_runtime::{FixedU128, SaturatedConversion};
+use sp_runtime::{DispatchError, FixedU128, Permill, SaturatedConversion};
+use sp_std::cell::RefCell;
 use xcm_executor::traits::AssetExchange;
 use xcm_executor::Assets;
 
 parameter_types! {
 	pub ExchangeTempAccount: AccountId = 12345;
 	pub DefaultPoolType: PoolType<crate::tests::mock::AssetId>  = PoolType::Omnipool;
+	pub FeeAsset: CurrencyId = HDX;
+	pub PreferredFeeSwapAssets: Vec<CurrencyId> = vec![DAI];
+	pub FeeSwapMaxSlippage: Permill = Permill::from_percent(10);
+}
+
+thread_local! {
+	/// Oracle price of DAI in HDX, matches the initial pool.
+	pub static DAI_HDX_ORACLE_PRICE: RefCell<EmaPrice> = RefCell::new(EmaPrice::new(1, 2));
+}
+
+fn set_dai_hdx_oracle_price(price: EmaPrice) {
+	DAI_HDX_ORACLE_PRICE.with(|v| *v.borrow_mut() = price);
+}
+
+pub struct MockOracle;
+
+impl PriceOracle<CurrencyId> for MockOracle {
+	type Price = EmaPrice;
+
+	fn price(route: &[Trade<CurrencyId>], _period: OraclePeriod) -> Option<EmaPrice> {
+		let price = DAI_HDX_ORACLE_PRICE.with(|v| *v.borrow());
+		match route {
+			[Trade {
+				asset_in: DAI,
+				asset_out: HDX,
+				..
+			}] => Some(price),
+			[Trade {
+				asset_in: HDX,
+				asset_out: DAI,
+				..
+			}] => Some(EmaPrice::new(price.d, price.n)),
+			_ => None,
+		}
+	}
 }
 
 const BUY: bool = false;
@@ -214,6 +254,181 @@ fn xcm_exchanger_works_with_specified_origin() {
 		});
 }
 
+#[test]
+fn omnipool_fee_swap_should_buy_fee_asset_with_omnipool_asset() {
+	// Arrange
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			let fee_amount = 10 * UNITS;
+			let max_amount_in = 100 * UNITS;
+
+			// Act
+			let spent = swap_to_fee_asset(DAI, fee_amount, max_amount_in).expect("swap should succeed");
+
+			// Assert
+			assert!(spent > 0);
+			assert!(spent < max_amount_in);
+			assert_eq!(Tokens::free_balance(DAI, &ExchangeTempAccount::get()), 0);
+			assert_eq!(Balances::free_balance(ExchangeTempAccount::get()), 0);
+		});
+}
+
+#[test]
+fn omnipool_fee_swap_should_fail_when_asset_is_not_tradable() {
+	// Arrange
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				DAI,
+				Tradability::ADD_LIQUIDITY | Tradability::REMOVE_LIQUIDITY
+			));
+
+			// Act and assert
+			assert!(swap_to_fee_asset(DAI, 10 * UNITS, 100 * UNITS).is_err());
+			assert_eq!(Tokens::free_balance(DAI, &ExchangeTempAccount::get()), 0);
+		});
+}
+
+#[test]
+fn omnipool_fee_swap_should_fail_when_max_amount_in_is_not_enough() {
+	// Arrange
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			// Act and assert
+			assert!(swap_to_fee_asset(DAI, 10 * UNITS, UNITS).is_err());
+			assert_eq!(Tokens::free_balance(DAI, &ExchangeTempAccount::get()), 0);
+			assert_eq!(Balances::free_balance(ExchangeTempAccount::get()), 0);
+		});
+}
+
+#[test]
+fn omnipool_fee_swap_should_fail_when_price_is_worse_than_oracle_price() {
+	// Arrange
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			set_dai_hdx_oracle_price(EmaPrice::new(1, 1));
+
+			// Act and assert
+			assert!(swap_to_fee_asset(DAI, 10 * UNITS, 100 * UNITS).is_err());
+			assert_eq!(Tokens::free_balance(DAI, &ExchangeTempAccount::get()), 0);
+			assert_eq!(Balances::free_balance(ExchangeTempAccount::get()), 0);
+
+			set_dai_hdx_oracle_price(EmaPrice::new(1, 2));
+		});
+}
+
+#[test]
+fn omnipool_fee_swap_should_not_sell_fee_asset_when_price_is_worse_than_oracle_price() {
+	// Arrange
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			set_dai_hdx_oracle_price(EmaPrice::new(1, 4));
+
+			// Act and assert
+			assert!(swap_from_fee_asset(DAI, 10 * UNITS).is_err());
+			assert_eq!(Tokens::free_balance(DAI, &ExchangeTempAccount::get()), 0);
+			assert_eq!(Balances::free_balance(ExchangeTempAccount::get()), 0);
+
+			set_dai_hdx_oracle_price(EmaPrice::new(1, 2));
+		});
+}
+
+#[test]
+fn omnipool_fee_swap_should_sell_fee_asset_for_omnipool_asset() {
+	// Arrange
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			let spent = swap_to_fee_asset(DAI, 10 * UNITS, 100 * UNITS).expect("swap should succeed");
+
+			// Act
+			let received = swap_from_fee_asset(DAI, 10 * UNITS).expect("swap should succeed");
+
+			// Assert
+			assert!(received > 0);
+			assert!(received < spent);
+			assert_eq!(Tokens::free_balance(DAI, &ExchangeTempAccount::get()), 0);
+			assert_eq!(Balances::free_balance(ExchangeTempAccount::get()), 0);
+		});
+}
+
+#[test]
+fn omnipool_fee_swap_should_not_sell_fee_asset_when_asset_is_not_tradable() {
+	// Arrange
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				DAI,
+				Tradability::SELL | Tradability::ADD_LIQUIDITY | Tradability::REMOVE_LIQUIDITY
+			));
+
+			// Act and assert
+			assert!(swap_from_fee_asset(DAI, 10 * UNITS).is_err());
+			assert_eq!(Balances::free_balance(ExchangeTempAccount::get()), 0);
+		});
+}
+
+type FeeSwap = OmnipoolFeeSwap<
+	Test,
+	ExchangeTempAccount,
+	Currencies,
+	FeeAsset,
+	PreferredFeeSwapAssets,
+	MockOracle,
+	FeeSwapMaxSlippage,
+>;
+
+fn swap_to_fee_asset(asset_in: CurrencyId, amount_out: u128, max_amount_in: u128) -> Result<u128, DispatchError> {
+	FeeSwap::swap_to_fee_asset(asset_in, amount_out, max_amount_in)
+}
+
+fn swap_from_fee_asset(asset_out: CurrencyId, amount_in: u128) -> Result<u128, DispatchError> {
+	FeeSwap::swap_from_fee_asset(asset_out, amount_in)
+}
+
 fn exchange_asset(
 	origin: Option<&MultiLocation>,
 	give: Assets,
@@ -1,9 +1,18 @@
+use crate::SwapFeeAsset;
+use frame_support::{ensure, weights::Weight};
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::router::{AmmTradeWeights, PoolType, Trade};
+use hydradx_traits::{OraclePeriod, PriceOracle};
 use orml_traits::MultiCurrency;
+use pallet_omnipool::types::Tradability;
 use polkadot_xcm::latest::prelude::*;
 use sp_core::Get;
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
 use sp_runtime::traits::{Convert, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, DispatchResult, Permill, Rounding};
 use sp_std::marker::PhantomData;
 use sp_std::vec;
+use sp_std::vec::Vec;
 use xcm_executor::traits::AssetExchange;
 
 /// Implements `AssetExchange` to support the `ExchangeAsset` XCM instruction.
@@ -127,3 +136,197 @@ where
 		}
 	}
 }
+
+/// Implements `SwapFeeAsset` to pay XCM execution fees with assets tradable in the Omnipool.
+///
+/// Trades the offered asset directly with the fee asset in the Omnipool, using pallet-route-executor. The traded
+/// asset is minted to and the remainder burned from `TempAccount`, same as in `XcmAssetExchanger`.
+///
+/// Offered assets listed in `PreferredAssets` are swapped first.
+///
+/// Both swaps are limited by the `Oracle` price of the short oracle period, the amounts can differ from
+/// the oracle price by at most `MaxSlippage`.
+pub struct OmnipoolFeeSwap<Runtime, TempAccount, Currency, FeeAsset, PreferredAssets, Oracle, MaxSlippage>(
+	PhantomData<(
+		Runtime,
+		TempAccount,
+		Currency,
+		FeeAsset,
+		PreferredAssets,
+		Oracle,
+		MaxSlippage,
+	)>,
+);
+
+impl<Runtime, TempAccount, Currency, FeeAsset, PreferredAssets, Oracle, MaxSlippage>
+	OmnipoolFeeSwap<Runtime, TempAccount, Currency, FeeAsset, PreferredAssets, Oracle, MaxSlippage>
+where
+	Runtime: pallet_route_executor::Config
+		+ pallet_omnipool::Config<AssetId = <Runtime as pallet_route_executor::Config>::AssetId>,
+	FeeAsset: Get<<Runtime as pallet_route_executor::Config>::AssetId>,
+	Oracle: PriceOracle<<Runtime as pallet_route_executor::Config>::AssetId, Price = EmaPrice>,
+	MaxSlippage: Get<Permill>,
+{
+	fn route(
+		asset_in: <Runtime as pallet_route_executor::Config>::AssetId,
+		asset_out: <Runtime as pallet_route_executor::Config>::AssetId,
+	) -> Vec<Trade<<Runtime as pallet_route_executor::Config>::AssetId>> {
+		vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in,
+			asset_out,
+		}]
+	}
+
+	fn ensure_tradable(
+		asset_id: <Runtime as pallet_route_executor::Config>::AssetId,
+		operation: Tradability,
+	) -> DispatchResult {
+		let tradable = pallet_omnipool::Pallet::<Runtime>::assets(asset_id)
+			.map(|state| state.tradable.contains(operation))
+			.unwrap_or(false);
+		ensure!(tradable, DispatchError::Other("Asset is not tradable in Omnipool"));
+		Ok(())
+	}
+
+	fn oracle_price(
+		asset_in: <Runtime as pallet_route_executor::Config>::AssetId,
+		asset_out: <Runtime as pallet_route_executor::Config>::AssetId,
+	) -> Result<EmaPrice, DispatchError> {
+		Oracle::price(&Self::route(asset_in, asset_out), OraclePeriod::Short)
+			.filter(|price| !price.n.is_zero() && !price.d.is_zero())
+			.ok_or(DispatchError::Other("Oracle price is not available"))
+	}
+
+	/// Maximum amount of `asset_in` paid for `amount_out` of `asset_out`, derived from the oracle price.
+	fn max_amount_in(
+		asset_in: <Runtime as pallet_route_executor::Config>::AssetId,
+		asset_out: <Runtime as pallet_route_executor::Config>::AssetId,
+		amount_out: u128,
+	) -> Result<u128, DispatchError> {
+		let price = Self::oracle_price(asset_in, asset_out)?;
+		let amount_in = multiply_by_rational_with_rounding(amount_out, price.d, price.n, Rounding::Up)
+			.ok_or(ArithmeticError::Overflow)?;
+		Ok(amount_in.saturating_add(MaxSlippage::get().mul_ceil(amount_in)))
+	}
+
+	/// Minimum amount of `asset_out` received for `amount_in` of `asset_in`, derived from the oracle price.
+	fn min_amount_out(
+		asset_in: <Runtime as pallet_route_executor::Config>::AssetId,
+		asset_out: <Runtime as pallet_route_executor::Config>::AssetId,
+		amount_in: u128,
+	) -> Result<u128, DispatchError> {
+		let price = Self::oracle_price(asset_in, asset_out)?;
+		let amount_out = multiply_by_rational_with_rounding(amount_in, price.n, price.d, Rounding::Down)
+			.ok_or(ArithmeticError::Overflow)?;
+		Ok(amount_out.saturating_sub(MaxSlippage::get().mul_ceil(amount_out)))
+	}
+
+	/// Weight of the tradability check, of the oracle price and of minting and burning the traded assets.
+	fn holding_weight() -> Weight {
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		db_weight
+			.reads(1)
+			.saturating_add(db_weight.reads(2))
+			.saturating_add(db_weight.reads_writes(2, 2).saturating_mul(3))
+	}
+}
+
+impl<Runtime, TempAccount, Currency, FeeAsset, PreferredAssets, Oracle, MaxSlippage>
+	SwapFeeAsset<<Runtime as pallet_route_executor::Config>::AssetId>
+	for OmnipoolFeeSwap<Runtime, TempAccount, Currency, FeeAsset, PreferredAssets, Oracle, MaxSlippage>
+where
+	Runtime: pallet_route_executor::Config
+		+ pallet_omnipool::Config<AssetId = <Runtime as pallet_route_executor::Config>::AssetId>,
+	TempAccount: Get<Runtime::AccountId>,
+	Currency: MultiCurrency<
+		Runtime::AccountId,
+		CurrencyId = <Runtime as pallet_route_executor::Config>::AssetId,
+		Balance = <Runtime as pallet_route_executor::Config>::Balance,
+	>,
+	FeeAsset: Get<<Runtime as pallet_route_executor::Config>::AssetId>,
+	PreferredAssets: Get<Vec<<Runtime as pallet_route_executor::Config>::AssetId>>,
+	Oracle: PriceOracle<<Runtime as pallet_route_executor::Config>::AssetId, Price = EmaPrice>,
+	MaxSlippage: Get<Permill>,
+	<Runtime as pallet_route_executor::Config>::Balance: From<u128> + Zero + Into<u128>,
+{
+	fn fee_asset() -> <Runtime as pallet_route_executor::Config>::AssetId {
+		FeeAsset::get()
+	}
+
+	fn preferred_assets() -> Vec<<Runtime as pallet_route_executor::Config>::AssetId> {
+		PreferredAssets::get()
+	}
+
+	fn swap_to_fee_asset(
+		asset_in: <Runtime as pallet_route_executor::Config>::AssetId,
+		amount_out: u128,
+		max_amount_in: u128,
+	) -> Result<u128, DispatchError> {
+		use orml_utilities::with_transaction_result;
+
+		Self::ensure_tradable(asset_in, Tradability::SELL)?;
+
+		let account = TempAccount::get();
+		let origin = Runtime::RuntimeOrigin::from(frame_system::RawOrigin::Signed(account.clone()));
+		let fee_asset = FeeAsset::get();
+		let max_amount_in = max_amount_in.min(Self::max_amount_in(asset_in, fee_asset, amount_out)?);
+
+		with_transaction_result(|| {
+			Currency::deposit(asset_in, &account, max_amount_in.into())?; // mint the incoming tokens
+			pallet_route_executor::Pallet::<Runtime>::buy(
+				origin,
+				asset_in,
+				fee_asset,
+				amount_out.into(),
+				max_amount_in.into(),
+				Self::route(asset_in, fee_asset),
+			)?;
+			let left_over = Currency::free_balance(asset_in, &account);
+			Currency::withdraw(asset_in, &account, left_over)?; // burn left over tokens
+			Currency::withdraw(fee_asset, &account, amount_out.into())?; // burn the bought fee asset
+			Ok(max_amount_in.saturating_sub(left_over.into()))
+		})
+	}
+
+	fn swap_from_fee_asset(
+		asset_out: <Runtime as pallet_route_executor::Config>::AssetId,
+		amount_in: u128,
+	) -> Result<u128, DispatchError> {
+		use orml_utilities::with_transaction_result;
+
+		Self::ensure_tradable(asset_out, Tradability::BUY)?;
+
+		let account = TempAccount::get();
+		let origin = Runtime::RuntimeOrigin::from(frame_system::RawOrigin::Signed(account.clone()));
+		let fee_asset = FeeAsset::get();
+		let min_amount_out = Self::min_amount_out(fee_asset, asset_out, amount_in)?;
+
+		with_transaction_result(|| {
+			Currency::deposit(fee_asset, &account, amount_in.into())?; // mint the refunded fee asset
+			pallet_route_executor::Pallet::<Runtime>::sell(
+				origin,
+				fee_asset,
+				asset_out,
+				amount_in.into(),
+				min_amount_out.into(),
+				Self::route(fee_asset, asset_out),
+			)?;
+			let amount_out = Currency::free_balance(asset_out, &account);
+			Currency::withdraw(asset_out, &account, amount_out)?; // burn the received tokens
+			Ok(amount_out.into())
+		})
+	}
+
+	fn swap_to_fee_asset_weight() -> Weight {
+		let fee_asset = FeeAsset::get();
+		<Runtime as pallet_route_executor::Config>::WeightInfo::buy_weight(&Self::route(fee_asset, fee_asset))
+			.saturating_add(Self::holding_weight())
+	}
+
+	fn swap_from_fee_asset_weight() -> Weight {
+		let fee_asset = FeeAsset::get();
+		<Runtime as pallet_route_executor::Config>::WeightInfo::sell_weight(&Self::route(fee_asset, fee_asset))
+			.saturating_add(Self::holding_weight())
+	}
+}
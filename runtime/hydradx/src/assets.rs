@@ -20,16 +20,19 @@ use crate::system::NativeAssetId;
 
 use frame_support::traits::Defensive;
 use hydradx_adapters::{
-	AssetFeeOraclePriceProvider, EmaOraclePriceAdapter, FreezableNFT, MultiCurrencyLockedBalance, OmnipoolHookAdapter,
-	OracleAssetVolumeProvider, PriceAdjustmentAdapter, StableswapHooksAdapter, VestingInfo,
+	AssetFeeOraclePriceProvider, EmaOracleListingAdapter, EmaOraclePriceAdapter, FreezableNFT,
+	MultiCurrencyLockedBalance, OmnipoolHookAdapter, OmnipoolTradingHalt, OracleAssetVolumeProvider,
+	PriceAdjustmentAdapter, StableswapHooksAdapter, VestingInfo,
 };
 
 use hydradx_adapters::{RelayChainBlockHashProvider, RelayChainBlockNumberProvider};
 use hydradx_traits::{
+	nft::CreateTypedCollection,
 	registry::Inspect,
 	router::{inverse_route, PoolType, Trade},
 	AccountIdFor, AssetKind, AssetPairAccountIdFor, NativePriceOracle, OnTradeHandler, OraclePeriod, Source,
 };
+use pallet_concentrated_liquidity::weights::WeightInfo as ConcentratedLiquidityWeights;
 use pallet_currencies::BasicCurrencyAdapter;
 use pallet_omnipool::{
 	traits::{EnsurePriceWithin, OmnipoolHooks},
@@ -38,8 +41,8 @@ use pallet_omnipool::{
 use pallet_otc::NamedReserveIdentifier;
 use pallet_stableswap::weights::WeightInfo as StableswapWeights;
 use pallet_transaction_multi_payment::{AddTxAssetOnAccount, RemoveTxAssetOnKilled};
-use primitives::constants::chain::XYK_SOURCE;
-use primitives::constants::time::DAYS;
+use primitives::constants::chain::{CONCENTRATED_SOURCE, STABLESWAP_SOURCE, XYK_SOURCE};
+use primitives::constants::time::{DAYS, HOURS, MINUTES};
 use primitives::constants::{
 	chain::OMNIPOOL_SOURCE,
 	currency::{NATIVE_EXISTENTIAL_DEPOSIT, UNITS},
@@ -67,6 +70,7 @@ use pallet_route_executor::{weights::WeightInfo as RouterWeights, AmmTradeWeight
 use pallet_staking::types::{Action, Point};
 use pallet_staking::SigmoidPercentage;
 use pallet_xyk::weights::WeightInfo as XykWeights;
+use sp_std::boxed::Box;
 use sp_std::num::NonZeroU16;
 
 parameter_types! {
@@ -357,6 +361,24 @@ impl pallet_claims::Config for Runtime {
 	type CurrencyBalance = Balance;
 }
 
+parameter_types! {
+	pub const AirdropPalletId: PalletId = PalletId(*b"airdrop!");
+	pub AirdropMessagePrefix: &'static [u8] = b"I hereby claim my HydraDX airdrop to wallet:";
+	pub const AirdropMaxProofLength: u32 = 32;
+}
+
+impl pallet_airdrop::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = TreasuryApproveOrigin;
+	type AssetId = AssetId;
+	type Currency = FungibleCurrencies<Runtime>;
+	type PalletId = AirdropPalletId;
+	type TreasuryAccount = TreasuryAccount;
+	type Prefix = AirdropMessagePrefix;
+	type MaxProofLength = AirdropMaxProofLength;
+	type WeightInfo = weights::airdrop::HydraWeight<Runtime>;
+}
+
 parameter_types! {
 	#[derive(PartialEq, Debug)]
 	pub const RegistryStrLimit: u32 = 32;
@@ -364,12 +386,17 @@ parameter_types! {
 	pub const MinRegistryStrLimit: u32 = 3;
 	pub const SequentialIdOffset: u32 = 1_000_000;
 	pub const RegExternalWeightMultiplier: u64 = 10;
+	#[derive(PartialEq, Debug)]
+	pub const RegistryMetadataValueLimit: u32 = 256;
+	pub const RegistryMetadataDepositBase: Balance = 10 * UNITS;
+	pub const RegistryMetadataDepositPerByte: Balance = UNITS / 10;
 }
 
 impl pallet_asset_registry::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RegistryOrigin = EnsureRoot<AccountId>;
 	type UpdateOrigin = SuperMajorityTechCommittee;
+	type AttestOrigin = MoreThanHalfTechCommittee;
 	type Currency = pallet_currencies::fungibles::FungibleCurrencies<Runtime>;
 	type AssetId = AssetId;
 	type AssetNativeLocation = AssetLocation;
@@ -377,6 +404,11 @@ impl pallet_asset_registry::Config for Runtime {
 	type MinStringLimit = MinRegistryStrLimit;
 	type SequentialIdStartAt = SequentialIdOffset;
 	type RegExternalWeightMultiplier = RegExternalWeightMultiplier;
+	type DepositCurrency = Currencies;
+	type DepositAssetId = NativeAssetId;
+	type MetadataDepositBase = RegistryMetadataDepositBase;
+	type MetadataDepositPerByte = RegistryMetadataDepositPerByte;
+	type MetadataValueLimit = RegistryMetadataValueLimit;
 	type WeightInfo = weights::registry::HydraWeight<Runtime>;
 }
 
@@ -413,6 +445,20 @@ impl pallet_uniques::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const ReserveCollectionIdUpTo: CollectionId = 999_999;
+}
+
+impl pallet_nft::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_nft::weights::BasiliskWeight<Runtime>;
+	type NftCollectionId = CollectionId;
+	type NftItemId = ItemId;
+	type CollectionType = pallet_nft::CollectionType;
+	type Permissions = pallet_nft::NftPermissions;
+	type ReserveCollectionIdUpTo = ReserveCollectionIdUpTo;
+}
+
 parameter_types! {
 	pub const LRNA: AssetId = 1;
 	pub const MinTradingLimit : Balance = 1_000u128;
@@ -424,6 +470,12 @@ parameter_types! {
 	pub const EmaOracleSpotPriceShort: OraclePeriod = OraclePeriod::Short;
 	pub const OmnipoolMaxAllowedPriceDifference: Permill = Permill::from_percent(1);
 	pub MinimumWithdrawalFee: Permill = Permill::from_rational(1u32,10000);
+	pub const OmnipoolListingBond: Balance = 100_000 * UNITS;
+	pub const OmnipoolListingChallengePeriod: BlockNumber = 7 * DAYS;
+	pub const OmnipoolListingWeightCap: Permill = Permill::from_percent(1);
+	pub const OmnipoolListingShortOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
+	pub const OmnipoolListingLongOraclePeriod: OraclePeriod = OraclePeriod::Day;
+	pub const OmnipoolListingMaxPriceDifference: Permill = Permill::from_percent(5);
 }
 
 impl pallet_omnipool::Config for Runtime {
@@ -464,6 +516,16 @@ impl pallet_omnipool::Config for Runtime {
 	);
 	type ExternalPriceOracle = EmaOraclePriceAdapter<EmaOracleSpotPriceShort, Runtime>;
 	type Fee = pallet_dynamic_fees::UpdateAndRetrieveFees<Runtime>;
+	type ListingPriceOracle = EmaOracleListingAdapter<
+		OmnipoolListingShortOraclePeriod,
+		OmnipoolListingLongOraclePeriod,
+		OmnipoolListingMaxPriceDifference,
+		XYKOracleSourceIdentifier,
+		Runtime,
+	>;
+	type ListingBond = OmnipoolListingBond;
+	type ListingChallengePeriod = OmnipoolListingChallengePeriod;
+	type ListingWeightCap = OmnipoolListingWeightCap;
 }
 
 pub struct CircuitBreakerWhitelist;
@@ -477,6 +539,9 @@ impl Contains<AccountId> for CircuitBreakerWhitelist {
 parameter_types! {
 	pub const DefaultMaxNetTradeVolumeLimitPerBlock: (u32, u32) = (5_000, 10_000);	// 50%
 	pub const DefaultMaxLiquidityLimitPerBlock: Option<(u32, u32)> = Some((500, 10_000));	// 5%
+	pub const DefaultMaxNetTradeVolumeLimitPerWindow: Option<(u32, u32)> = Some((7_500, 10_000));	// 75%
+	pub const TradeVolumeWindowLength: BlockNumber = 10 * MINUTES;
+	pub const TradingHaltCooldown: BlockNumber = HOURS;
 }
 
 impl pallet_circuit_breaker::Config for Runtime {
@@ -488,13 +553,17 @@ impl pallet_circuit_breaker::Config for Runtime {
 	type DefaultMaxNetTradeVolumeLimitPerBlock = DefaultMaxNetTradeVolumeLimitPerBlock;
 	type DefaultMaxAddLiquidityLimitPerBlock = DefaultMaxLiquidityLimitPerBlock;
 	type DefaultMaxRemoveLiquidityLimitPerBlock = DefaultMaxLiquidityLimitPerBlock;
+	type DefaultMaxNetTradeVolumeLimitPerWindow = DefaultMaxNetTradeVolumeLimitPerWindow;
+	type TradeVolumeWindowLength = TradeVolumeWindowLength;
+	type TradingHaltCooldown = TradingHaltCooldown;
+	type TradingHaltHandler = OmnipoolTradingHalt<Runtime>;
 	type OmnipoolHubAsset = LRNA;
 	type WeightInfo = weights::circuit_breaker::HydraWeight<Runtime>;
 }
 
 parameter_types! {
 	pub SupportedPeriods: BoundedVec<OraclePeriod, ConstU32<{ pallet_ema_oracle::MAX_PERIODS }>> = BoundedVec::truncate_from(vec![
-		OraclePeriod::LastBlock, OraclePeriod::Short, OraclePeriod::TenMinutes]);
+		OraclePeriod::LastBlock, OraclePeriod::Short, OraclePeriod::TenMinutes, OraclePeriod::Day]);
 }
 
 pub struct OracleWhitelist<Runtime>(PhantomData<Runtime>);
@@ -521,6 +590,8 @@ impl pallet_ema_oracle::Config for Runtime {
 	/// With every asset trading against LRNA we will only have as many pairs as there will be assets, so
 	/// 40 seems a decent upper bound for the foreseeable future.
 	type MaxUniqueEntries = ConstU32<40>;
+	/// With 6 second blocks this covers TWAP windows of up to 100 minutes.
+	type MaxTwapCheckpoints = ConstU32<1_000>;
 	type WeightInfo = weights::ema_oracle::HydraWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	/// Should take care of the overhead introduced by `OracleWhitelist`.
@@ -537,6 +608,8 @@ impl Contains<AccountId> for DustRemovalWhitelist {
 
 parameter_types! {
 	pub const DustingReward: u128 = 0;
+	pub const MaxDustAccountsPerBatch: u32 = 100;
+	pub const MaxSweepAccountsPerBlock: u32 = 50;
 }
 
 impl pallet_duster::Config for Runtime {
@@ -549,7 +622,27 @@ impl pallet_duster::Config for Runtime {
 	type Reward = DustingReward;
 	type NativeCurrencyId = NativeAssetId;
 	type BlacklistUpdateOrigin = SuperMajorityTechCommittee;
-	type WeightInfo = ();
+	type DustConversionOrigin = TreasuryApproveOrigin;
+	type TokenAccounts = TokenAccounts;
+	type RouteExecutor = Router;
+	type RouteProvider = Router;
+	type AmmTradeWeights = RouterWeightInfo;
+	type MaxAccountsPerBatch = MaxDustAccountsPerBatch;
+	type MaxSweepAccountsPerBlock = MaxSweepAccountsPerBlock;
+	type WeightInfo = weights::duster::HydraWeight<Runtime>;
+}
+
+/// Token accounts checked by duster's `on_idle` sweeper.
+pub struct TokenAccounts;
+impl pallet_duster::traits::TokenAccountsIterator<AccountId, AssetId> for TokenAccounts {
+	fn iter_from(last: Option<(AccountId, AssetId)>) -> Box<dyn Iterator<Item = (AccountId, AssetId)>> {
+		match last {
+			Some((who, asset_id)) => Box::new(orml_tokens::Accounts::<Runtime>::iter_keys_from(
+				orml_tokens::Accounts::<Runtime>::hashed_key_for(who, asset_id),
+			)),
+			None => Box::new(orml_tokens::Accounts::<Runtime>::iter_keys()),
+		}
+	}
 }
 
 parameter_types! {
@@ -741,7 +834,7 @@ impl pallet_dca::Config for Runtime {
 	type RelayChainBlockHashProvider = RelayChainBlockHashProviderAdapter<Runtime>;
 	type RandomnessProvider = DCA;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type OraclePriceProvider = OraclePriceProvider<AssetId, EmaOracle, LRNA>;
+	type OraclePriceProvider = OraclePriceProvider<AssetId, EmaOracle, LRNA, Stableswap>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type OraclePriceProvider = DummyOraclePriceProvider;
 	#[cfg(not(feature = "runtime-benchmarks"))]
@@ -765,7 +858,7 @@ impl pallet_dca::Config for Runtime {
 		NativeAssetId,
 		MultiTransactionPayment,
 		Router,
-		OraclePriceProvider<AssetId, EmaOracle, LRNA>,
+		OraclePriceProvider<AssetId, EmaOracle, LRNA, Stableswap>,
 		MultiTransactionPayment,
 		DCAOraclePeriod,
 	>;
@@ -787,6 +880,14 @@ pub struct RouterWeightInfo;
 // Calculates the overhead of Router extrinsics. To do that, we benchmark Router::sell with single LBP trade and subtract the weight of LBP::sell.
 // This allows us to calculate the weight of any route by adding the weight of AMM trades to the overhead of a router extrinsic.
 impl RouterWeightInfo {
+	// Trade through a stableswap metapool is executed as a trade in the metapool and a trade in its base pool,
+	// after both pools are read to split the trade.
+	fn stableswap_metapool_weight(stableswap_weight: Weight) -> Weight {
+		stableswap_weight
+			.saturating_mul(2)
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().reads(2))
+	}
+
 	pub fn sell_and_calculate_sell_trade_amounts_overhead_weight(
 		num_of_calc_sell: u32,
 		num_of_execute_sell: u32,
@@ -861,8 +962,16 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 					),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_sell(c, e),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e),
+				PoolType::StableswapMetapool(..) => Self::stableswap_metapool_weight(
+					weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e),
+				),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Concentrated(_) => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(c, e).saturating_add(
+						<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+					)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -899,8 +1008,16 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 					),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e),
+				PoolType::StableswapMetapool(..) => Self::stableswap_metapool_weight(
+					weights::stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e),
+				),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Concentrated(_) => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(c, e).saturating_add(
+						<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+					)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -921,8 +1038,16 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e),
+				PoolType::StableswapMetapool(..) => Self::stableswap_metapool_weight(
+					weights::stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e),
+				),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Concentrated(_) => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(c, e).saturating_add(
+						<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+					)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -943,8 +1068,16 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_sell(c, e),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_sell(c, e),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e),
+				PoolType::StableswapMetapool(..) => Self::stableswap_metapool_weight(
+					weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e),
+				),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Concentrated(_) => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(c, e).saturating_add(
+						<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+					)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -965,8 +1098,16 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e),
+				PoolType::StableswapMetapool(..) => Self::stableswap_metapool_weight(
+					weights::stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e),
+				),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Concentrated(_) => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(c, e).saturating_add(
+						<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+					)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -996,7 +1137,13 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::StableswapMetapool(..) => Self::stableswap_metapool_weight(
+					weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::Concentrated(_) => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1007,7 +1154,13 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::StableswapMetapool(..) => Self::stableswap_metapool_weight(
+					weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::Concentrated(_) => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1031,13 +1184,21 @@ impl pallet_route_executor::Config for Runtime {
 	type Balance = Balance;
 	type Currency = FungibleCurrencies<Runtime>;
 	type WeightInfo = RouterWeightInfo;
-	type AMM = (Omnipool, Stableswap, XYK, LBP);
+	type AMM = (Omnipool, Stableswap, XYK, LBP, ConcentratedLiquidity);
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type NativeAssetId = NativeAssetId;
 	type InspectRegistry = AssetRegistry;
 	type TechnicalOrigin = SuperMajorityTechCommittee;
 }
 
+impl pallet_zap::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RouteExecutor = Router;
+	type RouteProvider = Router;
+	type AmmTradeWeights = RouterWeightInfo;
+	type WeightInfo = weights::zap::HydraWeight<Runtime>;
+}
+
 parameter_types! {
 	pub const ExistentialDepositMultiplier: u8 = 5;
 }
@@ -1084,6 +1245,8 @@ impl pallet_dynamic_fees::Config for Runtime {
 // Stableswap
 parameter_types! {
 	pub StableswapAmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+	pub const StableswapRateOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
+	pub const StableswapOracleSource: Source = STABLESWAP_SOURCE;
 }
 
 pub struct StableswapAccountIdConstructor<T: frame_system::Config>(PhantomData<T>);
@@ -1152,6 +1315,13 @@ impl<T: pallet_asset_registry::Config> BenchmarkHelper<AssetId> for RegisterAsse
 	}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: pallet_asset_registry::Config> pallet_concentrated_liquidity::BenchmarkHelper<AssetId> for RegisterAsset<T> {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		<Self as BenchmarkHelper<AssetId>>::register_asset(asset_id, decimals)
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 impl<T: pallet_ema_oracle::Config> pallet_ema_oracle::BenchmarkHelper<AssetId> for RegisterAsset<T> {
 	fn register_asset(asset_id: AssetId) -> DispatchResult {
@@ -1191,6 +1361,10 @@ impl pallet_stableswap::Config for Runtime {
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MinTradingLimit = MinTradingLimit;
 	type AmplificationRange = StableswapAmplificationRange;
+	type RateOracle = EmaOracle;
+	type RateOraclePeriod = StableswapRateOraclePeriod;
+	type OracleSource = StableswapOracleSource;
+	type UpdateRateOrigin = EnsureRoot<AccountId>;
 	type WeightInfo = weights::stableswap::HydraWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = RegisterAsset<Runtime>;
@@ -1347,6 +1521,87 @@ impl pallet_xyk::Config for Runtime {
 	type OracleSource = XYKOracleSourceIdentifier;
 }
 
+parameter_types! {
+	pub const ConcentratedLiquidityPalletId: PalletId = PalletId(*b"conliqid");
+	pub const ConcentratedLiquidityCollectionId: CollectionId = 4242_u128;
+	pub const ConcentratedLiquidityOracleSource: Source = CONCENTRATED_SOURCE;
+	pub const ConcentratedLiquidityMaxInitializedTicks: u32 = 1_000;
+	pub const ConcentratedLiquidityMaxCrossedTicks: u32 = 100;
+}
+
+/// NFT handler of concentrated liquidity positions.
+///
+/// Positions are minted by `pallet_nft` into a collection of `CollectionType::ConcentratedLiquidity`,
+/// which can be transferred but not minted, burned or destroyed by users.
+pub struct ConcentratedLiquidityNFT;
+
+impl frame_support::traits::tokens::nonfungibles::Inspect<AccountId> for ConcentratedLiquidityNFT {
+	type ItemId = ItemId;
+	type CollectionId = CollectionId;
+
+	fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<AccountId> {
+		<NFT as frame_support::traits::tokens::nonfungibles::Inspect<AccountId>>::owner(collection, item)
+	}
+
+	fn collection_owner(collection: &Self::CollectionId) -> Option<AccountId> {
+		<NFT as frame_support::traits::tokens::nonfungibles::Inspect<AccountId>>::collection_owner(collection)
+	}
+
+	fn can_transfer(collection: &Self::CollectionId, item: &Self::ItemId) -> bool {
+		<NFT as frame_support::traits::tokens::nonfungibles::Inspect<AccountId>>::can_transfer(collection, item)
+	}
+}
+
+impl frame_support::traits::tokens::nonfungibles::Mutate<AccountId> for ConcentratedLiquidityNFT {
+	fn mint_into(collection: &Self::CollectionId, item: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		<NFT as frame_support::traits::tokens::nonfungibles::Mutate<AccountId>>::mint_into(collection, item, who)
+	}
+
+	fn burn(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		<NFT as frame_support::traits::tokens::nonfungibles::Mutate<AccountId>>::burn(
+			collection,
+			item,
+			maybe_check_owner,
+		)
+	}
+}
+
+impl frame_support::traits::tokens::nonfungibles::Create<AccountId> for ConcentratedLiquidityNFT {
+	fn create_collection(collection: &Self::CollectionId, who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		<NFT as CreateTypedCollection<_, _, _, _>>::create_typed_collection(
+			who.clone(),
+			*collection,
+			pallet_nft::CollectionType::ConcentratedLiquidity,
+			None,
+		)
+	}
+}
+
+impl pallet_concentrated_liquidity::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Currencies;
+	type AssetInspection = AssetRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type PalletId = ConcentratedLiquidityPalletId;
+	type CollectionId = CollectionId;
+	type NFTCollectionId = ConcentratedLiquidityCollectionId;
+	type NFTHandler = ConcentratedLiquidityNFT;
+	type DustAccountHandler = Duster;
+	type AMMHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
+	type OracleSource = ConcentratedLiquidityOracleSource;
+	type MinTradingLimit = MinTradingLimit;
+	type MaxInitializedTicks = ConcentratedLiquidityMaxInitializedTicks;
+	type MaxCrossedTicks = ConcentratedLiquidityMaxCrossedTicks;
+	type WeightInfo = weights::concentrated_liquidity::HydraWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RegisterAsset<Runtime>;
+}
+
 parameter_types! {
 	pub const ReferralsPalletId: PalletId = PalletId(*b"referral");
 	pub RegistrationFee: (AssetId,Balance, AccountId)= (NativeAssetId::get(), 222_000_000_000_000, TreasuryAccount::get());
@@ -1364,8 +1619,11 @@ impl pallet_referrals::Config for Runtime {
 	type Currency = FungibleCurrencies<Runtime>;
 	type Convert = ConvertViaOmnipool<Omnipool>;
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type PriceProvider =
-		OraclePriceProviderUsingRoute<Router, OraclePriceProvider<AssetId, EmaOracle, LRNA>, ReferralsOraclePeriod>;
+	type PriceProvider = OraclePriceProviderUsingRoute<
+		Router,
+		OraclePriceProvider<AssetId, EmaOracle, LRNA, Stableswap>,
+		ReferralsOraclePeriod,
+	>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type PriceProvider = ReferralsDummyPriceProvider;
 	type RewardAsset = NativeAssetId;
@@ -36,7 +36,7 @@ use hydradx_traits::router::PoolType;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, NamedMultiReservableCurrency};
 use pallet_dca::types::{Order, Schedule, ScheduleId};
-use pallet_dca::{ScheduleIdsPerBlock, Schedules};
+use pallet_dca::{PausedSchedules, RemainingAmounts, ScheduleExecutionBlock, ScheduleIdsPerBlock, Schedules};
 use pallet_route_executor::Trade;
 use pallet_route_executor::MAX_NUMBER_OF_TRADES;
 use scale_info::prelude::vec::Vec;
@@ -69,7 +69,9 @@ fn schedule_fake(
 		total_amount: 1100 * ONE,
 		max_retries: None,
 		stability_threshold: None,
+		oracle_bounds: Default::default(),
 		slippage: Some(Permill::from_percent(15)),
+		limit_price: None,
 		order: Order::Buy {
 			asset_in,
 			asset_out,
@@ -101,7 +103,9 @@ fn schedule_buy_fake(
 		total_amount: 2000 * ONE,
 		max_retries: None,
 		stability_threshold: None,
+		oracle_bounds: Default::default(),
 		slippage: Some(Permill::from_percent(15)),
+		limit_price: None,
 		order: Order::Buy {
 			asset_in,
 			asset_out,
@@ -129,7 +133,9 @@ fn schedule_sell_fake(
 		total_amount: 2000 * ONE,
 		max_retries: None,
 		stability_threshold: None,
+		oracle_bounds: Default::default(),
 		slippage: Some(Permill::from_percent(100)),
+		limit_price: None,
 		order: Order::Sell {
 			asset_in,
 			asset_out,
@@ -224,6 +230,7 @@ runtime_benchmarks! {
 	}
 	verify {
 		assert_eq!((MaxSchedulesPerBlock::get()) as usize, <ScheduleIdsPerBlock<Runtime>>::get::<BlockNumber>(next_block_to_replan + DELAY_AFTER_LAST_RADIUS).len());
+		assert_eq!(<ScheduleExecutionBlock<Runtime>>::get::<ScheduleId>(0), Some(next_block_to_replan + DELAY_AFTER_LAST_RADIUS));
 	}
 
 	on_initialize_with_sell_trade{
@@ -265,6 +272,7 @@ runtime_benchmarks! {
 	}
 	verify {
 		assert_eq!((MaxSchedulesPerBlock::get()) as usize, <ScheduleIdsPerBlock<Runtime>>::get::<BlockNumber>(next_block_to_replan + DELAY_AFTER_LAST_RADIUS).len());
+		assert_eq!(<ScheduleExecutionBlock<Runtime>>::get::<ScheduleId>(0), Some(next_block_to_replan + DELAY_AFTER_LAST_RADIUS));
 	}
 
 	on_initialize_with_empty_block{
@@ -349,7 +357,9 @@ runtime_benchmarks! {
 			total_amount: 1100 * ONE,
 			max_retries: None,
 			stability_threshold: None,
+			oracle_bounds: Default::default(),
 			slippage: Some(Permill::from_percent(15)),
+			limit_price: None,
 			order: Order::Buy {
 				asset_in: asset_1,
 				asset_out: DAI,
@@ -381,6 +391,7 @@ runtime_benchmarks! {
 		assert!(<Schedules<Runtime>>::get::<ScheduleId>(schedule_id).is_some());
 
 		assert_eq!((MaxSchedulesPerBlock::get()) as usize, <ScheduleIdsPerBlock<Runtime>>::get::<BlockNumber>(execution_block + DELAY_AFTER_LAST_RADIUS).len());
+		assert_eq!(<ScheduleExecutionBlock<Runtime>>::get::<ScheduleId>(schedule_id), Some(execution_block + DELAY_AFTER_LAST_RADIUS));
 	}
 
 	terminate {
@@ -397,9 +408,96 @@ runtime_benchmarks! {
 		let execution_block = 100u32;
 		assert_ok!(DCA::schedule(RawOrigin::Signed(caller).into(), schedule1, Option::Some(execution_block)));
 
-	}: _(RawOrigin::Root, schedule_id, None)
+	}: _(RawOrigin::Root, schedule_id)
 	verify {
 		assert!(<Schedules<Runtime>>::get::<ScheduleId>(schedule_id).is_none());
+		assert!(<ScheduleExecutionBlock<Runtime>>::get::<ScheduleId>(schedule_id).is_none());
+	}
+
+	pause {
+		let caller: AccountId = create_account_with_native_balance()?;
+		fund_treasury()?; //Fund treasury with some HDX to prevent BelowMinimum issue due to low fee
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block)));
+
+	}: _(RawOrigin::Signed(caller), schedule_id)
+	verify {
+		assert!(<PausedSchedules<Runtime>>::contains_key(schedule_id));
+		assert!(<ScheduleExecutionBlock<Runtime>>::get::<ScheduleId>(schedule_id).is_none());
+	}
+
+	resume {
+		let caller: AccountId = create_account_with_native_balance()?;
+		fund_treasury()?; //Fund treasury with some HDX to prevent BelowMinimum issue due to low fee
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1.clone(), Option::Some(execution_block)));
+		assert_ok!(DCA::pause(RawOrigin::Signed(caller.clone()).into(), schedule_id));
+
+		//We fill blocks with schedules leaving only one place
+		let number_of_all_schedules = MaxSchedulesPerBlock::get() + MaxSchedulesPerBlock::get() * RETRY_TO_SEARCH_FOR_FREE_BLOCK - 1;
+		for i in 0..number_of_all_schedules {
+			assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1.clone(), Option::Some(execution_block)));
+		}
+	}: _(RawOrigin::Signed(caller), schedule_id, Option::Some(execution_block))
+	verify {
+		assert!(!<PausedSchedules<Runtime>>::contains_key(schedule_id));
+		assert_eq!(<ScheduleExecutionBlock<Runtime>>::get::<ScheduleId>(schedule_id), Some(execution_block + DELAY_AFTER_LAST_RADIUS));
+	}
+
+	top_up {
+		let caller: AccountId = create_account_with_native_balance()?;
+		fund_treasury()?; //Fund treasury with some HDX to prevent BelowMinimum issue due to low fee
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+		let total_amount = schedule1.total_amount;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block)));
+
+	}: _(RawOrigin::Signed(caller), schedule_id, 100 * ONE)
+	verify {
+		assert_eq!(<RemainingAmounts<Runtime>>::get::<ScheduleId>(schedule_id), Some(total_amount + 100 * ONE));
+		assert_eq!(<Schedules<Runtime>>::get::<ScheduleId>(schedule_id).map(|schedule| schedule.total_amount), Some(total_amount + 100 * ONE));
+	}
+
+	update_schedule {
+		let caller: AccountId = create_account_with_native_balance()?;
+		fund_treasury()?; //Fund treasury with some HDX to prevent BelowMinimum issue due to low fee
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block)));
+
+	}: _(RawOrigin::Signed(caller), schedule_id, Some(10u32), Some(Some(Permill::from_percent(5))), Some(Some(Permill::from_percent(5))), Some(Some(5)))
+	verify {
+		assert_eq!(<Schedules<Runtime>>::get::<ScheduleId>(schedule_id).map(|schedule| schedule.period), Some(10u32));
 	}
 
 }
@@ -1,13 +1,19 @@
-use crate::{assets::DustingReward, AccountId, AssetId, Balance, Duster, NativeAssetId, Runtime, Tokens};
+use crate::{
+	assets::{DustingReward, MaxDustAccountsPerBatch},
+	AccountId, AssetId, Balance, Duster, NativeAssetId, Omnipool, Runtime, Tokens,
+};
 
 use super::*;
 
 use frame_benchmarking::account;
 use frame_benchmarking::BenchmarkError;
 use frame_support::assert_ok;
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
 use frame_system::RawOrigin;
 use orml_benchmarking::runtime_benchmarks;
 use sp_runtime::traits::SaturatedConversion;
+use sp_runtime::{FixedU128, Permill};
 
 use orml_traits::{GetByKey, MultiCurrency, MultiCurrencyExtended};
 
@@ -54,6 +60,79 @@ runtime_benchmarks! {
 		assert_eq!(Tokens::free_balance(asset_id, &dest_account.unwrap()), current_balance + dust_amount);
 	}
 
+	dust_accounts{
+		let c in 1..MaxDustAccountsPerBatch::get();
+
+		let caller: AccountId = account("caller", 0, SEED);
+		let dust_dest_account: AccountId = account("dest", 1, SEED);
+		pallet_duster::DustAccount::<Runtime>::put(dust_dest_account.clone());
+
+		let asset_id = register_asset(b"TST".to_vec(), 100u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let reward = DustingReward::get();
+
+		update_balance(asset_id, &dust_dest_account, 1_000u128);
+
+		let mut accounts = vec![];
+		for i in 0..c {
+			let to_dust_account: AccountId = account("dust", i, SEED);
+			update_balance(asset_id, &to_dust_account, 100u128);
+			accounts.push((to_dust_account, asset_id));
+		}
+
+		update_asset(asset_id, None, 110u128).map_err(|_| BenchmarkError::Stop("Failed to update asset"))?;
+
+	}: { pallet_duster::Pallet::<Runtime>::dust_accounts(RawOrigin::Signed(caller.clone()).into(), accounts.clone())? }
+	verify {
+		for (to_dust_account, asset_id) in accounts {
+			assert_eq!(Tokens::free_balance(asset_id, &to_dust_account), 0u128);
+		}
+		assert_eq!(Tokens::free_balance(NativeAssetId::get(), &caller), reward * c as Balance);
+		assert_eq!(Tokens::free_balance(asset_id, &dust_dest_account), 1_000u128 + 100u128 * c as Balance);
+	}
+
+	sweep_account{
+		let dust_dest_account: AccountId = account("dest", 1, SEED);
+		pallet_duster::DustAccount::<Runtime>::put(dust_dest_account.clone());
+		Duster::add_nondustable_account(RawOrigin::Root.into(), dust_dest_account.clone())?;
+
+		let asset_id = register_asset(b"TST".to_vec(), 100u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		update_balance(asset_id, &dust_dest_account, 1_000u128);
+
+		let to_dust_account: AccountId = account("dust", 0, SEED);
+		update_balance(asset_id, &to_dust_account, 100u128);
+
+		update_asset(asset_id, None, 110u128).map_err(|_| BenchmarkError::Stop("Failed to update asset"))?;
+
+	}: { Duster::on_idle(1u32, Weight::MAX) }
+	verify {
+		assert_eq!(Tokens::free_balance(asset_id, &to_dust_account), 0u128);
+		assert_eq!(Tokens::free_balance(asset_id, &dust_dest_account), 1_100u128);
+	}
+
+	// Dust is sold in a single Omnipool trade. Weight of the trade is subtracted in the pallet to get the overhead
+	// of the conversion.
+	convert_dust{
+		crate::benchmarking::omnipool::init()?;
+
+		let dust_dest_account: AccountId = account("dest", 1, SEED);
+		pallet_duster::DustAccount::<Runtime>::put(dust_dest_account.clone());
+
+		let asset_id = register_asset(b"TST".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		let owner: AccountId = account("owner", 0, SEED);
+		crate::benchmarking::omnipool::update_balance(asset_id, &Omnipool::protocol_account(), 200_000_000_000_000u128);
+		Omnipool::add_token(RawOrigin::Root.into(), asset_id, FixedU128::from((1, 5)), Permill::from_percent(100), owner)?;
+
+		let amount = 1_000_000_000_000u128;
+		update_balance(asset_id, &dust_dest_account, amount);
+
+	}: { pallet_duster::Pallet::<Runtime>::convert_dust(RawOrigin::Root.into(), asset_id, amount, 0u128)? }
+	verify {
+		assert_eq!(Tokens::free_balance(asset_id, &dust_dest_account), 0u128);
+		assert!(<Runtime as pallet_duster::Config>::MultiCurrency::free_balance(NativeAssetId::get(), &dust_dest_account) > 0u128);
+	}
+
 	add_nondustable_account{
 		let caller: AccountId = account("caller", 0, SEED);
 		let nondustable_account: AccountId = account("dust", 0, SEED);
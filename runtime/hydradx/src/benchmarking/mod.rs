@@ -9,8 +9,10 @@ pub mod omnipool;
 pub mod route_executor;
 pub mod tokens;
 pub mod vesting;
+pub mod xcm;
 pub mod xyk;
 pub mod xyk_liquidity_mining;
+pub mod zap;
 
 use crate::{AssetLocation, AssetRegistry, MultiTransactionPayment};
 use frame_system::RawOrigin;
@@ -1,4 +1,4 @@
-use crate::{AccountId, AssetId, Balance, EmaOracle, Omnipool, Referrals, Runtime, RuntimeOrigin, System};
+use crate::{AccountId, AssetId, Balance, EmaOracle, Omnipool, Referrals, Runtime, RuntimeOrigin, System, XYK};
 
 use super::*;
 
@@ -11,7 +11,7 @@ use frame_support::{
 		traits::{One, SaturatedConversion, Zero},
 		FixedU128, Permill,
 	},
-	traits::{OnFinalize, OnInitialize},
+	traits::{Get, OnFinalize, OnInitialize},
 };
 use frame_system::RawOrigin;
 use hydradx_traits::router::{PoolType, TradeExecution};
@@ -30,7 +30,7 @@ pub fn update_balance(currency_id: AssetId, who: &AccountId, balance: Balance) {
 	);
 }
 
-fn run_to_block(to: u32) {
+pub fn run_to_block(to: u32) {
 	while System::block_number() < to {
 		let b = System::block_number();
 
@@ -76,6 +76,37 @@ pub fn init() -> DispatchResult {
 	Ok(())
 }
 
+// Creates XYK pool of the asset and HDX to provide listing price oracle.
+fn create_xyk_pool(asset: AssetId, amount: Balance) -> DispatchResult {
+	let provider: AccountId = account("xyk_provider", 0, 1);
+
+	update_balance(HDX, &provider, amount);
+	update_balance(asset, &provider, amount);
+
+	XYK::create_pool(RawOrigin::Signed(provider).into(), HDX, amount, asset, amount)?;
+
+	run_to_block(System::block_number() + 10);
+
+	Ok(())
+}
+
+fn create_listing(proposer: &AccountId, amount: Balance) -> Result<AssetId, BenchmarkError> {
+	init()?;
+
+	let token_id = register_asset(b"FCK".to_vec(), Balance::one())
+		.map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+	create_xyk_pool(token_id, amount)?;
+
+	let bond = <Runtime as pallet_omnipool::Config>::ListingBond::get();
+	update_balance(token_id, proposer, amount);
+	update_balance(HDX, proposer, bond.saturating_mul(2));
+
+	Omnipool::propose_listing(RawOrigin::Signed(proposer.clone()).into(), token_id, amount)?;
+
+	Ok(token_id)
+}
+
 runtime_benchmarks! {
 	{Runtime, pallet_omnipool}
 
@@ -409,6 +440,118 @@ runtime_benchmarks! {
 		assert_eq!(<Runtime as pallet_omnipool::Config>::Currency::free_balance(token_id, &beneficiary), token_amount);
 	}
 
+	propose_listing {
+		init()?;
+
+		let token_id = register_asset(b"FCK".to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let token_amount = 200_000_000_000_000u128;
+
+		create_xyk_pool(token_id, token_amount)?;
+
+		let proposer: AccountId = account("proposer", 0, 1);
+		let bond = <Runtime as pallet_omnipool::Config>::ListingBond::get();
+		update_balance(token_id, &proposer, token_amount);
+		update_balance(HDX, &proposer, bond.saturating_mul(2));
+	}: { Omnipool::propose_listing(RawOrigin::Signed(proposer).into(), token_id, token_amount)? }
+	verify {
+		assert!(Omnipool::listings(token_id).is_some());
+	}
+
+	veto_listing {
+		let proposer: AccountId = account("proposer", 0, 1);
+		let token_id = create_listing(&proposer, 200_000_000_000_000u128)?;
+	}: { Omnipool::veto_listing(RawOrigin::Root.into(), token_id, false)? }
+	verify {
+		assert!(Omnipool::listings(token_id).is_none());
+	}
+
+	finalize_listing {
+		let proposer: AccountId = account("proposer", 0, 1);
+		let token_id = create_listing(&proposer, 200_000_000_000_000u128)?;
+
+		let challenge_period_end = Omnipool::listings(token_id).map(|l| l.challenge_period_end).ok_or(BenchmarkError::Stop("Listing not found"))?;
+		System::set_block_number(challenge_period_end + 1);
+
+		let current_position_id = Omnipool::next_position_id();
+		let caller: AccountId = account("caller", 0, 1);
+	}: { Omnipool::finalize_listing(RawOrigin::Signed(caller).into(), token_id)? }
+	verify {
+		assert!(Omnipool::positions(current_position_id).is_some());
+		assert!(Omnipool::assets(token_id).is_some());
+	}
+
+	merge_positions {
+		init()?;
+		let acc = Omnipool::protocol_account();
+		let token_id = register_asset(b"FCK".to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner)?;
+
+		// Create LP provider account with correct balance and add liquidity twice
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let position_id = Omnipool::next_position_id();
+
+		run_to_block(10);
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+		let other_position_id = Omnipool::next_position_id();
+
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+	}: { Omnipool::merge_positions(RawOrigin::Signed(lp_provider).into(), position_id, other_position_id)? }
+	verify {
+		assert!(Omnipool::positions(other_position_id).is_none());
+		assert_eq!(Omnipool::positions(position_id).unwrap().amount, 2 * liquidity_added);
+	}
+
+	split_position {
+		init()?;
+		let acc = Omnipool::protocol_account();
+		let token_id = register_asset(b"FCK".to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner)?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let position_id = Omnipool::next_position_id();
+
+		run_to_block(10);
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+		let shares = Omnipool::positions(position_id).unwrap().shares / 2;
+		let new_position_id = Omnipool::next_position_id();
+
+	}: { Omnipool::split_position(RawOrigin::Signed(lp_provider).into(), position_id, shares)? }
+	verify {
+		assert_eq!(Omnipool::positions(new_position_id).unwrap().shares, shares);
+	}
+
 	router_execution_sell {
 		let c in 1..2;
 		let e in 0..1;	// if e == 1, execute_sell is executed
@@ -0,0 +1,156 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration of `pallet_xcm_benchmarks` used to benchmark the weights of individual XCM instructions.
+
+use super::omnipool::{init, update_balance};
+use super::*;
+use crate::xcm::{LocalAssetTransactor, LocationToAccountId, MaxAssetsIntoHolding, XcmConfig};
+use crate::{AccountId, Omnipool, Runtime, RuntimeCall};
+use frame_benchmarking::BenchmarkError;
+use frame_support::parameter_types;
+use frame_support::sp_runtime::{FixedU128, Permill};
+use frame_system::RawOrigin;
+use polkadot_xcm::v3::prelude::*;
+use primitives::constants::chain::CORE_ASSET_ID;
+use xcm_builder::MintLocation;
+
+const UNITS: Balance = 1_000_000_000_000;
+
+parameter_types! {
+	pub const RelayLocation: MultiLocation = MultiLocation::parent();
+	pub RelayAsset: MultiAsset = MultiAsset {
+		id: Concrete(RelayLocation::get()),
+		fun: Fungible(1_000 * UNITS),
+	};
+	pub const NoCheckingAccount: Option<(AccountId, MintLocation)> = None;
+	pub const NoTeleporter: Option<(MultiLocation, MultiAsset)> = None;
+	pub TrustedReserve: Option<(MultiLocation, MultiAsset)> = Some((RelayLocation::get(), RelayAsset::get()));
+}
+
+fn native_asset(amount: Balance) -> MultiAsset {
+	MultiAsset {
+		id: Concrete(MultiLocation::new(0, X1(GeneralIndex(CORE_ASSET_ID.into())))),
+		fun: Fungible(amount),
+	}
+}
+
+impl pallet_xcm_benchmarks::Config for Runtime {
+	type XcmConfig = XcmConfig;
+	type AccountIdConverter = LocationToAccountId;
+
+	fn valid_destination() -> Result<MultiLocation, BenchmarkError> {
+		Ok(RelayLocation::get())
+	}
+
+	fn worst_case_holding(_depositable_count: u32) -> MultiAssets {
+		// All assets in the holding are registered, so every one of them can be deposited.
+		let mut assets = vec![native_asset(1_000 * UNITS)];
+		for i in 1..MaxAssetsIntoHolding::get() {
+			let mut name = b"XCM".to_vec();
+			name.extend_from_slice(&i.to_le_bytes());
+			let asset_id = register_asset(name, 1u128).expect("Failed to register asset");
+			let location = MultiLocation::new(1, X2(Parachain(2_000 + i), GeneralIndex(i.into())));
+			set_location(asset_id, AssetLocation(location)).expect("Failed to set asset location");
+			assets.push(MultiAsset {
+				id: Concrete(location),
+				fun: Fungible(1_000 * UNITS),
+			});
+		}
+		assets.into()
+	}
+}
+
+impl pallet_xcm_benchmarks::fungible::Config for Runtime {
+	type TransactAsset = LocalAssetTransactor;
+	type CheckedAccount = NoCheckingAccount;
+	type TrustedTeleporter = NoTeleporter;
+	type TrustedReserve = TrustedReserve;
+
+	fn get_multi_asset() -> MultiAsset {
+		native_asset(100 * UNITS)
+	}
+}
+
+impl pallet_xcm_benchmarks::generic::Config for Runtime {
+	type RuntimeCall = RuntimeCall;
+
+	fn worst_case_response() -> (u64, Response) {
+		(0u64, Response::Version(Default::default()))
+	}
+
+	// Sell of a registered asset for the native asset in the Omnipool, which is the default route.
+	fn worst_case_asset_exchange() -> Result<(MultiAssets, MultiAssets), BenchmarkError> {
+		init()?;
+		let asset_id =
+			register_asset(b"XCMX".to_vec(), 1u128).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+		let location = MultiLocation::new(1, X2(Parachain(3_000), GeneralIndex(asset_id.into())));
+		set_location(asset_id, AssetLocation(location))
+			.map_err(|_| BenchmarkError::Stop("Failed to set asset location"))?;
+
+		let acc = Omnipool::protocol_account();
+		update_balance(asset_id, &acc, 1_000_000 * UNITS);
+		Omnipool::add_token(
+			RawOrigin::Root.into(),
+			asset_id,
+			FixedU128::from(1),
+			Permill::from_percent(100),
+			acc,
+		)?;
+
+		let give = MultiAsset {
+			id: Concrete(location),
+			fun: Fungible(100 * UNITS),
+		};
+		Ok((give.into(), native_asset(1).into()))
+	}
+
+	fn universal_alias() -> Result<(MultiLocation, Junction), BenchmarkError> {
+		Err(BenchmarkError::Skip)
+	}
+
+	fn transact_origin_and_runtime_call() -> Result<(MultiLocation, RuntimeCall), BenchmarkError> {
+		Ok((
+			RelayLocation::get(),
+			frame_system::Call::remark_with_event { remark: vec![] }.into(),
+		))
+	}
+
+	fn subscribe_origin() -> Result<MultiLocation, BenchmarkError> {
+		Ok(RelayLocation::get())
+	}
+
+	fn claimable_asset() -> Result<(MultiLocation, MultiLocation, MultiAssets), BenchmarkError> {
+		let origin = RelayLocation::get();
+		let assets: MultiAssets = RelayAsset::get().into();
+		let ticket = MultiLocation::here();
+		Ok((origin, ticket, assets))
+	}
+
+	fn unlockable_asset() -> Result<(MultiLocation, MultiLocation, MultiAsset), BenchmarkError> {
+		Err(BenchmarkError::Skip)
+	}
+
+	fn export_message_origin_and_destination(
+	) -> Result<(MultiLocation, NetworkId, InteriorMultiLocation), BenchmarkError> {
+		Err(BenchmarkError::Skip)
+	}
+
+	fn alias_origin() -> Result<(MultiLocation, MultiLocation), BenchmarkError> {
+		Err(BenchmarkError::Skip)
+	}
+}
@@ -188,6 +188,7 @@ impl pallet_evm_accounts::Config for crate::Runtime {
 	type FeeMultiplier = sp_core::ConstU32<50>;
 	type EvmNonceProvider = EvmNonceProvider;
 	type ControllerOrigin = crate::SuperMajorityTechCommittee;
+	type ChainId = crate::EVMChainId;
 	type WeightInfo = crate::weights::evm_accounts::HydraWeight<crate::Runtime>;
 }
 
@@ -207,7 +208,7 @@ impl pallet_dynamic_evm_fee::Config for crate::Runtime {
 		NativeAssetId,
 		crate::MultiTransactionPayment,
 		crate::Router,
-		OraclePriceProvider<AssetId, crate::EmaOracle, LRNA>,
+		OraclePriceProvider<AssetId, crate::EmaOracle, LRNA, crate::Stableswap>,
 		crate::MultiTransactionPayment,
 		OracleEvmPeriod,
 	>;
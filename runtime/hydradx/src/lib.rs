@@ -47,6 +47,7 @@ pub use xcm::*;
 use crate::sp_api_hidden_includes_construct_runtime::hidden_include::traits::Hooks;
 use codec::{Decode, Encode};
 use hydradx_traits::evm::InspectEvmAccounts;
+use hydradx_traits::AggregatedTwapOracle;
 use sp_api::impl_runtime_apis;
 use sp_core::{ConstU128, Get, OpaqueMetadata, H160, H256, U256};
 use sp_runtime::{
@@ -109,10 +110,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("hydradx"),
 	impl_name: create_runtime_str!("hydradx"),
 	authoring_version: 1,
-	spec_version: 231,
+	spec_version: 284,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 3,
 	state_version: 1,
 };
 
@@ -155,11 +156,13 @@ construct_runtime!(
 		Proxy: pallet_proxy = 29,
 		Multisig: pallet_multisig = 31,
 		Uniques: pallet_uniques = 32,
+		NFT: pallet_nft = 33,
 		StateTrieMigration: pallet_state_trie_migration = 35,
 
 		// HydraDX related modules
 		AssetRegistry: pallet_asset_registry = 51,
 		Claims: pallet_claims = 53,
+		Airdrop: pallet_airdrop = 54,
 		GenesisHistory: pallet_genesis_history = 55,
 		CollatorRewards: pallet_collator_rewards = 57,
 		Omnipool: pallet_omnipool = 59,
@@ -169,12 +172,12 @@ construct_runtime!(
 		OmnipoolLiquidityMining: pallet_omnipool_liquidity_mining = 63,
 		OTC: pallet_otc = 64,
 		CircuitBreaker: pallet_circuit_breaker = 65,
-
 		Router: pallet_route_executor = 67,
 		DynamicFees: pallet_dynamic_fees = 68,
 		Staking: pallet_staking = 69,
 		Stableswap: pallet_stableswap = 70,
 		Bonds: pallet_bonds = 71,
+		ConcentratedLiquidity: pallet_concentrated_liquidity = 72,
 		LBP: pallet_lbp = 73,
 		XYK: pallet_xyk = 74,
 		Referrals: pallet_referrals = 75,
@@ -194,6 +197,7 @@ construct_runtime!(
 
 		XYKLiquidityMining: pallet_xyk_liquidity_mining = 95,
 		XYKWarehouseLM: warehouse_liquidity_mining::<Instance2> = 96,
+		Zap: pallet_zap = 97,
 
 		// Parachain
 		ParachainSystem: cumulus_pallet_parachain_system exclude_parts { Config } = 103,
@@ -251,6 +255,7 @@ pub type SignedExtra = (
 	frame_system::CheckWeight<Runtime>,
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 	pallet_claims::ValidateClaim<Runtime>,
+	pallet_airdrop::ValidateClaim<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = fp_self_contained::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
@@ -493,7 +498,8 @@ impl_runtime_apis! {
 						};
 
 			// don't allow calling EVM RPC or Runtime API from a bound address
-			if EVMAccounts::bound_account_id(from).is_some() {
+			// linked addresses are controlled by their EVM key, so they are allowed
+			if EVMAccounts::bound_account_id(from).is_some() && EVMAccounts::linked_account(from).is_none() {
 				return Err(pallet_evm_accounts::Error::<Runtime>::BoundAddressCannotBeUsed.into())
 			};
 
@@ -574,7 +580,8 @@ impl_runtime_apis! {
 				};
 
 			// don't allow calling EVM RPC or Runtime API from a bound address
-			if EVMAccounts::bound_account_id(from).is_some() {
+			// linked addresses are controlled by their EVM key, so they are allowed
+			if EVMAccounts::bound_account_id(from).is_some() && EVMAccounts::linked_account(from).is_none() {
 				return Err(pallet_evm_accounts::Error::<Runtime>::BoundAddressCannotBeUsed.into())
 				};
 
@@ -681,6 +688,23 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_ema_oracle_rpc_runtime_api::TwapApi<Block, AssetId, BlockNumber, pallet_ema_oracle::Price, pallet_ema_oracle::OracleError> for Runtime {
+		fn twap(
+			source: [u8; 8],
+			asset_a: AssetId,
+			asset_b: AssetId,
+			window: BlockNumber,
+		) -> Result<pallet_ema_oracle::Price, pallet_ema_oracle::OracleError> {
+			EmaOracle::get_twap(asset_a, asset_b, window, source)
+		}
+	}
+
+	impl pallet_dca_rpc_runtime_api::DcaApi<Block, AccountId, pallet_dca::types::ScheduleInfo<AccountId, AssetId, BlockNumber>> for Runtime {
+		fn schedules(owner: AccountId) -> Vec<pallet_dca::types::ScheduleInfo<AccountId, AssetId, BlockNumber>> {
+			DCA::schedules_of(owner)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -691,6 +715,8 @@ impl_runtime_apis! {
 			use frame_support::traits::StorageInfoTrait;
 			use orml_benchmarking::list_benchmark as orml_list_benchmark;
 			use frame_system_benchmarking::Pallet as SystemBench;
+			type XcmBalances = pallet_xcm_benchmarks::fungible::Pallet<Runtime>;
+			type XcmGeneric = pallet_xcm_benchmarks::generic::Pallet<Runtime>;
 
 			let mut list = Vec::<BenchmarkList>::new();
 
@@ -713,10 +739,12 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_circuit_breaker, CircuitBreaker);
 			list_benchmark!(list, extra, pallet_bonds, Bonds);
 			list_benchmark!(list, extra, pallet_stableswap, Stableswap);
+			list_benchmark!(list, extra, pallet_concentrated_liquidity, ConcentratedLiquidity);
 			list_benchmark!(list, extra, pallet_state_trie_migration, StateTrieMigration);
 
 			list_benchmark!(list, extra, pallet_asset_registry, AssetRegistry);
 			list_benchmark!(list, extra, pallet_claims, Claims);
+			list_benchmark!(list, extra, pallet_airdrop, Airdrop);
 			list_benchmark!(list, extra, pallet_ema_oracle, EmaOracle);
 			list_benchmark!(list, extra, pallet_staking, Staking);
 			list_benchmark!(list, extra, pallet_lbp, LBP);
@@ -728,6 +756,8 @@ impl_runtime_apis! {
 
 			list_benchmark!(list, extra, pallet_otc, OTC);
 			list_benchmark!(list, extra, pallet_xcm, PolkadotXcm);
+			list_benchmark!(list, extra, pallet_xcm_benchmarks::fungible, XcmBalances);
+			list_benchmark!(list, extra, pallet_xcm_benchmarks::generic, XcmGeneric);
 
 			orml_list_benchmark!(list, extra, pallet_currencies, benchmarking::currencies);
 			orml_list_benchmark!(list, extra, orml_tokens, benchmarking::tokens);
@@ -740,6 +770,7 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, pallet_xyk, benchmarking::xyk);
 			orml_list_benchmark!(list, extra, pallet_dynamic_evm_fee, benchmarking::dynamic_evm_fee);
 			orml_list_benchmark!(list, extra, pallet_xyk_liquidity_mining, benchmarking::xyk_liquidity_mining);
+			orml_list_benchmark!(list, extra, pallet_zap, benchmarking::zap);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -753,6 +784,8 @@ impl_runtime_apis! {
 			use frame_support::traits::TrackedStorageKey;
 			use orml_benchmarking::add_benchmark as orml_add_benchmark;
 			use frame_system_benchmarking::Pallet as SystemBench;
+			type XcmBalances = pallet_xcm_benchmarks::fungible::Pallet<Runtime>;
+			type XcmGeneric = pallet_xcm_benchmarks::generic::Pallet<Runtime>;
 			impl frame_system_benchmarking::Config for Runtime {
 				fn setup_set_code_requirements(code: &sp_std::vec::Vec<u8>) -> Result<(), BenchmarkError> {
 					ParachainSystem::initialize_for_set_code_benchmark(code.len() as u32);
@@ -800,11 +833,13 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_circuit_breaker, CircuitBreaker);
 			add_benchmark!(params, batches, pallet_asset_registry, AssetRegistry);
 			add_benchmark!(params, batches, pallet_claims, Claims);
+			add_benchmark!(params, batches, pallet_airdrop, Airdrop);
 			add_benchmark!(params, batches, pallet_ema_oracle, EmaOracle);
 			add_benchmark!(params, batches, pallet_bonds, Bonds);
 			add_benchmark!(params, batches, pallet_staking, Staking);
 			add_benchmark!(params, batches, pallet_lbp, LBP);
 			add_benchmark!(params, batches, pallet_stableswap, Stableswap);
+			add_benchmark!(params, batches, pallet_concentrated_liquidity, ConcentratedLiquidity);
 			add_benchmark!(params, batches, pallet_referrals, Referrals);
 			add_benchmark!(params, batches, pallet_evm_accounts, EVMAccounts);
 			add_benchmark!(params, batches, pallet_state_trie_migration, StateTrieMigration);
@@ -814,6 +849,8 @@ impl_runtime_apis! {
 
 			add_benchmark!(params, batches, pallet_otc, OTC);
 			add_benchmark!(params, batches, pallet_xcm, PolkadotXcm);
+			add_benchmark!(params, batches, pallet_xcm_benchmarks::fungible, XcmBalances);
+			add_benchmark!(params, batches, pallet_xcm_benchmarks::generic, XcmGeneric);
 
 			orml_add_benchmark!(params, batches, pallet_currencies, benchmarking::currencies);
 			orml_add_benchmark!(params, batches, orml_tokens, benchmarking::tokens);
@@ -826,6 +863,7 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, pallet_xyk, benchmarking::xyk);
 			orml_add_benchmark!(params, batches, pallet_dynamic_evm_fee, benchmarking::dynamic_evm_fee);
 			orml_add_benchmark!(params, batches, pallet_xyk_liquidity_mining, benchmarking::xyk_liquidity_mining);
+			orml_add_benchmark!(params, batches, pallet_zap, benchmarking::zap);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
@@ -37,6 +37,31 @@ impl OnRuntimeUpgrade for OnRuntimeUpgradeMigration {
 
 		weight = weight.saturating_add(pallet_referrals::migration::preregister_parachain_codes::<Runtime>());
 
+		log::info!("Migrate Transaction Pause Pallet to v2 start");
+		weight =
+			weight.saturating_add(pallet_transaction_pause::migration::v2::Migration::<Runtime>::on_runtime_upgrade());
+		log::info!("Migrate Transaction Pause Pallet to v2 end");
+
+		log::info!("Migrate DCA Pallet to v1 start");
+		weight = weight.saturating_add(pallet_dca::migration::v1::Migration::<Runtime>::on_runtime_upgrade());
+		log::info!("Migrate DCA Pallet to v1 end");
+
+		log::info!("Migrate DCA Pallet to v2 start");
+		weight = weight.saturating_add(pallet_dca::migration::v2::Migration::<Runtime>::on_runtime_upgrade());
+		log::info!("Migrate DCA Pallet to v2 end");
+
+		log::info!("Migrate DCA Pallet to v3 start");
+		weight = weight.saturating_add(pallet_dca::migration::v3::Migration::<Runtime>::on_runtime_upgrade());
+		log::info!("Migrate DCA Pallet to v3 end");
+
+		log::info!("Migrate Stableswap Pallet to v1 start");
+		weight = weight.saturating_add(pallet_stableswap::migration::v1::Migration::<Runtime>::on_runtime_upgrade());
+		log::info!("Migrate Stableswap Pallet to v1 end");
+
+		log::info!("Migrate Stableswap Pallet to v2 start");
+		weight = weight.saturating_add(pallet_stableswap::migration::v2::Migration::<Runtime>::on_runtime_upgrade());
+		log::info!("Migrate Stableswap Pallet to v2 end");
+
 		weight
 	}
 
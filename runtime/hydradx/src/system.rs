@@ -42,6 +42,8 @@ use frame_support::{
 };
 use frame_system::EnsureSignedBy;
 use hydradx_adapters::{OraclePriceProvider, RelayChainBlockNumberProvider};
+use hydradx_traits::router::{PoolType, Trade};
+use pallet_transaction_pause::CallAssetsInspector;
 use scale_info::TypeInfo;
 
 pub struct CallFilter;
@@ -99,6 +101,8 @@ impl Contains<RuntimeCall> for CallFilter {
 			// create and create2 are only allowed through RPC or Runtime API
 			RuntimeCall::EVM(pallet_evm::Call::create { .. }) => false,
 			RuntimeCall::EVM(pallet_evm::Call::create2 { .. }) => false,
+			// Standard collection creation is disallowed, collections are created by pallets only
+			RuntimeCall::NFT(pallet_nft::Call::create_collection { .. }) => false,
 			RuntimeCall::PolkadotXcm(_) => false,
 			RuntimeCall::OrmlXcm(_) => false,
 			_ => true,
@@ -351,7 +355,9 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			ProxyType::Liquidity => matches!(
 				c,
 				RuntimeCall::Omnipool(pallet_omnipool::Call::add_liquidity { .. })
+					| RuntimeCall::Omnipool(pallet_omnipool::Call::add_liquidity_with_limit { .. })
 					| RuntimeCall::Omnipool(pallet_omnipool::Call::remove_liquidity { .. })
+					| RuntimeCall::Omnipool(pallet_omnipool::Call::remove_liquidity_with_limit { .. })
 			),
 			ProxyType::LiquidityMining => matches!(
 				c,
@@ -481,7 +487,7 @@ impl pallet_transaction_multi_payment::Config for Runtime {
 	type AcceptedCurrencyOrigin = SuperMajorityTechCommittee;
 	type Currencies = Currencies;
 	type RouteProvider = Router;
-	type OraclePriceProvider = OraclePriceProvider<AssetId, EmaOracle, LRNA>;
+	type OraclePriceProvider = OraclePriceProvider<AssetId, EmaOracle, LRNA, Stableswap>;
 	type WeightInfo = weights::payment::HydraWeight<Runtime>;
 	type NativeAssetId = NativeAssetId;
 	type EvmAssetId = evm::WethAssetId;
@@ -530,9 +536,135 @@ impl pallet_collator_rewards::Config for Runtime {
 impl pallet_transaction_pause::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UpdateOrigin = SuperMajorityTechCommittee;
+	type AssetId = AssetId;
+	type CallAssetsInspector = TradeAndTransferCallAssets;
 	type WeightInfo = weights::transaction_pause::HydraWeight<Runtime>;
 }
 
+/// Provides ids of assets touched by trade, liquidity and transfer calls, so the calls can be paused for specific
+/// assets.
+///
+/// Assets are decoded from the call arguments only. The inspector is used by the call filter, so it must not read
+/// storage which is not accounted for in the weight of the call.
+///
+/// Trades executed by the router include assets of every hop of the given route.
+pub struct TradeAndTransferCallAssets;
+
+impl TradeAndTransferCallAssets {
+	fn route_assets(asset_in: AssetId, asset_out: AssetId, route: &[Trade<AssetId>]) -> Vec<AssetId> {
+		let mut assets = vec![asset_in, asset_out];
+		for trade in route {
+			assets.push(trade.asset_in);
+			assets.push(trade.asset_out);
+			match trade.pool {
+				PoolType::Stableswap(pool_id) => assets.push(pool_id),
+				PoolType::StableswapMetapool(pool_id, base_pool_id) => {
+					assets.push(pool_id);
+					assets.push(base_pool_id);
+				}
+				_ => {}
+			}
+		}
+		assets.sort();
+		assets.dedup();
+		assets
+	}
+}
+
+impl CallAssetsInspector<RuntimeCall, AssetId> for TradeAndTransferCallAssets {
+	fn assets(call: &RuntimeCall) -> Vec<AssetId> {
+		match call {
+			RuntimeCall::Omnipool(pallet_omnipool::Call::sell {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::Omnipool(pallet_omnipool::Call::buy {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::XYK(pallet_xyk::Call::sell {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::XYK(pallet_xyk::Call::buy {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::LBP(pallet_lbp::Call::sell {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::LBP(pallet_lbp::Call::buy {
+				asset_in, asset_out, ..
+			})
+			| RuntimeCall::OTC(pallet_otc::Call::place_order {
+				asset_in, asset_out, ..
+			}) => {
+				vec![*asset_in, *asset_out]
+			}
+			RuntimeCall::Stableswap(pallet_stableswap::Call::sell {
+				pool_id,
+				asset_in,
+				asset_out,
+				..
+			})
+			| RuntimeCall::Stableswap(pallet_stableswap::Call::buy {
+				pool_id,
+				asset_in,
+				asset_out,
+				..
+			}) => vec![*pool_id, *asset_in, *asset_out],
+			RuntimeCall::Router(pallet_route_executor::Call::sell {
+				asset_in,
+				asset_out,
+				route,
+				..
+			})
+			| RuntimeCall::Router(pallet_route_executor::Call::buy {
+				asset_in,
+				asset_out,
+				route,
+				..
+			}) => Self::route_assets(*asset_in, *asset_out, route),
+			RuntimeCall::DCA(pallet_dca::Call::schedule { schedule, .. }) => {
+				let (asset_in, asset_out) = (schedule.order.get_asset_in(), schedule.order.get_asset_out());
+				let route = match &schedule.order {
+					pallet_dca::types::Order::Sell { route, .. } | pallet_dca::types::Order::Buy { route, .. } => route,
+				};
+				Self::route_assets(asset_in, asset_out, route)
+			}
+			RuntimeCall::Omnipool(pallet_omnipool::Call::add_liquidity { asset, .. })
+			| RuntimeCall::Omnipool(pallet_omnipool::Call::add_liquidity_with_limit { asset, .. }) => vec![*asset],
+			RuntimeCall::Stableswap(pallet_stableswap::Call::add_liquidity { pool_id, assets }) => {
+				let mut ids = vec![*pool_id];
+				ids.extend(assets.iter().map(|asset| asset.asset_id));
+				ids
+			}
+			RuntimeCall::Stableswap(pallet_stableswap::Call::add_liquidity_shares { pool_id, asset_id, .. })
+			| RuntimeCall::Stableswap(pallet_stableswap::Call::remove_liquidity_one_asset {
+				pool_id, asset_id, ..
+			})
+			| RuntimeCall::Stableswap(pallet_stableswap::Call::withdraw_asset_amount { pool_id, asset_id, .. }) => {
+				vec![*pool_id, *asset_id]
+			}
+			RuntimeCall::Stableswap(pallet_stableswap::Call::remove_liquidity { pool_id, .. }) => vec![*pool_id],
+			RuntimeCall::XYK(pallet_xyk::Call::add_liquidity { asset_a, asset_b, .. })
+			| RuntimeCall::XYK(pallet_xyk::Call::remove_liquidity { asset_a, asset_b, .. }) => vec![*asset_a, *asset_b],
+			RuntimeCall::LBP(pallet_lbp::Call::add_liquidity { amount_a, amount_b }) => vec![amount_a.0, amount_b.0],
+			RuntimeCall::Zap(pallet_zap::Call::zap {
+				asset_in,
+				target,
+				route,
+				..
+			}) => {
+				let mut assets = Self::route_assets(*asset_in, target.liquidity_asset(), route);
+				assets.extend(target.stableswap_pool());
+				assets
+			}
+			RuntimeCall::Tokens(orml_tokens::Call::transfer { currency_id, .. })
+			| RuntimeCall::Tokens(orml_tokens::Call::transfer_keep_alive { currency_id, .. })
+			| RuntimeCall::Tokens(orml_tokens::Call::transfer_all { currency_id, .. })
+			| RuntimeCall::Currencies(pallet_currencies::Call::transfer { currency_id, .. }) => vec![*currency_id],
+			_ => vec![],
+		}
+	}
+}
+
 pub struct TechCommAccounts;
 impl SortedMembers<AccountId> for TechCommAccounts {
 	fn sorted_members() -> Vec<AccountId> {
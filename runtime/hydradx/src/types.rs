@@ -13,6 +13,6 @@ parameter_types! {
 
 // Helper aliases for the OraclePriceProvider using the Router and EmaOracle
 pub type LastBlockOraclePrice =
-	OraclePriceProviderUsingRoute<Router, OraclePriceProvider<AssetId, EmaOracle, LRNA>, LastBlockPeriod>;
+	OraclePriceProviderUsingRoute<Router, OraclePriceProvider<AssetId, EmaOracle, LRNA, Stableswap>, LastBlockPeriod>;
 pub type ShortOraclePrice =
-	OraclePriceProviderUsingRoute<Router, OraclePriceProvider<AssetId, EmaOracle, LRNA>, ShortPeriod>;
+	OraclePriceProviderUsingRoute<Router, OraclePriceProvider<AssetId, EmaOracle, LRNA, Stableswap>, ShortPeriod>;
@@ -150,13 +150,44 @@ impl<T: frame_system::Config> pallet_circuit_breaker::WeightInfo for HydraWeight
 	/// Proof: `CircuitBreaker::AllowedTradeVolumeLimitPerAsset` (`max_values`: None, `max_size`: Some(68), added: 2543, mode: `MaxEncodedLen`)
 	/// Storage: `CircuitBreaker::TradeVolumeLimitPerAsset` (r:2 w:0)
 	/// Proof: `CircuitBreaker::TradeVolumeLimitPerAsset` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::HaltedAssets` (r:2 w:0)
+	/// Proof: `CircuitBreaker::HaltedAssets` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::TradeVolumeWindowLimitPerAsset` (r:2 w:0)
+	/// Proof: `CircuitBreaker::TradeVolumeWindowLimitPerAsset` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::TradeVolumeWindowPerAsset` (r:2 w:2)
+	/// Proof: `CircuitBreaker::TradeVolumeWindowPerAsset` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
 	fn ensure_pool_state_change_limit() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `208`
-		//  Estimated: `6076`
+		//  Estimated: `6148`
 		// Minimum execution time: 18_569_000 picoseconds.
-		Weight::from_parts(18_816_000, 6076)
-			.saturating_add(T::DbWeight::get().reads(4))
+		Weight::from_parts(18_816_000, 6148)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `CircuitBreaker::TradeVolumeWindowLimitPerAsset` (r:0 w:1)
+	/// Proof: `CircuitBreaker::TradeVolumeWindowLimitPerAsset` (`max_values`: None, `max_size`: Some(29), added: 2504, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::TradeVolumeWindowPerAsset` (r:0 w:1)
+	/// Proof: `CircuitBreaker::TradeVolumeWindowPerAsset` (`max_values`: None, `max_size`: Some(104), added: 2579, mode: `MaxEncodedLen`)
+	fn set_trade_volume_window_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 13_412_000 picoseconds.
+		Weight::from_parts(13_705_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `CircuitBreaker::HaltedAssets` (r:1 w:1)
+	/// Proof: `CircuitBreaker::HaltedAssets` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::TradingResumes` (r:0 w:1)
+	/// Proof: `CircuitBreaker::TradingResumes` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn resume_trading() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3491`
+		// Minimum execution time: 16_031_000 picoseconds.
+		Weight::from_parts(16_372_000, 3491)
+			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
-}
\ No newline at end of file
+}
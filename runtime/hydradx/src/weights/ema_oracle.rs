@@ -86,20 +86,24 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
 	/// Storage: `EmaOracle::Oracles` (r:117 w:117)
 	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::TwapAccumulators` (r:39 w:39)
+	/// Proof: `EmaOracle::TwapAccumulators` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::TwapCheckpoints` (r:0 w:39)
+	/// Proof: `EmaOracle::TwapCheckpoints` (`max_values`: None, `max_size`: Some(144), added: 2619, mode: `MaxEncodedLen`)
 	/// The range of component `b` is `[1, 39]`.
 	fn on_finalize_multiple_tokens(b: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `304 + b * (626 ±0)`
-		//  Estimated: `7406 + b * (7956 ±0)`
-		// Minimum execution time: 48_505_000 picoseconds.
-		Weight::from_parts(12_673_348, 7406)
-			// Standard Error: 31_644
-			.saturating_add(Weight::from_parts(36_427_193, 0).saturating_mul(b.into()))
+		//  Measured:  `304 + b * (734 ±0)`
+		//  Estimated: `7406 + b * (10571 ±0)`
+		// Minimum execution time: 57_211_000 picoseconds.
+		Weight::from_parts(12_861_104, 7406)
+			// Standard Error: 33_017
+			.saturating_add(Weight::from_parts(44_912_417, 0).saturating_mul(b.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(b.into())))
 			.saturating_add(T::DbWeight::get().writes(1))
-			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(b.into())))
-			.saturating_add(Weight::from_parts(0, 7956).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(b.into())))
+			.saturating_add(Weight::from_parts(0, 10571).saturating_mul(b.into()))
 	}
 	/// Storage: `AssetRegistry::Assets` (r:2 w:0)
 	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
@@ -143,4 +147,16 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 		Weight::from_parts(19_258_000, 6294)
 			.saturating_add(T::DbWeight::get().reads(2))
 	}
+	/// Storage: `EmaOracle::TwapAccumulators` (r:1 w:0)
+	/// Proof: `EmaOracle::TwapAccumulators` (`max_values`: None, `max_size`: Some(140), added: 2615, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::TwapCheckpoints` (r:11 w:0)
+	/// Proof: `EmaOracle::TwapCheckpoints` (`max_values`: None, `max_size`: Some(144), added: 2619, mode: `MaxEncodedLen`)
+	fn get_twap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1924`
+		//  Estimated: `32414`
+		// Minimum execution time: 61_402_000 picoseconds.
+		Weight::from_parts(62_118_000, 32414)
+			.saturating_add(T::DbWeight::get().reads(12))
+	}
 }
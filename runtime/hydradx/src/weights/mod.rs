@@ -1,8 +1,10 @@
+pub mod airdrop;
 pub mod balances;
 pub mod bonds;
 pub mod circuit_breaker;
 pub mod claims;
 pub mod collator_selection;
+pub mod concentrated_liquidity;
 pub mod council;
 pub mod currencies;
 pub mod dca;
@@ -37,6 +39,9 @@ pub mod treasury;
 pub mod utility;
 pub mod vesting;
 pub mod xcm;
+pub mod xcm_fungible;
+pub mod xcm_generic;
 pub mod xcmp_queue;
 pub mod xyk;
 pub mod xyk_lm;
+pub mod zap;
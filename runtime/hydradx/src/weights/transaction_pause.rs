@@ -70,4 +70,35 @@ impl<T: frame_system::Config> pallet_transaction_pause::WeightInfo for HydraWeig
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-}
\ No newline at end of file
+	/// Storage: `TransactionPause::PausedTransactions` (r:0 w:1)
+	/// Proof: `TransactionPause::PausedTransactions` (`max_values`: None, `max_size`: Some(94), added: 2569, mode: `MaxEncodedLen`)
+	fn pause_transaction_until() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 14_812_000 picoseconds.
+		Weight::from_parts(15_104_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `TransactionPause::PausedAssetTransactions` (r:0 w:1)
+	/// Proof: `TransactionPause::PausedAssetTransactions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn pause_asset_transaction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 15_021_000 picoseconds.
+		Weight::from_parts(15_395_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `TransactionPause::PausedAssetTransactions` (r:1 w:1)
+	/// Proof: `TransactionPause::PausedAssetTransactions` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
+	fn unpause_asset_transaction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `164`
+		//  Estimated: `3563`
+		// Minimum execution time: 17_622_000 picoseconds.
+		Weight::from_parts(18_013_000, 3563)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -3,7 +3,7 @@ use sp_std::marker::PhantomData;
 
 use codec::MaxEncodedLen;
 use hydradx_adapters::{
-	MultiCurrencyTrader, RelayChainBlockNumberProvider, ReroutingMultiCurrencyAdapter, ToFeeReceiver,
+	MultiCurrencyTrader, RelayChainBlockNumberProvider, ReroutingMultiCurrencyAdapter, SwapFeeAsset, ToFeeReceiver,
 };
 use pallet_transaction_multi_payment::DepositAll;
 use primitives::AssetId; // shadow glob import of polkadot_xcm::v3::prelude::AssetId
@@ -16,21 +16,24 @@ use frame_support::{
 	PalletId,
 };
 use frame_system::EnsureRoot;
-use hydradx_adapters::xcm_exchange::XcmAssetExchanger;
+use hydradx_adapters::xcm_exchange::{OmnipoolFeeSwap, XcmAssetExchanger};
 use hydradx_adapters::xcm_execute_filter::AllowTransferAndSwap;
+use hydradx_traits::router::{AssetPair, PoolType, RouteProvider, Trade};
 use orml_traits::{location::AbsoluteReserveProvider, parameter_type_with_key};
 use orml_xcm_support::{DepositToAlternative, IsNativeConcrete, MultiNativeAsset};
 use pallet_evm::AddressMapping;
+use pallet_route_executor::{AmmTradeWeights, MAX_NUMBER_OF_TRADES};
 use pallet_xcm::XcmPassthrough;
 use polkadot_parachain::primitives::{RelayChainBlockNumber, Sibling};
-use polkadot_xcm::v3::{prelude::*, Weight as XcmWeight};
+use polkadot_xcm::v3::{prelude::*, Weight as XcmWeight, XcmWeightInfo};
+use polkadot_xcm::DoubleEncoded;
 use primitives::Price;
 use scale_info::TypeInfo;
 use xcm_builder::{
 	AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom, AllowTopLevelPaidExecutionFrom,
-	DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, FixedWeightBounds, HashedDescription, ParentIsPreset,
-	RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative,
-	SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit, WithComputedOrigin,
+	DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, HashedDescription, ParentIsPreset, RelayChainAsNative,
+	SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32,
+	SovereignSignedViaLocation, TakeWeightCredit, WeightInfoBounds, WithComputedOrigin,
 };
 use xcm_executor::{Config, XcmExecutor};
 
@@ -92,6 +95,7 @@ parameter_types! {
 	/// The amount of weight an XCM operation takes. This is a safe overestimate.
 	pub const BaseXcmWeight: XcmWeight = XcmWeight::from_parts(100_000_000, 0);
 	pub const MaxInstructions: u32 = 100;
+	pub const MaxAssetsIntoHolding: u32 = 64;
 	pub const MaxAssetsForTransfer: usize = 2;
 
 	pub TempAccountForXcmAssetExchange: AccountId = [42; 32].into();
@@ -101,6 +105,308 @@ parameter_types! {
 	pub UniversalLocation: InteriorMultiLocation = X2(GlobalConsensus(RelayNetwork::get()), Parachain(ParachainInfo::parachain_id().into()));
 }
 
+parameter_types! {
+	/// Assets which are swapped first to pay XCM execution fees, in order of preference.
+	/// Can be changed by root with `system.set_storage`.
+	pub storage XcmFeeSwapPreferredAssets: Vec<AssetId> = Vec::new();
+}
+
+parameter_types! {
+	/// Maximum difference of the XCM fee swap amounts from the oracle price.
+	pub const XcmFeeSwapMaxSlippage: Permill = Permill::from_percent(3);
+}
+
+/// Swaps offered assets which are not accepted as fee payment to the native asset in the Omnipool.
+pub type XcmFeeSwap = OmnipoolFeeSwap<
+	Runtime,
+	TempAccountForXcmAssetExchange,
+	Currencies,
+	NativeAssetId,
+	XcmFeeSwapPreferredAssets,
+	hydradx_adapters::OraclePriceProvider<AssetId, EmaOracle, LRNA, Stableswap>,
+	XcmFeeSwapMaxSlippage,
+>;
+
+/// Weighs XCM messages by the benchmarked weights of the individual instructions.
+pub type XcmWeigher = WeightInfoBounds<HydraXcmWeight<RuntimeCall>, RuntimeCall, MaxInstructions>;
+
+type XcmFungibleWeight = weights::xcm_fungible::HydraWeight<Runtime>;
+type XcmGenericWeight = weights::xcm_generic::HydraWeight<Runtime>;
+
+trait WeighMultiAssets {
+	fn weigh_multi_assets(&self, weight: Weight) -> Weight;
+}
+
+impl WeighMultiAssets for MultiAssetFilter {
+	fn weigh_multi_assets(&self, weight: Weight) -> Weight {
+		let max_assets = MaxAssetsIntoHolding::get() as u64;
+		match self {
+			Definite(assets) => weight.saturating_mul(assets.len() as u64),
+			Wild(AllOf {
+				fun: WildFungibility::Fungible,
+				..
+			}) => weight,
+			Wild(All | AllOf { .. }) => weight.saturating_mul(max_assets),
+			Wild(AllCounted(count) | AllOfCounted { count, .. }) => {
+				weight.saturating_mul(max_assets.min(*count as u64))
+			}
+		}
+	}
+}
+
+impl WeighMultiAssets for MultiAssets {
+	fn weigh_multi_assets(&self, weight: Weight) -> Weight {
+		weight.saturating_mul(self.len() as u64)
+	}
+}
+
+/// Weight of the `ExchangeAsset` instruction as executed by `XcmAssetExchanger`.
+///
+/// The exchanger mints the given asset, trades it via the router using the on-chain route of the asset pair and
+/// burns the result. The trade is weighed by the on-chain route of the asset pair. If the given asset is not
+/// specified, the most expensive route is assumed.
+fn exchange_asset_weight(give: &MultiAssetFilter, want: &MultiAssets, maximal: bool) -> Weight {
+	// mint of the given asset, burn of the received asset and of the left over given asset
+	let holding_weight =
+		XcmFungibleWeight::deposit_asset().saturating_add(XcmFungibleWeight::withdraw_asset().saturating_mul(2));
+
+	// the exchanger supports exactly one asset on each side
+	let give_id = match give {
+		Definite(assets) if assets.len() == 1 => assets.get(0).map(|asset| Some(asset.id)),
+		Definite(_) => None,
+		Wild(AllOf { id, .. } | AllOfCounted { id, .. }) => Some(Some(*id)),
+		Wild(All | AllCounted(_)) => Some(None),
+	};
+	let (Some(give_id), [wanted]) = (give_id, want.inner().as_slice()) else {
+		return holding_weight;
+	};
+
+	let trade_weight = |route: &[Trade<AssetId>]| {
+		if maximal {
+			RouterWeightInfo::sell_weight(route)
+		} else {
+			RouterWeightInfo::buy_weight(route)
+		}
+	};
+
+	let Some(give_id) = give_id else {
+		let worst_case_weight = [
+			PoolType::Omnipool,
+			PoolType::LBP,
+			PoolType::Stableswap(0),
+			PoolType::StableswapMetapool(0, 0),
+			PoolType::XYK,
+			PoolType::Concentrated(0),
+		]
+		.into_iter()
+		.map(|pool| {
+			let route = sp_std::vec![
+				Trade {
+					pool,
+					asset_in: 0,
+					asset_out: 0,
+				};
+				MAX_NUMBER_OF_TRADES as usize
+			];
+			trade_weight(&route)
+		})
+		.fold(Weight::zero(), |max, weight| max.max(weight));
+		return holding_weight.saturating_add(worst_case_weight);
+	};
+
+	// locations of both assets and the on-chain route are read
+	let lookup_weight = <Runtime as frame_system::Config>::DbWeight::get().reads(3);
+
+	let (Concrete(give_location), Concrete(want_location)) = (give_id, wanted.id) else {
+		return holding_weight.saturating_add(lookup_weight);
+	};
+	let (Some(asset_in), Some(asset_out)) = (
+		CurrencyIdConvert::convert(give_location),
+		CurrencyIdConvert::convert(want_location),
+	) else {
+		return holding_weight.saturating_add(lookup_weight);
+	};
+
+	let route = Router::get_route(AssetPair::new(asset_in, asset_out));
+
+	holding_weight
+		.saturating_add(lookup_weight)
+		.saturating_add(trade_weight(&route))
+}
+
+/// Benchmarked weights of XCM instructions. Instructions not supported by the executor configuration are weighed
+/// with `Weight::MAX`.
+pub struct HydraXcmWeight<RuntimeCall>(PhantomData<RuntimeCall>);
+
+impl<RuntimeCall> XcmWeightInfo<RuntimeCall> for HydraXcmWeight<RuntimeCall> {
+	fn withdraw_asset(assets: &MultiAssets) -> Weight {
+		assets.weigh_multi_assets(XcmFungibleWeight::withdraw_asset())
+	}
+	fn reserve_asset_deposited(assets: &MultiAssets) -> Weight {
+		assets.weigh_multi_assets(XcmFungibleWeight::reserve_asset_deposited())
+	}
+	fn receive_teleported_asset(_assets: &MultiAssets) -> Weight {
+		Weight::MAX
+	}
+	fn query_response(
+		_query_id: &u64,
+		_response: &Response,
+		_max_weight: &Weight,
+		_querier: &Option<MultiLocation>,
+	) -> Weight {
+		XcmGenericWeight::query_response()
+	}
+	fn transfer_asset(assets: &MultiAssets, _dest: &MultiLocation) -> Weight {
+		assets.weigh_multi_assets(XcmFungibleWeight::transfer_asset())
+	}
+	fn transfer_reserve_asset(assets: &MultiAssets, _dest: &MultiLocation, _xcm: &Xcm<()>) -> Weight {
+		assets.weigh_multi_assets(XcmFungibleWeight::transfer_reserve_asset())
+	}
+	fn transact(
+		_origin_type: &OriginKind,
+		_require_weight_at_most: &Weight,
+		_call: &DoubleEncoded<RuntimeCall>,
+	) -> Weight {
+		XcmGenericWeight::transact()
+	}
+	fn hrmp_new_channel_open_request(_sender: &u32, _max_message_size: &u32, _max_capacity: &u32) -> Weight {
+		// Only the relay chain can send this instruction.
+		Weight::MAX
+	}
+	fn hrmp_channel_accepted(_recipient: &u32) -> Weight {
+		Weight::MAX
+	}
+	fn hrmp_channel_closing(_initiator: &u32, _sender: &u32, _recipient: &u32) -> Weight {
+		Weight::MAX
+	}
+	fn clear_origin() -> Weight {
+		XcmGenericWeight::clear_origin()
+	}
+	fn descend_origin(_who: &InteriorMultiLocation) -> Weight {
+		XcmGenericWeight::descend_origin()
+	}
+	fn report_error(_query_response_info: &QueryResponseInfo) -> Weight {
+		XcmGenericWeight::report_error()
+	}
+	fn deposit_asset(assets: &MultiAssetFilter, _dest: &MultiLocation) -> Weight {
+		assets.weigh_multi_assets(XcmFungibleWeight::deposit_asset())
+	}
+	fn deposit_reserve_asset(assets: &MultiAssetFilter, _dest: &MultiLocation, _xcm: &Xcm<()>) -> Weight {
+		assets.weigh_multi_assets(XcmFungibleWeight::deposit_reserve_asset())
+	}
+	fn exchange_asset(give: &MultiAssetFilter, want: &MultiAssets, maximal: &bool) -> Weight {
+		exchange_asset_weight(give, want, *maximal)
+	}
+	fn initiate_reserve_withdraw(assets: &MultiAssetFilter, _reserve: &MultiLocation, _xcm: &Xcm<()>) -> Weight {
+		assets.weigh_multi_assets(XcmFungibleWeight::initiate_reserve_withdraw())
+	}
+	fn initiate_teleport(_assets: &MultiAssetFilter, _dest: &MultiLocation, _xcm: &Xcm<()>) -> Weight {
+		Weight::MAX
+	}
+	fn report_holding(_response_info: &QueryResponseInfo, _assets: &MultiAssetFilter) -> Weight {
+		XcmGenericWeight::report_holding()
+	}
+	fn buy_execution(_fees: &MultiAsset, _weight_limit: &WeightLimit) -> Weight {
+		// the trader swaps the fees to the native asset if they are not accepted as fee payment
+		XcmGenericWeight::buy_execution().saturating_add(XcmFeeSwap::swap_to_fee_asset_weight())
+	}
+	fn refund_surplus() -> Weight {
+		// refunds of swapped fees are swapped back
+		XcmGenericWeight::refund_surplus().saturating_add(XcmFeeSwap::swap_from_fee_asset_weight())
+	}
+	fn set_error_handler(_xcm: &Xcm<RuntimeCall>) -> Weight {
+		XcmGenericWeight::set_error_handler()
+	}
+	fn set_appendix(_xcm: &Xcm<RuntimeCall>) -> Weight {
+		XcmGenericWeight::set_appendix()
+	}
+	fn clear_error() -> Weight {
+		XcmGenericWeight::clear_error()
+	}
+	fn claim_asset(_assets: &MultiAssets, _ticket: &MultiLocation) -> Weight {
+		XcmGenericWeight::claim_asset()
+	}
+	fn trap(_code: &u64) -> Weight {
+		XcmGenericWeight::trap()
+	}
+	fn subscribe_version(_query_id: &QueryId, _max_response_weight: &Weight) -> Weight {
+		XcmGenericWeight::subscribe_version()
+	}
+	fn unsubscribe_version() -> Weight {
+		XcmGenericWeight::unsubscribe_version()
+	}
+	fn burn_asset(assets: &MultiAssets) -> Weight {
+		assets.weigh_multi_assets(XcmGenericWeight::burn_asset())
+	}
+	fn expect_asset(assets: &MultiAssets) -> Weight {
+		assets.weigh_multi_assets(XcmGenericWeight::expect_asset())
+	}
+	fn expect_origin(_origin: &Option<MultiLocation>) -> Weight {
+		XcmGenericWeight::expect_origin()
+	}
+	fn expect_error(_error: &Option<(u32, XcmError)>) -> Weight {
+		XcmGenericWeight::expect_error()
+	}
+	fn expect_transact_status(_transact_status: &MaybeErrorCode) -> Weight {
+		XcmGenericWeight::expect_transact_status()
+	}
+	fn query_pallet(_module_name: &Vec<u8>, _response_info: &QueryResponseInfo) -> Weight {
+		XcmGenericWeight::query_pallet()
+	}
+	fn expect_pallet(
+		_index: &u32,
+		_name: &Vec<u8>,
+		_module_name: &Vec<u8>,
+		_crate_major: &u32,
+		_min_crate_minor: &u32,
+	) -> Weight {
+		XcmGenericWeight::expect_pallet()
+	}
+	fn report_transact_status(_response_info: &QueryResponseInfo) -> Weight {
+		XcmGenericWeight::report_transact_status()
+	}
+	fn clear_transact_status() -> Weight {
+		XcmGenericWeight::clear_transact_status()
+	}
+	fn universal_origin(_: &Junction) -> Weight {
+		// `UniversalAliases` is `Nothing`.
+		Weight::MAX
+	}
+	fn export_message(_: &NetworkId, _: &Junctions, _: &Xcm<()>) -> Weight {
+		// `MessageExporter` is not configured.
+		Weight::MAX
+	}
+	fn lock_asset(_: &MultiAsset, _: &MultiLocation) -> Weight {
+		// `AssetLocker` is not configured.
+		Weight::MAX
+	}
+	fn unlock_asset(_: &MultiAsset, _: &MultiLocation) -> Weight {
+		Weight::MAX
+	}
+	fn note_unlockable(_: &MultiAsset, _: &MultiLocation) -> Weight {
+		Weight::MAX
+	}
+	fn request_unlock(_: &MultiAsset, _: &MultiLocation) -> Weight {
+		Weight::MAX
+	}
+	fn set_fees_mode(_: &bool) -> Weight {
+		XcmGenericWeight::set_fees_mode()
+	}
+	fn set_topic(_topic: &[u8; 32]) -> Weight {
+		XcmGenericWeight::set_topic()
+	}
+	fn clear_topic() -> Weight {
+		XcmGenericWeight::clear_topic()
+	}
+	fn alias_origin(_: &MultiLocation) -> Weight {
+		// `Aliasers` is `Nothing`.
+		Weight::MAX
+	}
+	fn unpaid_execution(_: &WeightLimit, _: &Option<MultiLocation>) -> Weight {
+		XcmGenericWeight::unpaid_execution()
+	}
+}
+
 pub struct XcmConfig;
 impl Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
@@ -114,7 +420,7 @@ impl Config for XcmConfig {
 	type UniversalLocation = UniversalLocation;
 
 	type Barrier = Barrier;
-	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	// We calculate weight fees the same way as for regular extrinsics and use the prices and choice
 	// of accepted currencies of the transaction payment pallet. Fees go to the same fee receiver as
 	// configured in `MultiTransactionPayment`.
@@ -126,6 +432,7 @@ impl Config for XcmConfig {
 		MultiTransactionPayment,
 		CurrencyIdConvert,
 		ToFeeReceiver<AccountId, AssetId, Balance, Price, CurrencyIdConvert, DepositAll<Runtime>, TreasuryAccount>,
+		XcmFeeSwap,
 	>;
 
 	type ResponseHandler = PolkadotXcm;
@@ -135,7 +442,7 @@ impl Config for XcmConfig {
 	type AssetClaims = PolkadotXcm;
 	type SubscriptionService = PolkadotXcm;
 	type PalletInstancesInfo = AllPalletsWithSystem;
-	type MaxAssetsIntoHolding = ConstU32<64>;
+	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
 	type FeeManager = ();
 	type MessageExporter = ();
 	type UniversalAliases = Nothing;
@@ -199,7 +506,7 @@ impl orml_xtokens::Config for Runtime {
 	type AccountIdToMultiLocation = AccountIdToMultiLocation;
 	type SelfLocation = SelfLocation;
 	type XcmExecutor = XcmExecutor<XcmConfig>;
-	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type BaseXcmWeight = BaseXcmWeight;
 	type MaxAssetsForTransfer = MaxAssetsForTransfer;
 	type MultiLocationsFilter = Everything;
@@ -233,7 +540,7 @@ impl pallet_xcm::Config for Runtime {
 	type XcmExecutor = XcmExecutor<XcmConfig>;
 	type XcmTeleportFilter = Nothing;
 	type XcmReserveTransferFilter = Everything;
-	type Weigher = FixedWeightBounds<BaseXcmWeight, RuntimeCall, MaxInstructions>;
+	type Weigher = XcmWeigher;
 	type UniversalLocation = UniversalLocation;
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
@@ -488,16 +795,61 @@ impl Contains<RuntimeCall> for SafeCallFilter {
 					| pallet_identity::Call::rename_sub { .. }
 					| pallet_identity::Call::remove_sub { .. }
 					| pallet_identity::Call::quit_sub { .. },
-			) | RuntimeCall::Omnipool(..)
-				| RuntimeCall::OmnipoolLiquidityMining(..)
-				| RuntimeCall::OTC(..)
+			) | RuntimeCall::OmnipoolLiquidityMining(..)
 				| RuntimeCall::CircuitBreaker(..)
-				| RuntimeCall::Router(..)
-				| RuntimeCall::DCA(..)
 				| RuntimeCall::MultiTransactionPayment(..)
 				| RuntimeCall::Currencies(..)
 				| RuntimeCall::Tokens(..)
 				| RuntimeCall::OrmlXcm(..)
-		)
+		) || RemoteDexCalls::contains(call)
+	}
+}
+
+/// Curated set of DEX calls which can be dispatched with XCM `Transact`.
+///
+/// Contains every call of Omnipool, Router, DCA and OTC which can be dispatched by a signed origin. Calls which
+/// require a privileged origin are not allowed.
+///
+/// Users of other chains trade on HydraDX from their home chain by sending `DescendOrigin` followed by `Transact`
+/// with `OriginKind::SovereignAccount`. The call is then dispatched as a signed origin of the account derived from
+/// the remote location by `LocationToAccountId`.
+pub struct RemoteDexCalls;
+impl Contains<RuntimeCall> for RemoteDexCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::Omnipool(
+				pallet_omnipool::Call::sell { .. }
+				| pallet_omnipool::Call::buy { .. }
+				| pallet_omnipool::Call::add_liquidity { .. }
+				| pallet_omnipool::Call::add_liquidity_with_limit { .. }
+				| pallet_omnipool::Call::remove_liquidity { .. }
+				| pallet_omnipool::Call::remove_liquidity_with_limit { .. }
+				| pallet_omnipool::Call::sacrifice_position { .. }
+				| pallet_omnipool::Call::merge_positions { .. }
+				| pallet_omnipool::Call::split_position { .. }
+				| pallet_omnipool::Call::propose_listing { .. },
+			) => true,
+			// the route is an unbounded vec, so only routes which can be executed are allowed
+			RuntimeCall::Router(
+				pallet_route_executor::Call::sell { route, .. }
+				| pallet_route_executor::Call::buy { route, .. }
+				| pallet_route_executor::Call::set_route { new_route: route, .. },
+			) => route.len() <= MAX_NUMBER_OF_TRADES as usize,
+			RuntimeCall::DCA(
+				pallet_dca::Call::schedule { .. }
+				| pallet_dca::Call::terminate { .. }
+				| pallet_dca::Call::pause { .. }
+				| pallet_dca::Call::resume { .. }
+				| pallet_dca::Call::top_up { .. }
+				| pallet_dca::Call::update_schedule { .. },
+			) => true,
+			RuntimeCall::OTC(
+				pallet_otc::Call::place_order { .. }
+				| pallet_otc::Call::partial_fill_order { .. }
+				| pallet_otc::Call::fill_order { .. }
+				| pallet_otc::Call::cancel_order { .. },
+			) => true,
+			_ => false,
+		}
 	}
 }
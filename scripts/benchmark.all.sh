@@ -6,6 +6,7 @@ pallets=(
 "pallet-circuit-breaker:circuit_breaker"
 "pallet-claims:claims"
 "pallet-collator-selection:collator_selection"
+"pallet-concentrated-liquidity:concentrated_liquidity"
 "council:council"
 "pallet-currencies:currencies"
 "pallet-dca:dca"
@@ -37,6 +38,7 @@ pallets=(
 "cumulus-pallet-xcmp-queue:xcmp_queue"
 "pallet-xyk:xyk"
 "pallet-referrals:referrals"
+"pallet-zap:zap"
 )
 
 command="cargo run --bin hydradx --release --features=runtime-benchmarks -- benchmark pallet --pallet=[pallet] --wasm-execution=compiled --heap-pages=4096 --chain=dev --extrinsic='*' --steps=5 --repeat=20 --output [output].rs --template .maintain/pallet-weight-template-no-back.hbs"
@@ -55,3 +57,23 @@ for string in "${pallets[@]}"; do
 
   eval "$replaced_command"
 done
+
+xcm_pallets=(
+"pallet_xcm_benchmarks::fungible:xcm_fungible"
+"pallet_xcm_benchmarks::generic:xcm_generic"
+)
+
+xcm_command="cargo run --bin hydradx --release --features=runtime-benchmarks -- benchmark pallet --pallet=[pallet] --wasm-execution=compiled --heap-pages=4096 --chain=dev --extrinsic='*' --steps=5 --repeat=20 --output [output].rs --template .maintain/xcm-weight-template.hbs"
+
+for string in "${xcm_pallets[@]}"; do
+
+  pallet="${string%:*}"
+  output="${string##*:}"
+
+  echo "Running benchmark for ${pallet}"
+
+  replaced_command="${xcm_command/\[pallet\]/$pallet}"
+  replaced_command="${replaced_command/\[output\]/$output}"
+
+  eval "$replaced_command"
+done
@@ -301,6 +301,35 @@ impl<AssetId, BlockNumber, Price> AggregatedPriceOracle<AssetId, BlockNumber, Pr
 	}
 }
 
+/// An oracle returning the time weighted average price over the last `window` blocks.
+///
+/// Unlike `AggregatedPriceOracle` the window is not limited to the predefined oracle periods but
+/// can be any number of blocks up to a maximum supported by the implementation.
+pub trait AggregatedTwapOracle<AssetId, BlockNumber, Price> {
+	type Error;
+	fn get_twap(asset_a: AssetId, asset_b: AssetId, window: BlockNumber, source: Source) -> Result<Price, Self::Error>;
+
+	fn get_twap_weight() -> Weight;
+}
+
+/// Default implementation of the oracle trait that always returns `Err`.
+impl<AssetId, BlockNumber, Price> AggregatedTwapOracle<AssetId, BlockNumber, Price> for () {
+	type Error = ();
+
+	fn get_twap(
+		_asset_a: AssetId,
+		_asset_b: AssetId,
+		_window: BlockNumber,
+		_source: Source,
+	) -> Result<Price, Self::Error> {
+		Err(())
+	}
+
+	fn get_twap_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 /// Mock implementation of the oracle trait that always returns `Price::one()` and oracle age of
 /// `BlockNumber::one()`.
 impl<AssetId, BlockNumber, Price> AggregatedPriceOracle<AssetId, BlockNumber, Price> for AlwaysPriceOfOne
@@ -61,6 +61,11 @@ pub enum PoolType<AssetId> {
 	LBP,
 	Stableswap(AssetId),
 	Omnipool,
+	/// Concentrated liquidity pool identified by pool id.
+	Concentrated(u32),
+	/// Trade between an asset of a stableswap metapool and an asset of its base pool.
+	/// Identified by the metapool id and the base pool id.
+	StableswapMetapool(AssetId, AssetId),
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -274,6 +279,19 @@ impl<E: PartialEq, Origin: Clone, AccountId, AssetId: Copy, Balance: Copy>
 	}
 }
 
+/// Provides trades which are executed when a single trade in a route goes through multiple pools,
+/// eg. trade between an asset of a stableswap metapool and an asset of its base pool.
+pub trait UnderlyingTrades<AssetId> {
+	/// Returns underlying trades of given trade, or `None` if the trade is executed in a single pool.
+	fn underlying_trades(trade: &Trade<AssetId>) -> Option<Vec<Trade<AssetId>>>;
+}
+
+impl<AssetId> UnderlyingTrades<AssetId> for () {
+	fn underlying_trades(_trade: &Trade<AssetId>) -> Option<Vec<Trade<AssetId>>> {
+		None
+	}
+}
+
 /// Provides weight info for the router. Calculates the weight of a route based on the AMMs.
 pub trait AmmTradeWeights<Trade> {
 	fn sell_weight(route: &[Trade]) -> Weight;